@@ -221,7 +221,7 @@ fn traverse_call(
     err: &mut Vec<usize>,
     cc: &mut Consts,
 ) -> Result<TokenStream, Error> {
-    let errmes = "unexpected function name. Only \"recip\", \"sqrt\", \"cbrt\", \"ln\", \"log2\", \"log10\", \"log\", \"exp\", \"pow\", \"sin\", \"cos\", \"tan\", \"asin\", \"acos\", \"atan\", \"sinh\", \"cosh\", \"tanh\", \"asinh\", \"acosh\", \"atanh\" are allowed.";
+    let errmes = "unexpected function name. Only \"recip\", \"sqrt\", \"cbrt\", \"ln\", \"log2\", \"log10\", \"log\", \"exp\", \"pow\", \"sin\", \"cos\", \"tan\", \"asin\", \"acos\", \"atan\", \"sinh\", \"cosh\", \"tanh\", \"asinh\", \"acosh\", \"atanh\", \"sec\", \"csc\", \"cot\", \"asec\", \"acsc\", \"acot\", \"sech\", \"csch\", \"coth\", \"asech\", \"acsch\", \"acoth\" are allowed.";
 
     if let Expr::Path(fun) = expr.func.as_ref() {
         if let Some(fname) = fun.path.get_ident() {
@@ -361,6 +361,83 @@ fn traverse_call(
                     quote!(astro_float::macro_util::ErrAlgo::Atanh(&arg, emin)),
                     cc,
                 ),
+                "sec" => trig_fun(
+                    quote!(astro_float::BigFloat::sec),
+                    expr,
+                    SPEC_ADD_ERR,
+                    err,
+                    quote!(astro_float::macro_util::TrigFun::Cos),
+                    cc,
+                ),
+                "csc" => trig_fun(
+                    quote!(astro_float::BigFloat::csc),
+                    expr,
+                    SPEC_ADD_ERR,
+                    err,
+                    quote!(astro_float::macro_util::TrigFun::Sin),
+                    cc,
+                ),
+                "cot" => trig_fun(
+                    quote!(astro_float::BigFloat::cot),
+                    expr,
+                    SPEC_ADD_ERR,
+                    err,
+                    quote!(astro_float::macro_util::TrigFun::Tan),
+                    cc,
+                ),
+                "asec" => one_arg_fun_errcheck(
+                    quote!(astro_float::BigFloat::asec),
+                    expr,
+                    SPEC_ADD_ERR / 2,
+                    err,
+                    quote!(astro_float::macro_util::ErrAlgo::Asec(&arg, emin)),
+                    cc,
+                ),
+                "acsc" => one_arg_fun_errcheck(
+                    quote!(astro_float::BigFloat::acsc),
+                    expr,
+                    SPEC_ADD_ERR / 2,
+                    err,
+                    quote!(astro_float::macro_util::ErrAlgo::Acsc(&arg, emin)),
+                    cc,
+                ),
+                "acot" => one_arg_fun(quote!(astro_float::BigFloat::acot), expr, 2, err, cc, true),
+                "sech" => one_arg_fun(
+                    quote!(astro_float::BigFloat::sech),
+                    expr,
+                    EXPONENT_BIT_SIZE + 1,
+                    err,
+                    cc,
+                    true,
+                ),
+                "csch" => one_arg_fun(
+                    quote!(astro_float::BigFloat::csch),
+                    expr,
+                    EXPONENT_BIT_SIZE + 1,
+                    err,
+                    cc,
+                    true,
+                ),
+                "coth" => one_arg_fun(quote!(astro_float::BigFloat::coth), expr, 2, err, cc, true),
+                "asech" => one_arg_fun_errcheck(
+                    quote!(astro_float::BigFloat::asech),
+                    expr,
+                    SPEC_ADD_ERR,
+                    err,
+                    quote!(astro_float::macro_util::ErrAlgo::Asech(&arg, emin)),
+                    cc,
+                ),
+                "acsch" => {
+                    one_arg_fun(quote!(astro_float::BigFloat::acsch), expr, 2, err, cc, true)
+                }
+                "acoth" => one_arg_fun_errcheck(
+                    quote!(astro_float::BigFloat::acoth),
+                    expr,
+                    SPEC_ADD_ERR,
+                    err,
+                    quote!(astro_float::macro_util::ErrAlgo::Acoth(&arg, emin)),
+                    cc,
+                ),
                 _ => return Err(Error::new(expr.span(), errmes)),
             }?;
 
@@ -444,7 +521,7 @@ fn traverse_expr(expr: &Expr, err: &mut Vec<usize>, cc: &mut Consts) -> Result<T
         Expr::Paren(e) => traverse_paren(e, err, cc),
         Expr::Path(e) => traverse_path(e),
         Expr::Unary(e) => traverse_unary(e, err, cc),
-        _ => Err(Error::new(expr.span(), "unexpected expression. Only operators \"+\", \"-\", \"*\", \"/\", \"%\", functions \"recip\", \"sqrt\", \"cbrt\", \"ln\", \"log2\", \"log10\", \"log\", \"exp\", \"pow\", \"sin\", \"cos\", \"tan\", \"asin\", \"acos\", \"atan\", \"sinh\", \"cosh\", \"tanh\", \"asinh\", \"acosh\", \"atanh\", literals and variables, and grouping with parentheses are supported.")),
+        _ => Err(Error::new(expr.span(), "unexpected expression. Only operators \"+\", \"-\", \"*\", \"/\", \"%\", functions \"recip\", \"sqrt\", \"cbrt\", \"ln\", \"log2\", \"log10\", \"log\", \"exp\", \"pow\", \"sin\", \"cos\", \"tan\", \"asin\", \"acos\", \"atan\", \"sinh\", \"cosh\", \"tanh\", \"asinh\", \"acosh\", \"atanh\", \"sec\", \"csc\", \"cot\", \"asec\", \"acsc\", \"acot\", \"sech\", \"csch\", \"coth\", \"asech\", \"acsch\", \"acoth\", literals and variables, and grouping with parentheses are supported.")),
     }
 }
 
@@ -474,12 +551,13 @@ pub fn expr(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
         let rm = ctx.rounding_mode();
         let emin = ctx.emin();
         let emax = ctx.emax();
+        let tuning = ctx.tuning();
         let cc = ctx.consts();
 
         let mut p_rnd = p + astro_float::WORD_BIT_SIZE;
         let mut errs: [usize; #err_sz] = [#(#err, )*];
 
-        loop {
+        astro_float::macro_util::with_tuning(tuning, || loop {
             let p_wrk = p_rnd.saturating_add(errs.iter().sum());
 
             let mut ret: astro_float::BigFloat = (#expr).into();
@@ -489,7 +567,7 @@ pub fn expr(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
             }
 
             break astro_float::macro_util::check_exponent_range(ret, emin, emax);
-        }
+        })
     });
 
     ret.into()
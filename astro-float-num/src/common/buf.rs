@@ -33,6 +33,12 @@ impl WordBuf {
         Ok(WordBuf { inner })
     }
 
+    /// Creates a buffer from a vector of words.
+    #[inline]
+    pub fn from_vec(inner: Vec<Word>) -> Self {
+        WordBuf { inner }
+    }
+
     #[inline]
     pub fn fill(&mut self, d: Word) {
         self.inner.fill(d);
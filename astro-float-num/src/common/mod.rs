@@ -3,4 +3,5 @@
 pub mod buf;
 pub mod consts;
 pub mod int;
+pub mod par;
 pub mod util;
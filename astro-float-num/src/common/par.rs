@@ -0,0 +1,101 @@
+//! Parallel computation of independent parts.
+
+use crate::defs::Error;
+use crate::tuning::Tuning;
+
+/// Number of terms in a binary splitting starting from which its halves are computed in parallel.
+pub const PAR_SPLIT_THRESHOLD: usize = 1000;
+
+/// Returns true if a computation on operands of `n` words should be split over threads.
+#[inline]
+pub fn par_enabled(n: usize) -> bool {
+    cfg!(feature = "parallel") && n >= Tuning::current().par_mul
+}
+
+/// Computes `a` and `b` in parallel if the feature `parallel` is enabled and `par` is true,
+/// or sequentially otherwise. The tuning of the calling thread is used for both computations.
+#[inline]
+pub fn join<A, B, RA, RB>(par: bool, a: A, b: B) -> (RA, RB)
+where
+    A: FnOnce() -> RA + Send,
+    B: FnOnce() -> RB + Send,
+    RA: Send,
+    RB: Send,
+{
+    #[cfg(feature = "parallel")]
+    if par {
+        let t = Tuning::thread_current();
+        return rayon::join(|| t.scope(a), || t.scope(b));
+    }
+
+    let _ = par;
+    (a(), b())
+}
+
+/// Calls `f` for each of `items`, in parallel if the feature `parallel` is enabled and `par` is true.
+/// Returns the first error encountered, if any.
+pub fn try_for_each<T, F>(par: bool, items: &mut [T], f: &F) -> Result<(), Error>
+where
+    T: Send,
+    F: Fn(&mut T) -> Result<(), Error> + Sync,
+{
+    if !par || items.len() < 2 {
+        return items.iter_mut().try_for_each(f);
+    }
+
+    let (lo, hi) = items.split_at_mut(items.len() / 2);
+    let (r1, r2) = join(
+        par,
+        || try_for_each(par, lo, f),
+        || try_for_each(par, hi, f),
+    );
+
+    r1.and(r2)
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_par() {
+        for par in [false, true] {
+            let (a, b) = join(par, || 1, || 2);
+            assert_eq!((a, b), (1, 2));
+
+            let mut items = [1, 2, 3, 4, 5];
+            try_for_each(par, &mut items, &|v| {
+                *v *= 2;
+                Ok(())
+            })
+            .unwrap();
+            assert_eq!(items, [2, 4, 6, 8, 10]);
+
+            let ret = try_for_each(par, &mut items, &|v| {
+                if *v == 6 {
+                    Err(Error::InvalidArgument)
+                } else {
+                    Ok(())
+                }
+            });
+            assert_eq!(ret, Err(Error::InvalidArgument));
+        }
+
+        // tuning of the calling thread is used.
+        #[cfg(feature = "std")]
+        {
+            let mut t = Tuning::default();
+            t.mul_toom3 = 1234;
+
+            let (a, b) = t.scope(|| {
+                join(
+                    true,
+                    || Tuning::thread_current().mul_toom3,
+                    || Tuning::thread_current().mul_toom3,
+                )
+            });
+            assert_eq!((a, b), (1234, 1234));
+        }
+    }
+}
@@ -1,29 +1,171 @@
 //! Conversion utilities.
 
-use crate::common::consts::TEN;
+use crate::common::buf::WordBuf;
 use crate::common::util::log2_ceil;
 use crate::common::util::round_p;
+use crate::defs::check_radix;
 use crate::defs::DoubleWord;
 use crate::defs::Error;
 use crate::defs::Exponent;
-use crate::defs::Radix;
 use crate::defs::RoundingMode;
 use crate::defs::Sign;
 use crate::defs::Word;
 use crate::defs::DEFAULT_P;
 use crate::defs::WORD_BIT_SIZE;
 use crate::defs::WORD_MAX;
+use crate::int::BigUint;
+use crate::mantissa::rdx_word_id;
 use crate::mantissa::Mantissa;
 use crate::num::BigFloatNumber;
 use crate::Consts;
 use crate::EXPONENT_MAX;
 use crate::EXPONENT_MIN;
+use core::cmp::Ordering;
 
 #[cfg(not(feature = "std"))]
 use alloc::vec::Vec;
 
-const TEN_PWR_MAX_TO_DEC: usize = EXPONENT_MAX as usize / 4;
-const TEN_PWR_MAX_FROM_DEC: usize = (EXPONENT_MAX as u64 * 301029995 / 1000000000) as usize;
+// Upper bounds of log2(rdx) * 10^9 for radixes from 0 to 36.
+const LOG2_RDX: [u64; 37] = [
+    0, 0, 1000000000, 1584962501, 2000000000, 2321928095, 2584962501, 2807354923, 3000000000,
+    3169925002, 3321928095, 3459431619, 3584962501, 3700439719, 3807354923, 3906890596, 4000000000,
+    4087462842, 4169925002, 4247927514, 4321928095, 4392317423, 4459431619, 4523561957, 4584962501,
+    4643856190, 4700439719, 4754887503, 4807354923, 4857980996, 4906890596, 4954196311, 5000000000,
+    5044394120, 5087462842, 5129283017, 5169925002,
+];
+
+// Upper bounds of log_rdx(2) * 10^9 for radixes from 0 to 36.
+pub(crate) const LOGRDX_2: [u64; 37] = [
+    0, 0, 1000000000, 630929754, 500000000, 430676559, 386852808, 356207188, 333333334, 315464877,
+    301029996, 289064827, 278942946, 270238155, 262649536, 255958025, 250000000, 244650543,
+    239812467, 235408914, 231378214, 227670249, 224243825, 221064730, 218104292, 215338280,
+    212746054, 210309918, 208014598, 205846833, 203795048, 201849087, 200000000, 198239864,
+    196561633, 194959022, 193426404,
+];
+
+/// Digits in radix `rdx` packed into words, the most significant word first.
+/// For radixes of power of 2, each word holds as many digits as fit in a word.
+/// For other radixes, each word holds 2^i digits, where `rdx`^(2^i) is the largest power of `rdx` of this form which fits in a word.
+#[derive(Debug)]
+pub(crate) struct PackedDigits {
+    rdx: Word,
+    dpw: usize,
+    words: Vec<Word>,
+    len: usize,
+}
+
+impl PackedDigits {
+    /// Creates an empty sequence of digits in radix `rdx`, which must be in the range from 2 to 36.
+    pub fn new(rdx: Word) -> Self {
+        debug_assert!((2..=36).contains(&rdx));
+
+        let dpw = if rdx.is_power_of_two() {
+            WORD_BIT_SIZE / rdx.trailing_zeros() as usize
+        } else {
+            1 << rdx_word_id(rdx)
+        };
+
+        PackedDigits {
+            rdx,
+            dpw,
+            words: Vec::new(),
+            len: 0,
+        }
+    }
+
+    /// Packs `digits` in radix `rdx`.
+    ///
+    /// ## Errors
+    ///
+    ///  - MemoryAllocation: failed to allocate memory.
+    ///  - InvalidArgument: `digits` contains unacceptable digits for given radix.
+    pub fn from_digits(digits: &[u8], rdx: Word) -> Result<Self, Error> {
+        let mut ret = Self::new(rdx);
+        ret.reserve(digits.len())?;
+
+        for &d in digits {
+            if d as Word >= rdx {
+                return Err(Error::InvalidArgument);
+            }
+
+            ret.push(d)?;
+        }
+
+        Ok(ret)
+    }
+
+    /// Reserves space for at least `n` additional digits.
+    pub fn reserve(&mut self, n: usize) -> Result<(), Error> {
+        self.words.try_reserve_exact(n.div_ceil(self.dpw))?;
+        Ok(())
+    }
+
+    /// Appends the digit `d` which must be smaller than the radix.
+    pub fn push(&mut self, d: u8) -> Result<(), Error> {
+        debug_assert!((d as Word) < self.rdx);
+
+        if self.len.is_multiple_of(self.dpw) {
+            self.words.try_reserve(1)?;
+            self.words.push(0);
+        }
+
+        let w = self.words.last_mut().unwrap(); // words is not empty as ensured above
+        *w = *w * self.rdx + d as Word;
+        self.len += 1;
+
+        Ok(())
+    }
+
+    /// Removes all digits.
+    pub fn clear(&mut self) {
+        self.words.clear();
+        self.len = 0;
+    }
+
+    /// Returns the number of digits.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Pads the last word with zeroes and returns the words, the least significant word first,
+    /// and the number of digits including padding.
+    fn into_words(self) -> (WordBuf, usize) {
+        let mut words = self.words;
+
+        let r = self.len % self.dpw;
+        if r > 0 {
+            let w = words.last_mut().unwrap(); // if len is not a multiple of dpw then words is not empty
+            for _ in r..self.dpw {
+                *w *= self.rdx;
+            }
+        }
+
+        words.reverse();
+
+        let n = words.len() * self.dpw;
+
+        (WordBuf::from_vec(words), n)
+    }
+
+    /// Returns unpacked digits.
+    #[cfg(test)]
+    pub fn to_digits(&self) -> Vec<u8> {
+        let mut ret = Vec::with_capacity(self.len);
+
+        for (i, &w) in self.words.iter().enumerate() {
+            let n = if (i + 1) * self.dpw > self.len { self.len - i * self.dpw } else { self.dpw };
+            let start = ret.len();
+            let mut w = w;
+            for _ in 0..n {
+                ret.push((w % self.rdx) as u8);
+                w /= self.rdx;
+            }
+            ret[start..].reverse();
+        }
+
+        ret
+    }
+}
 
 impl BigFloatNumber {
     /// Converts an array of digits in radix `rdx` to BigFloatNumber with precision `p`.
@@ -37,17 +179,19 @@ impl BigFloatNumber {
     ///
     ///  - MemoryAllocation: failed to allocate memory for mantissa.
     ///  - ExponentOverflow: the resulting exponent becomes greater than the maximum allowed value for the exponent.
-    ///  - InvalidArgument: the precision is incorrect, or `digits` contains unacceptable digits for given radix,
+    ///  - InvalidArgument: the precision is incorrect, the radix is not in the range from 2 to 36, or `digits` contains unacceptable digits for given radix,
     /// or when `e` is less than EXPONENT_MIN or greater than EXPONENT_MAX.
     pub fn convert_from_radix(
         sign: Sign,
         digits: &[u8],
         e: Exponent,
-        rdx: Radix,
+        rdx: impl Into<u32>,
         mut p: usize,
         rm: RoundingMode,
         cc: &mut Consts,
     ) -> Result<Self, Error> {
+        let rdx = check_radix(rdx.into())? as Word;
+
         if p < usize::MAX {
             p = round_p(p);
             Self::p_assertion(p)?;
@@ -62,233 +206,216 @@ impl BigFloatNumber {
             return Err(Error::InvalidArgument);
         }
 
-        match rdx {
-            Radix::Bin => Self::conv_from_binary(sign, digits, e, p, rm),
-            Radix::Oct => Self::conv_from_commensurable(sign, digits, e, 3, p, rm),
-            Radix::Dec => Self::conv_from_dec(sign, digits, e, p, rm, cc),
-            Radix::Hex => Self::conv_from_commensurable(sign, digits, e, 4, p, rm),
-        }
+        let digits = PackedDigits::from_digits(digits, rdx)?;
+
+        Self::conv_from_packed(sign, digits, e as isize, p, rm, cc)
     }
 
-    fn conv_from_binary(
+    /// Converts packed digits to BigFloatNumber with precision `p`.
+    /// `e` is the exponent part of the number, such that the number can be represented as `digits` * `rdx` ^ `e`,
+    /// where `digits` is interpreted as a number smaller than 1.
+    /// Precision is rounded upwards to the word size.
+    /// if `p` equals usize::MAX then the precision of the resulting number is determined automatically from the input.
+    ///
+    /// ## Errors
+    ///
+    ///  - MemoryAllocation: failed to allocate memory for mantissa.
+    ///  - ExponentOverflow: the resulting exponent becomes greater than the maximum allowed value for the exponent.
+    ///  - InvalidArgument: the precision is incorrect.
+    pub(crate) fn convert_from_packed(
         sign: Sign,
-        digits: &[u8],
-        mut e: Exponent,
-        p: usize,
+        digits: PackedDigits,
+        e: isize,
+        mut p: usize,
         rm: RoundingMode,
+        cc: &mut Consts,
     ) -> Result<Self, Error> {
-        if digits.is_empty() {
-            return Self::new(if p < usize::MAX { p } else { DEFAULT_P });
-        }
-
-        Self::p_assertion(round_p(digits.len()))?;
+        if p < usize::MAX {
+            p = round_p(p);
+            Self::p_assertion(p)?;
 
-        let mut mantissa = Mantissa::new(digits.len())?;
-        let mut d = 0;
-        let mut shift = digits.len() % WORD_BIT_SIZE;
-        if shift != 0 {
-            shift = WORD_BIT_SIZE - shift;
+            if p == 0 {
+                return Self::new(0);
+            }
         }
 
-        let mut dst = mantissa.digits_mut().iter_mut();
+        Self::conv_from_packed(sign, digits, e, p, rm, cc)
+    }
 
-        for v in digits.iter().rev() {
-            if *v > 1 {
-                return Err(Error::InvalidArgument);
-            }
+    /// Converts packed hexadecimal digits to BigFloatNumber exactly, and then rounds it to precision `p` using rounding mode `rm`.
+    /// `e` is the binary exponent of the number, such that the number can be represented as `digits` * 2 ^ `e`,
+    /// where `digits` is interpreted as a number smaller than 1.
+    /// if `p` equals usize::MAX then the precision of the resulting number is determined automatically from the input.
+    ///
+    /// ## Errors
+    ///
+    ///  - MemoryAllocation: failed to allocate memory for mantissa.
+    ///  - ExponentOverflow: the resulting exponent becomes greater than the maximum allowed value for the exponent.
+    ///  - InvalidArgument: the precision is incorrect.
+    pub(crate) fn convert_from_hex_float(
+        sign: Sign,
+        digits: PackedDigits,
+        e: isize,
+        mut p: usize,
+        rm: RoundingMode,
+    ) -> Result<Self, Error> {
+        debug_assert_eq!(digits.rdx, 16);
 
-            d |= (*v as Word) << shift;
-            shift += 1;
+        if p < usize::MAX {
+            p = round_p(p);
+            Self::p_assertion(p)?;
 
-            if shift == WORD_BIT_SIZE {
-                *dst.next().unwrap() = d; // mantissa has precision of digits.len()
-                shift = 0;
-                d = 0;
+            if p == 0 {
+                return Self::new(0);
             }
         }
 
-        if shift > 0 {
-            *dst.next().unwrap() = d; // mantissa has precision of digits.len()
-        }
-
-        mantissa.update_bit_len();
-
-        if p < usize::MAX {
-            let mut ret = BigFloatNumber::from_raw_unchecked(mantissa, sign, e, false);
-
-            ret.set_precision(p, rm)?;
+        Self::conv_from_packed_pow2(sign, digits, e, p, rm)
+    }
 
-            Ok(ret)
+    fn conv_from_packed(
+        sign: Sign,
+        digits: PackedDigits,
+        e: isize,
+        p: usize,
+        rm: RoundingMode,
+        cc: &mut Consts,
+    ) -> Result<Self, Error> {
+        if digits.rdx.is_power_of_two() {
+            let shift = digits.rdx.trailing_zeros() as isize;
+            Self::conv_from_packed_pow2(sign, digits, e.saturating_mul(shift), p, rm)
         } else {
-            let shift = mantissa.max_bit_len() - mantissa.bit_len();
-
-            if shift > 0 {
-                if e > EXPONENT_MIN {
-                    let e_shift = (e - EXPONENT_MIN) as usize;
-
-                    if e_shift >= shift {
-                        mantissa.normilize2();
-                        e -= shift as Exponent;
-                    } else {
-                        mantissa.shift_left(e_shift);
-                        mantissa.set_bit_len(mantissa.bit_len() + e_shift);
-                        e = EXPONENT_MIN;
-                    }
-                }
-            }
-
-            Ok(BigFloatNumber::from_raw_unchecked(mantissa, sign, e, false))
+            Self::conv_from_rdx(sign, digits, e, p, rm, cc)
         }
     }
 
-    // radix is power of 2.
-    fn conv_from_commensurable(
+    // radix is power of 2, `e` is the exponent in bits.
+    fn conv_from_packed_pow2(
         sign: Sign,
-        digits: &[u8],
-        e: Exponent,
-        shift: usize,
+        digits: PackedDigits,
+        e: isize,
         p: usize,
         rm: RoundingMode,
     ) -> Result<Self, Error> {
-        let significant_bit = 1 << (shift - 1);
-        let base = 1 << shift;
-
-        // exponent shift
-        let mut e_shift = 0;
-        let mut zeroes = 0;
-        let mut first_shift = 0;
-        for v in digits {
-            let mut v = *v;
-
-            if v == 0 {
-                e_shift -= shift as isize;
-                zeroes += 1;
-            } else {
-                if v >= base {
-                    return Err(Error::InvalidArgument);
-                }
+        let shift = digits.rdx.trailing_zeros() as usize;
+        let bits_per_word = digits.dpw * shift;
+        let (words, n) = digits.into_words();
 
-                while v & significant_bit == 0 {
-                    e_shift -= 1;
-                    v <<= 1;
-                    first_shift += 1;
+        let mut m = if bits_per_word == WORD_BIT_SIZE {
+            words
+        } else {
+            // concatenate bits of the words
+            let mut m = WordBuf::new((words.len() * bits_per_word).div_ceil(WORD_BIT_SIZE))?;
+            let mut dst = m.iter_mut();
+            let mut d: DoubleWord = 0;
+            let mut filled = 0;
+
+            for &w in words.iter() {
+                d |= (w as DoubleWord) << filled;
+                filled += bits_per_word;
+
+                if filled >= WORD_BIT_SIZE {
+                    *dst.next().unwrap() = d as Word; // m has enough space for all bits
+                    d >>= WORD_BIT_SIZE;
+                    filled -= WORD_BIT_SIZE;
                 }
-                break;
             }
-        }
 
-        if zeroes == digits.len() {
-            // mantissa is zero
-            let m = Mantissa::new(if p < usize::MAX { p } else { DEFAULT_P })?;
+            if let Some(w) = dst.next() {
+                *w = d as Word;
+            }
 
-            Ok(BigFloatNumber::from_raw_unchecked(m, sign, 0, false))
-        } else {
-            let msz = round_p((digits.len() - zeroes) * shift + WORD_BIT_SIZE);
+            m
+        };
 
-            Self::p_assertion(msz)?;
+        m.trunc_leading_zeroes();
 
-            let mut m = Mantissa::new(msz)?;
+        if m.is_empty() {
+            return Self::new(if p < usize::MAX { p } else { DEFAULT_P });
+        }
 
-            // exponent
-            let e = e as isize * shift as isize + e_shift;
+        Self::p_assertion(m.len() * WORD_BIT_SIZE)?;
 
-            if e > EXPONENT_MAX as isize {
-                return Err(Error::ExponentOverflow(sign));
-            }
+        let mut m = Mantissa::from_word_buf(m);
+        let e = e - (n * shift) as isize + m.bit_len() as isize;
+        m.normilize2();
 
-            // fill mantissa
-            let mut filled = shift - first_shift;
-            let mut dst = m.digits_mut().iter_mut().rev();
-            let mut d = 0;
+        Self::from_normalized(sign, m, e, p, rm)
+    }
 
-            'outer: for &v in digits.iter().skip(zeroes) {
-                if v >= base {
-                    return Err(Error::InvalidArgument);
-                }
+    // `m` is normalized, and `e` is the exponent which can be out of the range of exponent values.
+    fn from_normalized(
+        sign: Sign,
+        mut m: Mantissa,
+        e: isize,
+        p: usize,
+        rm: RoundingMode,
+    ) -> Result<Self, Error> {
+        if e > EXPONENT_MAX as isize {
+            return Err(Error::ExponentOverflow(sign));
+        }
 
-                if filled <= WORD_BIT_SIZE {
-                    d |= (v as Word) << (WORD_BIT_SIZE - filled);
-                } else {
-                    d |= (v as Word) >> (filled - WORD_BIT_SIZE);
+        let mut ret = if e < EXPONENT_MIN as isize {
+            if p < usize::MAX {
+                let mut num = BigFloatNumber::from_raw_unchecked(m, sign, EXPONENT_MIN, false);
 
-                    if let Some(w) = dst.next() {
-                        *w = d;
-                        filled -= WORD_BIT_SIZE;
-                        d = if filled > 0 { (v as Word) << (WORD_BIT_SIZE - filled) } else { 0 };
-                    } else {
-                        break 'outer;
-                    }
+                if p + WORD_BIT_SIZE > num.mantissa_max_bit_len() {
+                    num.set_precision(p + WORD_BIT_SIZE, RoundingMode::None)?;
                 }
 
-                filled += shift;
-            }
+                num.subnormalize(e, RoundingMode::None);
 
-            if d > 0 {
-                if let Some(w) = dst.next() {
-                    *w = d;
+                if num.inexact() {
+                    num.mantissa_mut().digits_mut()[0] |= 1; // sticky for correct rounding when calling set_precision()
                 }
-            }
-
-            m.set_bit_len(m.max_bit_len());
 
-            let mut ret = if e < EXPONENT_MIN as isize {
-                if p < usize::MAX {
-                    let mut num = BigFloatNumber::from_raw_unchecked(m, sign, EXPONENT_MIN, false);
-
-                    if p + WORD_BIT_SIZE > num.mantissa_max_bit_len() {
-                        num.set_precision(p + WORD_BIT_SIZE, RoundingMode::None)?;
-                    }
-
-                    num.subnormalize(e, RoundingMode::None);
-
-                    if num.inexact() {
-                        num.mantissa_mut().digits_mut()[0] |= 1; // sticky for correct rounding when calling set_precision()
-                    }
-
-                    num
-                } else {
-                    let e_shift = (EXPONENT_MIN as isize - e) as usize;
-                    let newbitlen = m.bit_len().saturating_add(e_shift);
-
-                    Self::p_assertion(round_p(newbitlen))?;
+                num
+            } else {
+                let e_shift = (EXPONENT_MIN as isize - e) as usize;
+                let newbitlen = m.bit_len().saturating_add(e_shift);
 
-                    m.extend_subnormal(e_shift)?;
+                Self::p_assertion(round_p(newbitlen))?;
 
-                    let leftshift = m.max_bit_len() - newbitlen;
-                    m.shift_left(leftshift);
+                m.extend_subnormal(e_shift)?;
 
-                    BigFloatNumber::from_raw_unchecked(m, sign, EXPONENT_MIN, false)
-                }
-            } else {
-                BigFloatNumber::from_raw_unchecked(m, sign, e as Exponent, false)
-            };
+                let leftshift = m.max_bit_len() - newbitlen;
+                m.shift_left(leftshift);
+                m.set_bit_len(m.bit_len() + leftshift);
 
-            if p < usize::MAX {
-                ret.set_precision(p, rm)?;
+                BigFloatNumber::from_raw_unchecked(m, sign, EXPONENT_MIN, false)
             }
+        } else {
+            BigFloatNumber::from_raw_unchecked(m, sign, e as Exponent, false)
+        };
 
-            Ok(ret)
+        if p < usize::MAX {
+            ret.set_precision(p, rm)?;
         }
+
+        Ok(ret)
     }
 
-    fn conv_from_dec(
+    // radix is not a power of 2.
+    fn conv_from_rdx(
         sign: Sign,
-        digits: &[u8],
-        e: Exponent,
+        digits: PackedDigits,
+        e: isize,
         p: usize,
         rm: RoundingMode,
         cc: &mut Consts,
     ) -> Result<Self, Error> {
         // mantissa part
-        let leadzeroes = digits.iter().take_while(|&&x| x == 0).count();
+        let rdx = digits.rdx;
+        let len = digits.len();
+        let (words, n) = digits.into_words();
 
-        if digits.len() - leadzeroes == 0 {
+        if words.iter().all(|&w| w == 0) {
             return Self::new(if p < usize::MAX { p } else { DEFAULT_P });
         }
 
-        let k = log2_ceil(digits.len() - leadzeroes);
-        let tenpowers = cc.tenpowers(k)?;
-        let mut m = Mantissa::conv_from_dec(&digits[leadzeroes..], tenpowers)?;
+        let k = log2_ceil(n);
+        let rdxpowers = cc.rdxpowers(rdx, k)?;
+        let mut m = Mantissa::from_rdx_words(words, rdx, rdxpowers)?;
 
         if m.bit_len() > EXPONENT_MAX as usize {
             return Err(Error::ExponentOverflow(sign));
@@ -300,13 +427,13 @@ impl BigFloatNumber {
         let x = BigFloatNumber::from_raw_unchecked(m, sign, me, false);
 
         // exponent part
-        let n = e as isize - digits.len() as isize;
+        let n = e - n as isize;
 
         let p = if p < usize::MAX {
             p
         } else {
             // determine from the input
-            let p = round_p((digits.len() as u64 * 3321928095 / 1000000000) as usize + 1);
+            let p = round_p((len as u64 * LOG2_RDX[rdx as usize] / 1000000000) as usize + 1);
             Self::p_assertion(p)?;
             p
         };
@@ -314,14 +441,18 @@ impl BigFloatNumber {
         let mut p_inc = WORD_BIT_SIZE;
         let mut p_wrk = p + p_inc;
 
+        // maximum power of radix which does not overflow
+        let rdx_pwr_max = (EXPONENT_MAX as u64 * 1000000000 / LOG2_RDX[rdx as usize]) as usize;
+        let rdx_num = Self::from_word(rdx, WORD_BIT_SIZE)?;
+
         // error estimation
         let mut err = 0;
-        let npowmax = n.unsigned_abs() / TEN_PWR_MAX_FROM_DEC;
-        let tenpowrem = n.unsigned_abs() % TEN_PWR_MAX_FROM_DEC;
+        let npowmax = n.unsigned_abs() / rdx_pwr_max;
+        let rdxpowrem = n.unsigned_abs() % rdx_pwr_max;
         if npowmax != 0 {
             err += 3 * npowmax;
         }
-        if tenpowrem != 0 {
+        if rdxpowrem != 0 {
             err += 3;
         }
 
@@ -331,7 +462,7 @@ impl BigFloatNumber {
             let mut f = x.clone()?;
 
             if npowmax != 0 {
-                let fpnmax = TEN.powi(TEN_PWR_MAX_FROM_DEC, p_f, RoundingMode::None)?;
+                let fpnmax = rdx_num.powi(rdx_pwr_max, p_f, RoundingMode::None)?;
 
                 for _ in 0..npowmax {
                     if n < 0 {
@@ -342,8 +473,8 @@ impl BigFloatNumber {
                 }
             };
 
-            if tenpowrem != 0 {
-                let fpn = TEN.powi(tenpowrem, p_f, RoundingMode::None)?;
+            if rdxpowrem != 0 {
+                let fpn = rdx_num.powi(rdxpowrem, p_f, RoundingMode::None)?;
                 if n < 0 {
                     f = f.div(&fpn, p_f, RoundingMode::None)?
                 } else {
@@ -371,65 +502,87 @@ impl BigFloatNumber {
     ///
     ///  - MemoryAllocation: failed to allocate memory for mantissa.
     ///  - ExponentOverflow: the resulting exponent becomes greater than the maximum allowed value for the exponent.
+    ///  - InvalidArgument: the radix is not in the range from 2 to 36.
     pub fn convert_to_radix(
         &self,
-        rdx: Radix,
+        rdx: impl Into<u32>,
         rm: RoundingMode,
         cc: &mut Consts,
     ) -> Result<(Sign, Vec<u8>, Exponent), Error> {
-        match rdx {
-            Radix::Bin => self.conv_to_binary(),
-            Radix::Oct => self.conv_to_commensurable(3),
-            Radix::Dec => self.conv_to_dec(rm, cc),
-            Radix::Hex => self.conv_to_commensurable(4),
+        match check_radix(rdx.into())? as Word {
+            2 => self.conv_to_binary(),
+            b @ (4 | 8 | 16 | 32) => self.conv_to_commensurable(b.trailing_zeros() as usize),
+            b => {
+                let n = (self.mantissa_max_bit_len() as u64 * LOGRDX_2[b as usize] / 1000000000)
+                    as usize
+                    + 1;
+                self.conv_to_rdx(b, n, rm, cc)
+            }
         }
     }
 
-    fn conv_to_dec(
+    /// Converts `self` to radix `rdx` with `n` significant digits using rounding mode `rm`.
+    /// `rdx` must be in the range from 3 to 36, and `n` must be greater than 0.
+    /// Trailing zeroes are removed from the resulting mantissa.
+    pub(crate) fn conv_to_rdx(
         &self,
+        rdx: Word,
+        n: usize,
         rm: RoundingMode,
         cc: &mut Consts,
     ) -> Result<(Sign, Vec<u8>, Exponent), Error> {
+        debug_assert!(n > 0 && (3..=36).contains(&rdx));
+
         if self.precision() == 0 {
             return Ok((self.sign(), Vec::new(), 0));
         }
 
+        if rdx & 1 == 1 && self.is_half_odd_int() {
+            return self.conv_half_odd_int_to_rdx(rdx, n, rm, cc);
+        }
+
+        let log2_rdx = LOG2_RDX[rdx as usize];
+        let logrdx_2 = LOGRDX_2[rdx as usize];
+
+        // maximum power of radix which does not overflow
+        let rdx_pwr_max = EXPONENT_MAX as usize / log2_ceil(rdx as usize);
+        let rdx_num = Self::from_word(rdx, WORD_BIT_SIZE)?;
+
         let p = self.mantissa_max_bit_len();
         let subn_e = p - self.precision();
-        let n = (p as u64 * 301029996 / 1000000000) as usize + 1;
 
         let mut err = WORD_BIT_SIZE; // speculative
-        let mut p_wrk = round_p((n as u64 * 3321928095 / 1000000000) as usize + 1 + err);
+        let mut p_wrk = round_p((n as u64 * log2_rdx / 1000000000) as usize + 1 + err);
         let mut p_inc = WORD_BIT_SIZE;
 
         loop {
             let mut x = self.clone()?;
             x.set_inexact(false);
 
-            let n_wrk = ((p_wrk as i64 - self.exponent() as i64 + subn_e as i64) * 301029996
+            let n_wrk = ((p_wrk as i64 - self.exponent() as i64 + subn_e as i64) * logrdx_2 as i64
                 / 1000000000) as isize
                 + 1;
 
             let mut err_acc = 0;
 
             let mut pwr = n_wrk.unsigned_abs();
-            if pwr > TEN_PWR_MAX_TO_DEC {
-                let tp = TEN.powi(TEN_PWR_MAX_TO_DEC, p_wrk, RoundingMode::None)?;
+            if pwr > rdx_pwr_max {
+                let tp = rdx_num.powi(rdx_pwr_max, p_wrk, RoundingMode::None)?;
                 err_acc += 1;
 
-                while pwr > TEN_PWR_MAX_TO_DEC {
+                while pwr > rdx_pwr_max {
                     if n_wrk < 0 {
                         x = x.div(&tp, p_wrk, RoundingMode::None)?;
                     } else {
                         x = x.mul(&tp, p_wrk, RoundingMode::None)?;
                     }
                     err_acc += 2;
-                    pwr -= TEN_PWR_MAX_TO_DEC;
+                    pwr -= rdx_pwr_max;
                 }
             }
 
             if pwr != 0 {
-                let tp = TEN.powi(pwr, p_wrk, RoundingMode::None)?;
+                let tp = rdx_num.powi(pwr, p_wrk, RoundingMode::None)?;
                 if n_wrk < 0 {
                     x = x.div(&tp, p_wrk, RoundingMode::None)?;
                 } else {
@@ -439,7 +592,7 @@ impl BigFloatNumber {
             }
 
             if err_acc > err {
-                err_acc += err_acc / TEN_PWR_MAX_TO_DEC + 3;
+                err_acc += err_acc / rdx_pwr_max + 3;
                 p_wrk += round_p(err_acc - err);
                 err = err_acc;
                 continue;
@@ -452,12 +605,19 @@ impl BigFloatNumber {
                 m.shift_left_resize(shift)?;
             }
 
-            let l = (m.bit_len() as u64 * 301029996 / 1000000000) as usize + 1;
+            let l = (m.bit_len() as u64 * logrdx_2 / 1000000000) as usize + 1;
 
             let k = log2_ceil(l);
 
-            let tenpowers = cc.tenpowers(k)?;
-            let mut digits = m.conv_to_dec(1 << k, tenpowers, k - 1, true)?;
+            let rdxpowers = cc.rdxpowers(rdx, k)?;
+            let mut digits = Vec::new();
+            digits.try_reserve_exact(l)?;
+            digits.resize(l, 0);
+
+            m.conv_to_rdx(rdx, &mut digits, rdxpowers, k - 1)?;
+
+            let lz = digits.iter().take_while(|&&d| d == 0).count();
+            digits.drain(..lz);
 
             let mut e_out = digits.len() as isize - n_wrk;
 
@@ -471,13 +631,14 @@ impl BigFloatNumber {
 
             // cut off digits with error
             let valid =
-                digits.len() as i64 - ((shift + err_acc) as i64 * 301029996 / 1000000000) - 1;
+                digits.len() as i64 - ((shift + err_acc) as i64 * logrdx_2 as i64 / 1000000000) - 1;
 
             if digits.len() > n && valid > n as i64 {
                 // try round
-                if Self::try_round_dec(
+                if Self::try_round_rdx(
                     &mut digits[..valid as usize],
                     n,
+                    rdx as u8,
                     rm,
                     self.sign(),
                     &mut e_out,
@@ -514,10 +675,320 @@ impl BigFloatNumber {
         }
     }
 
-    // Try to round a decimal mantissa.
-    fn try_round_dec(
+    // Conversion of a number with the fractional part equal to 1/2 to an odd radix `rdx`.
+    // In an odd radix, 1/2 is an infinite sequence of digits `rdx` / 2, and the result of rounding can be a tie.
+    fn conv_half_odd_int_to_rdx(
+        &self,
+        rdx: Word,
+        n: usize,
+        rm: RoundingMode,
+        cc: &mut Consts,
+    ) -> Result<(Sign, Vec<u8>, Exponent), Error> {
+        // 2 * self is an odd integer which is converted exactly.
+        let mut x = self.clone()?;
+        x.set_exponent(self.exponent() + 1);
+
+        let nd = (x.exponent() as u64 * LOGRDX_2[rdx as usize] / 1000000000) as usize + 1;
+        let (_, xd, xe) = x.conv_to_rdx(rdx, nd, RoundingMode::ToZero, cc)?;
+
+        // division by 2
+        let xe = xe as usize;
+        let half = (rdx / 2) as u8;
+
+        let mut digits = Vec::new();
+        digits.try_reserve_exact(xe.max(n) + 1)?;
+
+        let mut r = 0;
+        for i in 0..xe {
+            let cur = r * rdx + xd.get(i).copied().unwrap_or(0) as Word;
+            digits.push((cur / 2) as u8);
+            r = cur % 2;
+        }
+
+        debug_assert!(r == 1);
+
+        let mut e = xe as Exponent;
+        if digits[0] == 0 {
+            digits.remove(0);
+            e -= 1;
+        }
+
+        while digits.len() <= n {
+            digits.push(half);
+        }
+
+        // the remaining infinite sequence of digits `half` is a tie
+        if (rm == RoundingMode::ToEven || rm == RoundingMode::ToOdd)
+            && digits[n..].iter().all(|&d| d == half)
+        {
+            let is_even = digits[n - 1] % 2 == 0;
+
+            // make the finite tail be above or below the half
+            digits[n] = if (rm == RoundingMode::ToEven) != is_even { half + 1 } else { 0 };
+        }
+
+        if Self::round_rdx(
+            &mut digits,
+            n,
+            rdx as u8,
+            rm,
+            self.is_positive(),
+            &mut false,
+        ) {
+            e += 1;
+        }
+
+        digits.truncate(n);
+
+        // remove trailing zeroes
+        let nzr = digits.iter().rev().take_while(|&&x| x == 0).count();
+        digits.truncate(digits.len() - nzr);
+
+        Ok((self.sign(), digits, e))
+    }
+
+    /// Converts `self` to radix `rdx` with `n` significant digits using rounding mode `rm`.
+    /// `rdx` must be in the range from 3 to 36, `n` must be greater than 0, and `self` must not be zero.
+    /// The function returns sign, an integer of exactly `n` digits in radix `rdx`, and exponent such that the converted number
+    /// can be represented as `integer` * `rdx` ^ (`exponent` - `n`).
+    /// Unlike `conv_to_rdx`, rounding is done before the digits are produced, so the digits can be produced by parts.
+    pub(crate) fn conv_to_rdx_int(
+        &self,
+        rdx: Word,
+        n: usize,
+        rm: RoundingMode,
+    ) -> Result<(Sign, BigUint, isize), Error> {
+        debug_assert!(n > 0 && (3..=36).contains(&rdx) && !self.is_zero());
+
+        let b = BigUint::from_words(&[rdx])?;
+
+        if rdx & 1 == 1 && self.is_half_odd_int() {
+            // 2 * |self| is an odd integer, and |self| * rdx^k = m + 1/2 for an integer m.
+            let p = self.mantissa_max_bit_len();
+            let m2 = BigUint::from_words(self.mantissa().digits())?
+                .shr(p - self.exponent() as usize - 1)?;
+
+            // m2 * rdx^k has at least n + 1 digits, so m has at least n digits.
+            let k = (n + 1).saturating_sub(Self::rdx_digits_lower_bound(&m2, rdx));
+            let m = m2.mul(&b.pow(k)?)?.shr(1)?;
+
+            let ret =
+                Self::round_int_rdx(m, &b, rdx, n, -(k as isize), None, true, rm, self.sign())?;
+
+            return Ok(ret.unwrap()); // exact value can always be rounded
+        }
+
+        let log2_rdx = LOG2_RDX[rdx as usize];
+        let logrdx_2 = LOGRDX_2[rdx as usize];
+
+        // maximum power of radix which does not overflow
+        let rdx_pwr_max = EXPONENT_MAX as usize / log2_ceil(rdx as usize);
+        let rdx_num = Self::from_word(rdx, WORD_BIT_SIZE)?;
+
+        let p = self.mantissa_max_bit_len();
+        let subn_e = p - self.precision();
+
+        let mut err = WORD_BIT_SIZE; // speculative
+        let mut p_wrk = round_p((n as u64 * log2_rdx / 1000000000) as usize + 1 + err);
+        let mut p_inc = WORD_BIT_SIZE;
+
+        loop {
+            let mut x = self.clone()?;
+            x.set_inexact(false);
+
+            let n_wrk = ((p_wrk as i64 - self.exponent() as i64 + subn_e as i64) * logrdx_2 as i64
+                / 1000000000) as isize
+                + 1;
+
+            let mut err_acc = 0;
+
+            let mut pwr = n_wrk.unsigned_abs();
+            if pwr > rdx_pwr_max {
+                let tp = rdx_num.powi(rdx_pwr_max, p_wrk, RoundingMode::None)?;
+                err_acc += 1;
+
+                while pwr > rdx_pwr_max {
+                    if n_wrk < 0 {
+                        x = x.div(&tp, p_wrk, RoundingMode::None)?;
+                    } else {
+                        x = x.mul(&tp, p_wrk, RoundingMode::None)?;
+                    }
+                    err_acc += 2;
+                    pwr -= rdx_pwr_max;
+                }
+            }
+
+            if pwr != 0 {
+                let tp = rdx_num.powi(pwr, p_wrk, RoundingMode::None)?;
+                if n_wrk < 0 {
+                    x = x.div(&tp, p_wrk, RoundingMode::None)?;
+                } else {
+                    x = x.mul(&tp, p_wrk, RoundingMode::None)?;
+                }
+                err_acc += 3;
+            }
+
+            if err_acc > err {
+                err_acc += err_acc / rdx_pwr_max + 3;
+                p_wrk += round_p(err_acc - err);
+                err = err_acc;
+                continue;
+            }
+
+            let (m, _, e, inexact) = x.into_raw_parts();
+
+            let m = BigUint::from_words(m.digits())?;
+
+            // m is converted to an integer with an error smaller than 2^err_bits.
+            let (m, err_bits, inexact) = if e as isize >= p_wrk as isize {
+                let shift = e as usize - p_wrk;
+                (m.shl(shift)?, err_acc + shift, inexact)
+            } else {
+                // n_wrk was overestimated, and the fractional part is dropped
+                let shift = (p_wrk as isize - e as isize) as usize;
+                (m.shr(shift)?, err_acc + 1, true)
+            };
+
+            let eps = BigUint::from_u64(1)?.shl(err_bits)?;
+            let eps = if inexact { Some(&eps) } else { None };
+
+            if let Some(ret) =
+                Self::round_int_rdx(m, &b, rdx, n, -n_wrk, eps, false, rm, self.sign())?
+            {
+                return Ok(ret);
+            }
+
+            p_wrk += p_inc;
+            p_inc = round_p(p_wrk / 5);
+        }
+    }
+
+    // Returns a lower bound of the number of digits of `m` in radix `rdx`.
+    fn rdx_digits_lower_bound(m: &BigUint, rdx: Word) -> usize {
+        (m.bit_len().saturating_sub(1) as u64 * (LOGRDX_2[rdx as usize] - 1) / 1000000000) as usize
+            + 1
+    }
+
+    // Rounds `m` + `half` / 2 to `n` digits in radix `rdx` using rounding mode `rm`.
+    // If `eps` is specified, then `m` is known with an absolute error smaller than `eps`, and `half` must be false.
+    // The number being rounded is `m` * `rdx` ^ `scale`, and its sign is `s`.
+    // Returns None if the error does not allow to round correctly.
+    #[allow(clippy::too_many_arguments)]
+    fn round_int_rdx(
+        m: BigUint,
+        b: &BigUint,
+        rdx: Word,
+        n: usize,
+        scale: isize,
+        eps: Option<&BigUint>,
+        half: bool,
+        rm: RoundingMode,
+        s: Sign,
+    ) -> Result<Option<(Sign, BigUint, isize)>, Error> {
+        debug_assert!(eps.is_none() || !half);
+
+        let bn1 = b.pow(n - 1)?;
+        let bn = bn1.mul(b)?;
+
+        // m = h * bt + r, where h has exactly n digits.
+        let mut t = Self::rdx_digits_lower_bound(&m, rdx).saturating_sub(n);
+        let mut bt = b.pow(t)?;
+        let (mut h, mut r) = m.div_rem(&bt)?;
+        drop(m);
+
+        while h >= bn {
+            let (q, d) = h.div_rem(b)?;
+            r = d.mul(&bt)?.add(&r)?;
+            bt = bt.mul(b)?;
+            h = q;
+            t += 1;
+        }
+
+        if h < bn1 {
+            // not enough digits computed
+            return Ok(None);
+        }
+
+        let two_eps = match eps {
+            Some(eps) => {
+                let two_eps = eps.shl(1)?;
+                if two_eps >= bt {
+                    return Ok(None);
+                }
+                Some(two_eps)
+            }
+            None => None,
+        };
+
+        let up = match rm {
+            RoundingMode::None | RoundingMode::ToZero => false,
+            RoundingMode::ToEven | RoundingMode::ToOdd => {
+                // compare the remainder with the half of bt
+                let mut r2 = r.shl(1)?;
+                if half {
+                    r2 = r2.add(&BigUint::from_u64(1)?)?;
+                }
+
+                match &two_eps {
+                    None => match r2.cmp(&bt) {
+                        Ordering::Greater => true,
+                        Ordering::Less => false,
+                        Ordering::Equal => {
+                            // the parity of the last digit, which differs from the parity of h in odd radixes
+                            h.rem(b)?.is_odd() == (rm == RoundingMode::ToEven)
+                        }
+                    },
+                    Some(two_eps) => {
+                        if r2 > bt && r2.sub(&bt)? > *two_eps {
+                            true
+                        } else if r2 < bt && bt.sub(&r2)? > *two_eps {
+                            false
+                        } else {
+                            return Ok(None);
+                        }
+                    }
+                }
+            }
+            RoundingMode::Up | RoundingMode::Down | RoundingMode::FromZero => {
+                let nonzero = match eps {
+                    None => half || !r.is_zero(),
+                    Some(eps) => {
+                        if r > *eps && bt.sub(&r)? > *eps {
+                            true
+                        } else {
+                            return Ok(None);
+                        }
+                    }
+                };
+
+                nonzero
+                    && (rm == RoundingMode::FromZero || (rm == RoundingMode::Up) == s.is_positive())
+            }
+        };
+
+        let mut e = (n + t) as isize + scale;
+
+        if up {
+            h = h.add(&BigUint::from_u64(1)?)?;
+
+            if h == bn {
+                h = bn1;
+                e += 1;
+            }
+        }
+
+        if e > EXPONENT_MAX as isize {
+            return Err(Error::ExponentOverflow(s));
+        }
+
+        Ok(Some((s, h, e)))
+    }
+
+    // Try to round a mantissa in radix `rdx`.
+    fn try_round_rdx(
         digits: &mut [u8],
         n: usize,
+        rdx: u8,
         rm: RoundingMode,
         s: Sign,
         e: &mut Exponent,
@@ -526,7 +997,7 @@ impl BigFloatNumber {
         let mut check_roundable = inexact;
 
         if n > 0 {
-            let ovf = Self::round_dec(digits, n, rm, s.is_positive(), &mut check_roundable);
+            let ovf = Self::round_rdx(digits, n, rdx, rm, s.is_positive(), &mut check_roundable);
 
             if check_roundable {
                 return Ok(false);
@@ -544,11 +1015,14 @@ impl BigFloatNumber {
         Ok(true)
     }
 
-    // Round decimal mantissa.
+    // Round mantissa in radix `rdx`.
     // The function is similar to Mantissa::round_mantissa.
-    fn round_dec(
+    // For odd radixes, a half of the unit is an infinite sequence of digits `rdx` / 2,
+    // so a tie can not be represented by a finite number of digits.
+    fn round_rdx(
         digits: &mut [u8],
         n: usize,
+        rdx: u8,
         rm: RoundingMode,
         is_positive: bool,
         check_roundable: &mut bool,
@@ -558,20 +1032,23 @@ impl BigFloatNumber {
             return false;
         }
 
+        let dmax = rdx - 1;
+        let half = rdx / 2;
+
         #[inline]
-        fn get_rem(arr: &[u8]) -> (bool, bool) {
-            let mut rem9 = true;
+        fn get_rem(arr: &[u8], dmax: u8) -> (bool, bool) {
+            let mut remmax = true;
             let mut rem0 = true;
 
             for &d in arr.iter() {
-                if d != 9 {
-                    rem9 = false;
+                if d != dmax {
+                    remmax = false;
                 }
                 if d != 0 {
                     rem0 = false;
                 }
             }
-            (rem0, rem9)
+            (rem0, remmax)
         }
 
         if n > 0 && n < digits.len() {
@@ -581,38 +1058,38 @@ impl BigFloatNumber {
                 let is_even = digits[n - 1] % 2 == 0;
                 let dn = digits[n];
 
-                let (rem0, rem9) = get_rem(&digits[n + 1..]);
+                let (above_half, is_half) = if rdx & 1 == 0 {
+                    let (rem0, remmax) = get_rem(&digits[n + 1..], dmax);
 
-                if *check_roundable && (rem0 || rem9) {
-                    return false;
-                }
-
-                // need adding 1?
-                match rm {
-                    RoundingMode::ToEven => {
-                        if dn == 5 {
-                            if !is_even || !rem0 {
-                                c = true;
-                            }
-                        } else if dn > 5 {
-                            c = true;
-                        }
+                    if *check_roundable && (rem0 || remmax) {
+                        return false;
                     }
-                    RoundingMode::ToOdd => {
-                        if dn == 5 {
-                            if is_even || !rem0 {
-                                c = true;
-                            }
-                        } else if dn > 5 {
-                            c = true;
-                        }
+
+                    (dn > half || (dn == half && !rem0), dn == half && rem0)
+                } else {
+                    // compare with the sequence of digits `half`
+                    let tail = &digits[n..];
+                    let pos = tail.iter().position(|&d| d != half);
+
+                    if *check_roundable && !matches!(pos, Some(i) if i + 1 < tail.len()) {
+                        return false;
                     }
-                    _ => unreachable!(),
+
+                    (matches!(pos, Some(i) if tail[i] > half), false)
                 };
+
+                // need adding 1?
+                c = above_half
+                    || is_half
+                        && match rm {
+                            RoundingMode::ToEven => !is_even,
+                            RoundingMode::ToOdd => is_even,
+                            _ => unreachable!(),
+                        };
             } else {
-                let (rem0, rem9) = get_rem(&digits[n..]);
+                let (rem0, remmax) = get_rem(&digits[n..], dmax);
 
-                if *check_roundable && (rem0 || rem9) {
+                if *check_roundable && (rem0 || remmax) {
                     return false;
                 }
 
@@ -647,7 +1124,7 @@ impl BigFloatNumber {
 
             if c {
                 for v in digits[..n].iter_mut().rev() {
-                    if *v < 9 {
+                    if *v < dmax {
                         *v += 1;
                         return false;
                     } else {
@@ -663,8 +1140,9 @@ impl BigFloatNumber {
         false
     }
 
-    /// Conversion for radixes of power of 2.
-    fn conv_to_commensurable(&self, shift: usize) -> Result<(Sign, Vec<u8>, Exponent), Error> {
+    /// Returns the exponent of `self` in radix 2^`shift`,
+    /// and the number of leading zero bits in the first digit of the mantissa.
+    pub(crate) fn commensurable_exponent(&self, shift: usize) -> (Exponent, usize) {
         let mut e = self.exponent();
         let mut e_shift = e.unsigned_abs() as usize % shift;
         e /= shift as Exponent;
@@ -673,6 +1151,61 @@ impl BigFloatNumber {
             e += 1;
         }
 
+        (e, e_shift)
+    }
+
+    /// Passes all digits of the mantissa of `self` in radix 2^`shift` to `out` in blocks,
+    /// starting from the most significant digit.
+    /// The exponent of the digits is given by `commensurable_exponent`.
+    pub(crate) fn write_commensurable<E, F: FnMut(&[u8]) -> Result<(), E>>(
+        &self,
+        shift: usize,
+        out: &mut F,
+    ) -> Result<(), E> {
+        let (_, e_shift) = self.commensurable_exponent(shift);
+
+        let mask = (WORD_MAX >> (WORD_BIT_SIZE - shift)) as DoubleWord;
+        let mut buf = [0u8; 256];
+        let mut len = 0;
+
+        // the first digit starts with e_shift zero bits.
+        let mut d: DoubleWord = 0;
+        let mut done = e_shift;
+
+        for &v in self.mantissa().digits().iter().rev() {
+            d = (d << WORD_BIT_SIZE) | v as DoubleWord;
+            done += WORD_BIT_SIZE;
+
+            while done >= shift {
+                done -= shift;
+                buf[len] = ((d >> done) & mask) as u8;
+                len += 1;
+
+                if len == buf.len() {
+                    out(&buf)?;
+                    len = 0;
+                }
+            }
+
+            d &= mask >> (shift - done);
+        }
+
+        if done > 0 {
+            buf[len] = ((d << (shift - done)) & mask) as u8;
+            len += 1;
+        }
+
+        if len > 0 {
+            out(&buf[..len])?;
+        }
+
+        Ok(())
+    }
+
+    /// Conversion for radixes of power of 2.
+    fn conv_to_commensurable(&self, shift: usize) -> Result<(Sign, Vec<u8>, Exponent), Error> {
+        let (e, e_shift) = self.commensurable_exponent(shift);
+
         let mut ret = Vec::new();
         ret.try_reserve_exact(self.mantissa_max_bit_len() / shift)?;
 
@@ -756,7 +1289,7 @@ mod tests {
     use super::*;
     use crate::common::consts::ONE;
     use crate::common::util::random_subnormal;
-    use crate::defs::{Sign, EXPONENT_MAX, EXPONENT_MIN};
+    use crate::defs::{Radix, Sign, EXPONENT_MAX, EXPONENT_MIN};
     use crate::WORD_SIGNIFICANT_BIT;
     use rand::random;
 
@@ -988,7 +1521,7 @@ mod tests {
             //println!("{:?} {:?} {}", s1, m1, e1);
             //println!("{:?}\n{:?}", n, g);
 
-            if rdx == Radix::Dec {
+            if rdx == Radix::Dec as u32 {
                 eps.set_exponent(n.exponent() - p as Exponent + 4);
                 assert!(
                     n.sub(&g, p, RoundingMode::None)
@@ -1040,7 +1573,7 @@ mod tests {
             //println!("{:?} {:?} {}", s1, m1, e1);
             //println!("{:?}\n{:?}", n, g);
 
-            if rdx == Radix::Dec {
+            if rdx == Radix::Dec as u32 {
                 let mut eps = BigFloatNumber::min_positive(p).unwrap();
                 eps.set_exponent(eps.exponent() + 1);
 
@@ -1133,7 +1666,15 @@ mod tests {
 
         // misc/invalid input
         let s1 = Sign::Pos;
-        for rdx in [Radix::Bin, Radix::Oct, Radix::Dec, Radix::Hex] {
+        for rdx in [
+            Radix::Bin as u32,
+            Radix::Oct as u32,
+            Radix::Dec as u32,
+            Radix::Hex as u32,
+            3,
+            32,
+            36,
+        ] {
             for e1 in [123, -123, 0] {
                 let m1 = [];
                 assert!(BigFloatNumber::convert_from_radix(
@@ -1471,12 +2012,14 @@ mod tests {
         // unknown p: decimal
     }
 
-    fn random_radix() -> Radix {
-        match random::<usize>() % 4 {
-            0 => Radix::Bin,
-            1 => Radix::Oct,
-            2 => Radix::Dec,
-            3 => Radix::Hex,
+    fn random_radix() -> u32 {
+        match random::<usize>() % 6 {
+            0 => Radix::Bin as u32,
+            1 => Radix::Oct as u32,
+            2 => Radix::Dec as u32,
+            3 => Radix::Hex as u32,
+            4 => 4,
+            5 => 32,
             _ => unreachable!(),
         }
     }
@@ -1731,9 +2274,10 @@ mod tests {
                     | RoundingMode::None => {
                         // indifferent of sign
                         for is_positive in [true, false] {
-                            let ovf = BigFloatNumber::round_dec(
+                            let ovf = BigFloatNumber::round_rdx(
                                 input,
                                 *n,
+                                10,
                                 *rm,
                                 is_positive,
                                 check_roundable,
@@ -1744,9 +2288,10 @@ mod tests {
                         }
                     }
                     RoundingMode::Down | RoundingMode::Up => {
-                        let ovf = BigFloatNumber::round_dec(
+                        let ovf = BigFloatNumber::round_rdx(
                             input,
                             *n,
+                            10,
                             *rm,
                             *is_positive,
                             check_roundable,
@@ -1762,48 +2307,74 @@ mod tests {
         // overflow
         let mut input = [9, 9, 9, 9, 9, 9];
         let mut check_roundable = false;
-        let ovf =
-            BigFloatNumber::round_dec(&mut input, 3, RoundingMode::Up, true, &mut check_roundable);
+        let ovf = BigFloatNumber::round_rdx(
+            &mut input,
+            3,
+            10,
+            RoundingMode::Up,
+            true,
+            &mut check_roundable,
+        );
         assert!(ovf);
         assert_eq!(input, [1, 0, 0, 0, 0, 0]);
 
         // n = input.len()
         let mut input = [9, 9, 9, 9, 9, 9];
         let mut check_roundable = false;
-        let ovf =
-            BigFloatNumber::round_dec(&mut input, 6, RoundingMode::Up, true, &mut check_roundable);
+        let ovf = BigFloatNumber::round_rdx(
+            &mut input,
+            6,
+            10,
+            RoundingMode::Up,
+            true,
+            &mut check_roundable,
+        );
         assert!(!ovf);
         assert_eq!(input, [9, 9, 9, 9, 9, 9]);
 
         // n > input.len()
         let mut input = [9, 9, 9, 9, 9, 9];
         let mut check_roundable = false;
-        let ovf =
-            BigFloatNumber::round_dec(&mut input, 7, RoundingMode::Up, true, &mut check_roundable);
+        let ovf = BigFloatNumber::round_rdx(
+            &mut input,
+            7,
+            10,
+            RoundingMode::Up,
+            true,
+            &mut check_roundable,
+        );
         assert!(!ovf);
         assert_eq!(input, [9, 9, 9, 9, 9, 9]);
 
         // n = 0
         let mut input = [9, 9, 9, 9, 9, 9];
         let mut check_roundable = false;
-        let ovf =
-            BigFloatNumber::round_dec(&mut input, 0, RoundingMode::Up, true, &mut check_roundable);
+        let ovf = BigFloatNumber::round_rdx(
+            &mut input,
+            0,
+            10,
+            RoundingMode::Up,
+            true,
+            &mut check_roundable,
+        );
         assert!(!ovf);
         assert_eq!(input, [9, 9, 9, 9, 9, 9]);
 
         // try round
-        assert!(BigFloatNumber::try_round_dec(
+        assert!(BigFloatNumber::try_round_rdx(
             &mut [9, 9, 9, 9, 9, 9],
             3,
+            10,
             RoundingMode::Up,
             Sign::Pos,
             &mut 0,
             false
         )
         .unwrap());
-        assert!(!BigFloatNumber::try_round_dec(
+        assert!(!BigFloatNumber::try_round_rdx(
             &mut [9, 9, 9, 9, 9, 9],
             3,
+            10,
             RoundingMode::Up,
             Sign::Pos,
             &mut 0,
@@ -1812,9 +2383,10 @@ mod tests {
         .unwrap());
 
         let mut e = 0;
-        assert!(BigFloatNumber::try_round_dec(
+        assert!(BigFloatNumber::try_round_rdx(
             &mut [9, 9, 9, 9, 9, 0],
             3,
+            10,
             RoundingMode::Up,
             Sign::Pos,
             &mut e,
@@ -1825,9 +2397,10 @@ mod tests {
 
         e = EXPONENT_MAX;
         assert_eq!(
-            BigFloatNumber::try_round_dec(
+            BigFloatNumber::try_round_rdx(
                 &mut [9, 9, 9, 9, 9, 0],
                 3,
+                10,
                 RoundingMode::FromZero,
                 Sign::Neg,
                 &mut e,
@@ -5,6 +5,7 @@ use crate::Consts;
 use crate::Error;
 use crate::Exponent;
 use crate::RoundingMode;
+use crate::Tuning;
 use crate::EXPONENT_MAX;
 use crate::EXPONENT_MIN;
 
@@ -16,6 +17,7 @@ pub struct Context {
     rm: RoundingMode,
     emin: Exponent,
     emax: Exponent,
+    tuning: Option<Tuning>,
 }
 
 impl Context {
@@ -29,6 +31,7 @@ impl Context {
             rm,
             emin: emin.clamp(EXPONENT_MIN, 0),
             emax: emax.clamp(0, EXPONENT_MAX),
+            tuning: None,
         }
     }
 
@@ -41,6 +44,7 @@ impl Context {
             cc,
             emin,
             emax,
+            ..
         } = self;
         (p, rm, cc, emin, emax)
     }
@@ -72,6 +76,13 @@ impl Context {
         self.emax = emax.clamp(0, EXPONENT_MAX);
     }
 
+    /// Sets the tuning of algorithm thresholds used for expressions computed with the context.
+    /// If `None` is given, the tuning in effect for the current thread is used.
+    /// The tuning of the context is applied only if the `std` feature is enabled.
+    pub fn set_tuning(&mut self, tuning: Option<Tuning>) {
+        self.tuning = tuning;
+    }
+
     /// Returns the precision of the context.
     pub fn precision(&self) -> usize {
         self.p
@@ -117,6 +128,11 @@ impl Context {
         self.emax
     }
 
+    /// Returns the tuning of algorithm thresholds of the context.
+    pub fn tuning(&self) -> Option<Tuning> {
+        self.tuning
+    }
+
     /// Clones `self` and returns the cloned context.
     ///
     /// # Errors
@@ -131,6 +147,7 @@ impl Context {
             cc,
             emin: self.emin,
             emax: self.emax,
+            tuning: self.tuning,
         })
     }
 }
@@ -182,6 +199,12 @@ pub trait Contextable {
 
     /// Returns the maximum exponent.
     fn emax(&self) -> Exponent;
+
+    /// Returns the tuning of algorithm thresholds.
+    /// By default, `None` is returned, meaning the tuning in effect for the current thread is used.
+    fn tuning(&self) -> Option<Tuning> {
+        None
+    }
 }
 
 impl Contextable for (usize, RoundingMode, &mut Consts) {
@@ -304,4 +327,8 @@ impl Contextable for Context {
     fn emax(&self) -> Exponent {
         Context::emax(self)
     }
+
+    fn tuning(&self) -> Option<Tuning> {
+        Context::tuning(self)
+    }
 }
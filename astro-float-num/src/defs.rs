@@ -161,6 +161,9 @@ impl From<TryReserveError> for Error {
 }
 
 /// Radix.
+///
+/// Functions which parse or format numbers accept either a `Radix` or a numeric base from 2 to 36 given as `u32`.
+/// Digits from 10 to 35 are represented by latin letters from "a" to "z".
 #[derive(PartialEq, Eq, Copy, Clone, Debug)]
 pub enum Radix {
     /// Binary.
@@ -176,6 +179,20 @@ pub enum Radix {
     Hex = 16,
 }
 
+impl From<Radix> for u32 {
+    fn from(rdx: Radix) -> Self {
+        rdx as u32
+    }
+}
+
+/// Returns `rdx` if the radix is in the supported range from 2 to 36.
+pub(crate) fn check_radix(rdx: u32) -> Result<u32, Error> {
+    match rdx {
+        2..=36 => Ok(rdx),
+        _ => Err(Error::InvalidArgument),
+    }
+}
+
 /// Rounding modes.
 #[derive(Eq, PartialEq, Debug, Copy, Clone)]
 pub enum RoundingMode {
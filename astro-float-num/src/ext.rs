@@ -1,12 +1,17 @@
 //! BigFloat including finite numbers, NaN, and `Inf`.
 
+use crate::ctx::Contextable;
 use crate::defs::SignedWord;
 use crate::defs::DEFAULT_P;
+use crate::int::BigInt;
+use crate::int::BigUint;
 use crate::num::BigFloatNumber;
+use crate::rational::BigRational;
+use crate::strop::FixedFormat;
+use crate::strop::WriteError;
 use crate::Consts;
 use crate::Error;
 use crate::Exponent;
-use crate::Radix;
 use crate::RoundingMode;
 use crate::Sign;
 use crate::Word;
@@ -14,7 +19,7 @@ use core::num::FpCategory;
 use lazy_static::lazy_static;
 
 #[cfg(feature = "std")]
-use core::fmt::Write;
+use crate::Radix;
 
 #[cfg(not(feature = "std"))]
 use alloc::{string::String, vec::Vec};
@@ -322,6 +327,94 @@ impl BigFloat {
         }
     }
 
+    /// Computes the remainder of division of `self` by `d2` as defined by IEEE 754:
+    /// `self` - n * `d2`, where n is the integer nearest to `self` / `d2` with ties rounded to even.
+    /// The result is exact, and its absolute value does not exceed |`d2`| / 2.
+    /// If the result is zero, its sign is the sign of `self`.
+    /// The function returns NaN if `self` is Inf or `d2` is zero, and `self` if `d2` is Inf.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// # use astro_float_num::BigFloat;
+    /// let r = BigFloat::from_f64(7.0, 64).remainder(&BigFloat::from_f64(2.0, 64));
+    /// assert_eq!(r, BigFloat::from_f64(-1.0, 64));
+    /// let r = BigFloat::from_f64(5.0, 64).remainder(&BigFloat::from_f64(2.0, 64));
+    /// assert_eq!(r, BigFloat::from_f64(1.0, 64));
+    /// ```
+    pub fn remainder(&self, d2: &Self) -> Self {
+        self.remquo(d2).0
+    }
+
+    /// Computes the remainder of division of `self` by `d2` in the same way as `remainder`,
+    /// and the quotient n used in the computation of the remainder.
+    /// The quotient is returned as an integer which has the sign of `self` / `d2`,
+    /// and the absolute value equal to the 31 least significant bits of |n|.
+    /// If the remainder is Inf or NaN, the quotient is 0.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// # use astro_float_num::BigFloat;
+    /// let (r, q) = BigFloat::from_f64(-10.5, 64).remquo(&BigFloat::from_f64(3.0, 64));
+    /// assert_eq!(r, BigFloat::from_f64(1.5, 64));
+    /// assert_eq!(q, -4);
+    /// ```
+    pub fn remquo(&self, d2: &Self) -> (Self, i32) {
+        match &self.inner {
+            Flavor::Value(v1) => match &d2.inner {
+                Flavor::Value(v2) => match v1.remquo(v2) {
+                    Ok((r, q)) => (
+                        BigFloat {
+                            inner: Flavor::Value(r),
+                        },
+                        q,
+                    ),
+                    Err(e) => (
+                        Self::result_to_ext(Err(e), v1.is_zero(), v1.sign() == v2.sign()),
+                        0,
+                    ),
+                },
+                Flavor::Inf(_) => (self.clone(), 0),
+                Flavor::NaN(err) => (Self::nan(*err), 0),
+            },
+            Flavor::Inf(_) => (NAN, 0),
+            Flavor::NaN(err) => (Self::nan(*err), 0),
+        }
+    }
+
+    /// Returns `self` rounded to an integer using the rounding mode of the context `ctx`.
+    /// See `round_to_integer` for details.
+    pub fn rint<T: Contextable>(&self, ctx: &T) -> Self {
+        self.round_to_integer(ctx.rounding_mode())
+    }
+
+    /// Converts `self` to an integer number exactly.
+    ///
+    /// ## Errors
+    ///
+    ///  - MemoryAllocation: failed to allocate memory.
+    ///  - InvalidArgument: `self` is not an integer, or `self` is Inf or NaN.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// # use astro_float_num::BigFloat;
+    /// # use astro_float_num::RoundingMode;
+    /// let n = BigFloat::from_f64(-2.5, 64);
+    /// assert!(n.to_integer().is_err());
+    ///
+    /// let i = n.round_to_integer(RoundingMode::ToEven).to_integer().unwrap();
+    /// assert_eq!(i.to_i64(), Some(-2));
+    /// ```
+    pub fn to_integer(&self) -> Result<BigInt, Error> {
+        if !self.is_int() {
+            return Err(Error::InvalidArgument);
+        }
+
+        BigInt::from_big_float(self, RoundingMode::None)
+    }
+
     /// Compares `self` to `d2`.
     /// Returns positive if `self` > `d2`, negative if `self` < `d2`, zero if `self` == `d2`, None if `self` or `d2` is NaN.
     #[allow(clippy::should_implement_trait)]
@@ -599,7 +692,9 @@ impl BigFloat {
     }
 
     /// Parses a number from the string `s`.
-    /// The function expects `s` to be a number in scientific format in base 10, or +-Inf, or NaN.
+    /// The function expects `s` to be a number in scientific format in radix `rdx`, or +-Inf, or NaN.
+    /// In radixes greater than 14 the character "e" is a digit, and the exponent part must be separated from the mantissa by "_".
+    /// In radixes where "i" or "n" are digits, "Inf" and "NaN" are recognized only if they constitute the whole string after the sign.
     /// if `p` equals to usize::MAX then the precision of the resulting number is determined automatically from the input.
     ///
     /// ## Examples
@@ -622,9 +717,139 @@ impl BigFloat {
     ///
     /// let n = BigFloat::parse("NaN", Radix::Oct, 2, RoundingMode::None, &mut cc);
     /// assert!(n.is_nan());
+    ///
+    /// let n = BigFloat::parse("z.1_e+1", 36u32, 64, RoundingMode::None, &mut cc);
+    /// assert_eq!(n, BigFloat::from_f64(1261.0, 64));
+    /// ```
+    pub fn parse(
+        s: &str,
+        rdx: impl Into<u32>,
+        p: usize,
+        rm: RoundingMode,
+        cc: &mut Consts,
+    ) -> Self {
+        Self::parse_from_iter(s.bytes(), rdx, p, rm, cc)
+    }
+
+    /// Parses a number from the sequence of bytes `bytes` in the same format as [`parse`](Self::parse).
+    /// The input is consumed one byte at a time and is never collected in full:
+    /// the mantissa digits are packed into words as they arrive,
+    /// so the memory required for parsing is close to the size of the resulting mantissa.
+    /// Parsing stops at the first byte which does not belong to the number.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// # use astro_float_num::BigFloat;
+    /// # use astro_float_num::Radix;
+    /// # use astro_float_num::RoundingMode;
+    /// # use astro_float_num::Consts;
+    /// let mut cc = Consts::new().expect("Constants cache initialized.");
+    ///
+    /// let digits = b"3.".iter().copied().chain(core::iter::repeat(b'3').take(1000));
+    /// let n = BigFloat::parse_from_iter(digits, Radix::Dec, 128, RoundingMode::ToEven, &mut cc);
+    /// assert_eq!(n, BigFloat::parse("3.333333333333333333333333333333333333333334", Radix::Dec, 128, RoundingMode::ToEven, &mut cc));
+    /// ```
+    pub fn parse_from_iter<I: IntoIterator<Item = u8>>(
+        bytes: I,
+        rdx: impl Into<u32>,
+        p: usize,
+        rm: RoundingMode,
+        cc: &mut Consts,
+    ) -> Self {
+        match crate::parser::parse_iter(bytes.into_iter(), rdx.into()) {
+            Ok(ps) => {
+                if ps.is_inf() {
+                    if ps.sign() == Sign::Pos {
+                        INF_POS
+                    } else {
+                        INF_NEG
+                    }
+                } else if ps.is_nan() {
+                    NAN
+                } else {
+                    let (m, s, e) = ps.into_raw_parts();
+                    Self::result_to_ext(
+                        BigFloatNumber::convert_from_packed(s, m, e, p, rm, cc),
+                        false,
+                        true,
+                    )
+                }
+            }
+            Err(e) => Self::nan(Some(e)),
+        }
+    }
+
+    /// Parses a number from the reader `r` in the same format as [`parse`](Self::parse).
+    /// The input is read in buffered chunks and is never collected in full.
+    /// Parsing stops at the first byte which does not belong to the number, or at the end of the input.
+    ///
+    /// ## Errors
+    ///
+    /// Returns the first error produced by the reader.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// # use astro_float_num::BigFloat;
+    /// # use astro_float_num::Radix;
+    /// # use astro_float_num::RoundingMode;
+    /// # use astro_float_num::Consts;
+    /// let mut cc = Consts::new().expect("Constants cache initialized.");
+    ///
+    /// let input: &[u8] = b"-1.5e+3";
+    /// let n = BigFloat::parse_from_reader(input, Radix::Dec, 64, RoundingMode::ToEven, &mut cc).unwrap();
+    /// assert_eq!(n, BigFloat::from_f64(-1500.0, 64));
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn parse_from_reader<R: std::io::Read>(
+        r: R,
+        rdx: impl Into<u32>,
+        p: usize,
+        rm: RoundingMode,
+        cc: &mut Consts,
+    ) -> std::io::Result<Self> {
+        use std::io::Read;
+
+        let mut err = None;
+        let bytes = std::io::BufReader::new(r).bytes().map_while(|b| match b {
+            Ok(b) => Some(b),
+            Err(e) => {
+                err = Some(e);
+                None
+            }
+        });
+
+        let ret = Self::parse_from_iter(bytes, rdx, p, rm, cc);
+
+        match err {
+            Some(e) => Err(e),
+            None => Ok(ret),
+        }
+    }
+
+    /// Parses a hexadecimal floating-point literal `s`, such as `0x1.921fb54442d18p+1`, as produced by C's `%a`,
+    /// MPFR's `%Ra`, or Julia. The mantissa is hexadecimal, the prefix `0x` is optional,
+    /// and the optional exponent after `p` is a power of 2 written in decimal.
+    /// The input is converted exactly and then rounded to precision `p` using rounding mode `rm`.
+    /// The function also accepts +-Inf and NaN, and does not require a constants cache.
+    /// Precision is rounded upwards to the word size.
+    /// if `p` equals to usize::MAX then the precision of the resulting number is determined automatically from the input.
+    /// The function returns NaN if the input can't be parsed or the precision `p` is incorrect.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// # use astro_float_num::BigFloat;
+    /// # use astro_float_num::RoundingMode;
+    /// let n = BigFloat::parse_hex_float("0x1.921fb54442d18p+1", 64, RoundingMode::None);
+    /// assert_eq!(n, BigFloat::from_f64(core::f64::consts::PI, 64));
+    ///
+    /// let n = BigFloat::parse_hex_float("-0x.8p-3", usize::MAX, RoundingMode::None);
+    /// assert_eq!(n, BigFloat::from_f64(-0.0625, 64));
     /// ```
-    pub fn parse(s: &str, rdx: Radix, p: usize, rm: RoundingMode, cc: &mut Consts) -> Self {
-        match crate::parser::parse(s, rdx) {
+    pub fn parse_hex_float(s: &str, p: usize, rm: RoundingMode) -> Self {
+        match crate::parser::parse_hex_float(s) {
             Ok(ps) => {
                 if ps.is_inf() {
                     if ps.sign() == Sign::Pos {
@@ -635,9 +860,9 @@ impl BigFloat {
                 } else if ps.is_nan() {
                     NAN
                 } else {
-                    let (m, s, e) = ps.raw_parts();
+                    let (m, s, e) = ps.into_raw_parts();
                     Self::result_to_ext(
-                        BigFloatNumber::convert_from_radix(s, m, e, rdx, p, rm, cc),
+                        BigFloatNumber::convert_from_hex_float(s, m, e, p, rm),
                         false,
                         true,
                     )
@@ -648,45 +873,100 @@ impl BigFloat {
     }
 
     #[cfg(feature = "std")]
-    pub(crate) fn write_str<T: Write>(
+    fn write_fmt_spec(
         &self,
-        w: &mut T,
+        f: &mut Formatter<'_>,
         rdx: Radix,
-        rm: RoundingMode,
+        style: FmtStyle,
         cc: &mut Consts,
     ) -> Result<(), core::fmt::Error> {
+        let rm = RoundingMode::ToEven;
+
         match &self.inner {
-            Flavor::Value(v) => match v.format(rdx, rm, cc) {
-                Ok(s) => w.write_str(&s),
-                Err(e) => match e {
-                    Error::ExponentOverflow(s) => {
-                        if s.is_positive() {
-                            w.write_str("Inf")
-                        } else {
-                            w.write_str("-Inf")
+            Flavor::Value(v) => {
+                let res = match (style, f.precision()) {
+                    (FmtStyle::Display, Some(n)) => {
+                        v.format_fixed(rdx, &FixedFormat::new(n), rm, cc)
+                    }
+                    (_, n) => v.format_sci(rdx, n, rm, cc),
+                };
+
+                match res {
+                    Ok(mut s) => {
+                        match style {
+                            FmtStyle::SciLower => s.make_ascii_lowercase(),
+                            FmtStyle::SciUpper => s.make_ascii_uppercase(),
+                            FmtStyle::Display | FmtStyle::Sci => {}
+                        };
+
+                        match s.strip_prefix('-') {
+                            Some(abs) => f.pad_integral(false, "", abs),
+                            None => f.pad_integral(true, "", &s),
                         }
                     }
-                    _ => w.write_str("Err"),
-                },
-            },
-            Flavor::Inf(sign) => {
-                let s = if sign.is_negative() { "-Inf" } else { "Inf" };
-                w.write_str(s)
+                    Err(Error::ExponentOverflow(s)) => Self::pad_special(f, "Inf", Some(s)),
+                    Err(_) => Self::pad_special(f, "Err", None),
+                }
             }
-            crate::ext::Flavor::NaN(_) => w.write_str("NaN"),
+            Flavor::Inf(s) => Self::pad_special(f, "Inf", Some(*s)),
+            Flavor::NaN(_) => Self::pad_special(f, "NaN", None),
+        }
+    }
+
+    // Writes a string representation of a value which is not a finite number,
+    // taking into account the sign flag, width, fill, and alignment of the formatter.
+    #[cfg(feature = "std")]
+    fn pad_special(
+        f: &mut Formatter<'_>,
+        s: &str,
+        sign: Option<Sign>,
+    ) -> Result<(), core::fmt::Error> {
+        let sign = match sign {
+            Some(Sign::Neg) => "-",
+            Some(Sign::Pos) if f.sign_plus() => "+",
+            _ => "",
+        };
+
+        let len = sign.len() + s.len();
+        let pad = f.width().map(|w| w.saturating_sub(len)).unwrap_or(0);
+
+        let (pre, post) = match f.align() {
+            Some(Alignment::Left) => (0, pad),
+            Some(Alignment::Center) => (pad / 2, pad - pad / 2),
+            Some(Alignment::Right) | None => (pad, 0),
+        };
+
+        let fill = f.fill();
+        for _ in 0..pre {
+            f.write_char(fill)?;
         }
+
+        f.write_str(sign)?;
+        f.write_str(s)?;
+
+        for _ in 0..post {
+            f.write_char(fill)?;
+        }
+
+        Ok(())
     }
 
     /// Formats the number using radix `rdx` and rounding mode `rm`.
     /// Note, since hexadecimal digits include the character "e", the exponent part is separated
-    /// from the mantissa by "_".
+    /// from the mantissa by "_". The same applies to all radixes greater than 14.
     /// For example, a number with mantissa `123abcdef` and exponent `123` would be formatted as `123abcdef_e+123`.
     ///
     /// ## Errors
     ///
     ///  - MemoryAllocation: failed to allocate memory for mantissa.
     ///  - ExponentOverflow: the resulting exponent becomes greater than the maximum allowed value for the exponent.
-    pub fn format(&self, rdx: Radix, rm: RoundingMode, cc: &mut Consts) -> Result<String, Error> {
+    ///  - InvalidArgument: the radix is not in the range from 2 to 36.
+    pub fn format(
+        &self,
+        rdx: impl Into<u32>,
+        rm: RoundingMode,
+        cc: &mut Consts,
+    ) -> Result<String, Error> {
         let s = match &self.inner {
             Flavor::Value(v) => match v.format(rdx, rm, cc) {
                 Ok(s) => return Ok(s),
@@ -718,64 +998,319 @@ impl BigFloat {
         Ok(ret)
     }
 
-    /// Returns a random normalized (not subnormal) BigFloat number with exponent in the range
-    /// from `exp_from` to `exp_to` inclusive. The sign can be positive and negative. Zero is excluded.
-    /// Precision is rounded upwards to the word size.
-    /// Function does not follow any specific distribution law.
-    /// The intended use of this function is for testing.
-    /// The function returns NaN if the precision `p` is incorrect or when `exp_from` is less than EXPONENT_MIN or `exp_to` is greater than EXPONENT_MAX.
-    #[cfg(feature = "random")]
-    pub fn random_normal(p: usize, exp_from: Exponent, exp_to: Exponent) -> Self {
-        Self::result_to_ext(
-            BigFloatNumber::random_normal(p, exp_from, exp_to),
-            false,
-            true,
-        )
-    }
-
-    /// Returns category of `self`.
-    pub fn classify(&self) -> FpCategory {
-        match &self.inner {
-            Flavor::Value(v) => {
-                if v.is_subnormal() {
-                    FpCategory::Subnormal
-                } else if v.is_zero() {
-                    FpCategory::Zero
+    /// Writes the number using radix `rdx` and rounding mode `rm` to `w`.
+    /// The output is the same as the output of [`format`](Self::format), but it is written in chunks
+    /// and is never collected in a single string, which is useful for numbers with millions of digits.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an error if `w` returns an error.
+    /// If the number can't be formatted, "Inf", "-Inf", or "Err" is written instead, similarly to `format`.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// # use astro_float_num::BigFloat;
+    /// # use astro_float_num::Radix;
+    /// # use astro_float_num::RoundingMode;
+    /// # use astro_float_num::Consts;
+    /// let mut cc = Consts::new().expect("Constants cache initialized.");
+    ///
+    /// let n = BigFloat::from_f64(-1.5e+3, 64);
+    /// let mut s = String::new();
+    /// n.format_to(&mut s, Radix::Dec, RoundingMode::ToEven, &mut cc).unwrap();
+    /// assert_eq!(s, n.format(Radix::Dec, RoundingMode::ToEven, &mut cc).unwrap());
+    /// ```
+    pub fn format_to<W: core::fmt::Write>(
+        &self,
+        w: &mut W,
+        rdx: impl Into<u32>,
+        rm: RoundingMode,
+        cc: &mut Consts,
+    ) -> core::fmt::Result {
+        let s = match &self.inner {
+            Flavor::Value(v) => match v.write_sci(w, rdx.into(), None, rm, cc) {
+                Ok(()) => return Ok(()),
+                Err(WriteError::Fmt) => return Err(core::fmt::Error),
+                Err(WriteError::Num(e)) => match e {
+                    Error::ExponentOverflow(s) => {
+                        if s.is_positive() {
+                            "Inf"
+                        } else {
+                            "-Inf"
+                        }
+                    }
+                    _ => "Err",
+                },
+            },
+            Flavor::Inf(sign) => {
+                if sign.is_negative() {
+                    "-Inf"
                 } else {
-                    FpCategory::Normal
+                    "Inf"
                 }
             }
-            Flavor::Inf(_) => FpCategory::Infinite,
-            Flavor::NaN(_) => FpCategory::Nan,
-        }
+            crate::ext::Flavor::NaN(_) => "NaN",
+        };
+
+        w.write_str(s)
     }
 
-    /// Computes the arctangent of a number with precision `p`. The result is rounded using the rounding mode `rm`.
-    /// This function requires constants cache `cc` for computing the result.
-    /// Precision is rounded upwards to the word size.
-    /// The function returns NaN if the precision `p` is incorrect.
-    pub fn atan(&self, p: usize, rm: RoundingMode, cc: &mut Consts) -> Self {
-        match &self.inner {
-            Flavor::Value(v) => Self::result_to_ext(v.atan(p, rm, cc), v.is_zero(), true),
-            Flavor::Inf(s) => Self::result_to_ext(Self::half_pi(*s, p, rm, cc), false, true),
-            Flavor::NaN(err) => Self::nan(*err),
+    /// Writes the number using radix `rdx` and rounding mode `rm` to the writer `w`.
+    /// The output is the same as the output of [`format`](Self::format), and is written in chunks.
+    ///
+    /// ## Errors
+    ///
+    /// Returns the error produced by the writer.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// # use astro_float_num::BigFloat;
+    /// # use astro_float_num::Radix;
+    /// # use astro_float_num::RoundingMode;
+    /// # use astro_float_num::Consts;
+    /// let mut cc = Consts::new().expect("Constants cache initialized.");
+    ///
+    /// let n = BigFloat::from_f64(0.75, 64);
+    /// let mut out = Vec::new();
+    /// n.format_to_io(&mut out, Radix::Bin, RoundingMode::ToEven, &mut cc).unwrap();
+    /// assert_eq!(out, b"1.1e-1");
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn format_to_io<W: std::io::Write>(
+        &self,
+        w: &mut W,
+        rdx: impl Into<u32>,
+        rm: RoundingMode,
+        cc: &mut Consts,
+    ) -> std::io::Result<()> {
+        // passes formatted chunks to the writer and keeps the error of the writer.
+        struct IoAdapter<'a, W: std::io::Write> {
+            w: &'a mut W,
+            err: Option<std::io::Error>,
         }
-    }
 
-    /// Computes the hyperbolic tangent of a number with precision `p`. The result is rounded using the rounding mode `rm`.
-    /// This function requires constants cache `cc` for computing the result.
-    /// Precision is rounded upwards to the word size.
-    /// The function returns NaN if the precision `p` is incorrect.
-    pub fn tanh(&self, p: usize, rm: RoundingMode, cc: &mut Consts) -> Self {
-        match &self.inner {
-            Flavor::Value(v) => Self::result_to_ext(v.tanh(p, rm, cc), v.is_zero(), true),
-            Flavor::Inf(s) => Self::from_i8(s.to_int(), p),
-            Flavor::NaN(err) => Self::nan(*err),
+        impl<W: std::io::Write> core::fmt::Write for IoAdapter<'_, W> {
+            fn write_str(&mut self, s: &str) -> core::fmt::Result {
+                self.w.write_all(s.as_bytes()).map_err(|e| {
+                    self.err = Some(e);
+                    core::fmt::Error
+                })
+            }
         }
+
+        let mut adapter = IoAdapter { w, err: None };
+
+        self.format_to(&mut adapter, rdx, rm, cc).map_err(|_| {
+            adapter
+                .err
+                .take()
+                .unwrap_or_else(|| std::io::Error::other("formatting error"))
+        })
     }
 
-    fn half_pi(
-        s: Sign,
+    /// Formats the number in scientific notation using radix `rdx` with `n` digits after the radix point.
+    /// The mantissa is rounded using rounding mode `rm`.
+    /// The exponent part follows the same conventions as in [`BigFloat::format`].
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// # use astro_float_num::{BigFloat, Consts, Radix, RoundingMode};
+    /// let mut cc = Consts::new().expect("Constants cache initialized.");
+    ///
+    /// let n = BigFloat::from_f64(123.456, 64);
+    ///
+    /// let s = n.format_sci(Radix::Dec, 3, RoundingMode::ToEven, &mut cc).expect("Formatted");
+    /// assert_eq!(s, "1.235e+2");
+    /// ```
+    ///
+    /// ## Errors
+    ///
+    ///  - MemoryAllocation: failed to allocate memory for mantissa.
+    ///  - ExponentOverflow: the resulting exponent becomes greater than the maximum allowed value for the exponent.
+    ///  - InvalidArgument: the radix is not in the range from 2 to 36.
+    pub fn format_sci(
+        &self,
+        rdx: impl Into<u32>,
+        n: usize,
+        rm: RoundingMode,
+        cc: &mut Consts,
+    ) -> Result<String, Error> {
+        match &self.inner {
+            Flavor::Value(v) => v.format_sci(rdx, Some(n), rm, cc),
+            _ => self.format(rdx, rm, cc),
+        }
+    }
+
+    /// Formats the number in fixed-point notation using radix `rdx` with exactly `n` digits after the radix point.
+    /// The result is rounded using rounding mode `rm`.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// # use astro_float_num::{BigFloat, Consts, Radix, RoundingMode};
+    /// let mut cc = Consts::new().expect("Constants cache initialized.");
+    ///
+    /// let n = BigFloat::from_f64(-1234.5678, 64);
+    ///
+    /// let s = n.format_fixed(Radix::Dec, 2, RoundingMode::ToEven, &mut cc).expect("Formatted");
+    /// assert_eq!(s, "-1234.57");
+    /// ```
+    ///
+    /// ## Errors
+    ///
+    ///  - MemoryAllocation: failed to allocate memory for mantissa.
+    ///  - ExponentOverflow: the resulting exponent becomes greater than the maximum allowed value for the exponent.
+    ///  - InvalidArgument: `n` is too large, or the radix is not in the range from 2 to 36.
+    pub fn format_fixed(
+        &self,
+        rdx: impl Into<u32>,
+        n: usize,
+        rm: RoundingMode,
+        cc: &mut Consts,
+    ) -> Result<String, Error> {
+        self.format_fixed_with(rdx, &FixedFormat::new(n), rm, cc)
+    }
+
+    /// Formats the number in fixed-point notation using radix `rdx` and formatting parameters `ff`,
+    /// which control the number of digits after the radix point, trailing zeroes, grouping of the digits of the integer part,
+    /// and the radix point character. The result is rounded using rounding mode `rm`.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// # use astro_float_num::{BigFloat, Consts, FixedFormat, Radix, RoundingMode};
+    /// let mut cc = Consts::new().expect("Constants cache initialized.");
+    ///
+    /// let n = BigFloat::from_f64(-1234567.25, 64);
+    ///
+    /// let mut ff = FixedFormat::new(4);
+    /// ff.set_pad_zeroes(false);
+    /// ff.set_group_separator(Some('.'));
+    /// ff.set_decimal_separator(',');
+    ///
+    /// let s = n.format_fixed_with(Radix::Dec, &ff, RoundingMode::ToEven, &mut cc).expect("Formatted");
+    /// assert_eq!(s, "-1.234.567,25");
+    /// ```
+    ///
+    /// ## Errors
+    ///
+    ///  - MemoryAllocation: failed to allocate memory for mantissa.
+    ///  - ExponentOverflow: the resulting exponent becomes greater than the maximum allowed value for the exponent.
+    ///  - InvalidArgument: the number of digits after the radix point is too large, or the radix is not in the range from 2 to 36.
+    pub fn format_fixed_with(
+        &self,
+        rdx: impl Into<u32>,
+        ff: &FixedFormat,
+        rm: RoundingMode,
+        cc: &mut Consts,
+    ) -> Result<String, Error> {
+        match &self.inner {
+            Flavor::Value(v) => v.format_fixed(rdx, ff, rm, cc),
+            _ => self.format(rdx, rm, cc),
+        }
+    }
+
+    /// Formats the number exactly as a hexadecimal floating-point literal, e.g. `0x1.921fb54442d18p+1`,
+    /// which can be read back by [`BigFloat::parse_hex_float`], C's `strtod`, MPFR, or Julia.
+    /// The first hexadecimal digit of a nonzero number is always 1, trailing zeroes are omitted,
+    /// and the exponent after `p` is a power of 2 written in decimal.
+    /// Infinity is formatted as `inf` or `-inf`, and NaN as `nan`.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// # use astro_float_num::BigFloat;
+    /// let n = BigFloat::from_f64(core::f64::consts::PI, 64);
+    /// assert_eq!(n.format_hex_float().expect("Formatted"), "0x1.921fb54442d18p+1");
+    ///
+    /// let n = BigFloat::from_f64(-0.0625, 64);
+    /// assert_eq!(n.format_hex_float().expect("Formatted"), "-0x1p-4");
+    /// ```
+    ///
+    /// ## Errors
+    ///
+    ///  - MemoryAllocation: failed to allocate memory for the string.
+    pub fn format_hex_float(&self) -> Result<String, Error> {
+        let s = match &self.inner {
+            Flavor::Value(v) => return v.format_hex_float(),
+            Flavor::Inf(sign) => {
+                if sign.is_negative() {
+                    "-inf"
+                } else {
+                    "inf"
+                }
+            }
+            Flavor::NaN(_) => "nan",
+        };
+
+        let mut ret = String::new();
+        ret.try_reserve_exact(s.len())?;
+        ret.push_str(s);
+
+        Ok(ret)
+    }
+
+    /// Returns a random normalized (not subnormal) BigFloat number with exponent in the range
+    /// from `exp_from` to `exp_to` inclusive. The sign can be positive and negative. Zero is excluded.
+    /// Precision is rounded upwards to the word size.
+    /// Function does not follow any specific distribution law.
+    /// The intended use of this function is for testing.
+    /// The function returns NaN if the precision `p` is incorrect or when `exp_from` is less than EXPONENT_MIN or `exp_to` is greater than EXPONENT_MAX.
+    #[cfg(feature = "random")]
+    pub fn random_normal(p: usize, exp_from: Exponent, exp_to: Exponent) -> Self {
+        Self::result_to_ext(
+            BigFloatNumber::random_normal(p, exp_from, exp_to),
+            false,
+            true,
+        )
+    }
+
+    /// Returns category of `self`.
+    pub fn classify(&self) -> FpCategory {
+        match &self.inner {
+            Flavor::Value(v) => {
+                if v.is_subnormal() {
+                    FpCategory::Subnormal
+                } else if v.is_zero() {
+                    FpCategory::Zero
+                } else {
+                    FpCategory::Normal
+                }
+            }
+            Flavor::Inf(_) => FpCategory::Infinite,
+            Flavor::NaN(_) => FpCategory::Nan,
+        }
+    }
+
+    /// Computes the arctangent of a number with precision `p`. The result is rounded using the rounding mode `rm`.
+    /// This function requires constants cache `cc` for computing the result.
+    /// Precision is rounded upwards to the word size.
+    /// The function returns NaN if the precision `p` is incorrect.
+    pub fn atan(&self, p: usize, rm: RoundingMode, cc: &mut Consts) -> Self {
+        match &self.inner {
+            Flavor::Value(v) => Self::result_to_ext(v.atan(p, rm, cc), v.is_zero(), true),
+            Flavor::Inf(s) => Self::result_to_ext(Self::half_pi(*s, p, rm, cc), false, true),
+            Flavor::NaN(err) => Self::nan(*err),
+        }
+    }
+
+    /// Computes the hyperbolic tangent of a number with precision `p`. The result is rounded using the rounding mode `rm`.
+    /// This function requires constants cache `cc` for computing the result.
+    /// Precision is rounded upwards to the word size.
+    /// The function returns NaN if the precision `p` is incorrect.
+    pub fn tanh(&self, p: usize, rm: RoundingMode, cc: &mut Consts) -> Self {
+        match &self.inner {
+            Flavor::Value(v) => Self::result_to_ext(v.tanh(p, rm, cc), v.is_zero(), true),
+            Flavor::Inf(s) => Self::from_i8(s.to_int(), p),
+            Flavor::NaN(err) => Self::nan(*err),
+        }
+    }
+
+    fn half_pi(
+        s: Sign,
         p: usize,
         rm: RoundingMode,
         cc: &mut Consts,
@@ -941,6 +1476,7 @@ impl BigFloat {
     /// Sets the exponent of `self`.
     /// Note that if `self` is subnormal, the exponent may not change, but the mantissa will shift instead.
     /// `e` will be clamped to the range from EXPONENT_MIN to EXPONENT_MAX if it's outside of the range.
+    /// Use `ldexp` to scale a number without clamping.
     /// See example below.
     ///
     /// ## Examples
@@ -968,6 +1504,100 @@ impl BigFloat {
         }
     }
 
+    /// Decomposes `self` into a normalized fraction and an exponent.
+    /// The absolute value of the fraction is greater than or equal to 0.5 and less than 1,
+    /// and `self` equals the fraction multiplied by 2 to the power of the exponent.
+    /// Subnormal numbers are normalized, and their exponent can be less than EXPONENT_MIN.
+    /// If `self` is zero, Inf, or NaN, the function returns a copy of `self` and 0.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// # use astro_float_num::BigFloat;
+    /// # use astro_float_num::EXPONENT_MIN;
+    /// let (f, e) = BigFloat::from_f64(-12.0, 64).frexp();
+    /// assert_eq!(f, BigFloat::from_f64(-0.75, 64));
+    /// assert_eq!(e, 4);
+    ///
+    /// // subnormal
+    /// let (f, e) = BigFloat::min_positive(128).frexp();
+    /// assert_eq!(f, BigFloat::from_f64(0.5, 128));
+    /// assert_eq!(e, EXPONENT_MIN as isize - 127);
+    /// ```
+    pub fn frexp(&self) -> (Self, isize) {
+        match &self.inner {
+            Flavor::Value(v) => match v.frexp() {
+                Ok((f, e)) => (
+                    BigFloat {
+                        inner: Flavor::Value(f),
+                    },
+                    e,
+                ),
+                Err(e) => (Self::result_to_ext(Err(e), false, true), 0),
+            },
+            _ => (self.clone(), 0),
+        }
+    }
+
+    /// Multiplies `self` by 2 to the power of `n`.
+    /// The mantissa is not changed unless the result is subnormal, in which case it is rounded using rounding mode `rm`.
+    /// Unlike `set_exponent`, the exponent is not clamped: the function returns Inf if the result is too large.
+    /// Inf and NaN are returned unchanged.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// # use astro_float_num::BigFloat;
+    /// # use astro_float_num::RoundingMode;
+    /// # use astro_float_num::EXPONENT_MAX;
+    /// let (f, e) = BigFloat::from_f64(-12.0, 64).frexp();
+    /// assert_eq!(f.ldexp(e, RoundingMode::None), BigFloat::from_f64(-12.0, 64));
+    ///
+    /// assert!(f.ldexp(EXPONENT_MAX as isize + 1, RoundingMode::None).is_inf_neg());
+    /// ```
+    pub fn ldexp(&self, n: isize, rm: RoundingMode) -> Self {
+        match &self.inner {
+            Flavor::Value(v) => Self::result_to_ext(v.ldexp(n, rm), false, true),
+            _ => self.clone(),
+        }
+    }
+
+    /// Multiplies `self` by 2 to the power of `n`. This function is the same as `ldexp`.
+    pub fn scalbn(&self, n: isize, rm: RoundingMode) -> Self {
+        self.ldexp(n, rm)
+    }
+
+    /// Returns the exponent of `self` as an integer `n` such that the absolute value of `self`
+    /// is greater than or equal to 2^`n` and less than 2^(`n` + 1).
+    /// Subnormal numbers are taken into account, so `n` can be less than EXPONENT_MIN.
+    /// The function returns None if `self` is zero, Inf, or NaN.
+    pub fn ilogb(&self) -> Option<isize> {
+        match &self.inner {
+            Flavor::Value(v) => v.ilogb(),
+            _ => None,
+        }
+    }
+
+    /// Returns `self` as an exact ratio of an integer numerator and a positive denominator which is a power of 2.
+    /// The ratio is reduced to its lowest terms, and the denominator is 1 if `self` is an integer.
+    ///
+    /// ## Errors
+    ///
+    ///  - MemoryAllocation: failed to allocate memory.
+    ///  - InvalidArgument: `self` is Inf or NaN.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// # use astro_float_num::BigFloat;
+    /// let (n, d) = BigFloat::from_f64(-2.375, 64).to_integer_ratio().unwrap();
+    /// assert_eq!(n.to_i64(), Some(-19));
+    /// assert_eq!(d.to_u64(), Some(8));
+    /// ```
+    pub fn to_integer_ratio(&self) -> Result<(BigInt, BigUint), Error> {
+        BigRational::from_big_float(self).map(|r| r.into_parts())
+    }
+
     /// Returns the maximum mantissa length of `self` in bits regardless of whether `self` is normal or subnormal.
     pub fn mantissa_max_bit_len(&self) -> Option<usize> {
         if let Flavor::Value(v) = &self.inner {
@@ -992,6 +1622,89 @@ impl BigFloat {
         }
     }
 
+    /// Computes the factorial of `n` with precision `p`.
+    /// The result is rounded using the rounding mode `rm`.
+    /// Precision is rounded upwards to the word size.
+    /// The function returns NaN if the precision `p` is incorrect, and Inf if the result is too large.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// # use astro_float_num::BigFloat;
+    /// # use astro_float_num::RoundingMode;
+    /// let f = BigFloat::factorial(20, 64, RoundingMode::ToEven);
+    /// assert_eq!(f, BigFloat::from_u64(2432902008176640000, 64));
+    ///
+    /// let c = BigFloat::binomial(50, 25, 64, RoundingMode::ToEven);
+    /// assert_eq!(c, BigFloat::from_u64(126410606437752, 64));
+    /// ```
+    pub fn factorial(n: usize, p: usize, rm: RoundingMode) -> Self {
+        Self::result_to_ext(BigFloatNumber::factorial(n, p, rm), false, true)
+    }
+
+    /// Computes the binomial coefficient of `n` and `k` with precision `p`.
+    /// The result is zero if `k` is greater than `n`.
+    /// The result is rounded using the rounding mode `rm`.
+    /// Precision is rounded upwards to the word size.
+    /// The function returns NaN if the precision `p` is incorrect, and Inf if the result is too large.
+    pub fn binomial(n: usize, k: usize, p: usize, rm: RoundingMode) -> Self {
+        Self::result_to_ext(BigFloatNumber::binomial(n, k, p, rm), false, true)
+    }
+
+    /// Computes the rising factorial (the Pochhammer symbol) `self` * (`self` + 1) * ... * (`self` + `n` - 1)
+    /// with precision `p`. The result is rounded using the rounding mode `rm`.
+    /// If the exact result fits the precision, it is returned without rounding.
+    /// Precision is rounded upwards to the word size.
+    /// The function returns NaN if the precision `p` is incorrect.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// # use astro_float_num::BigFloat;
+    /// # use astro_float_num::RoundingMode;
+    /// let x = BigFloat::from_f64(0.5, 64);
+    /// let r = x.rising_factorial(3, 64, RoundingMode::ToEven);
+    /// assert_eq!(r, BigFloat::from_f64(1.875, 64));
+    /// assert!(!r.inexact());
+    /// ```
+    pub fn rising_factorial(&self, n: usize, p: usize, rm: RoundingMode) -> Self {
+        self.pochhammer(n, p, rm, false)
+    }
+
+    /// Computes the falling factorial `self` * (`self` - 1) * ... * (`self` - `n` + 1)
+    /// with precision `p`. The result is rounded using the rounding mode `rm`.
+    /// If the exact result fits the precision, it is returned without rounding.
+    /// Precision is rounded upwards to the word size.
+    /// The function returns NaN if the precision `p` is incorrect.
+    pub fn falling_factorial(&self, n: usize, p: usize, rm: RoundingMode) -> Self {
+        self.pochhammer(n, p, rm, true)
+    }
+
+    fn pochhammer(&self, n: usize, p: usize, rm: RoundingMode, falling: bool) -> Self {
+        match &self.inner {
+            Flavor::Value(v) => Self::result_to_ext(
+                if falling {
+                    v.falling_factorial(n, p, rm)
+                } else {
+                    v.rising_factorial(n, p, rm)
+                },
+                false,
+                true,
+            ),
+            Flavor::Inf(s) => {
+                if n == 0 {
+                    Self::from_word(1, p)
+                } else if s.is_positive() || n & 1 == 0 {
+                    // all factors are infinite and have the same sign.
+                    INF_POS
+                } else {
+                    INF_NEG
+                }
+            }
+            Flavor::NaN(err) => Self::nan(*err),
+        }
+    }
+
     /// Computes the reciprocal of a number with precision `p`.
     /// The result is rounded using the rounding mode `rm`.
     /// Precision is rounded upwards to the word size.
@@ -1067,7 +1780,7 @@ impl BigFloat {
         sign: Sign,
         digits: &[u8],
         e: Exponent,
-        rdx: Radix,
+        rdx: impl Into<u32>,
         p: usize,
         rm: RoundingMode,
         cc: &mut Consts,
@@ -1106,7 +1819,7 @@ impl BigFloat {
     ///  - InvalidArgument: `self` is Inf or NaN.
     pub fn convert_to_radix(
         &self,
-        rdx: Radix,
+        rdx: impl Into<u32>,
         rm: RoundingMode,
         cc: &mut Consts,
     ) -> Result<(Sign, Vec<u8>, Exponent), Error> {
@@ -1210,137 +1923,595 @@ macro_rules! gen_wrapper_arg_rm_cc {
                 Flavor::NaN(err) => Self::nan(*err),
             }
         }
-    };
-}
+    };
+}
+
+macro_rules! gen_wrapper_log {
+    ($comment:literal, $fname:ident, $ret:ty, $pos_inf:block, $neg_inf:block, $($arg:ident, $arg_type:ty),*) => {
+        #[doc=$comment]
+        pub fn $fname(&self$(,$arg: $arg_type)*, rm: RoundingMode, cc: &mut Consts) -> $ret {
+            match &self.inner {
+                Flavor::Value(v) => {
+                    if v.is_zero() {
+                        return INF_NEG;
+                    }
+                    Self::result_to_ext(v.$fname($($arg,)* rm, cc), v.is_zero(), true)
+                },
+                Flavor::Inf(s) => if s.is_positive() $pos_inf else $neg_inf,
+                Flavor::NaN(err) => Self::nan(*err),
+            }
+        }
+    };
+}
+
+macro_rules! gen_wrapper_bessel {
+    // function of the order `nu` and the argument `self`
+    ($comment:literal, $fname:ident, $pos_inf:expr, $neg_inf:expr) => {
+        #[doc=$comment]
+        pub fn $fname(&self, nu: &Self, p: usize, rm: RoundingMode, cc: &mut Consts) -> Self {
+            match (&self.inner, &nu.inner) {
+                (Flavor::NaN(err), _) | (_, Flavor::NaN(err)) => Self::nan(*err),
+                (Flavor::Value(v), Flavor::Value(n)) => {
+                    Self::result_to_ext(v.$fname(n, p, rm, cc), v.is_zero(), true)
+                }
+                (Flavor::Inf(s), Flavor::Value(n)) => {
+                    if s.is_positive() {
+                        $pos_inf(n, p)
+                    } else {
+                        $neg_inf(n, p)
+                    }
+                }
+                (_, Flavor::Inf(_)) => NAN,
+            }
+        }
+    };
+}
+
+impl BigFloat {
+    gen_wrapper_arg!(
+        "Returns the absolute value of `self`.",
+        abs,
+        Self,
+        { INF_POS },
+        { INF_POS },
+    );
+    gen_wrapper_arg!("Returns the integer part of `self`.", int, Self, { NAN }, {
+        NAN
+    },);
+    gen_wrapper_arg!(
+        "Returns the fractional part of `self`.",
+        fract,
+        Self,
+        { NAN },
+        { NAN },
+    );
+    gen_wrapper_arg!(
+        "Returns the smallest integer greater than or equal to `self`.",
+        ceil,
+        Self,
+        { INF_POS },
+        { INF_NEG },
+    );
+    gen_wrapper_arg!(
+        "Returns the largest integer less than or equal to `self`.",
+        floor,
+        Self,
+        { INF_POS },
+        { INF_NEG },
+    );
+    gen_wrapper_arg_rm!("Returns the rounded number with `n` binary positions in the fractional part of the number using rounding mode `rm`.", 
+        round,
+        Self,
+        { INF_POS },
+        { INF_NEG },
+        n,
+        usize
+    );
+    gen_wrapper_arg_rm!(
+        "Returns `self` rounded to an integer using rounding mode `rm`. `RoundingMode::ToEven` and `RoundingMode::ToOdd` round to the nearest integer with the corresponding rule for ties, other rounding modes are directed, and `RoundingMode::None` truncates the fractional part.",
+        round_to_integer,
+        Self,
+        { INF_POS },
+        { INF_NEG },
+    );
+    gen_wrapper_arg!(
+        "Returns the integer part of `self`. Unlike `int`, the function returns Inf if `self` is Inf.",
+        trunc,
+        Self,
+        { INF_POS },
+        { INF_NEG },
+    );
+    gen_wrapper_arg_rm!(
+        "Computes the square root of a number with precision `p`. The result is rounded using the rounding mode `rm`.
+        Precision is rounded upwards to the word size. The function returns NaN if the precision `p` is incorrect.",
+        sqrt,
+        Self,
+        { INF_POS },
+        { NAN },
+        p,
+        usize
+    );
+    gen_wrapper_arg_rm!(
+        "Computes the cube root of a number with precision `p`. The result is rounded using the rounding mode `rm`.
+        Precision is rounded upwards to the word size. The function returns NaN if the precision `p` is incorrect.",
+        cbrt,
+        Self,
+        { INF_POS },
+        { INF_NEG },
+        p,
+        usize
+    );
+    gen_wrapper_arg_rm!(
+        "Computes the reciprocal of the square root of a number with precision `p`. The result is rounded using the rounding mode `rm`.
+        Precision is rounded upwards to the word size. The function returns NaN if the precision `p` is incorrect.",
+        rec_sqrt,
+        Self,
+        { BigFloat::new(p) },
+        { NAN },
+        p,
+        usize
+    );
+    gen_wrapper_arg_rm_cc!(
+        "Computes the root of degree `n` of a number with precision `p`. The result is rounded using the rounding mode `rm`.
+        Roots of odd degree of negative numbers are negative. If the root is exactly representable with precision `p`, the exact value is returned.
+        This function requires constants cache `cc` for computing the result.
+        Precision is rounded upwards to the word size. The function returns NaN if `n` is zero, or the precision `p` is incorrect.",
+        root,
+        Self,
+        {
+            if n > 0 {
+                INF_POS
+            } else {
+                NAN
+            }
+        },
+        {
+            if n & 1 == 1 {
+                INF_NEG
+            } else {
+                NAN
+            }
+        },
+        n,
+        usize,
+        p,
+        usize
+    );
+    gen_wrapper_log!(
+        "Computes the natural logarithm of a number with precision `p`. The result is rounded using the rounding mode `rm`.
+        This function requires constants cache `cc` for computing the result.
+        Precision is rounded upwards to the word size. The function returns NaN if the precision `p` is incorrect.",
+        ln,
+        Self,
+        { INF_POS },
+        { NAN },
+        p,
+        usize
+    );
+    gen_wrapper_log!(
+        "Computes the logarithm base 2 of a number with precision `p`. The result is rounded using the rounding mode `rm`.
+        This function requires constants cache `cc` for computing the result.
+        Precision is rounded upwards to the word size. The function returns NaN if the precision `p` is incorrect.",
+        log2,
+        Self,
+        { INF_POS },
+        { NAN },
+        p,
+        usize
+    );
+    gen_wrapper_log!(
+        "Computes the logarithm base 10 of a number with precision `p`. The result is rounded using the rounding mode `rm`.
+        This function requires constants cache `cc` for computing the result.
+        Precision is rounded upwards to the word size. The function returns NaN if the precision `p` is incorrect.",
+        log10,
+        Self,
+        { INF_POS },
+        { NAN },
+        p,
+        usize
+    );
+    gen_wrapper_arg_rm_cc!(
+        "Computes `e` to the power of `self` with precision `p`. The result is rounded using the rounding mode `rm`.
+        This function requires constants cache `cc` for computing the result.
+        Precision is rounded upwards to the word size. The function returns NaN if the precision `p` is incorrect.",
+        exp,
+        Self,
+        { INF_POS },
+        { INF_NEG },
+        p,
+        usize
+    );
+    gen_wrapper_arg_rm_cc!(
+        "Computes 2 to the power of `self` with precision `p`. The result is rounded using the rounding mode `rm`.
+        If `self` is an integer number, the result is exact.
+        This function requires constants cache `cc` for computing the result.
+        Precision is rounded upwards to the word size. The function returns NaN if the precision `p` is incorrect.",
+        exp2,
+        Self,
+        { INF_POS },
+        { Self::new(p) },
+        p,
+        usize
+    );
+    gen_wrapper_arg_rm_cc!(
+        "Computes 10 to the power of `self` with precision `p`. The result is rounded using the rounding mode `rm`.
+        If `self` is an integer number, and the result is exactly representable with precision `p`, the exact value is returned.
+        This function requires constants cache `cc` for computing the result.
+        Precision is rounded upwards to the word size. The function returns NaN if the precision `p` is incorrect.",
+        exp10,
+        Self,
+        { INF_POS },
+        { Self::new(p) },
+        p,
+        usize
+    );
+    gen_wrapper_arg_rm_cc!(
+        "Computes the principal branch of the Lambert W function of a number with precision `p`. The result is rounded using the rounding mode `rm`.
+        The principal branch is the solution `w` >= -1 of the equation `w*e^w = self`, and it is defined for `self` >= -1/e.
+        This function requires constants cache `cc` for computing the result.
+        Precision is rounded upwards to the word size. The function returns NaN if the precision `p` is incorrect.",
+        lambert_w0,
+        Self,
+        { INF_POS },
+        { NAN },
+        p,
+        usize
+    );
+    gen_wrapper_arg_rm_cc!(
+        "Computes the lower branch of the Lambert W function of a number with precision `p`. The result is rounded using the rounding mode `rm`.
+        The lower branch is the solution `w` <= -1 of the equation `w*e^w = self`, and it is defined for -1/e <= `self` < 0.
+        This function requires constants cache `cc` for computing the result.
+        Precision is rounded upwards to the word size. The function returns NaN if the precision `p` is incorrect.",
+        lambert_wm1,
+        Self,
+        { NAN },
+        { NAN },
+        p,
+        usize
+    );
+    gen_wrapper_bessel!(
+        "Computes the Bessel function of the first kind J of order `nu` of a number with precision `p`.
+        The result is rounded using the rounding mode `rm`.
+        The order can be any real number, but for a negative argument it must be an integer.
+        This function requires constants cache `cc` for computing the result.
+        Precision is rounded upwards to the word size. The function returns NaN if the precision `p` is incorrect.",
+        bessel_j,
+        |_: &BigFloatNumber, p: usize| Self::new(p),
+        |n: &BigFloatNumber, p: usize| if n.is_int() { Self::new(p) } else { NAN }
+    );
+    gen_wrapper_bessel!(
+        "Computes the Bessel function of the second kind Y of order `nu` of a number with precision `p`.
+        The result is rounded using the rounding mode `rm`.
+        The order can be any real number, and the argument must be positive.
+        This function requires constants cache `cc` for computing the result.
+        Precision is rounded upwards to the word size. The function returns NaN if the precision `p` is incorrect.",
+        bessel_y,
+        |_: &BigFloatNumber, p: usize| Self::new(p),
+        |_: &BigFloatNumber, _p: usize| NAN
+    );
+    gen_wrapper_bessel!(
+        "Computes the modified Bessel function of the first kind I of order `nu` of a number with precision `p`.
+        The result is rounded using the rounding mode `rm`.
+        The order can be any real number, but for a negative argument it must be an integer.
+        This function requires constants cache `cc` for computing the result.
+        Precision is rounded upwards to the word size. The function returns NaN if the precision `p` is incorrect.",
+        bessel_i,
+        |_: &BigFloatNumber, _p: usize| INF_POS,
+        |n: &BigFloatNumber, _p: usize| if !n.is_int() {
+            NAN
+        } else if n.is_odd_int() && !n.is_zero() {
+            INF_NEG
+        } else {
+            INF_POS
+        }
+    );
+    gen_wrapper_bessel!(
+        "Computes the modified Bessel function of the second kind K of order `nu` of a number with precision `p`.
+        The result is rounded using the rounding mode `rm`.
+        The order can be any real number, and the argument must be positive.
+        This function requires constants cache `cc` for computing the result.
+        Precision is rounded upwards to the word size. The function returns NaN if the precision `p` is incorrect.",
+        bessel_k,
+        |_: &BigFloatNumber, p: usize| Self::new(p),
+        |_: &BigFloatNumber, _p: usize| NAN
+    );
+
+    gen_wrapper_arg_rm_cc!(
+        "Computes the sine of a number with precision `p`. The result is rounded using the rounding mode `rm`.
+        This function requires constants cache `cc` for computing the result.
+        Precision is rounded upwards to the word size. The function returns NaN if the precision `p` is incorrect.",
+        sin,
+        Self,
+        { NAN },
+        { NAN },
+        p,
+        usize
+    );
+    gen_wrapper_arg_rm_cc!(
+        "Computes the cosine of a number with precision `p`. The result is rounded using the rounding mode `rm`.
+        This function requires constants cache `cc` for computing the result.
+        Precision is rounded upwards to the word size. The function returns NaN if the precision `p` is incorrect.",
+        cos,
+        Self,
+        { NAN },
+        { NAN },
+        p,
+        usize
+    );
+    gen_wrapper_arg_rm_cc!(
+        "Computes the tangent of a number with precision `p`. The result is rounded using the rounding mode `rm`.
+        This function requires constants cache `cc` for computing the result.
+        Precision is rounded upwards to the word size. The function returns NaN if the precision `p` is incorrect.",
+        tan,
+        Self,
+        { NAN },
+        { NAN },
+        p,
+        usize
+    );
+    gen_wrapper_arg_rm_cc!(
+        "Computes the arcsine of a number with precision `p`. The result is rounded using the rounding mode `rm`.
+        This function requires constants cache `cc` for computing the result.
+        Precision is rounded upwards to the word size. The function returns NaN if the precision `p` is incorrect.", 
+        asin,
+        Self,
+        {NAN},
+        {NAN},
+        p,
+        usize
+    );
+    gen_wrapper_arg_rm_cc!(
+        "Computes the arccosine of a number with precision `p`. The result is rounded using the rounding mode `rm`.
+        This function requires constants cache `cc` for computing the result.
+        Precision is rounded upwards to the word size. The function returns NaN if the precision `p` is incorrect.",
+        acos,
+        Self,
+        { NAN },
+        { NAN },
+        p,
+        usize
+    );
+
+    gen_wrapper_arg_rm_cc!(
+        "Computes the hyperbolic sine of a number with precision `p`. The result is rounded using the rounding mode `rm`.
+        This function requires constants cache cc for computing the result. 
+        Precision is rounded upwards to the word size. The function returns NaN if the precision `p` is incorrect.",
+        sinh,
+        Self,
+        { INF_POS },
+        { INF_NEG },
+        p,
+        usize
+    );
+    gen_wrapper_arg_rm_cc!(
+        "Computes the hyperbolic cosine of a number with precision `p`. The result is rounded using the rounding mode `rm`.
+        This function requires constants cache cc for computing the result. 
+        Precision is rounded upwards to the word size. The function returns NaN if the precision `p` is incorrect.",
+        cosh,
+        Self,
+        { INF_POS },
+        { INF_POS },
+        p,
+        usize
+    );
+
+    /// Computes the sine and the cosine of a number with precision `p`. The results are rounded using the rounding mode `rm`.
+    /// The argument reduction and the series evaluation are shared by both results,
+    /// which is faster than calling `sin` and `cos` separately.
+    /// This function requires constants cache `cc` for computing the result.
+    /// Precision is rounded upwards to the word size. The function returns NaN if the precision `p` is incorrect.
+    pub fn sin_cos(&self, p: usize, rm: RoundingMode, cc: &mut Consts) -> (Self, Self) {
+        match &self.inner {
+            Flavor::Value(v) => match v.sin_cos(p, rm, cc) {
+                Ok((s, c)) => (s.into(), c.into()),
+                Err(e) => (
+                    Self::result_to_ext(Err(e), v.is_zero(), true),
+                    Self::result_to_ext(Err(e), v.is_zero(), true),
+                ),
+            },
+            Flavor::Inf(_) => (NAN, NAN),
+            Flavor::NaN(err) => (Self::nan(*err), Self::nan(*err)),
+        }
+    }
 
-macro_rules! gen_wrapper_log {
-    ($comment:literal, $fname:ident, $ret:ty, $pos_inf:block, $neg_inf:block, $($arg:ident, $arg_type:ty),*) => {
-        #[doc=$comment]
-        pub fn $fname(&self$(,$arg: $arg_type)*, rm: RoundingMode, cc: &mut Consts) -> $ret {
-            match &self.inner {
-                Flavor::Value(v) => {
-                    if v.is_zero() {
-                        return INF_NEG;
-                    }
-                    Self::result_to_ext(v.$fname($($arg,)* rm, cc), v.is_zero(), true)
+    /// Computes the hyperbolic sine and the hyperbolic cosine of a number with precision `p`.
+    /// The results are rounded using the rounding mode `rm`. The exponent is computed only once for both results,
+    /// which is faster than calling `sinh` and `cosh` separately.
+    /// This function requires constants cache `cc` for computing the result.
+    /// Precision is rounded upwards to the word size. The function returns NaN if the precision `p` is incorrect.
+    pub fn sinh_cosh(&self, p: usize, rm: RoundingMode, cc: &mut Consts) -> (Self, Self) {
+        match &self.inner {
+            Flavor::Value(v) => match v.sinh_cosh(p, rm, cc) {
+                Ok((s, c)) => (s.into(), c.into()),
+                Err(Error::ExponentOverflow(s)) => (
+                    BigFloat {
+                        inner: Flavor::Inf(s),
+                    },
+                    INF_POS,
+                ),
+                Err(e) => (
+                    Self::result_to_ext(Err(e), v.is_zero(), true),
+                    Self::result_to_ext(Err(e), v.is_zero(), true),
+                ),
+            },
+            Flavor::Inf(s) => (
+                BigFloat {
+                    inner: Flavor::Inf(*s),
                 },
-                Flavor::Inf(s) => if s.is_positive() $pos_inf else $neg_inf,
-                Flavor::NaN(err) => Self::nan(*err),
-            }
+                INF_POS,
+            ),
+            Flavor::NaN(err) => (Self::nan(*err), Self::nan(*err)),
         }
-    };
-}
-
-impl BigFloat {
-    gen_wrapper_arg!(
-        "Returns the absolute value of `self`.",
-        abs,
+    }
+    gen_wrapper_arg_rm_cc!(
+        "Computes the secant of a number with precision `p`. The result is rounded using the rounding mode `rm`.
+        This function requires constants cache `cc` for computing the result.
+        Precision is rounded upwards to the word size. The function returns NaN if the precision `p` is incorrect.",
+        sec,
         Self,
-        { INF_POS },
-        { INF_POS },
+        { NAN },
+        { NAN },
+        p,
+        usize
     );
-    gen_wrapper_arg!("Returns the integer part of `self`.", int, Self, { NAN }, {
-        NAN
-    },);
-    gen_wrapper_arg!(
-        "Returns the fractional part of `self`.",
-        fract,
+    gen_wrapper_arg_rm_cc!(
+        "Computes the cosecant of a number with precision `p`. The result is rounded using the rounding mode `rm`.
+        This function requires constants cache `cc` for computing the result.
+        Precision is rounded upwards to the word size. The function returns NaN if the precision `p` is incorrect.",
+        csc,
         Self,
         { NAN },
         { NAN },
+        p,
+        usize
     );
-    gen_wrapper_arg!(
-        "Returns the smallest integer greater than or equal to `self`.",
-        ceil,
+    gen_wrapper_arg_rm_cc!(
+        "Computes the cotangent of a number with precision `p`. The result is rounded using the rounding mode `rm`.
+        This function requires constants cache `cc` for computing the result.
+        Precision is rounded upwards to the word size. The function returns NaN if the precision `p` is incorrect.",
+        cot,
         Self,
-        { INF_POS },
-        { INF_NEG },
+        { NAN },
+        { NAN },
+        p,
+        usize
     );
-    gen_wrapper_arg!(
-        "Returns the largest integer less than or equal to `self`.",
-        floor,
+    /// Computes the arcsecant of a number with precision `p`. The result is rounded using the rounding mode `rm`.
+    /// This function requires constants cache `cc` for computing the result.
+    /// Precision is rounded upwards to the word size. The function returns NaN if the precision `p` is incorrect.
+    pub fn asec(&self, p: usize, rm: RoundingMode, cc: &mut Consts) -> Self {
+        match &self.inner {
+            Flavor::Value(v) => Self::result_to_ext(v.asec(p, rm, cc), v.is_zero(), true),
+            Flavor::Inf(_) => Self::result_to_ext(Self::half_pi(Sign::Pos, p, rm, cc), false, true),
+            Flavor::NaN(err) => Self::nan(*err),
+        }
+    }
+    gen_wrapper_arg_rm_cc!(
+        "Computes the arccosecant of a number with precision `p`. The result is rounded using the rounding mode `rm`.
+        This function requires constants cache `cc` for computing the result.
+        Precision is rounded upwards to the word size. The function returns NaN if the precision `p` is incorrect.",
+        acsc,
         Self,
-        { INF_POS },
-        { INF_NEG },
+        { BigFloat::new(p) },
+        { -BigFloat::new(p) },
+        p,
+        usize
     );
-    gen_wrapper_arg_rm!("Returns the rounded number with `n` binary positions in the fractional part of the number using rounding mode `rm`.", 
-        round,
+    gen_wrapper_arg_rm_cc!(
+        "Computes the arccotangent of a number with precision `p`. The result is rounded using the rounding mode `rm`.
+        This function requires constants cache `cc` for computing the result.
+        Precision is rounded upwards to the word size. The function returns NaN if the precision `p` is incorrect.",
+        acot,
         Self,
-        { INF_POS },
-        { INF_NEG },
-        n,
+        { BigFloat::new(p) },
+        { -BigFloat::new(p) },
+        p,
         usize
     );
-    gen_wrapper_arg_rm!(
-        "Computes the square root of a number with precision `p`. The result is rounded using the rounding mode `rm`.
+    gen_wrapper_arg_rm_cc!(
+        "Computes the hyperbolic secant of a number with precision `p`. The result is rounded using the rounding mode `rm`.
+        This function requires constants cache `cc` for computing the result.
         Precision is rounded upwards to the word size. The function returns NaN if the precision `p` is incorrect.",
-        sqrt,
+        sech,
         Self,
-        { INF_POS },
-        { NAN },
+        { BigFloat::new(p) },
+        { BigFloat::new(p) },
         p,
         usize
     );
-    gen_wrapper_arg_rm!(
-        "Computes the cube root of a number with precision `p`. The result is rounded using the rounding mode `rm`.
+    gen_wrapper_arg_rm_cc!(
+        "Computes the hyperbolic cosecant of a number with precision `p`. The result is rounded using the rounding mode `rm`.
+        This function requires constants cache `cc` for computing the result.
         Precision is rounded upwards to the word size. The function returns NaN if the precision `p` is incorrect.",
-        cbrt,
+        csch,
         Self,
-        { INF_POS },
-        { INF_NEG },
+        { BigFloat::new(p) },
+        { -BigFloat::new(p) },
         p,
         usize
     );
-    gen_wrapper_log!(
-        "Computes the natural logarithm of a number with precision `p`. The result is rounded using the rounding mode `rm`.
+    gen_wrapper_arg_rm_cc!(
+        "Computes the hyperbolic cotangent of a number with precision `p`. The result is rounded using the rounding mode `rm`.
         This function requires constants cache `cc` for computing the result.
         Precision is rounded upwards to the word size. The function returns NaN if the precision `p` is incorrect.",
-        ln,
+        coth,
+        Self,
+        { BigFloat::from_i8(1, p) },
+        { BigFloat::from_i8(-1, p) },
+        p,
+        usize
+    );
+    gen_wrapper_arg_rm_cc!(
+        "Computes the hyperbolic arcsecant of a number with precision `p`. The result is rounded using the rounding mode `rm`.
+        This function requires constants cache `cc` for computing the result.
+        Precision is rounded upwards to the word size. The function returns NaN if the precision `p` is incorrect.",
+        asech,
         Self,
-        { INF_POS },
+        { NAN },
         { NAN },
         p,
         usize
     );
-    gen_wrapper_log!(
-        "Computes the logarithm base 2 of a number with precision `p`. The result is rounded using the rounding mode `rm`.
+    gen_wrapper_arg_rm_cc!(
+        "Computes the hyperbolic arccosecant of a number with precision `p`. The result is rounded using the rounding mode `rm`.
         This function requires constants cache `cc` for computing the result.
         Precision is rounded upwards to the word size. The function returns NaN if the precision `p` is incorrect.",
-        log2,
+        acsch,
+        Self,
+        { BigFloat::new(p) },
+        { -BigFloat::new(p) },
+        p,
+        usize
+    );
+    gen_wrapper_arg_rm_cc!(
+        "Computes the hyperbolic arccotangent of a number with precision `p`. The result is rounded using the rounding mode `rm`.
+        This function requires constants cache `cc` for computing the result.
+        Precision is rounded upwards to the word size. The function returns NaN if the precision `p` is incorrect.",
+        acoth,
+        Self,
+        { BigFloat::new(p) },
+        { -BigFloat::new(p) },
+        p,
+        usize
+    );
+    gen_wrapper_arg_rm_cc!(
+        "Computes sin(pi * `self`) with precision `p`. The result is rounded using the rounding mode `rm`.
+        This function requires constants cache `cc` for computing the result.
+        Precision is rounded upwards to the word size. The function returns NaN if the precision `p` is incorrect.",
+        sin_pi,
         Self,
-        { INF_POS },
+        { NAN },
         { NAN },
         p,
         usize
     );
-    gen_wrapper_log!(
-        "Computes the logarithm base 10 of a number with precision `p`. The result is rounded using the rounding mode `rm`.
+    gen_wrapper_arg_rm_cc!(
+        "Computes cos(pi * `self`) with precision `p`. The result is rounded using the rounding mode `rm`.
         This function requires constants cache `cc` for computing the result.
         Precision is rounded upwards to the word size. The function returns NaN if the precision `p` is incorrect.",
-        log10,
+        cos_pi,
         Self,
-        { INF_POS },
+        { NAN },
         { NAN },
         p,
         usize
     );
     gen_wrapper_arg_rm_cc!(
-        "Computes `e` to the power of `self` with precision `p`. The result is rounded using the rounding mode `rm`.
+        "Computes tan(pi * `self`) with precision `p`. The result is rounded using the rounding mode `rm`.
         This function requires constants cache `cc` for computing the result.
         Precision is rounded upwards to the word size. The function returns NaN if the precision `p` is incorrect.",
-        exp,
+        tan_pi,
         Self,
-        { INF_POS },
-        { INF_NEG },
+        { NAN },
+        { NAN },
         p,
         usize
     );
-
     gen_wrapper_arg_rm_cc!(
-        "Computes the sine of a number with precision `p`. The result is rounded using the rounding mode `rm`.
+        "Computes asin(`self`) / pi with precision `p`. The result is rounded using the rounding mode `rm`.
         This function requires constants cache `cc` for computing the result.
         Precision is rounded upwards to the word size. The function returns NaN if the precision `p` is incorrect.",
-        sin,
+        asin_pi,
         Self,
         { NAN },
         { NAN },
@@ -1348,21 +2519,43 @@ impl BigFloat {
         usize
     );
     gen_wrapper_arg_rm_cc!(
-        "Computes the cosine of a number with precision `p`. The result is rounded using the rounding mode `rm`.
+        "Computes acos(`self`) / pi with precision `p`. The result is rounded using the rounding mode `rm`.
         This function requires constants cache `cc` for computing the result.
         Precision is rounded upwards to the word size. The function returns NaN if the precision `p` is incorrect.",
-        cos,
+        acos_pi,
         Self,
         { NAN },
         { NAN },
         p,
         usize
     );
+
+    /// Computes atan(`self`) / pi with precision `p`. The result is rounded using the rounding mode `rm`.
+    /// This function requires constants cache `cc` for computing the result.
+    /// Precision is rounded upwards to the word size. The function returns NaN if the precision `p` is incorrect.
+    pub fn atan_pi(&self, p: usize, rm: RoundingMode, cc: &mut Consts) -> Self {
+        match &self.inner {
+            Flavor::Value(v) => Self::result_to_ext(v.atan_pi(p, rm, cc), v.is_zero(), true),
+            Flavor::Inf(s) => {
+                let one = BigFloatNumber::from_i8(s.to_int(), p);
+                Self::result_to_ext(
+                    one.and_then(|v| v.atan_pi(p, rm, cc)).map(|mut v| {
+                        v.set_exponent(v.exponent() + 1);
+                        v
+                    }),
+                    false,
+                    true,
+                )
+            }
+            Flavor::NaN(err) => Self::nan(*err),
+        }
+    }
+
     gen_wrapper_arg_rm_cc!(
-        "Computes the tangent of a number with precision `p`. The result is rounded using the rounding mode `rm`.
+        "Computes the sine of `self` degrees with precision `p`. The result is rounded using the rounding mode `rm`.
         This function requires constants cache `cc` for computing the result.
         Precision is rounded upwards to the word size. The function returns NaN if the precision `p` is incorrect.",
-        tan,
+        sin_deg,
         Self,
         { NAN },
         { NAN },
@@ -1370,50 +2563,71 @@ impl BigFloat {
         usize
     );
     gen_wrapper_arg_rm_cc!(
-        "Computes the arcsine of a number with precision `p`. The result is rounded using the rounding mode `rm`.
+        "Computes the cosine of `self` degrees with precision `p`. The result is rounded using the rounding mode `rm`.
         This function requires constants cache `cc` for computing the result.
-        Precision is rounded upwards to the word size. The function returns NaN if the precision `p` is incorrect.", 
-        asin,
+        Precision is rounded upwards to the word size. The function returns NaN if the precision `p` is incorrect.",
+        cos_deg,
         Self,
-        {NAN},
-        {NAN},
+        { NAN },
+        { NAN },
         p,
         usize
     );
     gen_wrapper_arg_rm_cc!(
-        "Computes the arccosine of a number with precision `p`. The result is rounded using the rounding mode `rm`.
+        "Computes the tangent of `self` degrees with precision `p`. The result is rounded using the rounding mode `rm`.
         This function requires constants cache `cc` for computing the result.
         Precision is rounded upwards to the word size. The function returns NaN if the precision `p` is incorrect.",
-        acos,
+        tan_deg,
         Self,
         { NAN },
         { NAN },
         p,
         usize
     );
-
     gen_wrapper_arg_rm_cc!(
-        "Computes the hyperbolic sine of a number with precision `p`. The result is rounded using the rounding mode `rm`.
-        This function requires constants cache cc for computing the result. 
+        "Computes the arcsine of `self` in degrees with precision `p`. The result is rounded using the rounding mode `rm`.
+        This function requires constants cache `cc` for computing the result.
         Precision is rounded upwards to the word size. The function returns NaN if the precision `p` is incorrect.",
-        sinh,
+        asin_deg,
         Self,
-        { INF_POS },
-        { INF_NEG },
+        { NAN },
+        { NAN },
         p,
         usize
     );
     gen_wrapper_arg_rm_cc!(
-        "Computes the hyperbolic cosine of a number with precision `p`. The result is rounded using the rounding mode `rm`.
-        This function requires constants cache cc for computing the result. 
+        "Computes the arccosine of `self` in degrees with precision `p`. The result is rounded using the rounding mode `rm`.
+        This function requires constants cache `cc` for computing the result.
         Precision is rounded upwards to the word size. The function returns NaN if the precision `p` is incorrect.",
-        cosh,
+        acos_deg,
         Self,
-        { INF_POS },
-        { INF_POS },
+        { NAN },
+        { NAN },
         p,
         usize
     );
+
+    /// Computes the arctangent of `self` in degrees with precision `p`. The result is rounded using the rounding mode `rm`.
+    /// This function requires constants cache `cc` for computing the result.
+    /// Precision is rounded upwards to the word size. The function returns NaN if the precision `p` is incorrect.
+    pub fn atan_deg(&self, p: usize, rm: RoundingMode, cc: &mut Consts) -> Self {
+        match &self.inner {
+            Flavor::Value(v) => Self::result_to_ext(v.atan_deg(p, rm, cc), v.is_zero(), true),
+            Flavor::Inf(s) => {
+                let one = BigFloatNumber::from_i8(s.to_int(), p);
+                Self::result_to_ext(
+                    one.and_then(|v| v.atan_deg(p, rm, cc)).map(|mut v| {
+                        v.set_exponent(v.exponent() + 1);
+                        v
+                    }),
+                    false,
+                    true,
+                )
+            }
+            Flavor::NaN(err) => Self::nan(*err),
+        }
+    }
+
     gen_wrapper_arg_rm_cc!(
         "Computes the hyperbolic arcsine of a number with precision `p`. The result is rounded using the rounding mode `rm`.
         This function requires constants cache `cc` for computing the result.
@@ -1492,7 +2706,9 @@ impl From<BigFloatNumber> for BigFloat {
 
 #[cfg(feature = "std")]
 use core::{
-    fmt::{Binary, Display, Formatter, Octal, UpperHex},
+    fmt::{
+        Alignment, Binary, Display, Formatter, LowerExp, LowerHex, Octal, UpperExp, UpperHex, Write,
+    },
     str::FromStr,
 };
 
@@ -1622,16 +2838,32 @@ impl_from!(u32, from_u32);
 impl_from!(u64, from_u64);
 impl_from!(u128, from_u128);
 
+// Output style of the formatting traits.
+#[cfg(feature = "std")]
+#[derive(Clone, Copy)]
+enum FmtStyle {
+    // Fixed-point notation if precision is specified, scientific notation otherwise.
+    Display,
+    // Scientific notation as produced by `BigFloat::format`.
+    Sci,
+    // Scientific notation in lower case.
+    SciLower,
+    // Scientific notation in upper case.
+    SciUpper,
+}
+
 #[cfg(feature = "std")]
 macro_rules! impl_format_rdx {
-    ($trait:ty, $rdx:path) => {
+    ($trait:ty, $rdx:path, $style:path) => {
         impl $trait for BigFloat {
             /// Formats the number.
+            /// The flags, width, fill, alignment, and precision of the formatter are honored.
+            /// The result is rounded to the nearest even.
             /// The implementation is not available in no_std environment.
             fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
                 crate::common::consts::TENPOWERS.with(|tp| {
                     let cc = &mut tp.borrow_mut();
-                    self.write_str(f, $rdx, RoundingMode::ToEven, cc)
+                    self.write_fmt_spec(f, $rdx, $style, cc)
                 })
             }
         }
@@ -1639,13 +2871,19 @@ macro_rules! impl_format_rdx {
 }
 
 #[cfg(feature = "std")]
-impl_format_rdx!(Binary, Radix::Bin);
+impl_format_rdx!(Binary, Radix::Bin, FmtStyle::Sci);
+#[cfg(feature = "std")]
+impl_format_rdx!(Octal, Radix::Oct, FmtStyle::Sci);
+#[cfg(feature = "std")]
+impl_format_rdx!(Display, Radix::Dec, FmtStyle::Display);
 #[cfg(feature = "std")]
-impl_format_rdx!(Octal, Radix::Oct);
+impl_format_rdx!(LowerExp, Radix::Dec, FmtStyle::SciLower);
 #[cfg(feature = "std")]
-impl_format_rdx!(Display, Radix::Dec);
+impl_format_rdx!(UpperExp, Radix::Dec, FmtStyle::SciUpper);
 #[cfg(feature = "std")]
-impl_format_rdx!(UpperHex, Radix::Hex);
+impl_format_rdx!(LowerHex, Radix::Hex, FmtStyle::SciLower);
+#[cfg(feature = "std")]
+impl_format_rdx!(UpperHex, Radix::Hex, FmtStyle::Sci);
 
 /// A trait for conversion with additional arguments.
 pub trait FromExt<T> {
@@ -1876,6 +3114,27 @@ mod tests {
         assert!(BigFloat::new(rand_p()).rem(&INF_POS).is_zero());
         assert!(BigFloat::new(rand_p()).rem(&INF_NEG).is_zero());
 
+        assert!(TWO.remainder(&INF_POS).cmp(&TWO) == Some(0));
+        assert!(INF_POS.remainder(&TWO).is_nan());
+        assert!(NAN.remainder(&TWO).is_nan());
+        assert!(TWO.remainder(&BigFloat::new(rand_p())).is_nan());
+        assert!(TWO.remquo(&NAN).0.is_nan());
+        assert_eq!(INF_NEG.remquo(&TWO).1, 0);
+
+        assert!(INF_POS.round_to_integer(rm).is_inf_pos());
+        assert!(INF_NEG.trunc().is_inf_neg());
+        assert!(NAN.round_to_integer(rm).is_nan());
+        assert!(INF_POS.to_integer() == Err(Error::InvalidArgument));
+        assert!(NAN.to_integer() == Err(Error::InvalidArgument));
+        let ctx = (rand_p(), RoundingMode::Down, &mut cc);
+        assert!(BigFloat::from_f64(-0.5, 64).rint(&ctx) == BigFloat::from_f64(-1.0, 64));
+
+        assert!(INF_POS.rising_factorial(3, rand_p(), rm).is_inf_pos());
+        assert!(INF_NEG.rising_factorial(3, rand_p(), rm).is_inf_neg());
+        assert!(INF_NEG.falling_factorial(2, rand_p(), rm).is_inf_pos());
+        assert!(INF_NEG.falling_factorial(0, rand_p(), rm) == *ONE);
+        assert!(NAN.rising_factorial(3, rand_p(), rm).is_nan());
+
         for op in [BigFloat::add, BigFloat::sub, BigFloat::mul, BigFloat::div] {
             assert!(op(&NAN, &ONE, rand_p(), rm).is_nan());
             assert!(op(&ONE, &NAN, rand_p(), rm).is_nan());
@@ -2085,6 +3344,17 @@ mod tests {
         assert!(INF_POS.cbrt(rand_p(), rm).is_inf_pos());
         assert!(NAN.cbrt(rand_p(), rm).is_nan());
 
+        assert!(INF_NEG.rec_sqrt(rand_p(), rm).is_nan());
+        assert!(INF_POS.rec_sqrt(rand_p(), rm).is_zero());
+        assert!(NAN.rec_sqrt(rand_p(), rm).is_nan());
+        assert!(BigFloat::new(rand_p()).rec_sqrt(rand_p(), rm).is_inf_pos());
+
+        assert!(INF_NEG.root(4, rand_p(), rm, &mut cc).is_nan());
+        assert!(INF_NEG.root(5, rand_p(), rm, &mut cc).is_inf_neg());
+        assert!(INF_POS.root(0, rand_p(), rm, &mut cc).is_nan());
+        assert!(INF_POS.root(6, rand_p(), rm, &mut cc).is_inf_pos());
+        assert!(NAN.root(5, rand_p(), rm, &mut cc).is_nan());
+
         for op in [BigFloat::ln, BigFloat::log2, BigFloat::log10] {
             assert!(op(&INF_NEG, rand_p(), rm, &mut cc).is_nan());
             assert!(op(&INF_POS, rand_p(), rm, &mut cc).is_inf_pos());
@@ -2094,6 +3364,44 @@ mod tests {
         assert!(INF_NEG.exp(rand_p(), rm, &mut cc).is_inf_neg());
         assert!(INF_POS.exp(rand_p(), rm, &mut cc).is_inf_pos());
         assert!(NAN.exp(rand_p(), rm, &mut cc).is_nan());
+        assert!(INF_NEG.exp2(rand_p(), rm, &mut cc).is_zero());
+        assert!(INF_POS.exp2(rand_p(), rm, &mut cc).is_inf_pos());
+        assert!(NAN.exp2(rand_p(), rm, &mut cc).is_nan());
+        assert!(INF_NEG.exp10(rand_p(), rm, &mut cc).is_zero());
+        assert!(INF_POS.exp10(rand_p(), rm, &mut cc).is_inf_pos());
+        assert!(NAN.exp10(rand_p(), rm, &mut cc).is_nan());
+        assert!(INF_NEG.lambert_w0(rand_p(), rm, &mut cc).is_nan());
+        assert!(INF_POS.lambert_w0(rand_p(), rm, &mut cc).is_inf_pos());
+        assert!(NAN.lambert_w0(rand_p(), rm, &mut cc).is_nan());
+        assert!(INF_NEG.lambert_wm1(rand_p(), rm, &mut cc).is_nan());
+        assert!(INF_POS.lambert_wm1(rand_p(), rm, &mut cc).is_nan());
+        assert!(NAN.lambert_wm1(rand_p(), rm, &mut cc).is_nan());
+        assert!(BigFloat::new(rand_p())
+            .lambert_wm1(rand_p(), rm, &mut cc)
+            .is_inf_neg());
+
+        let one = BigFloat::from_u8(1, rand_p());
+        let half = BigFloat::from_f64(0.5, rand_p());
+        assert!(INF_POS.bessel_j(&one, rand_p(), rm, &mut cc).is_zero());
+        assert!(INF_NEG.bessel_j(&one, rand_p(), rm, &mut cc).is_zero());
+        assert!(INF_NEG.bessel_j(&half, rand_p(), rm, &mut cc).is_nan());
+        assert!(one.bessel_j(&INF_POS, rand_p(), rm, &mut cc).is_nan());
+        assert!(NAN.bessel_j(&one, rand_p(), rm, &mut cc).is_nan());
+        assert!(one.bessel_j(&NAN, rand_p(), rm, &mut cc).is_nan());
+        assert!(INF_POS.bessel_y(&one, rand_p(), rm, &mut cc).is_zero());
+        assert!(INF_NEG.bessel_y(&one, rand_p(), rm, &mut cc).is_nan());
+        assert!(BigFloat::new(rand_p())
+            .bessel_y(&one, rand_p(), rm, &mut cc)
+            .is_inf_neg());
+        assert!(INF_POS.bessel_i(&one, rand_p(), rm, &mut cc).is_inf_pos());
+        assert!(INF_NEG.bessel_i(&one, rand_p(), rm, &mut cc).is_inf_neg());
+        assert!(INF_NEG.bessel_i(&half, rand_p(), rm, &mut cc).is_nan());
+        assert!(INF_POS.bessel_k(&one, rand_p(), rm, &mut cc).is_zero());
+        assert!(INF_NEG.bessel_k(&one, rand_p(), rm, &mut cc).is_nan());
+        assert!(BigFloat::new(rand_p())
+            .bessel_k(&half, rand_p(), rm, &mut cc)
+            .is_inf_pos());
+        assert!(one.neg().bessel_k(&half, rand_p(), rm, &mut cc).is_nan());
 
         assert!(INF_NEG.sin(rand_p(), rm, &mut cc).is_nan());
         assert!(INF_POS.sin(rand_p(), rm, &mut cc).is_nan());
@@ -2130,6 +3438,48 @@ mod tests {
         assert!(INF_POS.cosh(rand_p(), rm, &mut cc).is_inf_pos());
         assert!(NAN.cosh(rand_p(), rm, &mut cc).is_nan());
 
+        let (s, c) = INF_NEG.sin_cos(rand_p(), rm, &mut cc);
+        assert!(s.is_nan() && c.is_nan());
+        let (s, c) = NAN.sin_cos(rand_p(), rm, &mut cc);
+        assert!(s.is_nan() && c.is_nan());
+
+        assert!(INF_POS.sec(rand_p(), rm, &mut cc).is_nan());
+        assert!(INF_NEG.cot(rand_p(), rm, &mut cc).is_nan());
+        assert!(INF_NEG.acsc(rand_p(), rm, &mut cc).is_zero());
+        assert!(INF_NEG.acot(rand_p(), rm, &mut cc).is_negative());
+        assert!(INF_POS.sech(rand_p(), rm, &mut cc).is_zero());
+        assert!(INF_NEG.coth(rand_p(), rm, &mut cc) == BigFloat::from_i8(-1, 64));
+        assert!(INF_POS.asech(rand_p(), rm, &mut cc).is_nan());
+        assert!(INF_POS.acoth(rand_p(), rm, &mut cc).is_zero());
+        assert!(BigFloat::new(rand_p())
+            .csc(rand_p(), rm, &mut cc)
+            .is_inf_pos());
+        assert!((-BigFloat::new(rand_p()))
+            .coth(rand_p(), rm, &mut cc)
+            .is_inf_neg());
+        assert!(ONE.acoth(rand_p(), rm, &mut cc).is_inf_pos());
+        assert!(INF_NEG.asec(64, rm, &mut cc) == ONE.asin(64, rm, &mut cc));
+
+        assert!(INF_POS.sin_pi(rand_p(), rm, &mut cc).is_nan());
+        assert!(INF_NEG.cos_deg(rand_p(), rm, &mut cc).is_nan());
+        assert!(NAN.tan_pi(rand_p(), rm, &mut cc).is_nan());
+        assert!(INF_POS.asin_deg(rand_p(), rm, &mut cc).is_nan());
+        assert!(BigFloat::from_f64(0.5, rand_p())
+            .tan_pi(rand_p(), rm, &mut cc)
+            .is_inf_pos());
+        assert!(BigFloat::from_f64(-90.0, rand_p())
+            .tan_deg(rand_p(), rm, &mut cc)
+            .is_inf_neg());
+        assert!(INF_POS.atan_pi(rand_p(), rm, &mut cc) == BigFloat::from_f64(0.5, 64));
+        assert!(INF_NEG.atan_deg(rand_p(), rm, &mut cc) == BigFloat::from_f64(-90.0, 64));
+
+        let (s, c) = INF_NEG.sinh_cosh(rand_p(), rm, &mut cc);
+        assert!(s.is_inf_neg() && c.is_inf_pos());
+        let (s, c) = INF_POS.sinh_cosh(rand_p(), rm, &mut cc);
+        assert!(s.is_inf_pos() && c.is_inf_pos());
+        let (s, c) = NAN.sinh_cosh(rand_p(), rm, &mut cc);
+        assert!(s.is_nan() && c.is_nan());
+
         assert!(INF_NEG.tanh(rand_p(), rm, &mut cc).cmp(&ONE.neg()) == Some(0));
         assert!(INF_POS.tanh(rand_p(), rm, &mut cc).cmp(&ONE) == Some(0));
         assert!(NAN.tanh(rand_p(), rm, &mut cc).is_nan());
@@ -2339,6 +3689,119 @@ mod tests {
 
         assert!(BigFloat::from_str("abc").is_ok());
         assert!(BigFloat::from_str("abc").unwrap().is_nan());
+
+        // formatter flags
+        let d1 = BigFloat::from_f64(-1234.5678, DEFAULT_P);
+        let d2 = BigFloat::from_f64(10.8125, DEFAULT_P);
+
+        assert_eq!(format!("{:.2}", d1), "-1234.57");
+        assert_eq!(format!("{:.0}", d2), "11");
+        assert_eq!(
+            format!("{:.2}", BigFloat::from_f64(-0.0, DEFAULT_P)),
+            "-0.00"
+        );
+        assert_eq!(
+            format!("{:.2}", BigFloat::from_f64(-0.001, DEFAULT_P)),
+            "-0.00"
+        );
+        assert_eq!(format!("{:.2}", BigFloat::from_f64(0.0, DEFAULT_P)), "0.00");
+        assert_eq!(format!("{:+.1}", d2), "+10.8");
+        assert_eq!(format!("{:12.2}", d1), "    -1234.57");
+        assert_eq!(format!("{:<12.2}", d1), "-1234.57    ");
+        assert_eq!(format!("{:*^12.2}", d1), "**-1234.57**");
+        assert_eq!(format!("{:012.2}", d1), "-00001234.57");
+        assert_eq!(format!("{:.3e}", d1), "-1.235e+3");
+        assert_eq!(format!("{:.3E}", d1), "-1.235E+3");
+        assert_eq!(format!("{:E}", d2), "1.08125E+1");
+        assert_eq!(format!("{:x}", d2), "a.d_e+0");
+        assert_eq!(format!("{:X}", d2), "A.D_e+0");
+        assert_eq!(format!("{:.0X}", d2), "B_e+0");
+        assert_eq!(format!("{:.2b}", d2), "1.01e+11");
+        assert_eq!(format!("{:o}", d2), "1.264e+1");
+        assert_eq!(format!("{:>6}", INF_POS), "   Inf");
+        assert_eq!(format!("{:+}", INF_POS), "+Inf");
+        assert_eq!(format!("{:<5.2}", INF_NEG), "-Inf ");
+        assert_eq!(format!("{:^7.1}", NAN), "  NaN  ");
+        assert_eq!(format!("{}", d2), "1.08125e+1");
+
+        // streaming parsing and formatting
+        let s = "1234567890".repeat(2000);
+        let input = String::from("-0.") + &s + "e+3 tail";
+        let d1 = BigFloat::parse_from_reader(
+            input.as_bytes(),
+            Radix::Dec,
+            usize::MAX,
+            RoundingMode::ToEven,
+            &mut cc,
+        )
+        .unwrap();
+        let d2 = BigFloat::parse(
+            &input[..input.len() - 5],
+            Radix::Dec,
+            usize::MAX,
+            RoundingMode::ToEven,
+            &mut cc,
+        );
+        assert_eq!(d1, d2);
+        assert!(d1.precision().unwrap() >= 20000 * 3);
+
+        let mut out = Vec::new();
+        d1.format_to_io(&mut out, Radix::Dec, RoundingMode::ToEven, &mut cc)
+            .unwrap();
+        let s1 = d1
+            .format(Radix::Dec, RoundingMode::ToEven, &mut cc)
+            .unwrap();
+        assert_eq!(out, s1.as_bytes());
+
+        let d3 = BigFloat::parse_from_iter(
+            out.iter().copied(),
+            Radix::Dec,
+            d1.precision().unwrap(),
+            RoundingMode::ToEven,
+            &mut cc,
+        );
+        assert_eq!(d1, d3);
+
+        for (d, s) in [(INF_POS, "Inf"), (INF_NEG, "-Inf"), (NAN, "NaN")] {
+            let mut out = Vec::new();
+            d.format_to_io(&mut out, Radix::Dec, RoundingMode::ToEven, &mut cc)
+                .unwrap();
+            assert_eq!(out, s.as_bytes());
+        }
+
+        // errors of the reader and the writer are returned
+        struct Failing;
+
+        impl std::io::Read for Failing {
+            fn read(&mut self, _buf: &mut [u8]) -> std::io::Result<usize> {
+                Err(std::io::Error::other("read"))
+            }
+        }
+
+        impl std::io::Write for Failing {
+            fn write(&mut self, _buf: &[u8]) -> std::io::Result<usize> {
+                Err(std::io::Error::other("write"))
+            }
+
+            fn flush(&mut self) -> std::io::Result<()> {
+                Ok(())
+            }
+        }
+
+        let err = BigFloat::parse_from_reader(
+            Failing,
+            Radix::Dec,
+            DEFAULT_P,
+            RoundingMode::ToEven,
+            &mut cc,
+        )
+        .unwrap_err();
+        assert_eq!(err.to_string(), "read");
+
+        let err = d1
+            .format_to_io(&mut Failing, Radix::Dec, RoundingMode::ToEven, &mut cc)
+            .unwrap_err();
+        assert_eq!(err.to_string(), "write");
     }
 
     #[test]
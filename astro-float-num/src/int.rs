@@ -0,0 +1,1227 @@
+//! Arbitrary-precision integer numbers.
+
+use crate::common::buf::WordBuf;
+use crate::common::util::add_carry;
+use crate::common::util::log2_ceil;
+use crate::common::util::shift_slice_left_copy;
+use crate::common::util::shift_slice_right;
+use crate::common::util::sub_borrow;
+use crate::conv::LOGRDX_2;
+use crate::defs::check_radix;
+use crate::defs::Error;
+use crate::defs::Radix;
+use crate::defs::RoundingMode;
+use crate::defs::Sign;
+use crate::defs::Word;
+use crate::defs::EXPONENT_MAX;
+use crate::defs::WORD_BIT_SIZE;
+use crate::defs::WORD_SIGNIFICANT_BIT;
+use crate::ext::BigFloat;
+use crate::mantissa::rdx_word_id;
+use crate::mantissa::Mantissa;
+use crate::strop::DIGIT_CHARS;
+use crate::Exponent;
+use crate::INF_NEG;
+use crate::INF_POS;
+use core::cmp::Ordering;
+use core::fmt::Display;
+use core::fmt::Formatter;
+use core::hash::Hash;
+use core::hash::Hasher;
+use core::str::FromStr;
+
+#[cfg(not(feature = "std"))]
+use {alloc::string::String, alloc::vec::Vec};
+
+/// Arbitrary-precision non-negative integer number.
+/// Multiplication and division use the same algorithms as the mantissa of `BigFloat`.
+#[derive(Debug)]
+pub struct BigUint {
+    // the least significant word first, no leading zero words.
+    m: WordBuf,
+}
+
+/// Arbitrary-precision signed integer number.
+#[derive(Debug)]
+pub struct BigInt {
+    // zero is always positive.
+    s: Sign,
+    m: BigUint,
+}
+
+impl BigUint {
+    fn from_word_buf(mut m: WordBuf) -> Self {
+        m.trunc_leading_zeroes();
+        BigUint { m }
+    }
+
+    pub(crate) fn into_word_buf(self) -> WordBuf {
+        self.m
+    }
+
+    /// Returns zero.
+    pub fn zero() -> Self {
+        BigUint {
+            m: WordBuf::from_vec(Vec::new()),
+        }
+    }
+
+    /// Constructs a number from the value `v`.
+    ///
+    /// ## Errors
+    ///
+    ///  - MemoryAllocation: failed to allocate memory.
+    pub fn from_u64(v: u64) -> Result<Self, Error> {
+        let mut m = WordBuf::new(64usize.div_ceil(WORD_BIT_SIZE))?;
+
+        for (i, w) in m.iter_mut().enumerate() {
+            *w = (v >> (i * WORD_BIT_SIZE)) as Word;
+        }
+
+        Ok(Self::from_word_buf(m))
+    }
+
+    /// Constructs a number from the words `m`, the least significant word first.
+    ///
+    /// ## Errors
+    ///
+    ///  - MemoryAllocation: failed to allocate memory.
+    pub fn from_words(m: &[Word]) -> Result<Self, Error> {
+        let mut buf = WordBuf::new(m.len())?;
+        buf.copy_from_slice(m);
+
+        Ok(Self::from_word_buf(buf))
+    }
+
+    /// Returns the words of the number, the least significant word first.
+    /// The most significant word is never zero, and zero is represented by an empty slice.
+    pub fn words(&self) -> &[Word] {
+        &self.m
+    }
+
+    /// Returns the value of `self` if it fits in u64.
+    pub fn to_u64(&self) -> Option<u64> {
+        if self.bit_len() > 64 {
+            return None;
+        }
+
+        #[cfg(not(target_arch = "x86"))]
+        {
+            Some(self.m.first().copied().unwrap_or(0))
+        }
+
+        #[cfg(target_arch = "x86")]
+        {
+            let mut ret = 0u64;
+            for (i, &w) in self.m.iter().enumerate() {
+                ret |= (w as u64) << (i * WORD_BIT_SIZE);
+            }
+
+            Some(ret)
+        }
+    }
+
+    /// Returns true if `self` is zero.
+    pub fn is_zero(&self) -> bool {
+        self.m.is_empty()
+    }
+
+    /// Returns true if `self` is odd.
+    pub fn is_odd(&self) -> bool {
+        self.m.first().is_some_and(|&w| w & 1 != 0)
+    }
+
+    /// Returns the number of significant bits.
+    pub fn bit_len(&self) -> usize {
+        Mantissa::bit_len_nat(&self.m)
+    }
+
+    /// Returns the value of the bit at position `n`.
+    pub fn bit(&self, n: usize) -> bool {
+        self.m
+            .get(n / WORD_BIT_SIZE)
+            .is_some_and(|&w| (w >> (n % WORD_BIT_SIZE)) & 1 != 0)
+    }
+
+    /// Returns the number of trailing zero bits, or None if `self` is zero.
+    pub fn trailing_zeros(&self) -> Option<usize> {
+        let i = self.m.iter().position(|&w| w != 0)?;
+        Some(i * WORD_BIT_SIZE + self.m[i].trailing_zeros() as usize)
+    }
+
+    /// Returns a copy of `self`.
+    ///
+    /// ## Errors
+    ///
+    ///  - MemoryAllocation: failed to allocate memory.
+    #[allow(clippy::should_implement_trait)]
+    pub fn clone(&self) -> Result<Self, Error> {
+        Self::from_words(&self.m)
+    }
+
+    /// Adds `d2` to `self` and returns the result of the operation.
+    ///
+    /// ## Errors
+    ///
+    ///  - MemoryAllocation: failed to allocate memory.
+    pub fn add(&self, d2: &Self) -> Result<Self, Error> {
+        let (a, b) = if self.m.len() >= d2.m.len() { (&self.m, &d2.m) } else { (&d2.m, &self.m) };
+
+        let mut m = WordBuf::new(a.len() + 1)?;
+
+        let mut c = 0;
+        for (r, (x, y)) in m
+            .iter_mut()
+            .zip(a.iter().zip(b.iter().chain(core::iter::repeat(&0))))
+        {
+            c = add_carry(*x, *y, c, r);
+        }
+        m[a.len()] = c;
+
+        Ok(Self::from_word_buf(m))
+    }
+
+    /// Subtracts `d2` from `self` and returns the result of the operation.
+    ///
+    /// ## Errors
+    ///
+    ///  - MemoryAllocation: failed to allocate memory.
+    ///  - InvalidArgument: `d2` is greater than `self`.
+    pub fn sub(&self, d2: &Self) -> Result<Self, Error> {
+        if self.cmp(d2) == Ordering::Less {
+            return Err(Error::InvalidArgument);
+        }
+
+        Self::sub_nat(&self.m, &d2.m)
+    }
+
+    // `a` must be greater or equal to `b`.
+    fn sub_nat(a: &[Word], b: &[Word]) -> Result<Self, Error> {
+        let mut m = WordBuf::new(a.len())?;
+
+        let mut c = 0;
+        for (r, (x, y)) in m
+            .iter_mut()
+            .zip(a.iter().zip(b.iter().chain(core::iter::repeat(&0))))
+        {
+            c = sub_borrow(*x, *y, c, r);
+        }
+        debug_assert!(c == 0);
+
+        Ok(Self::from_word_buf(m))
+    }
+
+    /// Multiplies `self` by `d2` and returns the result of the operation.
+    ///
+    /// ## Errors
+    ///
+    ///  - MemoryAllocation: failed to allocate memory.
+    pub fn mul(&self, d2: &Self) -> Result<Self, Error> {
+        Ok(Self::from_word_buf(Mantissa::mul_nat(&self.m, &d2.m)?))
+    }
+
+    /// Divides `self` by `d2` and returns the quotient and the remainder.
+    ///
+    /// ## Errors
+    ///
+    ///  - MemoryAllocation: failed to allocate memory.
+    ///  - DivisionByZero: `d2` is zero.
+    pub fn div_rem(&self, d2: &Self) -> Result<(Self, Self), Error> {
+        if d2.is_zero() {
+            return Err(Error::DivisionByZero);
+        }
+
+        let (q, r) = Mantissa::div_rem_nat(&self.m, &d2.m)?;
+
+        Ok((Self::from_word_buf(q), Self::from_word_buf(r)))
+    }
+
+    /// Divides `self` by `d2` and returns the quotient.
+    ///
+    /// ## Errors
+    ///
+    ///  - MemoryAllocation: failed to allocate memory.
+    ///  - DivisionByZero: `d2` is zero.
+    pub fn div(&self, d2: &Self) -> Result<Self, Error> {
+        self.div_rem(d2).map(|(q, _)| q)
+    }
+
+    /// Returns the remainder of division of `self` by `d2`.
+    ///
+    /// ## Errors
+    ///
+    ///  - MemoryAllocation: failed to allocate memory.
+    ///  - DivisionByZero: `d2` is zero.
+    pub fn rem(&self, d2: &Self) -> Result<Self, Error> {
+        self.div_rem(d2).map(|(_, r)| r)
+    }
+
+    /// Returns `self` shifted left by `n` bits.
+    ///
+    /// ## Errors
+    ///
+    ///  - MemoryAllocation: failed to allocate memory.
+    pub fn shl(&self, n: usize) -> Result<Self, Error> {
+        if self.is_zero() {
+            return Ok(Self::zero());
+        }
+
+        let mut m = WordBuf::new(self.m.len() + n.div_ceil(WORD_BIT_SIZE))?;
+        shift_slice_left_copy(&self.m, &mut m, n);
+
+        Ok(Self::from_word_buf(m))
+    }
+
+    /// Returns `self` shifted right by `n` bits.
+    ///
+    /// ## Errors
+    ///
+    ///  - MemoryAllocation: failed to allocate memory.
+    pub fn shr(&self, n: usize) -> Result<Self, Error> {
+        if n >= self.bit_len() {
+            return Ok(Self::zero());
+        }
+
+        let mut m = WordBuf::new(self.m.len())?;
+        m.copy_from_slice(&self.m);
+        shift_slice_right(&mut m, n);
+
+        Ok(Self::from_word_buf(m))
+    }
+
+    fn bitop(&self, d2: &Self, l: usize, f: impl Fn(Word, Word) -> Word) -> Result<Self, Error> {
+        let mut m = WordBuf::new(l)?;
+
+        let mut a = self.m.iter().chain(core::iter::repeat(&0));
+        let mut b = d2.m.iter().chain(core::iter::repeat(&0));
+        for r in m.iter_mut() {
+            *r = f(*a.next().unwrap(), *b.next().unwrap()); // iterators are infinite
+        }
+
+        Ok(Self::from_word_buf(m))
+    }
+
+    /// Returns bitwise AND of `self` and `d2`.
+    ///
+    /// ## Errors
+    ///
+    ///  - MemoryAllocation: failed to allocate memory.
+    pub fn bitand(&self, d2: &Self) -> Result<Self, Error> {
+        self.bitop(d2, self.m.len().min(d2.m.len()), |a, b| a & b)
+    }
+
+    /// Returns bitwise OR of `self` and `d2`.
+    ///
+    /// ## Errors
+    ///
+    ///  - MemoryAllocation: failed to allocate memory.
+    pub fn bitor(&self, d2: &Self) -> Result<Self, Error> {
+        self.bitop(d2, self.m.len().max(d2.m.len()), |a, b| a | b)
+    }
+
+    /// Returns bitwise XOR of `self` and `d2`.
+    ///
+    /// ## Errors
+    ///
+    ///  - MemoryAllocation: failed to allocate memory.
+    pub fn bitxor(&self, d2: &Self) -> Result<Self, Error> {
+        self.bitop(d2, self.m.len().max(d2.m.len()), |a, b| a ^ b)
+    }
+
+    /// Returns the greatest common divisor of `self` and `d2`.
+    /// The greatest common divisor of two zeroes is zero.
+    ///
+    /// ## Errors
+    ///
+    ///  - MemoryAllocation: failed to allocate memory.
+    pub fn gcd(&self, d2: &Self) -> Result<Self, Error> {
+        let (mut a, mut b) = if self.cmp(d2) == Ordering::Less {
+            (d2.clone()?, self.clone()?)
+        } else {
+            (self.clone()?, d2.clone()?)
+        };
+
+        while !b.is_zero() {
+            let r = a.rem(&b)?;
+            a = b;
+            b = r;
+        }
+
+        Ok(a)
+    }
+
+    /// Computes `self` to the power of `n`.
+    ///
+    /// ## Errors
+    ///
+    ///  - MemoryAllocation: failed to allocate memory.
+    pub fn pow(&self, n: usize) -> Result<Self, Error> {
+        let mut ret = Self::from_u64(1)?;
+
+        for i in (0..usize::BITS - n.leading_zeros()).rev() {
+            ret = ret.mul(&ret)?;
+
+            if (n >> i) & 1 != 0 {
+                ret = ret.mul(self)?;
+            }
+        }
+
+        Ok(ret)
+    }
+
+    /// Computes `self` to the power of `e` modulo `n`.
+    ///
+    /// ## Errors
+    ///
+    ///  - MemoryAllocation: failed to allocate memory.
+    ///  - DivisionByZero: `n` is zero.
+    pub fn pow_mod(&self, e: &Self, n: &Self) -> Result<Self, Error> {
+        if n.is_zero() {
+            return Err(Error::DivisionByZero);
+        }
+
+        let x = self.rem(n)?;
+        let mut ret = Self::from_u64(1)?.rem(n)?;
+
+        for i in (0..e.bit_len()).rev() {
+            ret = ret.mul(&ret)?.rem(n)?;
+
+            if e.bit(i) {
+                ret = ret.mul(&x)?.rem(n)?;
+            }
+        }
+
+        Ok(ret)
+    }
+
+    /// Parses the number from the string `s` in radix `rdx`.
+    /// The string can contain only digits, optionally preceded by "+".
+    ///
+    /// ## Errors
+    ///
+    ///  - MemoryAllocation: failed to allocate memory.
+    ///  - InvalidArgument: the string is empty or contains characters which are not digits,
+    ///    or the radix is not in the range from 2 to 36.
+    pub fn parse(s: &str, rdx: impl Into<u32>) -> Result<Self, Error> {
+        let s = s.strip_prefix('+').unwrap_or(s);
+        Self::parse_digits(s, rdx.into())
+    }
+
+    fn parse_digits(s: &str, rdx: u32) -> Result<Self, Error> {
+        let b = check_radix(rdx)? as Word;
+
+        if s.is_empty() {
+            return Err(Error::InvalidArgument);
+        }
+
+        let mut digits = Vec::new();
+        digits.try_reserve_exact(s.len())?;
+
+        for c in s.chars() {
+            digits.push(c.to_digit(b as u32).ok_or(Error::InvalidArgument)? as Word);
+        }
+
+        // words with 2^i digits each, where b^(2^i) is the largest power of this form which fits in a word.
+        let k = rdx_word_id(b);
+        let dpw = 1 << k;
+        let mut words = WordBuf::new(digits.len().div_ceil(dpw))?;
+
+        for (w, chunk) in words.iter_mut().zip(digits.rchunks(dpw)) {
+            *w = chunk.iter().fold(0, |acc, &d| acc * b + d);
+        }
+
+        let mut rdxpowers = Vec::new();
+        Mantissa::compute_rdxpowers(&mut rdxpowers, b, k + log2_ceil(words.len()))?;
+
+        let m = Mantissa::from_rdx_words(words, b, &rdxpowers)?;
+        let (m, _) = m.as_raw_parts();
+
+        Self::from_words(m)
+    }
+
+    /// Formats the number using radix `rdx`.
+    ///
+    /// ## Errors
+    ///
+    ///  - MemoryAllocation: failed to allocate memory.
+    ///  - InvalidArgument: the radix is not in the range from 2 to 36.
+    pub fn format(&self, rdx: impl Into<u32>) -> Result<String, Error> {
+        let b = check_radix(rdx.into())? as Word;
+
+        let mut ret = String::new();
+
+        if self.is_zero() {
+            ret.try_reserve_exact(1)?;
+            ret.push('0');
+            return Ok(ret);
+        }
+
+        let n = (self.bit_len() as u64 * LOGRDX_2[b as usize] / 1000000000) as usize + 1;
+        let p = log2_ceil(n).max(1);
+
+        let mut rdxpowers = Vec::new();
+        Mantissa::compute_rdxpowers(&mut rdxpowers, b, p)?;
+
+        let mut digits = Vec::new();
+        digits.try_reserve_exact(n)?;
+        digits.resize(n, 0);
+
+        let mut m = WordBuf::new(self.m.len())?;
+        m.copy_from_slice(&self.m);
+
+        Mantissa::from_word_buf(m).conv_to_rdx(b, &mut digits, &rdxpowers, p - 1)?;
+
+        let lz = digits.iter().take_while(|&&d| d == 0).count();
+
+        ret.try_reserve_exact(n - lz)?;
+        digits[lz..]
+            .iter()
+            .for_each(|&d| ret.push(DIGIT_CHARS[d as usize].to_ascii_lowercase()));
+
+        Ok(ret)
+    }
+
+    /// Converts `self` to BigFloat with precision `p` rounded using rounding mode `rm`.
+    /// If `p` equals to usize::MAX then the conversion is exact.
+    /// Precision is rounded upwards to the word size.
+    /// The function returns Inf if the number is too large, or NaN if the precision is incorrect.
+    pub fn to_big_float(&self, p: usize, rm: RoundingMode) -> BigFloat {
+        self.to_big_float_signed(Sign::Pos, p, rm)
+    }
+
+    fn to_big_float_signed(&self, s: Sign, p: usize, rm: RoundingMode) -> BigFloat {
+        if self.is_zero() {
+            return BigFloat::from_word(0, if p < usize::MAX { p } else { 1 });
+        }
+
+        let n = self.bit_len();
+
+        if n > EXPONENT_MAX as usize {
+            return if s.is_positive() { INF_POS } else { INF_NEG };
+        }
+
+        let mut m = match WordBuf::new(self.m.len()) {
+            Ok(m) => m,
+            Err(e) => return BigFloat::nan(Some(e)),
+        };
+
+        let l = self.m.len() * WORD_BIT_SIZE;
+        shift_slice_left_copy(&self.m, &mut m, l - n);
+        debug_assert!(m[m.len() - 1] & WORD_SIGNIFICANT_BIT != 0);
+
+        let mut ret = BigFloat::from_raw_parts(&m, l, s, n as Exponent, false);
+
+        if p < usize::MAX {
+            if let Err(e) = ret.set_precision(p, rm) {
+                return BigFloat::nan(Some(e));
+            }
+        }
+
+        ret
+    }
+
+    /// Converts `x` to an integer number rounding it using rounding mode `rm`.
+    ///
+    /// ## Errors
+    ///
+    ///  - MemoryAllocation: failed to allocate memory.
+    ///  - InvalidArgument: `x` is negative after rounding, or `x` is Inf or NaN.
+    pub fn from_big_float(x: &BigFloat, rm: RoundingMode) -> Result<Self, Error> {
+        let (s, m) = BigInt::big_float_parts(x, rm)?;
+
+        if s.is_negative() && !m.is_zero() {
+            return Err(Error::InvalidArgument);
+        }
+
+        Ok(m)
+    }
+}
+
+impl PartialEq for BigUint {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for BigUint {}
+
+impl Hash for BigUint {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.m[..].hash(state);
+    }
+}
+
+impl PartialOrd for BigUint {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for BigUint {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.m
+            .len()
+            .cmp(&other.m.len())
+            .then_with(|| self.m.iter().rev().cmp(other.m.iter().rev()))
+    }
+}
+
+impl Display for BigUint {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        let s = self.format(Radix::Dec).map_err(|_| core::fmt::Error)?;
+        f.pad_integral(true, "", &s)
+    }
+}
+
+impl FromStr for BigUint {
+    type Err = Error;
+
+    /// Parses a decimal number.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s, Radix::Dec)
+    }
+}
+
+impl BigInt {
+    /// Returns zero.
+    pub fn zero() -> Self {
+        BigInt {
+            s: Sign::Pos,
+            m: BigUint::zero(),
+        }
+    }
+
+    /// Constructs a number from the sign `s` and the absolute value `m`.
+    pub fn from_parts(s: Sign, m: BigUint) -> Self {
+        let s = if m.is_zero() { Sign::Pos } else { s };
+        BigInt { s, m }
+    }
+
+    /// Decomposes `self` into the sign and the absolute value.
+    pub fn into_parts(self) -> (Sign, BigUint) {
+        (self.s, self.m)
+    }
+
+    /// Constructs a number from the value `v`.
+    ///
+    /// ## Errors
+    ///
+    ///  - MemoryAllocation: failed to allocate memory.
+    pub fn from_i64(v: i64) -> Result<Self, Error> {
+        let s = if v < 0 { Sign::Neg } else { Sign::Pos };
+        Ok(Self::from_parts(s, BigUint::from_u64(v.unsigned_abs())?))
+    }
+
+    /// Returns the value of `self` if it fits in i64.
+    pub fn to_i64(&self) -> Option<i64> {
+        let v = self.m.to_u64()?;
+
+        if self.s.is_negative() {
+            0i64.checked_sub_unsigned(v)
+        } else {
+            i64::try_from(v).ok()
+        }
+    }
+
+    /// Returns the sign of `self`. The sign of zero is positive.
+    pub fn sign(&self) -> Sign {
+        self.s
+    }
+
+    /// Returns the absolute value of `self`.
+    pub fn magnitude(&self) -> &BigUint {
+        &self.m
+    }
+
+    /// Returns true if `self` is zero.
+    pub fn is_zero(&self) -> bool {
+        self.m.is_zero()
+    }
+
+    /// Returns true if `self` is positive. Zero is positive.
+    pub fn is_positive(&self) -> bool {
+        self.s.is_positive()
+    }
+
+    /// Returns true if `self` is negative.
+    pub fn is_negative(&self) -> bool {
+        self.s.is_negative()
+    }
+
+    /// Returns a copy of `self`.
+    ///
+    /// ## Errors
+    ///
+    ///  - MemoryAllocation: failed to allocate memory.
+    #[allow(clippy::should_implement_trait)]
+    pub fn clone(&self) -> Result<Self, Error> {
+        Ok(BigInt {
+            s: self.s,
+            m: self.m.clone()?,
+        })
+    }
+
+    /// Returns the absolute value of `self`.
+    ///
+    /// ## Errors
+    ///
+    ///  - MemoryAllocation: failed to allocate memory.
+    pub fn abs(&self) -> Result<Self, Error> {
+        Ok(Self::from_parts(Sign::Pos, self.m.clone()?))
+    }
+
+    /// Returns the negated value of `self`.
+    ///
+    /// ## Errors
+    ///
+    ///  - MemoryAllocation: failed to allocate memory.
+    pub fn neg(&self) -> Result<Self, Error> {
+        Ok(Self::from_parts(self.s.invert(), self.m.clone()?))
+    }
+
+    // Adds values with signs `s1` and `s2` and absolute values `m1` and `m2`.
+    fn add_signed(s1: Sign, m1: &BigUint, s2: Sign, m2: &BigUint) -> Result<Self, Error> {
+        if s1 == s2 {
+            Ok(Self::from_parts(s1, m1.add(m2)?))
+        } else if m1.cmp(m2) == Ordering::Less {
+            Ok(Self::from_parts(s2, BigUint::sub_nat(&m2.m, &m1.m)?))
+        } else {
+            Ok(Self::from_parts(s1, BigUint::sub_nat(&m1.m, &m2.m)?))
+        }
+    }
+
+    /// Adds `d2` to `self` and returns the result of the operation.
+    ///
+    /// ## Errors
+    ///
+    ///  - MemoryAllocation: failed to allocate memory.
+    pub fn add(&self, d2: &Self) -> Result<Self, Error> {
+        Self::add_signed(self.s, &self.m, d2.s, &d2.m)
+    }
+
+    /// Subtracts `d2` from `self` and returns the result of the operation.
+    ///
+    /// ## Errors
+    ///
+    ///  - MemoryAllocation: failed to allocate memory.
+    pub fn sub(&self, d2: &Self) -> Result<Self, Error> {
+        Self::add_signed(self.s, &self.m, d2.s.invert(), &d2.m)
+    }
+
+    /// Multiplies `self` by `d2` and returns the result of the operation.
+    ///
+    /// ## Errors
+    ///
+    ///  - MemoryAllocation: failed to allocate memory.
+    pub fn mul(&self, d2: &Self) -> Result<Self, Error> {
+        let s = if self.s == d2.s { Sign::Pos } else { Sign::Neg };
+        Ok(Self::from_parts(s, self.m.mul(&d2.m)?))
+    }
+
+    /// Divides `self` by `d2` and returns the quotient and the remainder.
+    /// The quotient is rounded towards zero, and the remainder has the same sign as `self`.
+    ///
+    /// ## Errors
+    ///
+    ///  - MemoryAllocation: failed to allocate memory.
+    ///  - DivisionByZero: `d2` is zero.
+    pub fn div_rem(&self, d2: &Self) -> Result<(Self, Self), Error> {
+        let (q, r) = self.m.div_rem(&d2.m)?;
+        let s = if self.s == d2.s { Sign::Pos } else { Sign::Neg };
+
+        Ok((Self::from_parts(s, q), Self::from_parts(self.s, r)))
+    }
+
+    /// Divides `self` by `d2` and returns the quotient rounded towards zero.
+    ///
+    /// ## Errors
+    ///
+    ///  - MemoryAllocation: failed to allocate memory.
+    ///  - DivisionByZero: `d2` is zero.
+    pub fn div(&self, d2: &Self) -> Result<Self, Error> {
+        self.div_rem(d2).map(|(q, _)| q)
+    }
+
+    /// Returns the remainder of division of `self` by `d2`. The remainder has the same sign as `self`.
+    ///
+    /// ## Errors
+    ///
+    ///  - MemoryAllocation: failed to allocate memory.
+    ///  - DivisionByZero: `d2` is zero.
+    pub fn rem(&self, d2: &Self) -> Result<Self, Error> {
+        self.div_rem(d2).map(|(_, r)| r)
+    }
+
+    /// Returns `self` multiplied by 2 to the power of `n`.
+    ///
+    /// ## Errors
+    ///
+    ///  - MemoryAllocation: failed to allocate memory.
+    pub fn shl(&self, n: usize) -> Result<Self, Error> {
+        Ok(Self::from_parts(self.s, self.m.shl(n)?))
+    }
+
+    /// Returns `self` divided by 2 to the power of `n` and rounded towards negative infinity,
+    /// which is an arithmetic shift right of the two's complement representation.
+    ///
+    /// ## Errors
+    ///
+    ///  - MemoryAllocation: failed to allocate memory.
+    pub fn shr(&self, n: usize) -> Result<Self, Error> {
+        if self.s.is_positive() {
+            Ok(Self::from_parts(Sign::Pos, self.m.shr(n)?))
+        } else {
+            // -((|x| - 1) >> n) - 1
+            let one = BigUint::from_u64(1)?;
+            let q = BigUint::sub_nat(&self.m.m, &one.m)?.shr(n)?;
+            Ok(Self::from_parts(Sign::Neg, q.add(&one)?))
+        }
+    }
+
+    // Returns two's complement representation of `self` in `l` words.
+    fn to_twos_complement(&self, l: usize) -> Result<WordBuf, Error> {
+        let mut m = WordBuf::new(l)?;
+        m.fill(0);
+        m[..self.m.m.len()].copy_from_slice(&self.m.m);
+
+        if self.s.is_negative() {
+            let mut c = 1;
+            for w in m.iter_mut() {
+                c = add_carry(!*w, 0, c, w);
+            }
+        }
+
+        Ok(m)
+    }
+
+    fn from_twos_complement(mut m: WordBuf) -> Self {
+        let s = if m.last().is_some_and(|&w| w & WORD_SIGNIFICANT_BIT != 0) {
+            let mut c = 1;
+            for w in m.iter_mut() {
+                c = add_carry(!*w, 0, c, w);
+            }
+            Sign::Neg
+        } else {
+            Sign::Pos
+        };
+
+        Self::from_parts(s, BigUint::from_word_buf(m))
+    }
+
+    fn bitop(&self, d2: &Self, f: impl Fn(Word, Word) -> Word) -> Result<Self, Error> {
+        // one extra word for the sign.
+        let l = self.m.m.len().max(d2.m.m.len()) + 1;
+
+        let mut a = self.to_twos_complement(l)?;
+        let b = d2.to_twos_complement(l)?;
+
+        for (x, y) in a.iter_mut().zip(b.iter()) {
+            *x = f(*x, *y);
+        }
+
+        Ok(Self::from_twos_complement(a))
+    }
+
+    /// Returns bitwise AND of the two's complement representations of `self` and `d2`.
+    ///
+    /// ## Errors
+    ///
+    ///  - MemoryAllocation: failed to allocate memory.
+    pub fn bitand(&self, d2: &Self) -> Result<Self, Error> {
+        self.bitop(d2, |a, b| a & b)
+    }
+
+    /// Returns bitwise OR of the two's complement representations of `self` and `d2`.
+    ///
+    /// ## Errors
+    ///
+    ///  - MemoryAllocation: failed to allocate memory.
+    pub fn bitor(&self, d2: &Self) -> Result<Self, Error> {
+        self.bitop(d2, |a, b| a | b)
+    }
+
+    /// Returns bitwise XOR of the two's complement representations of `self` and `d2`.
+    ///
+    /// ## Errors
+    ///
+    ///  - MemoryAllocation: failed to allocate memory.
+    pub fn bitxor(&self, d2: &Self) -> Result<Self, Error> {
+        self.bitop(d2, |a, b| a ^ b)
+    }
+
+    /// Returns the greatest common divisor of the absolute values of `self` and `d2`.
+    ///
+    /// ## Errors
+    ///
+    ///  - MemoryAllocation: failed to allocate memory.
+    pub fn gcd(&self, d2: &Self) -> Result<BigUint, Error> {
+        self.m.gcd(&d2.m)
+    }
+
+    /// Computes `self` to the power of `n`.
+    ///
+    /// ## Errors
+    ///
+    ///  - MemoryAllocation: failed to allocate memory.
+    pub fn pow(&self, n: usize) -> Result<Self, Error> {
+        let s = if n & 1 == 0 { Sign::Pos } else { self.s };
+        Ok(Self::from_parts(s, self.m.pow(n)?))
+    }
+
+    /// Computes `self` to the power of `e` modulo `n`. The result is in the range from 0 to `n` - 1.
+    ///
+    /// ## Errors
+    ///
+    ///  - MemoryAllocation: failed to allocate memory.
+    ///  - DivisionByZero: `n` is zero.
+    pub fn pow_mod(&self, e: &BigUint, n: &BigUint) -> Result<BigUint, Error> {
+        let r = self.m.pow_mod(e, n)?;
+
+        if self.s.is_negative() && e.is_odd() && !r.is_zero() {
+            n.sub(&r)
+        } else {
+            Ok(r)
+        }
+    }
+
+    /// Parses the number from the string `s` in radix `rdx`.
+    /// The string can contain only digits, optionally preceded by the sign.
+    ///
+    /// ## Errors
+    ///
+    ///  - MemoryAllocation: failed to allocate memory.
+    ///  - InvalidArgument: the string is empty or contains characters which are not digits,
+    ///    or the radix is not in the range from 2 to 36.
+    pub fn parse(s: &str, rdx: impl Into<u32>) -> Result<Self, Error> {
+        let (sign, digits) = match s.strip_prefix('-') {
+            Some(d) => (Sign::Neg, d),
+            None => (Sign::Pos, s.strip_prefix('+').unwrap_or(s)),
+        };
+
+        Ok(Self::from_parts(
+            sign,
+            BigUint::parse_digits(digits, rdx.into())?,
+        ))
+    }
+
+    /// Formats the number using radix `rdx`.
+    ///
+    /// ## Errors
+    ///
+    ///  - MemoryAllocation: failed to allocate memory.
+    ///  - InvalidArgument: the radix is not in the range from 2 to 36.
+    pub fn format(&self, rdx: impl Into<u32>) -> Result<String, Error> {
+        let mut ret = self.m.format(rdx)?;
+
+        if self.s.is_negative() {
+            ret.try_reserve_exact(1)?;
+            ret.insert(0, '-');
+        }
+
+        Ok(ret)
+    }
+
+    /// Converts `self` to BigFloat with precision `p` rounded using rounding mode `rm`.
+    /// If `p` equals to usize::MAX then the conversion is exact.
+    /// Precision is rounded upwards to the word size.
+    /// The function returns Inf if the number is too large, or NaN if the precision is incorrect.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// # use astro_float_num::BigFloat;
+    /// # use astro_float_num::BigInt;
+    /// # use astro_float_num::Radix;
+    /// # use astro_float_num::RoundingMode;
+    /// let n = BigInt::parse("-123456789012345678901234567890", Radix::Dec).unwrap();
+    /// let f = n.to_big_float(usize::MAX, RoundingMode::None);
+    /// assert_eq!(BigInt::from_big_float(&f, RoundingMode::None).unwrap(), n);
+    ///
+    /// let f = BigFloat::from_f64(-2.5, 64);
+    /// let n = BigInt::from_big_float(&f, RoundingMode::ToEven).unwrap();
+    /// assert_eq!(n.to_i64(), Some(-2));
+    /// ```
+    pub fn to_big_float(&self, p: usize, rm: RoundingMode) -> BigFloat {
+        self.m.to_big_float_signed(self.s, p, rm)
+    }
+
+    /// Converts `x` to an integer number rounding it using rounding mode `rm`.
+    ///
+    /// ## Errors
+    ///
+    ///  - MemoryAllocation: failed to allocate memory.
+    ///  - InvalidArgument: `x` is Inf or NaN.
+    pub fn from_big_float(x: &BigFloat, rm: RoundingMode) -> Result<Self, Error> {
+        let (s, m) = Self::big_float_parts(x, rm)?;
+        Ok(Self::from_parts(s, m))
+    }
+
+    // Returns the sign and the absolute value of `x` rounded to an integer.
+    fn big_float_parts(x: &BigFloat, rm: RoundingMode) -> Result<(Sign, BigUint), Error> {
+        if x.is_nan() || x.is_inf() {
+            return Err(Error::InvalidArgument);
+        }
+
+        let x = x.round(0, rm);
+
+        if let Some(err) = x.err() {
+            return Err(err);
+        }
+
+        let (m, n, s, e, _) = x.as_raw_parts().ok_or(Error::InvalidArgument)?;
+
+        if n == 0 || e <= 0 {
+            return Ok((s, BigUint::zero()));
+        }
+
+        // the value is `m` * 2^(e - bits in m).
+        let l = m.len() * WORD_BIT_SIZE;
+        let e = e as usize;
+
+        let ret = if e >= l {
+            BigUint::from_words(m)?.shl(e - l)?
+        } else {
+            BigUint::from_words(m)?.shr(l - e)?
+        };
+
+        Ok((s, ret))
+    }
+}
+
+impl PartialEq for BigInt {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for BigInt {}
+
+impl Hash for BigInt {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.s.hash(state);
+        self.m.hash(state);
+    }
+}
+
+impl PartialOrd for BigInt {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for BigInt {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self.s, other.s) {
+            (Sign::Pos, Sign::Pos) => self.m.cmp(&other.m),
+            (Sign::Neg, Sign::Neg) => other.m.cmp(&self.m),
+            (Sign::Pos, Sign::Neg) => Ordering::Greater,
+            (Sign::Neg, Sign::Pos) => Ordering::Less,
+        }
+    }
+}
+
+impl Display for BigInt {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        let s = self.m.format(Radix::Dec).map_err(|_| core::fmt::Error)?;
+        f.pad_integral(self.s.is_positive(), "", &s)
+    }
+}
+
+impl FromStr for BigInt {
+    type Err = Error;
+
+    /// Parses a decimal number.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s, Radix::Dec)
+    }
+}
+
+impl From<BigUint> for BigInt {
+    fn from(m: BigUint) -> Self {
+        Self::from_parts(Sign::Pos, m)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use rand::random;
+
+    #[cfg(not(feature = "std"))]
+    use alloc::format;
+
+    fn random_int(max_words: usize) -> BigInt {
+        let l = random::<usize>() % max_words;
+        let m: Vec<Word> = (0..l).map(|_| random()).collect();
+        let s = if random::<bool>() { Sign::Pos } else { Sign::Neg };
+        BigInt::from_parts(s, BigUint::from_words(&m).unwrap())
+    }
+
+    fn int(v: i64) -> BigInt {
+        BigInt::from_i64(v).unwrap()
+    }
+
+    #[test]
+    fn test_int_small() {
+        // compare with i64 arithmetic
+        for _ in 0..10000 {
+            let a = random::<i32>() as i64;
+            let b = (random::<i16>() as i64) | 1;
+            let (x, y) = (int(a), int(b));
+
+            assert_eq!(x.add(&y).unwrap().to_i64(), Some(a + b));
+            assert_eq!(x.sub(&y).unwrap().to_i64(), Some(a - b));
+            assert_eq!(x.mul(&y).unwrap().to_i64(), Some(a * b));
+            assert_eq!(x.div(&y).unwrap().to_i64(), Some(a / b));
+            assert_eq!(x.rem(&y).unwrap().to_i64(), Some(a % b));
+            assert_eq!(x.bitand(&y).unwrap().to_i64(), Some(a & b));
+            assert_eq!(x.bitor(&y).unwrap().to_i64(), Some(a | b));
+            assert_eq!(x.bitxor(&y).unwrap().to_i64(), Some(a ^ b));
+
+            let n = random::<usize>() % 70;
+            assert_eq!(x.shr(n).unwrap().to_i64(), Some(a >> n.min(63)));
+            let n = n % 30;
+            assert_eq!(x.shl(n).unwrap().to_i64(), Some(a << n));
+
+            assert_eq!(x.cmp(&y), a.cmp(&b));
+            assert_eq!(x.to_string(), a.to_string());
+            assert_eq!(BigInt::from_str(&a.to_string()).unwrap(), x);
+        }
+
+        assert_eq!(int(i64::MIN).to_i64(), Some(i64::MIN));
+        assert_eq!(int(i64::MAX).to_i64(), Some(i64::MAX));
+        assert_eq!(int(i64::MIN).sub(&int(1)).unwrap().to_i64(), None);
+        assert!(int(0).sub(&int(0)).unwrap().is_positive());
+        assert!(int(5).rem(&int(0)) == Err(Error::DivisionByZero));
+        assert!(
+            BigUint::from_u64(1)
+                .unwrap()
+                .sub(&BigUint::from_u64(2).unwrap())
+                == Err(Error::InvalidArgument)
+        );
+
+        assert_eq!(int(-7).pow(3).unwrap().to_i64(), Some(-343));
+        assert_eq!(int(3).pow(0).unwrap().to_i64(), Some(1));
+        assert_eq!(int(0).pow(0).unwrap().to_i64(), Some(1));
+
+        let n = BigUint::from_u64(1000000007).unwrap();
+        let e = BigUint::from_u64(1000000005).unwrap();
+        // Fermat's little theorem: 3^(p-2) is the inverse of 3 modulo p.
+        let inv = int(3).pow_mod(&e, &n).unwrap();
+        assert_eq!(inv.to_u64().unwrap() * 3 % 1000000007, 1);
+        let inv = int(-3).pow_mod(&e, &n).unwrap();
+        assert_eq!(inv.to_u64().unwrap() * 3 % 1000000007, 1000000006);
+
+        assert_eq!(int(-12).gcd(&int(18)).unwrap().to_u64(), Some(6));
+        assert_eq!(int(0).gcd(&int(0)).unwrap().to_u64(), Some(0));
+
+        assert_eq!(format!("{:>6}", int(-42)), "   -42");
+        assert_eq!(format!("{:+}", int(42)), "+42");
+        assert!(BigInt::from_str("").is_err());
+        assert!(BigInt::from_str("-").is_err());
+        assert!(BigInt::from_str("1a").is_err());
+        assert_eq!(BigInt::parse("-zz", 36u32).unwrap().to_i64(), Some(-1295));
+        assert_eq!(int(-1295).format(36u32).unwrap(), "-zz");
+    }
+
+    #[test]
+    fn test_int_large() {
+        for _ in 0..200 {
+            let a = random_int(200);
+            let b = random_int(100);
+
+            // (a + b) - b = a
+            assert_eq!(a.add(&b).unwrap().sub(&b).unwrap(), a);
+
+            // (a * b) / b = a, (a * b + r) % b = r
+            if !b.is_zero() {
+                let (q, r) = a.div_rem(&b).unwrap();
+                assert!(r.magnitude() < b.magnitude());
+                assert!(r.is_zero() || r.sign() == a.sign());
+                assert_eq!(q.mul(&b).unwrap().add(&r).unwrap(), a);
+            }
+
+            // gcd divides both
+            let g = a.gcd(&b).unwrap();
+            if !g.is_zero() {
+                assert!(a.magnitude().rem(&g).unwrap().is_zero());
+                assert!(b.magnitude().rem(&g).unwrap().is_zero());
+                let (a1, b1) = (
+                    a.magnitude().div(&g).unwrap(),
+                    b.magnitude().div(&g).unwrap(),
+                );
+                assert_eq!(a1.gcd(&b1).unwrap().to_u64(), Some(1));
+            }
+
+            // shifts
+            let n = random::<usize>() % 500;
+            assert_eq!(a.shl(n).unwrap().shr(n).unwrap(), a);
+
+            let p2 = BigInt::from(BigUint::from_u64(1).unwrap().shl(n).unwrap());
+            let (q, r) = a.div_rem(&p2).unwrap();
+            let floor = if r.is_negative() { q.sub(&int(1)).unwrap() } else { q };
+            assert_eq!(a.shr(n).unwrap(), floor);
+
+            // bit ops: a ^ b = (a | b) - (a & b)
+            let x = a.bitxor(&b).unwrap();
+            let y = a.bitor(&b).unwrap().sub(&a.bitand(&b).unwrap()).unwrap();
+            assert_eq!(x, y);
+
+            // formatting and parsing
+            for rdx in [Radix::Bin as u32, Radix::Dec as u32, Radix::Hex as u32, 7] {
+                let s = a.format(rdx).unwrap();
+                assert_eq!(BigInt::parse(&s, rdx).unwrap(), a);
+            }
+
+            // conversion to BigFloat
+            let f = a.to_big_float(usize::MAX, RoundingMode::None);
+            assert_eq!(BigInt::from_big_float(&f, RoundingMode::None).unwrap(), a);
+
+            let f = a.to_big_float(128, RoundingMode::ToZero);
+            let b = BigInt::from_big_float(&f, RoundingMode::None).unwrap();
+            assert!(b.magnitude() <= a.magnitude());
+            assert!(
+                a.sub(&b).unwrap().magnitude().bit_len() + 127 <= a.magnitude().bit_len().max(127)
+            );
+        }
+
+        // pow
+        let ten = int(10);
+        let s = ten.pow(1000).unwrap().to_string();
+        assert_eq!(s.len(), 1001);
+        assert!(s.starts_with('1') && s[1..].bytes().all(|c| c == b'0'));
+
+        // pow_mod: a^(phi(n)) = 1 mod n for n = 2^k, a odd
+        let n = BigUint::from_u64(1).unwrap().shl(300).unwrap();
+        let phi = BigUint::from_u64(1).unwrap().shl(299).unwrap();
+        let a = BigInt::parse("-123456789123456789123456789", Radix::Dec).unwrap();
+        assert_eq!(a.pow_mod(&phi, &n).unwrap().to_u64(), Some(1));
+
+        // conversion from BigFloat
+        let f = BigFloat::from_f64(1.5e300, 64);
+        let n = BigInt::from_big_float(&f, RoundingMode::None).unwrap();
+        assert_eq!(n.to_big_float(64, RoundingMode::None), f);
+        assert_eq!(
+            BigInt::from_big_float(&BigFloat::from_f64(-0.5, 64), RoundingMode::Down)
+                .unwrap()
+                .to_i64(),
+            Some(-1)
+        );
+        assert_eq!(
+            BigInt::from_big_float(&BigFloat::from_f64(0.7, 64), RoundingMode::ToZero)
+                .unwrap()
+                .to_i64(),
+            Some(0)
+        );
+        assert!(
+            BigInt::from_big_float(&INF_POS, RoundingMode::None) == Err(Error::InvalidArgument)
+        );
+        assert!(
+            BigUint::from_big_float(&BigFloat::from_f64(-3.0, 64), RoundingMode::None)
+                == Err(Error::InvalidArgument)
+        );
+
+        assert!(int(0).to_big_float(64, RoundingMode::None).is_zero());
+    }
+}
@@ -1,6 +1,8 @@
 //! Astro-float (astronomically large floating point numbers) is a library that implements arbitrary precision floating point numbers.
 //!
 //! See main crate [docs](https://docs.rs/astro-float/latest/astro_float/).
+//!
+//! With the feature `parallel` enabled, large multiplications and the computation of constants use all available cores.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 #![deny(missing_docs)]
@@ -17,11 +19,14 @@ mod conv;
 pub mod ctx;
 mod defs;
 mod ext;
+mod int;
 mod mantissa;
 mod num;
 mod ops;
 mod parser;
+mod rational;
 mod strop;
+mod tuning;
 
 #[cfg(feature = "std")]
 mod for_3rd;
@@ -40,7 +45,12 @@ pub use crate::ext::FromExt;
 pub use crate::ext::INF_NEG;
 pub use crate::ext::INF_POS;
 pub use crate::ext::NAN;
+pub use crate::int::BigInt;
+pub use crate::int::BigUint;
 pub use crate::ops::consts::Consts;
+pub use crate::rational::BigRational;
+pub use crate::strop::FixedFormat;
+pub use crate::tuning::Tuning;
 
 pub use crate::defs::EXPONENT_BIT_SIZE;
 pub use crate::defs::EXPONENT_MAX;
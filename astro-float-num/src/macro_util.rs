@@ -3,7 +3,7 @@
 use crate::{
     common::util::{count_leading_ones, count_leading_zeroes_skip_first},
     defs::DEFAULT_P,
-    BigFloat, Consts, Exponent, RoundingMode, Sign, EXPONENT_BIT_SIZE, INF_NEG, INF_POS,
+    BigFloat, Consts, Exponent, RoundingMode, Sign, Tuning, EXPONENT_BIT_SIZE, INF_NEG, INF_POS,
 };
 
 /// Computes error for BigFloat values near 1. This function is for internal use by macro `expr`.
@@ -25,6 +25,17 @@ pub fn compute_added_err_near_one(arg: &BigFloat, emin: Exponent) -> usize {
     }
 }
 
+/// Calls `f` with the tuning of a context applied, if any. This function is for internal use by macro `expr`.
+/// Without the `std` feature the tuning of the context is ignored.
+#[inline]
+pub fn with_tuning<R>(tuning: Option<Tuning>, f: impl FnOnce() -> R) -> R {
+    match tuning {
+        #[cfg(feature = "std")]
+        Some(t) => t.scope(f),
+        _ => f(),
+    }
+}
+
 #[derive(Debug)]
 pub enum TrigFun {
     Sin,
@@ -43,6 +54,10 @@ pub enum ErrAlgo<'a> {
     Acos(&'a BigFloat, Exponent),
     Acosh(&'a BigFloat, Exponent),
     Atanh(&'a BigFloat, Exponent),
+    Asec(&'a BigFloat, Exponent),
+    Acsc(&'a BigFloat, Exponent),
+    Asech(&'a BigFloat, Exponent),
+    Acoth(&'a BigFloat, Exponent),
 }
 
 /// Computes the precision increment of an arguments to cover the error for a given algorithm.
@@ -209,6 +224,36 @@ pub fn compute_added_err(algo: ErrAlgo<'_>) -> usize {
                 0
             }
         }
+        ErrAlgo::Asec(arg, emin) => {
+            if arg.inexact() && arg.exponent().unwrap_or(0) == 1 {
+                let n = compute_added_err_near_one(arg, emin);
+                2 + if arg.is_positive() { n } else { n / 2 }
+            } else {
+                0
+            }
+        }
+        ErrAlgo::Acsc(arg, emin) => {
+            if arg.inexact() && arg.exponent().unwrap_or(0) == 1 {
+                let n = compute_added_err_near_one(arg, emin);
+                2 + n.div_ceil(2)
+            } else {
+                0
+            }
+        }
+        ErrAlgo::Asech(arg, emin) => {
+            if arg.inexact() && arg.is_positive() && arg.exponent().unwrap_or(1) < 1 {
+                2 + compute_added_err_near_one(arg, emin)
+            } else {
+                0
+            }
+        }
+        ErrAlgo::Acoth(arg, emin) => {
+            if arg.inexact() && arg.exponent().unwrap_or(0) == 1 {
+                2 + compute_added_err_near_one(arg, emin)
+            } else {
+                0
+            }
+        }
     }
 }
 
@@ -9,8 +9,13 @@ use crate::defs::Error;
 use crate::defs::Word;
 use crate::defs::WORD_BASE;
 use crate::defs::WORD_BIT_SIZE;
+use crate::defs::WORD_MAX;
 use crate::defs::WORD_SIGNIFICANT_BIT;
 use crate::mantissa::Mantissa;
+use crate::tuning::Tuning;
+
+// Number of words in the divisor up to which the reciprocal is computed directly by division.
+const RECIP_BASE_LEN: usize = 2000;
 
 impl Mantissa {
     // Basic integer division.
@@ -200,7 +205,11 @@ impl Mantissa {
 
     // Recursive integer division divides m1 by m2, returns quotinent and remainder.
     // prereq: m <= n, m2 is normalized
-    fn div_recursive(m1: &[Word], m2: &[Word]) -> Result<(WordBuf, WordBuf), Error> {
+    pub(super) fn div_recursive(
+        m1: &[Word],
+        m2: &[Word],
+        t: &Tuning,
+    ) -> Result<(WordBuf, WordBuf), Error> {
         debug_assert!(m2[m2.len() - 1] & WORD_SIGNIFICANT_BIT != 0);
 
         if m1.len() < m2.len() {
@@ -217,7 +226,7 @@ impl Mantissa {
 
         debug_assert!(m <= m2.len());
 
-        if m < 70 {
+        if m < t.div_recursive {
             Self::div_basic(m1, m2)
         } else {
             let k = m / 2;
@@ -233,7 +242,7 @@ impl Mantissa {
             let b1 = SliceWithSign::new(&m2[k..], 1); // m2 div 2^k
             let b0 = SliceWithSign::new(&m2[..k], 1); // m2 mod 2^k
 
-            let (mut q1buf, r1) = Self::div_recursive(&a1, &b1)?;
+            let (mut q1buf, r1) = Self::div_recursive(&a1, &b1, t)?;
             let mut q1 = SliceWithSign::new_mut(&mut q1buf, 1);
 
             // a3 = a0 + r1*2^(2*k) - q1*b0*2^k
@@ -243,7 +252,7 @@ impl Mantissa {
 
             tmpbuf[..k].fill(0);
 
-            Self::mul_unbalanced(&q1, &b0, &mut tmpbuf[k..])?;
+            Self::mul_unbalanced_tuned(&q1, &b0, &mut tmpbuf[k..], t)?;
 
             let qbk = SliceWithSign::new(&tmpbuf[..q1.len() + b0.len() + k], 1);
             let mut a3 = SliceWithSign::new_mut(&mut rembuf, 1);
@@ -272,7 +281,7 @@ impl Mantissa {
 
             if ub > k {
                 let a31 = SliceWithSign::new(&rembuf[k..ub], 1); // a3 div 2^(k)
-                let (mut q0, r0) = Self::div_recursive(&a31, &b1)?;
+                let (mut q0, r0) = Self::div_recursive(&a31, &b1, t)?;
                 let mut q0 = SliceWithSign::new_mut(&mut q0, 1);
 
                 // a4 = r0*2^k + (a3 mod 2^k) - q0*b0
@@ -280,7 +289,7 @@ impl Mantissa {
                 rembuf[k + r0.len()..].fill(0);
                 let mut a4 = SliceWithSign::new_mut(&mut rembuf, 1);
 
-                Self::mul_unbalanced(&q0, &b0, &mut tmpbuf)?;
+                Self::mul_unbalanced_tuned(&q0, &b0, &mut tmpbuf, t)?;
 
                 let qb = SliceWithSign::new(&tmpbuf[..q0.len() + b0.len()], 1);
                 a4.sub_assign(&qb);
@@ -327,9 +336,12 @@ impl Mantissa {
 
         let mut m = m1.len() - m2.len();
         let n = m2.len();
+        let t = Tuning::current();
 
-        if m <= n {
-            Self::div_recursive(m1, m2)
+        if n >= t.div_newton && m2[n - 1] & WORD_SIGNIFICANT_BIT != 0 {
+            Self::div_newton(m1, m2)
+        } else if m <= n {
+            Self::div_recursive(m1, m2, &t)
         } else if Self::div_basic_prefer(n, m) {
             Self::div_basic(m1, m2)
         } else {
@@ -343,7 +355,7 @@ impl Mantissa {
             while m > n {
                 let mn = m - n;
 
-                let (q, r) = Self::div_recursive(&buf3[mn..ub], m2)?;
+                let (q, r) = Self::div_recursive(&buf3[mn..ub], m2, &t)?;
 
                 buf1[mn..m].copy_from_slice(&q[..n]);
                 let mut q1 = SliceWithSign::new_mut(&mut buf1[m..], 1);
@@ -358,7 +370,7 @@ impl Mantissa {
                 m -= n;
             }
 
-            let (q, r) = Self::div_recursive(&buf3[..ub], m2)?;
+            let (q, r) = Self::div_recursive(&buf3[..ub], m2, &t)?;
 
             buf1[..m].copy_from_slice(&q[..m]);
             let mut q1 = SliceWithSign::new_mut(&mut buf1[m..], 1);
@@ -369,6 +381,214 @@ impl Mantissa {
         }
     }
 
+    // Approximate reciprocal of a normalized number `a` of n words computed with Newton iteration.
+    // Returns x of n+1 words such that a*x < B^(2n) <= a*(x+2), where B = 2^WORD_BIT_SIZE.
+    // See R. Brent, P. Zimmermann, Modern Computer Arithmetic, algorithm 3.5.
+    fn recip_newton(a: &[Word]) -> Result<WordBuf, Error> {
+        debug_assert!(a[a.len() - 1] & WORD_SIGNIFICANT_BIT != 0);
+
+        let n = a.len();
+
+        let mut x = WordBuf::new(n + 1)?;
+
+        if n <= RECIP_BASE_LEN {
+            // x = floor((B^(2n) - 1) / a)
+            let mut buf = WordBuf::new(2 * n)?;
+            buf.fill(WORD_MAX);
+
+            let (q, _) = Self::div_recursive(&buf, a, &Tuning::current())?;
+
+            let l = q.len().min(n + 1);
+            x[..l].copy_from_slice(&q[..l]);
+            x[l..].fill(0);
+
+            return Ok(x);
+        }
+
+        let l = (n - 1) / 2;
+        let h = n - l;
+
+        let mut xh = Self::recip_newton(&a[l..])?;
+
+        // a*xh is close to B^(n+h): d = B^(n+h) - a*xh, and abs(d) < 2*B^n.
+        // Only d is needed, so the product is computed modulo B^nw + 1, nw > n + 1.
+        let (t, nw) = Self::mul_mod_fermat(a, &xh, n + 2)?;
+
+        let mut buf = WordBuf::new(3 * (nw + 2))?;
+        let (d_buf, rest) = buf.split_at_mut(nw + 2);
+        let (c_buf, modulus_buf) = rest.split_at_mut(nw + 2);
+
+        modulus_buf.fill(0);
+        modulus_buf[0] = 1;
+        modulus_buf[nw] = 1;
+        let modulus = SliceWithSign::new(modulus_buf, 1);
+
+        // c = B^(n+h) mod (B^nw + 1)
+        c_buf.fill(0);
+        if n + h < nw {
+            c_buf[n + h] = 1;
+        } else if n + h == nw {
+            c_buf[nw] = 1;
+        } else {
+            c_buf[0] = 1;
+            c_buf[n + h - nw..nw].fill(WORD_MAX);
+        }
+        let c = SliceWithSign::new(c_buf, 1);
+
+        // d = c - t mod (B^nw + 1), negative values are represented by the upper part of the range.
+        d_buf[..nw + 1].copy_from_slice(&t);
+        d_buf[nw + 1] = 0;
+        let mut d = SliceWithSign::new_mut(d_buf, -1);
+        d.add_assign(&c);
+
+        if d.sign() < 0 && !d.is_zero() {
+            d.add_assign(&modulus);
+        }
+
+        if d[nw] != 0 || d[nw - 1] != 0 {
+            d.sub_assign(&modulus);
+        }
+
+        let a_s = SliceWithSign::new(a, 1);
+        while d.sign() < 0 && !d.is_zero() {
+            SliceWithSign::new_mut(&mut xh, 1).decrement_abs();
+            d.add_assign(&a_s);
+        }
+
+        debug_assert!(d[n + 1..].iter().all(|&v| v == 0));
+
+        // x = xh*B^l + (d div B^l)*xh div B^(2h - l)
+        let mut u = WordBuf::new(2 * h + 2)?;
+        Self::mul_unbalanced(&d[l..n + 1], &xh, &mut u)?;
+
+        x[..l].fill(0);
+        x[l..].copy_from_slice(&xh);
+
+        let mut xs = SliceWithSign::new_mut(&mut x, 1);
+        xs.add_assign(&SliceWithSign::new(&u[2 * h - l..], 1));
+
+        Ok(x)
+    }
+
+    // Division using the reciprocal of the divisor computed with Newton iteration.
+    // prereq: m2 is normalized, m1.len() >= m2.len()
+    pub(super) fn div_newton(m1: &[Word], m2: &[Word]) -> Result<(WordBuf, WordBuf), Error> {
+        debug_assert!(m2[m2.len() - 1] & WORD_SIGNIFICANT_BIT != 0);
+        debug_assert!(m1.len() >= m2.len());
+
+        let n = m2.len();
+        let m = m1.len() - n;
+
+        let d = SliceWithSign::new(m2, 1);
+
+        if m + 2 < n {
+            // The quotient is short: its estimate is given by the leading part of the divisor,
+            // and it exceeds the true quotient by at most 2.
+            let s = n - m - 2;
+
+            let (q0, _) = Self::div_unbalanced(&m1[s..], &m2[s..])?;
+
+            let mut qbuf = WordBuf::new(m + 1)?;
+            qbuf.copy_from_slice(&q0[..m + 1]);
+            debug_assert!(q0[m + 1..].iter().all(|&v| v == 0));
+
+            let mut buf = WordBuf::new(2 * (m1.len() + 1))?;
+            let (prod_buf, rbuf) = buf.split_at_mut(m1.len() + 1);
+
+            Self::mul_unbalanced(&qbuf, m2, prod_buf)?;
+
+            rbuf[..m1.len()].copy_from_slice(m1);
+            rbuf[m1.len()] = 0;
+
+            let mut r = SliceWithSign::new_mut(rbuf, 1);
+            r.sub_assign(&SliceWithSign::new(prod_buf, 1));
+
+            let mut q = SliceWithSign::new_mut(&mut qbuf, 1);
+            Self::div_correction(&mut r, &mut q, d);
+
+            let mut rem = WordBuf::new(n)?;
+            rem.copy_from_slice(&rbuf[..n]);
+
+            return Ok((qbuf, rem));
+        }
+
+        let x = Self::recip_newton(m2)?;
+
+        let mut qbuf = WordBuf::new(m + 1)?;
+        let mut rem = WordBuf::new(n)?;
+
+        let mut buf = WordBuf::new(4 * n + 1)?;
+        let (a_buf, prod_buf) = buf.split_at_mut(2 * n);
+
+        // the leading part of the dividend can be greater than the divisor
+        rem.copy_from_slice(&m1[m..]);
+        let mut r = SliceWithSign::new_mut(&mut rem, 1);
+        if r.cmp(&d) >= 0 {
+            r.sub_assign(&d);
+            qbuf[m] = 1;
+        } else {
+            qbuf[m] = 0;
+        }
+
+        let one = [1];
+        let one = SliceWithSign::new(&one, 1);
+
+        // each step divides the remainder of the previous step followed by the next chunk of the dividend.
+        let mut ub = m;
+        while ub > 0 {
+            let c = ub.min(n);
+            let lb = ub - c;
+
+            // a = rem*B^c + m1[lb..ub], a < d*B^c
+            let a = &mut a_buf[..n + c];
+            a[..c].copy_from_slice(&m1[lb..ub]);
+            a[c..].copy_from_slice(&rem);
+
+            // q = (a div B^n)*x div B^n, and q <= a div d <= q + 4, since x < B^(2n)/d <= x + 2 and d >= B^n/2.
+            // The quotient is corrected below.
+            let prod = &mut prod_buf[..n + c + 1];
+            Self::mul_unbalanced(&a[n..], &x, prod)?;
+            debug_assert!(prod[n + c] == 0);
+
+            let q = &mut qbuf[lb..ub];
+            q.copy_from_slice(&prod[n..n + c]);
+
+            // r = a - q*d is small, and can be computed modulo B^nw + 1, nw > n.
+            let (t, nw) = Self::mul_mod_fermat(q, m2, n + 1)?;
+            let amod = Self::reduce_mod_fermat(a, nw)?;
+
+            let mut rbuf = WordBuf::new(2 * (nw + 2))?;
+            let (rb, modulus_buf) = rbuf.split_at_mut(nw + 2);
+
+            modulus_buf.fill(0);
+            modulus_buf[0] = 1;
+            modulus_buf[nw] = 1;
+            let modulus = SliceWithSign::new(modulus_buf, 1);
+
+            rb[..nw + 1].copy_from_slice(&amod);
+            rb[nw + 1] = 0;
+            let mut r = SliceWithSign::new_mut(rb, 1);
+            r.sub_assign(&SliceWithSign::new(&t, 1));
+
+            if r.sign() < 0 && !r.is_zero() {
+                r.add_assign(&modulus);
+            }
+
+            let mut qs = SliceWithSign::new_mut(q, 1);
+            while r.cmp(&d) >= 0 {
+                r.sub_assign(&d);
+                qs.add_assign(&one);
+            }
+
+            debug_assert!(r[n..].iter().all(|&v| v == 0));
+            rem.copy_from_slice(&r[..n]);
+
+            ub = lb;
+        }
+
+        Ok((qbuf, rem))
+    }
+
     // short division
     // prepreq: m1.len() = 2*m2.len()
     #[allow(dead_code)] // TODO: consider performance improvement
@@ -494,6 +714,82 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_div_newton() {
+        // reciprocal
+        for _ in 0..3 {
+            let a = random_normalized_slice(RECIP_BASE_LEN + 1, RECIP_BASE_LEN * 3);
+            let n = a.len();
+            let x = Mantissa::recip_newton(&a).unwrap();
+
+            // a*x < B^(2n) <= a*(x + 2)
+            let mut prod = vec![0; 2 * n + 1];
+            Mantissa::mul_unbalanced(&a, &x, &mut prod).unwrap();
+            assert!(prod[2 * n] == 0);
+
+            let mut ps = SliceWithSign::new_mut(&mut prod, 1);
+            ps.add_assign(&SliceWithSign::new(&a, 1));
+            ps.add_assign(&SliceWithSign::new(&a, 1));
+            assert!(prod[2 * n] != 0);
+        }
+
+        // division
+        for (l1, l2) in [(4000, 3000), (6000, 2500), (2500, 2400), (9000, 2100), (3000, 3000)] {
+            let s1 = random_normalized_slice(l1, l1);
+            let s2 = random_normalized_slice(l2, l2);
+
+            let (q, r) = Mantissa::div_newton(&s1, &s2).unwrap();
+            let (q2, r2) = Mantissa::div_basic(&s1, &s2).unwrap();
+
+            assert!(q[..] == q2[..q.len()] && q2[q.len()..].iter().all(|&v| v == 0));
+            assert!(r[..] == r2[..]);
+        }
+
+        // automatic selection
+        let n = Tuning::DEFAULT.div_newton;
+        for (l1, l2) in [(2 * n + 1, n), (n + 100, n)] {
+            let s1 = random_normalized_slice(l1, l1);
+            let s2 = random_normalized_slice(l2, l2);
+
+            let (q, r) = Mantissa::div_unbalanced(&s1, &s2).unwrap();
+            let (q2, r2) = Mantissa::div_basic(&s1, &s2).unwrap();
+
+            assert!(q[..] == q2[..q.len()] && q2[q.len()..].iter().all(|&v| v == 0));
+            assert!(r[..] == r2[..]);
+        }
+
+        // small thresholds
+        #[cfg(feature = "std")]
+        {
+            let t = Tuning {
+                mul_basic: 0,
+                mul_toom2: 0,
+                mul_toom3: 0,
+                mul_fft: 0,
+                mul_ntt: usize::MAX,
+                mul_balance: 0,
+                div_recursive: 0,
+                div_newton: 0,
+                series_rect: 0,
+                series_bsplit: 0,
+                ln_agm: 0,
+                exp_newton: 0,
+                par_mul: 0,
+            };
+
+            for (l1, l2) in [(700, 300), (301, 300), (1000, 40), (40, 40)] {
+                let s1 = random_normalized_slice(l1, l1);
+                let s2 = random_normalized_slice(l2, l2);
+
+                let (q, r) = t.scope(|| Mantissa::div_unbalanced(&s1, &s2)).unwrap();
+                let (q2, r2) = Mantissa::div_basic(&s1, &s2).unwrap();
+
+                assert!(q[..] == q2[..q.len()] && q2[q.len()..].iter().all(|&v| v == 0));
+                assert!(r[..] == r2[..]);
+            }
+        }
+    }
+
     #[ignore]
     #[test]
     #[cfg(feature = "std")]
@@ -527,6 +823,46 @@ mod tests {
         }
     }
 
+    #[ignore]
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_div_newton_perf() {
+        for sz in [2000, 4000, 8000, 16000, 32000, 64000] {
+            let f = random_normalized_slice(sz * 2, sz * 2);
+            let mut n = vec![];
+            let l = 5;
+            for _ in 0..l {
+                let v = random_normalized_slice(sz, sz);
+                n.push(v);
+            }
+
+            // recursive
+            let start_time = std::time::Instant::now();
+            for ni in &n {
+                let _ = Mantissa::div_recursive(&f, ni, &Tuning::DEFAULT).unwrap();
+            }
+            let time = start_time.elapsed();
+            println!("{} div_recursive {}", sz, time.as_millis());
+
+            // newton
+            let start_time = std::time::Instant::now();
+            for ni in &n {
+                let _ = Mantissa::div_newton(&f, ni).unwrap();
+            }
+            let time = start_time.elapsed();
+            println!("{} div_newton {}", sz, time.as_millis());
+
+            // multiplication for reference
+            let mut ret = vec![0; sz * 2];
+            let start_time = std::time::Instant::now();
+            for ni in &n {
+                Mantissa::mul_unbalanced(&f[sz..], ni, &mut ret).unwrap();
+            }
+            let time = start_time.elapsed();
+            println!("{} mul_unbalanced {}", sz, time.as_millis());
+        }
+    }
+
     #[ignore]
     #[test]
     #[cfg(feature = "std")]
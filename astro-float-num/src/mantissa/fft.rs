@@ -2,6 +2,8 @@
 
 use crate::common::buf::WordBuf;
 use crate::common::int::SliceWithSign;
+use crate::common::par::join;
+use crate::common::par::par_enabled;
 use crate::common::util::add_carry;
 use crate::common::util::log2_ceil;
 use crate::common::util::shift_slice_left_copy;
@@ -12,11 +14,13 @@ use crate::defs::Word;
 use crate::defs::WORD_BIT_SIZE;
 use crate::defs::WORD_MAX;
 use crate::mantissa::Mantissa;
-use itertools::izip;
 
 #[cfg(not(feature = "std"))]
 use alloc::vec::Vec;
 
+// Number of words above which a product modulo B^n+1 is computed with FFT directly.
+const FFT_MOD_FERMAT_THRESHOLD: usize = 2500;
+
 impl Mantissa {
     fn fft_w_shift(j: usize, k: usize) -> usize {
         let mut j = j as u64;
@@ -400,30 +404,163 @@ impl Mantissa {
         Ok(parts)
     }
 
+    // compute the negacyclic convolution of parts1 and parts2 modulo 2^n1+1 into parts3,
+    // i.e. the coefficients of the product of two polynomials modulo x^k1+1.
+    // If parts2 is None, parts1 is convolved with itself using a single forward transform.
+    #[allow(clippy::too_many_arguments)]
+    fn fft_convolve(
+        parts1: &mut [SliceWithSign],
+        mut parts2: Option<&mut [SliceWithSign]>,
+        parts3: &mut [SliceWithSign],
+        k1: usize,
+        k: usize,
+        n1: usize,
+        t: usize,
+        modulus: &SliceWithSign,
+        tmp_buf: &mut [Word],
+        tmp_buf2: &mut [Word],
+    ) -> Result<(), Error> {
+        let w = t * 2;
+
+        for (j, part1) in parts1.iter_mut().enumerate() {
+            Self::fft_mul_mod(part1, t * j, n1, modulus, tmp_buf);
+        }
+
+        Self::fft_forward(parts1, w, k1, k, 1, n1, modulus, tmp_buf);
+
+        if let Some(parts2) = parts2.as_deref_mut() {
+            for (j, part2) in parts2.iter_mut().enumerate() {
+                Self::fft_mul_mod(part2, t * j, n1, modulus, tmp_buf);
+            }
+
+            Self::fft_forward(parts2, w, k1, k, 1, n1, modulus, tmp_buf);
+        }
+
+        Self::fft_pointwise(
+            parts1,
+            parts2.as_deref(),
+            parts3,
+            n1,
+            modulus,
+            tmp_buf,
+            tmp_buf2,
+        )?;
+
+        Self::fft_reverse(parts3, w, k1, n1, modulus, tmp_buf);
+
+        for (j, part3) in parts3.iter_mut().enumerate() {
+            Self::fft_div_mod(part3, k + t * j, n1, modulus, tmp_buf);
+
+            while part3.sign() < 0 && !part3.is_zero() {
+                part3.add_assign(modulus);
+            }
+        }
+
+        Ok(())
+    }
+
+    // compute the pointwise products of parts1 and parts2 (or squares of parts1 if parts2 is None) modulo 2^n1+1 into parts3.
+    // The products are split in two halves computed in parallel if the parts are large enough.
+    fn fft_pointwise(
+        parts1: &[SliceWithSign],
+        parts2: Option<&[SliceWithSign]>,
+        parts3: &mut [SliceWithSign],
+        n1: usize,
+        modulus: &SliceWithSign,
+        tmp_buf: &mut [Word],
+        tmp_buf2: &mut [Word],
+    ) -> Result<(), Error> {
+        let part_len = n1 / WORD_BIT_SIZE + 1;
+
+        if parts3.len() > 1 && par_enabled(parts3.len() * part_len) {
+            let h = parts3.len() / 2;
+            let (parts1_lo, parts1_hi) = parts1.split_at(h);
+            let parts2 = parts2.map(|p| p.split_at(h));
+            let (parts3_lo, parts3_hi) = parts3.split_at_mut(h);
+            let (l1, l2) = (tmp_buf.len(), tmp_buf2.len());
+
+            let (r1, r2) = join(
+                true,
+                || {
+                    let parts2_lo = parts2.map(|p| p.0);
+                    Self::fft_pointwise(
+                        parts1_lo, parts2_lo, parts3_lo, n1, modulus, tmp_buf, tmp_buf2,
+                    )
+                },
+                || {
+                    let parts2_hi = parts2.map(|p| p.1);
+                    let mut buf = WordBuf::new(l1 + l2)?;
+                    let (tmp_buf, tmp_buf2) = buf.split_at_mut(l1);
+                    Self::fft_pointwise(
+                        parts1_hi, parts2_hi, parts3_hi, n1, modulus, tmp_buf, tmp_buf2,
+                    )
+                },
+            );
+
+            return r1.and(r2);
+        }
+
+        for (j, (part1, part3)) in parts1.iter().zip(parts3.iter_mut()).enumerate() {
+            let part2 = match parts2 {
+                Some(parts2) => &parts2[j],
+                None => part1,
+            };
+
+            Self::mul_unbalanced(part1, part2, tmp_buf2)?;
+
+            part3.set_sign(part1.sign() * part2.sign());
+
+            let mut t0 = SliceWithSign::new_mut(tmp_buf2, part3.sign());
+            Self::fft_mul_mod(&mut t0, 0, n1, modulus, tmp_buf);
+
+            part3.copy_from_slice(&tmp_buf2[..part_len]);
+        }
+
+        Ok(())
+    }
+
     // multiply two integer numbers.
+    #[inline]
     pub(super) fn fft_mul(d1: &[Word], d2: &[Word], d3: &mut [Word]) -> Result<(), Error> {
-        let l: usize = (d1.len() + d2.len()) * WORD_BIT_SIZE;
+        Self::fft_mul_impl(d1, Some(d2), d3)
+    }
+
+    // square an integer number.
+    #[inline]
+    pub(super) fn fft_sqr(d1: &[Word], d3: &mut [Word]) -> Result<(), Error> {
+        Self::fft_mul_impl(d1, None, d3)
+    }
+
+    // multiply d1 by d2, or square d1 if d2 is None.
+    fn fft_mul_impl(d1: &[Word], d2: Option<&[Word]>, d3: &mut [Word]) -> Result<(), Error> {
+        let l: usize = (d1.len() + d2.unwrap_or(d1).len()) * WORD_BIT_SIZE;
 
         let (_n, k1, k, m, n1, t) = Self::fft_params(l);
 
-        let w = t * 2;
         let part_len = n1 / WORD_BIT_SIZE + 1;
+        let nparts = if d2.is_some() { 3 } else { 2 };
 
-        let mut buf = WordBuf::new(3 * k1 * part_len + 6 * part_len)?;
+        let mut buf = WordBuf::new(nparts * k1 * part_len + 6 * part_len)?;
 
         let (parts1_buf, rest) = buf.split_at_mut(k1 * part_len);
-        let (parts2_buf, rest) = rest.split_at_mut(k1 * part_len);
         let (parts3_buf, rest) = rest.split_at_mut(k1 * part_len);
+        let (parts2_buf, rest) = rest.split_at_mut((nparts - 2) * k1 * part_len);
         //let (thres_buf, rest) = rest.split_at_mut(2*part_len);
         let (modulus_buf, rest) = rest.split_at_mut(part_len);
         let (tmp_buf, tmp_buf2) = rest.split_at_mut(part_len * 3);
 
         let mut parts1 = Self::fft_prepare_parts(parts1_buf, k1, part_len)?;
-        let mut parts2 = Self::fft_prepare_parts(parts2_buf, k1, part_len)?;
         let mut parts3 = Self::fft_prepare_parts(parts3_buf, k1, part_len)?;
 
         Self::fft_decompose(d1, m, &mut parts1);
-        Self::fft_decompose(d2, m, &mut parts2);
+
+        let mut parts2 = if let Some(d2) = d2 {
+            let mut parts2 = Self::fft_prepare_parts(parts2_buf, k1, part_len)?;
+            Self::fft_decompose(d2, m, &mut parts2);
+            Some(parts2)
+        } else {
+            None
+        };
 
         modulus_buf.fill(0);
         modulus_buf[0] = 1;
@@ -432,36 +569,22 @@ impl Mantissa {
 
         //let mut thres = SliceWithSign::new_mut(thres_buf, 1);
 
-        for (j, (part1, part2)) in parts1.iter_mut().zip(parts2.iter_mut()).enumerate() {
-            Self::fft_mul_mod(part1, t * j, n1, &modulus, tmp_buf);
-            Self::fft_mul_mod(part2, t * j, n1, &modulus, tmp_buf);
-        }
-
-        Self::fft_forward(&mut parts1, w, k1, k, 1, n1, &modulus, tmp_buf);
-        Self::fft_forward(&mut parts2, w, k1, k, 1, n1, &modulus, tmp_buf);
-
-        for (part1, part2, part3) in izip!(parts1.iter(), parts2.iter(), parts3.iter_mut()) {
-            Self::mul_unbalanced(part1, part2, tmp_buf2)?;
-
-            part3.set_sign(part1.sign() * part2.sign());
-
-            let mut t0 = SliceWithSign::new_mut(tmp_buf2, part3.sign());
-            Self::fft_mul_mod(&mut t0, 0, n1, &modulus, tmp_buf);
-
-            part3.copy_from_slice(&tmp_buf2[..part_len]);
-        }
-
-        Self::fft_reverse(&mut parts3, w, k1, n1, &modulus, tmp_buf);
+        Self::fft_convolve(
+            &mut parts1,
+            parts2.as_deref_mut(),
+            &mut parts3,
+            k1,
+            k,
+            n1,
+            t,
+            &modulus,
+            tmp_buf,
+            tmp_buf2,
+        )?;
 
         d3.fill(0);
 
         for (j, part3) in parts3.iter_mut().enumerate() {
-            Self::fft_div_mod(part3, k + t * j, n1, &modulus, tmp_buf);
-
-            while part3.sign() < 0 && !part3.is_zero() {
-                part3.add_assign(&modulus);
-            }
-
             /*             thres[m*2 / WORD_BIT_SIZE] = 0;
             thres[m*2 / WORD_BIT_SIZE + 1] = 0;
             thres[0] = (j + 1) as Word;
@@ -490,6 +613,165 @@ impl Mantissa {
 
         Ok(())
     }
+
+    // reduce d modulo B^nw+1, where B = 2^WORD_BIT_SIZE, assuming d has not more than a few times nw words.
+    // The result has nw+1 words, and is in the range [0, B^nw].
+    pub(super) fn reduce_mod_fermat(d: &[Word], nw: usize) -> Result<WordBuf, Error> {
+        let mut buf = WordBuf::new(2 * (nw + 2))?;
+
+        let (acc_buf, modulus_buf) = buf.split_at_mut(nw + 2);
+
+        modulus_buf.fill(0);
+        modulus_buf[0] = 1;
+        modulus_buf[nw] = 1;
+        let modulus = SliceWithSign::new(modulus_buf, 1);
+
+        let n = nw * WORD_BIT_SIZE;
+
+        let mut acc = Self::fft_compute_chunks(d, n, acc_buf, 1);
+        Self::fft_normalize(&mut acc, n, &modulus);
+
+        let mut ret = WordBuf::new(nw + 1)?;
+        ret.copy_from_slice(&acc_buf[..nw + 1]);
+
+        Ok(ret)
+    }
+
+    // multiply two integer numbers modulo 2^n+1, where n >= l is the returned number of bits.
+    // The result has n / WORD_BIT_SIZE + 1 words, and is in the range [0, 2^n].
+    fn fft_mul_mod_fermat(d1: &[Word], d2: &[Word], l: usize) -> Result<(WordBuf, usize), Error> {
+        let (n, k1, k, m, n1, _t) = Self::fft_params(l);
+
+        // coefficients of a negacyclic convolution can be negative, and one more bit is needed to recover the sign.
+        let n1 = if n1 < 2 * m + k + 1 { n1 + k1 } else { n1 };
+
+        // decomposition in parts requires the part size to be large enough.
+        debug_assert!(m >= 2 * WORD_BIT_SIZE);
+        let t = n1 / k1;
+
+        let nw = n / WORD_BIT_SIZE;
+        let part_len = n1 / WORD_BIT_SIZE + 1;
+        let acc_len = nw + part_len + 2;
+
+        // 2^n = -1 (mod 2^n+1) does not fit in k1 parts.
+        let mut negate = false;
+        let mut r1 = Self::reduce_mod_fermat(d1, nw)?;
+        let mut r2 = Self::reduce_mod_fermat(d2, nw)?;
+        for r in [&mut r1, &mut r2] {
+            if r[nw] != 0 {
+                r[nw] = 0;
+                r[0] = 1;
+                negate = !negate;
+            }
+        }
+
+        let mut buf = WordBuf::new(3 * k1 * part_len + 6 * part_len + 2 * acc_len + 2 * (nw + 2))?;
+
+        let (parts1_buf, rest) = buf.split_at_mut(k1 * part_len);
+        let (parts2_buf, rest) = rest.split_at_mut(k1 * part_len);
+        let (parts3_buf, rest) = rest.split_at_mut(k1 * part_len);
+        let (modulus_buf, rest) = rest.split_at_mut(part_len);
+        let (tmp_buf, rest) = rest.split_at_mut(part_len * 3);
+        let (tmp_buf2, rest) = rest.split_at_mut(part_len * 2);
+        let (pos_buf, rest) = rest.split_at_mut(acc_len);
+        let (neg_buf, acc_buf) = rest.split_at_mut(acc_len);
+
+        let mut parts1 = Self::fft_prepare_parts(parts1_buf, k1, part_len)?;
+        let mut parts2 = Self::fft_prepare_parts(parts2_buf, k1, part_len)?;
+        let mut parts3 = Self::fft_prepare_parts(parts3_buf, k1, part_len)?;
+
+        Self::fft_decompose(&r1[..nw], m, &mut parts1);
+        Self::fft_decompose(&r2[..nw], m, &mut parts2);
+
+        modulus_buf.fill(0);
+        modulus_buf[0] = 1;
+        modulus_buf[n1 / WORD_BIT_SIZE] = 1;
+        let modulus = SliceWithSign::new_mut(modulus_buf, 1);
+
+        Self::fft_convolve(
+            &mut parts1,
+            Some(&mut parts2),
+            &mut parts3,
+            k1,
+            k,
+            n1,
+            t,
+            &modulus,
+            tmp_buf,
+            tmp_buf2,
+        )?;
+
+        // positive and negative coefficients are accumulated separately.
+        pos_buf.fill(0);
+        neg_buf.fill(0);
+
+        let sign_idx = (n1 - 1) / WORD_BIT_SIZE;
+        let sign_bit = 1 << ((n1 - 1) % WORD_BIT_SIZE);
+
+        for (j, part3) in parts3.iter_mut().enumerate() {
+            let out_buf = if part3[n1 / WORD_BIT_SIZE] != 0 || part3[sign_idx] & sign_bit != 0 {
+                part3.sub_assign(&modulus);
+                part3.set_sign(1);
+                &mut *neg_buf
+            } else {
+                &mut *pos_buf
+            };
+
+            let jm = j * m;
+            let idx = jm / WORD_BIT_SIZE;
+            let shift = jm % WORD_BIT_SIZE;
+
+            let mut out = SliceWithSign::new_mut(&mut out_buf[idx..idx + part_len + 2], 1);
+            part3.shift_left(shift);
+            out.add_assign(part3);
+        }
+
+        let mut acc = SliceWithSign::new_mut(pos_buf, 1);
+        let neg = SliceWithSign::new(neg_buf, 1);
+        acc.sub_assign(&neg);
+
+        let s = if acc.sign() < 0 { -1 } else { 1 };
+
+        // reduce modulo 2^n+1
+        let (acc_buf, modulus_n_buf) = acc_buf.split_at_mut(nw + 2);
+        modulus_n_buf.fill(0);
+        modulus_n_buf[0] = 1;
+        modulus_n_buf[nw] = 1;
+        let modulus_n = SliceWithSign::new(modulus_n_buf, 1);
+
+        let mut r = Self::fft_compute_chunks(&acc, n, acc_buf, s);
+        Self::fft_normalize(&mut r, n, &modulus_n);
+
+        if negate && !r.is_zero() {
+            r.set_sign(-1);
+            r.add_assign(&modulus_n);
+        }
+
+        let mut ret = WordBuf::new(nw + 1)?;
+        ret.copy_from_slice(&r[..nw + 1]);
+
+        Ok((ret, n))
+    }
+
+    // multiply two integer numbers modulo B^n+1, where B = 2^WORD_BIT_SIZE, and n >= nw is the returned number of words.
+    // The result has n+1 words, and is in the range [0, B^n].
+    // Computing the product modulo B^n+1 is cheaper than computing the full product
+    // when only a part of the product is unknown (the wrap-around trick).
+    pub(super) fn mul_mod_fermat(
+        d1: &[Word],
+        d2: &[Word],
+        nw: usize,
+    ) -> Result<(WordBuf, usize), Error> {
+        if nw > FFT_MOD_FERMAT_THRESHOLD && d1.len() + d2.len() > nw {
+            let (ret, n) = Self::fft_mul_mod_fermat(d1, d2, nw * WORD_BIT_SIZE)?;
+            Ok((ret, n / WORD_BIT_SIZE))
+        } else {
+            let mut buf = WordBuf::new(d1.len() + d2.len())?;
+            Self::mul_unbalanced(d1, d2, &mut buf)?;
+            let ret = Self::reduce_mod_fermat(&buf, nw)?;
+            Ok((ret, nw))
+        }
+    }
 }
 
 #[cfg(test)]
@@ -616,6 +898,54 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_fft_mul_mod_fermat() {
+        for _ in 0..100 {
+            let s1 = random_slice(1, 3000);
+            let s2 = random_slice(1, 3000);
+            let nw = random::<usize>() % (s1.len() + s2.len()) + 500;
+
+            let (ret, n) = Mantissa::fft_mul_mod_fermat(&s1, &s2, nw * WORD_BIT_SIZE).unwrap();
+            let n = n / WORD_BIT_SIZE;
+
+            let mut prod = vec![0; s1.len() + s2.len()];
+            mul(&s1, &s2, &mut prod);
+            let ref_s = Mantissa::reduce_mod_fermat(&prod, n).unwrap();
+
+            assert!(n >= nw);
+            assert!(ret[..] == ref_s[..]);
+        }
+
+        // factors equal to -1 modulo 2^n+1
+        let (_, n) = Mantissa::fft_mul_mod_fermat(&[1], &[1], 1000 * WORD_BIT_SIZE).unwrap();
+        let n = n / WORD_BIT_SIZE;
+        let mut s1 = vec![0; n + 1];
+        s1[n] = 1;
+        let s2 = random_slice(n, n);
+
+        let (ret, _) = Mantissa::fft_mul_mod_fermat(&s1, &s2, n * WORD_BIT_SIZE).unwrap();
+        let mut prod = vec![0; s1.len() + s2.len()];
+        mul(&s1, &s2, &mut prod);
+        assert!(ret[..] == Mantissa::reduce_mod_fermat(&prod, n).unwrap()[..]);
+
+        let (ret, _) = Mantissa::fft_mul_mod_fermat(&s1, &s1, n * WORD_BIT_SIZE).unwrap();
+        assert!(ret[0] == 1 && ret[1..].iter().all(|&x| x == 0));
+
+        // modulo B^n+1 with the wrap-around
+        for _ in 0..10 {
+            let s1 = random_slice(1, 10000);
+            let s2 = random_slice(1, 10000);
+            let nw = random::<usize>() % (s1.len() + s2.len()) + 1;
+
+            let (ret, n) = Mantissa::mul_mod_fermat(&s1, &s2, nw).unwrap();
+
+            let mut prod = vec![0; s1.len() + s2.len()];
+            Mantissa::mul_unbalanced(&s1, &s2, &mut prod).unwrap();
+
+            assert!(ret[..] == Mantissa::reduce_mod_fermat(&prod, n).unwrap()[..]);
+        }
+    }
+
     #[ignore]
     #[test]
     #[cfg(feature = "std")]
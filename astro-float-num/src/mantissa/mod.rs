@@ -7,9 +7,14 @@ mod fft;
 #[allow(clippy::module_inception)]
 mod mantissa;
 mod mul;
+mod nat;
+mod ntt;
 mod sqrt;
 mod toom2;
 mod toom3;
+#[cfg(feature = "std")]
+mod tune;
 mod util;
 
+pub(crate) use conv::rdx_word_id;
 pub use mantissa::Mantissa;
@@ -2,11 +2,15 @@
 
 use crate::common::buf::WordBuf;
 use crate::common::int::SliceWithSign;
+use crate::common::util::add_carry;
+use crate::common::util::shift_slice_left;
 use crate::defs::DoubleWord;
 use crate::defs::Error;
 use crate::defs::Word;
 use crate::defs::WORD_BIT_SIZE;
 use crate::mantissa::Mantissa;
+use crate::tuning::Tuning;
+use crate::tuning::MUL_BASIC_MIN;
 
 impl Mantissa {
     pub(super) fn mul_basic(m1: &[Word], m2: &[Word], m3: &mut [Word]) {
@@ -29,26 +33,109 @@ impl Mantissa {
         }
     }
 
-    fn mul_slices(m1: &[Word], m2: &[Word], m3: &mut [Word]) -> Result<(), Error> {
+    // Symmetric basic squaring: products of different words are computed once and doubled.
+    pub(super) fn sqr_basic(m1: &[Word], m3: &mut [Word]) {
+        m3.fill(0);
+
+        for (i, d1mi) in m1.iter().enumerate() {
+            let d1mi = *d1mi as DoubleWord;
+            if d1mi == 0 {
+                continue;
+            }
+
+            let mut k = 0;
+            for (m1j, m3ij) in m1[i + 1..].iter().zip(m3[2 * i + 1..].iter_mut()) {
+                let m = d1mi * (*m1j as DoubleWord) + *m3ij as DoubleWord + k;
+                *m3ij = m as Word;
+                k = m >> (WORD_BIT_SIZE);
+            }
+
+            m3[i + m1.len()] += k as Word;
+        }
+
+        shift_slice_left(&mut m3[..2 * m1.len()], 1);
+
+        let mut c = 0;
+        for (d1mi, m3i) in m1.iter().zip(m3.chunks_mut(2)) {
+            let m = *d1mi as DoubleWord * *d1mi as DoubleWord;
+            c = add_carry(m3i[0], m as Word, c, &mut m3i[0]);
+            c = add_carry(m3i[1], (m >> WORD_BIT_SIZE) as Word, c, &mut m3i[1]);
+        }
+
+        debug_assert!(c == 0);
+    }
+
+    // squaring with tuning `t`, m3 must have at least 2*m1.len() words.
+    pub(super) fn sqr_tuned(m1: &[Word], m3: &mut [Word], t: &Tuning) -> Result<(), Error> {
+        if m1.len() <= t.mul_basic {
+            Self::sqr_basic(m1, m3);
+        } else if m1.len() <= t.mul_toom2 {
+            Self::toom2_sqr(m1, m3, t)?;
+        } else if m1.len() <= t.mul_toom3 {
+            Self::toom3_sqr(m1, m3, t)?;
+        } else if m1.len() <= t.mul_fft || m1.len() > t.mul_ntt {
+            Mantissa::fft_sqr(m1, m3)?;
+        } else {
+            Mantissa::ntt_sqr(m1, m3)?;
+        }
+        Ok(())
+    }
+
+    // squaring, m3 must have at least 2*m1.len() words.
+    #[inline]
+    pub(super) fn sqr(m1: &[Word], m3: &mut [Word]) -> Result<(), Error> {
+        if m1.len() <= MUL_BASIC_MIN {
+            Self::sqr_basic(m1, m3);
+            Ok(())
+        } else {
+            Self::sqr_tuned(m1, m3, &Tuning::current())
+        }
+    }
+
+    fn mul_slices(m1: &[Word], m2: &[Word], m3: &mut [Word], t: &Tuning) -> Result<(), Error> {
         debug_assert!(m1.len() <= m2.len());
 
-        if m1.len() <= 32 || m2.len() <= 32 {
+        if m1.len() <= t.mul_basic || m2.len() <= t.mul_basic {
             Self::mul_basic(m1, m2, m3);
-        } else if m1.len() <= 220 || m2.len() <= 220 {
-            Self::toom2(m1, m2, m3)?;
-        } else if m1.len() <= 5400 && m2.len() <= 5400 {
-            Self::toom3(m1, m2, m3)?;
-        } else {
+        } else if m1.len() <= t.mul_toom2 || m2.len() <= t.mul_toom2 {
+            Self::toom2(m1, m2, m3, t)?;
+        } else if m1.len() <= t.mul_toom3 && m2.len() <= t.mul_toom3 {
+            Self::toom3(m1, m2, m3, t)?;
+        } else if m2.len() <= t.mul_fft || m2.len() > t.mul_ntt {
             Mantissa::fft_mul(m1, m2, m3)?;
+        } else {
+            Mantissa::ntt_mul(m1, m2, m3)?;
         }
         Ok(())
     }
 
     // general case multiplication
+    #[inline]
     pub(super) fn mul_unbalanced(m1: &[Word], m2: &[Word], m3: &mut [Word]) -> Result<(), Error> {
+        if core::ptr::eq(m1, m2) {
+            Self::sqr(m1, m3)
+        } else if m1.len().min(m2.len()) <= MUL_BASIC_MIN {
+            Self::mul_basic(m1, m2, m3);
+            Ok(())
+        } else {
+            Self::mul_unbalanced_tuned(m1, m2, m3, &Tuning::current())
+        }
+    }
+
+    // general case multiplication with tuning `t`
+    pub(super) fn mul_unbalanced_tuned(
+        m1: &[Word],
+        m2: &[Word],
+        m3: &mut [Word],
+        t: &Tuning,
+    ) -> Result<(), Error> {
+        if core::ptr::eq(m1, m2) {
+            return Self::sqr_tuned(m1, m3, t);
+        }
+
         let (sm, lg) = if m1.len() < m2.len() { (m1, m2) } else { (m2, m1) };
 
-        if lg.len() / 2 >= sm.len() && sm.len() > 70 {
+        if lg.len() / 2 >= sm.len() && sm.len() > t.mul_balance {
             // balancing
 
             let mut buf = WordBuf::new(2 * sm.len())?;
@@ -60,7 +147,7 @@ impl Mantissa {
                 while lb < lg.len() {
                     ub = if lb + sm.len() <= lg.len() { lb + sm.len() } else { lg.len() };
 
-                    Self::mul_slices(&lg[lb..ub], sm, &mut buf)?;
+                    Self::mul_slices(&lg[lb..ub], sm, &mut buf, t)?;
 
                     let src = SliceWithSign::new(&buf[..ub - lb + sm.len()], 1);
                     let mut dst = SliceWithSign::new_mut(&mut m3[lb..], 1);
@@ -86,7 +173,7 @@ impl Mantissa {
 
             Ok(())
         } else {
-            Self::mul_slices(sm, lg, m3)
+            Self::mul_slices(sm, lg, m3, t)
         }
     }
 
@@ -159,11 +246,53 @@ mod tests {
         for _ in 0..1000 {
             let v = random_slice(sz1, sz2);
             Mantissa::mul_unbalanced(&f, &v, &mut ret1).unwrap();
-            Mantissa::mul_slices(&f, &v, &mut ret2).unwrap();
+            Mantissa::mul_slices(&f, &v, &mut ret2, &Tuning::DEFAULT).unwrap();
             assert!(ret1[..] == ret2[..]);
         }
     }
 
+    #[test]
+    fn test_sqr() {
+        // squaring kernels
+        for (min_len, max_len) in [(1, 40), (33, 220), (221, 1000), (5401, 7000)] {
+            for _ in 0..10 {
+                let s1 = random_slice(min_len, max_len);
+                let s2 = s1.clone();
+
+                let mut ret1 = WordBuf::new(s1.len() * 2).unwrap();
+                let mut ret2 = WordBuf::new(s1.len() * 2).unwrap();
+
+                Mantissa::mul_unbalanced(&s1, &s1, &mut ret1).unwrap();
+                Mantissa::mul_unbalanced(&s1, &s2, &mut ret2).unwrap();
+
+                assert!(ret1[..] == ret2[..]);
+            }
+        }
+
+        // max values
+        let s1 = [WORD_MAX; 300];
+        let mut ret1 = [0; 600];
+        let mut ret2 = [0; 600];
+        Mantissa::sqr(&s1, &mut ret1).unwrap();
+        Mantissa::mul_basic(&s1, &s1.clone(), &mut ret2);
+        assert!(ret1 == ret2);
+
+        for l in 1..70 {
+            let s1 = random_slice(l, l);
+            let mut ret1 = vec![WORD_MAX; l * 2 + 1];
+            let mut ret2 = vec![0; l * 2 + 1];
+            Mantissa::sqr_basic(&s1, &mut ret1);
+            Mantissa::mul_basic(&s1, &s1, &mut ret2);
+            assert!(ret1 == ret2);
+
+            Mantissa::toom2_sqr(&s1, &mut ret1[..l * 2], &Tuning::DEFAULT).unwrap();
+            assert!(ret1 == ret2);
+
+            Mantissa::toom3_sqr(&s1, &mut ret1[..l * 2], &Tuning::DEFAULT).unwrap();
+            assert!(ret1 == ret2);
+        }
+    }
+
     #[ignore]
     #[test]
     fn test_mul_short() {
@@ -195,6 +324,71 @@ mod tests {
         assert!(ret[..10] == s3[10..]);
     }
 
+    #[ignore]
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_sqr_perf() {
+        for sz in [20, 100, 1000, 5000, 20000] {
+            let mut n = vec![];
+            let l = 200000 / sz + 2;
+            for _ in 0..l {
+                let v = random_slice(sz, sz);
+                n.push(v);
+            }
+
+            let mut ret = WordBuf::new(sz * 2).unwrap();
+
+            let start_time = std::time::Instant::now();
+            for ni in n.iter() {
+                Mantissa::sqr(ni, &mut ret).unwrap();
+            }
+            let time = start_time.elapsed();
+            println!("{} sqr {}", sz, time.as_millis());
+
+            let start_time = std::time::Instant::now();
+            for ni in n.iter() {
+                Mantissa::mul_slices(ni, &ni.clone(), &mut ret, &Tuning::DEFAULT).unwrap();
+            }
+            let time = start_time.elapsed();
+            println!("{} mul_slices {}", sz, time.as_millis());
+        }
+    }
+
+    #[ignore]
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_small_mul_perf() {
+        // overhead of choosing the algorithm for small operands
+        let t = Tuning::current();
+        for sz in [1, 2, 4, 8, 12, 16] {
+            let f = random_slice(sz, sz);
+            let mut n = vec![];
+            for _ in 0..1000 {
+                n.push(random_slice(sz, sz));
+            }
+
+            let mut ret = WordBuf::new(sz * 2).unwrap();
+
+            let start_time = std::time::Instant::now();
+            for _ in 0..1000 {
+                for ni in n.iter() {
+                    Mantissa::mul_unbalanced(&f, ni, &mut ret).unwrap();
+                }
+            }
+            let time = start_time.elapsed();
+            println!("{} mul_unbalanced {}", sz, time.as_millis());
+
+            let start_time = std::time::Instant::now();
+            for _ in 0..1000 {
+                for ni in n.iter() {
+                    Mantissa::mul_slices(&f, ni, &mut ret, &t).unwrap();
+                }
+            }
+            let time = start_time.elapsed();
+            println!("{} mul_slices {}", sz, time.as_millis());
+        }
+    }
+
     #[ignore]
     #[test]
     #[cfg(feature = "std")]
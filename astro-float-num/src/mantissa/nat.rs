@@ -0,0 +1,121 @@
+//! Natural number arithmetic on top of the mantissa multiplication and division algos.
+//! Numbers are represented by slices of words, the least significant word first.
+
+use crate::common::buf::WordBuf;
+use crate::common::util::shift_slice_left_copy;
+use crate::common::util::shift_slice_right;
+use crate::defs::Error;
+use crate::defs::Word;
+use crate::defs::WORD_BIT_SIZE;
+use crate::mantissa::Mantissa;
+
+impl Mantissa {
+    /// Multiplies `m1` by `m2`. The result has no leading zero words.
+    pub(crate) fn mul_nat(m1: &[Word], m2: &[Word]) -> Result<WordBuf, Error> {
+        if m1.is_empty() || m2.is_empty() {
+            return WordBuf::new(0);
+        }
+
+        let mut m3 = WordBuf::new(m1.len() + m2.len())?;
+
+        Self::mul_unbalanced(m1, m2, &mut m3)?;
+
+        m3.trunc_leading_zeroes();
+
+        Ok(m3)
+    }
+
+    /// Divides `m1` by `m2` and returns the quotient and the remainder without leading zero words.
+    /// `m2` must not have leading zero words and must not be zero.
+    pub(crate) fn div_rem_nat(m1: &[Word], m2: &[Word]) -> Result<(WordBuf, WordBuf), Error> {
+        debug_assert!(m2.last().is_some_and(|&w| w != 0));
+
+        let l1 = m1.iter().rposition(|&w| w != 0).map_or(0, |i| i + 1);
+        let m1 = &m1[..l1];
+
+        if m1.len() < m2.len() {
+            let q = WordBuf::new(0)?;
+            let mut r = WordBuf::new(m1.len())?;
+            r.copy_from_slice(m1);
+
+            return Ok((q, r));
+        }
+
+        // division algos require the most significant bit of the divisor to be set.
+        let shift = m2[m2.len() - 1].leading_zeros() as usize;
+
+        let mut d = WordBuf::new(m2.len())?;
+        shift_slice_left_copy(m2, &mut d, shift);
+
+        let mut n = WordBuf::new(m1.len() + 1)?;
+        shift_slice_left_copy(m1, &mut n, shift);
+
+        let (mut q, mut r) = Self::div_unbalanced(&n, &d)?;
+
+        shift_slice_right(&mut r, shift);
+
+        q.trunc_leading_zeroes();
+        r.trunc_leading_zeroes();
+
+        Ok((q, r))
+    }
+
+    /// Returns the number of significant bits in `m`.
+    pub(crate) fn bit_len_nat(m: &[Word]) -> usize {
+        match m.iter().rposition(|&w| w != 0) {
+            Some(i) => (i + 1) * WORD_BIT_SIZE - m[i].leading_zeros() as usize,
+            None => 0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use rand::random;
+
+    #[cfg(not(feature = "std"))]
+    use alloc::vec::Vec;
+
+    #[test]
+    fn test_div_rem_nat() {
+        for _ in 0..1000 {
+            let l1 = random::<usize>() % 300 + 1;
+            let l2 = random::<usize>() % 300 + 1;
+
+            let mut m1: Vec<Word> = (0..l1).map(|_| random()).collect();
+            let mut m2: Vec<Word> = (0..l2).map(|_| random()).collect();
+
+            // various bit lengths of the most significant words.
+            let s = random::<usize>() % WORD_BIT_SIZE;
+            m1[l1 - 1] >>= s;
+            m2[l2 - 1] >>= s;
+            m2[l2 - 1] |= 1;
+
+            let (q, r) = Mantissa::div_rem_nat(&m1, &m2).unwrap();
+
+            // r < m2
+            assert!(
+                r.len() < m2.len()
+                    || (r.len() == m2.len() && r.iter().rev().cmp(m2.iter().rev()).is_lt())
+            );
+
+            // q * m2 + r = m1
+            let mut p = Mantissa::mul_nat(&q, &m2).unwrap();
+            let mut v: Vec<Word> = p.iter().copied().collect();
+            v.resize(v.len().max(r.len()) + 1, 0);
+
+            let mut c = 0;
+            for (a, b) in v.iter_mut().zip(r.iter().chain(core::iter::repeat(&0))) {
+                c = crate::common::util::add_carry(*a, *b, c, a);
+            }
+
+            p = WordBuf::from_vec(v);
+            p.trunc_leading_zeroes();
+
+            let l1 = m1.iter().rposition(|&w| w != 0).map_or(0, |i| i + 1);
+            assert_eq!(&p[..], &m1[..l1]);
+        }
+    }
+}
@@ -0,0 +1,509 @@
+//! Multiplication with number-theoretic transform modulo three 64-bit primes.
+
+use crate::common::par::join;
+use crate::common::par::par_enabled;
+use crate::common::par::try_for_each;
+use crate::defs::Error;
+use crate::defs::Word;
+use crate::defs::WORD_BIT_SIZE;
+use crate::mantissa::Mantissa;
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+// Number of words in a coefficient of the convolution.
+const COEF_WORDS: usize = 64 / WORD_BIT_SIZE;
+
+// Primes of the form c*2^k+1 with their primitive roots, ordered by value.
+// The product of the primes exceeds 2^183, which allows convolution of up to 2^55 coefficients of 64 bits.
+const PRIMES: [(u64, u64); 3] = [
+    (1945555039024054273, 5), // 27*2^56+1
+    (2485986994308513793, 5), // 69*2^55+1
+    (4179340454199820289, 3), // 29*2^57+1
+];
+
+// Largest supported transform length is 2^NTT_MAX_LOG.
+const NTT_MAX_LOG: u32 = 55;
+
+// Number of coefficients in a part of the transform which is processed at once to stay in cache.
+const NTT_BLOCK_SIZE: usize = 1 << 12;
+
+// Computes a^e mod p.
+fn pow_mod(a: u64, mut e: u64, p: u64) -> u64 {
+    let p = p as u128;
+    let mut a = a as u128 % p;
+    let mut ret = 1;
+
+    while e > 0 {
+        if e & 1 != 0 {
+            ret = ret * a % p;
+        }
+        a = a * a % p;
+        e >>= 1;
+    }
+
+    ret as u64
+}
+
+// Arithmetic modulo p < 2^62 in Montgomery form with R = 2^64.
+#[derive(Clone, Copy)]
+struct Modulus {
+    p: u64,
+    pinv: u64, // -p^-1 mod R
+    r2: u64,   // R^2 mod p
+}
+
+impl Modulus {
+    fn new(p: u64) -> Self {
+        // Newton's iteration doubles the number of correct low bits starting from 3.
+        let mut inv = p;
+        for _ in 0..5 {
+            inv = inv.wrapping_mul(2u64.wrapping_sub(p.wrapping_mul(inv)));
+        }
+
+        let r = ((1u128 << 64) % p as u128) as u64;
+        let r2 = (r as u128 * r as u128 % p as u128) as u64;
+
+        Modulus {
+            p,
+            pinv: inv.wrapping_neg(),
+            r2,
+        }
+    }
+
+    // a*b*R^-1 mod p
+    #[inline]
+    fn mul(&self, a: u64, b: u64) -> u64 {
+        let t = a as u128 * b as u128;
+        let m = (t as u64).wrapping_mul(self.pinv);
+        let u = ((t + m as u128 * self.p as u128) >> 64) as u64;
+        u.min(u.wrapping_sub(self.p))
+    }
+
+    // Precomputes floor(w*R/p) for multiplication by a constant w < p with `mul_const`.
+    // w*R - (w*R mod p) is divisible by p, and the quotient fits in a word,
+    // so the quotient is obtained by multiplication by p^-1 mod R instead of division.
+    #[inline]
+    fn precompute(&self, w: u64) -> u64 {
+        self.mont_form(w).wrapping_mul(self.pinv)
+    }
+
+    // a*w mod p using wp = floor(w*R/p) (Shoup's multiplication).
+    #[inline]
+    fn mul_const(&self, a: u64, w: u64, wp: u64) -> u64 {
+        let q = ((a as u128 * wp as u128) >> 64) as u64;
+        let r = a.wrapping_mul(w).wrapping_sub(q.wrapping_mul(self.p));
+        r.min(r.wrapping_sub(self.p))
+    }
+
+    // The conditional subtraction and addition of p are done with `min` which compiles without branches.
+    #[inline]
+    fn add(&self, a: u64, b: u64) -> u64 {
+        let s = a + b;
+        s.min(s.wrapping_sub(self.p))
+    }
+
+    #[inline]
+    fn sub(&self, a: u64, b: u64) -> u64 {
+        let d = a.wrapping_sub(b);
+        d.min(d.wrapping_add(self.p))
+    }
+
+    // Converts a < p to the Montgomery form.
+    #[inline]
+    fn mont_form(&self, a: u64) -> u64 {
+        self.mul(a, self.r2)
+    }
+}
+
+// Transform of length n = 2^k modulo a prime.
+struct Transform {
+    md: Modulus,
+    roots: Vec<(u64, u64)>, // roots[m + j] = w_2m^j with its precomputed value for m = 1, 2, 4, .., n/2.
+    scale: (u64, u64),      // R*n^-1 mod p with its precomputed value.
+    par: bool,              // halves of the transform are computed in parallel.
+}
+
+impl Transform {
+    fn new(p: u64, g: u64, k: u32, par: bool) -> Result<Self, Error> {
+        let n = 1usize << k;
+        let md = Modulus::new(p);
+
+        let mut roots = Vec::new();
+        roots.try_reserve_exact(n)?;
+        roots.resize(n, (0, 0));
+
+        let mut m = n / 2;
+        let mut w = pow_mod(g, (p - 1) >> k, p);
+
+        while m >= 1 {
+            let wp = md.precompute(w);
+            let mut x = 1;
+            for r in roots[m..2 * m].iter_mut() {
+                *r = (x, md.precompute(x));
+                x = md.mul_const(x, w, wp);
+            }
+            w = md.mul_const(w, w, wp);
+            m /= 2;
+        }
+
+        // pointwise products computed in the Montgomery form have the extra factor of R^-1.
+        let s = pow_mod(n as u64, p - 2, p);
+        let s = (s as u128 * ((1u128 << 64) % p as u128) % p as u128) as u64;
+
+        Ok(Transform {
+            md,
+            roots,
+            scale: (s, md.precompute(s)),
+            par,
+        })
+    }
+
+    // Decimation in frequency: natural order of input, bit-reversed order of output.
+    fn forward(&self, a: &mut [u64]) {
+        let md = &self.md;
+        let mut m = a.len() / 2;
+
+        while m >= 1 {
+            let roots = &self.roots[m..2 * m];
+            for chunk in a.chunks_exact_mut(2 * m) {
+                let (lo, hi) = chunk.split_at_mut(m);
+                for ((u, v), &(w, wp)) in lo.iter_mut().zip(hi.iter_mut()).zip(roots) {
+                    let (x, y) = (*u, *v);
+                    *u = md.add(x, y);
+                    *v = md.mul_const(md.sub(x, y), w, wp);
+                }
+            }
+
+            // the remaining stages are done on parts which fit in cache.
+            if m > NTT_BLOCK_SIZE {
+                let (lo, hi) = a.split_at_mut(m);
+                join(self.par, || self.forward(lo), || self.forward(hi));
+                break;
+            }
+
+            m /= 2;
+        }
+    }
+
+    // Decimation in time: bit-reversed order of input, natural order of output.
+    // Since w_2m^m = -1, the inverse root w_2m^-j is equal to -w_2m^(m-j).
+    fn inverse(&self, a: &mut [u64]) {
+        let md = &self.md;
+        let mut m = 1;
+
+        // the first stages are done on parts which fit in cache.
+        if a.len() > 2 * NTT_BLOCK_SIZE {
+            m = a.len() / 2;
+            let (lo, hi) = a.split_at_mut(m);
+            join(self.par, || self.inverse(lo), || self.inverse(hi));
+        }
+
+        while m < a.len() {
+            let roots = &self.roots[m + 1..2 * m];
+            for chunk in a.chunks_exact_mut(2 * m) {
+                let (lo, hi) = chunk.split_at_mut(m);
+
+                let (x, y) = (lo[0], hi[0]);
+                lo[0] = md.add(x, y);
+                hi[0] = md.sub(x, y);
+
+                for ((u, v), &(w, wp)) in lo[1..]
+                    .iter_mut()
+                    .zip(hi[1..].iter_mut())
+                    .zip(roots.iter().rev())
+                {
+                    let x = *u;
+                    let y = md.mul_const(*v, w, wp);
+                    *u = md.sub(x, y);
+                    *v = md.add(x, y);
+                }
+            }
+            m *= 2;
+        }
+    }
+
+    // Computes in `a` the cyclic convolution of `a` and `b` (or of `a` with itself if `b` is None) modulo p.
+    // Both `a` and `b` contain reduced coefficients in natural order, `b` is used as a temporary buffer.
+    fn convolve(&self, a: &mut [u64], b: Option<&mut [u64]>) {
+        let md = &self.md;
+        let (s, sp) = self.scale;
+
+        self.forward(a);
+
+        if let Some(b) = b {
+            self.forward(b);
+            for (x, y) in a.iter_mut().zip(b.iter()) {
+                *x = md.mul_const(md.mul(*x, *y), s, sp);
+            }
+        } else {
+            for x in a.iter_mut() {
+                *x = md.mul_const(md.mul(*x, *x), s, sp);
+            }
+        }
+
+        self.inverse(a);
+    }
+}
+
+// Splits d into 64-bit coefficients reduced modulo p and places them in buf, filling the rest of buf with zeroes.
+#[allow(clippy::unnecessary_cast)] // Word can be u32
+fn ntt_load(d: &[Word], buf: &mut [u64], p: u64) {
+    let mut iter = buf.iter_mut();
+
+    for (c, v) in d.chunks(COEF_WORDS).zip(iter.by_ref()) {
+        let mut x = 0u64;
+        for (k, w) in c.iter().enumerate() {
+            x |= (*w as u64) << (k * WORD_BIT_SIZE);
+        }
+        *v = x % p;
+    }
+
+    for v in iter {
+        *v = 0;
+    }
+}
+
+impl Mantissa {
+    // multiply two integer numbers.
+    #[inline]
+    pub(super) fn ntt_mul(d1: &[Word], d2: &[Word], d3: &mut [Word]) -> Result<(), Error> {
+        Self::ntt_mul_impl(d1, Some(d2), d3)
+    }
+
+    // square an integer number.
+    #[inline]
+    pub(super) fn ntt_sqr(d1: &[Word], d3: &mut [Word]) -> Result<(), Error> {
+        Self::ntt_mul_impl(d1, None, d3)
+    }
+
+    // multiply d1 by d2, or square d1 if d2 is None; d3 must have at least d1.len() + d2.len() words.
+    fn ntt_mul_impl(d1: &[Word], d2: Option<&[Word]>, d3: &mut [Word]) -> Result<(), Error> {
+        let n1 = d1.len().div_ceil(COEF_WORDS);
+        let n2 = d2.unwrap_or(d1).len().div_ceil(COEF_WORDS);
+
+        if n1 == 0 || n2 == 0 {
+            d3.fill(0);
+            return Ok(());
+        }
+
+        let l = n1 + n2 - 1;
+        let k = l.next_power_of_two().trailing_zeros().max(1);
+
+        if k > NTT_MAX_LOG {
+            return Err(Error::MemoryAllocation);
+        }
+
+        let n = 1usize << k;
+
+        let par = par_enabled(d1.len().max(d2.map_or(0, |d2| d2.len())));
+
+        let mut buf = Vec::new();
+        buf.try_reserve_exact(3 * n)?;
+        buf.resize(3 * n, 0);
+
+        let (r1, rest) = buf.split_at_mut(n);
+        let (r2, r3) = rest.split_at_mut(n);
+        let mut residues = [(r1, PRIMES[0]), (r2, PRIMES[1]), (r3, PRIMES[2])];
+
+        try_for_each(par, &mut residues, &|(r, (p, g))| {
+            let tr = Transform::new(*p, *g, k, par)?;
+
+            ntt_load(d1, r, *p);
+
+            if let Some(d2) = d2 {
+                let mut tmp = Vec::new();
+                tmp.try_reserve_exact(n)?;
+                tmp.resize(n, 0);
+
+                ntt_load(d2, &mut tmp, *p);
+                tr.convolve(r, Some(&mut tmp));
+            } else {
+                tr.convolve(r, None);
+            }
+
+            Ok(())
+        })?;
+
+        Self::ntt_crt(&buf[..n], &buf[n..2 * n], &buf[2 * n..], l, d3);
+
+        Ok(())
+    }
+
+    // Recovers l coefficients from the residues modulo the three primes using Garner's algorithm,
+    // and accumulates them with carry in d3.
+    fn ntt_crt(r1: &[u64], r2: &[u64], r3: &[u64], l: usize, d3: &mut [Word]) {
+        let (p1, p2, p3) = (PRIMES[0].0, PRIMES[1].0, PRIMES[2].0);
+        let md2 = Modulus::new(p2);
+        let md3 = Modulus::new(p3);
+
+        // constants in the Montgomery form
+        let c12 = md2.mont_form(pow_mod(p1, p2 - 2, p2));
+        let c13 = md3.mont_form(pow_mod(p1, p3 - 2, p3));
+        let c23 = md3.mont_form(pow_mod(p2, p3 - 2, p3));
+
+        let p12 = p1 as u128 * p2 as u128;
+        let p12_lo = p12 as u64 as u128;
+        let p12_hi = p12 >> 64;
+
+        let mut carry: u128 = 0;
+
+        for (i, c) in d3.chunks_mut(COEF_WORDS).enumerate() {
+            let v = if i < l {
+                // p1 < p2 < p3, so y1 and y2 are already reduced modulo p2 and p3.
+                let y1 = r1[i];
+                let y2 = md2.mul(md2.sub(r2[i], y1), c12);
+                let y3 = md3.mul(md3.sub(md3.mul(md3.sub(r3[i], y1), c13), y2), c23);
+
+                // x = y1 + p1*y2 + p1*p2*y3 = lo + hi*2^64
+                let x = y1 as u128 + p1 as u128 * y2 as u128;
+                let t = p12_lo * y3 as u128;
+                let x_lo = (x as u64 as u128) + (t as u64 as u128);
+                let hi = (x >> 64) + (t >> 64) + p12_hi * y3 as u128 + (x_lo >> 64);
+
+                let s = (x_lo as u64 as u128) + (carry as u64 as u128);
+                carry = hi + (carry >> 64) + (s >> 64);
+                s as u64
+            } else {
+                let s = carry as u64;
+                carry >>= 64;
+                s
+            };
+
+            for (k, w) in c.iter_mut().enumerate() {
+                *w = (v >> (k * WORD_BIT_SIZE)) as Word;
+            }
+        }
+
+        debug_assert!(carry == 0);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::common::buf::WordBuf;
+    use rand::random;
+
+    #[cfg(not(feature = "std"))]
+    use alloc::vec::Vec;
+
+    #[test]
+    fn test_ntt_params() {
+        for &(p, g) in PRIMES.iter() {
+            let md = Modulus::new(p);
+            assert!(p < 1 << 62);
+            assert_eq!(p.wrapping_mul(md.pinv), u64::MAX);
+            assert_eq!((p - 1) % (1 << NTT_MAX_LOG), 0);
+
+            // g has order p-1
+            assert_eq!(pow_mod(g, p - 1, p), 1);
+            assert_ne!(pow_mod(g, (p - 1) / 2, p), 1);
+
+            let a = random::<u64>() % p;
+            let b = random::<u64>() % p;
+            let ab = (a as u128 * b as u128 % p as u128) as u64;
+            assert_eq!(md.mul(md.mont_form(a), b), ab);
+            assert_eq!(md.mul_const(a, b, md.precompute(b)), ab);
+            assert_eq!(md.precompute(b), (((b as u128) << 64) / p as u128) as u64);
+            assert_eq!(
+                md.precompute(p - 1),
+                (((p as u128 - 1) << 64) / p as u128) as u64
+            );
+            assert_eq!(
+                md.mul_const(u64::MAX, b, md.precompute(b)),
+                (u64::MAX as u128 * b as u128 % p as u128) as u64
+            );
+        }
+    }
+
+    #[test]
+    fn test_ntt_mul() {
+        // special values
+        let s1 = [Word::MAX; 50];
+        let mut ref_s = [0; 100];
+        let mut ret_s = [0; 100];
+        Mantissa::mul_basic(&s1, &s1, &mut ref_s);
+        Mantissa::ntt_mul(&s1, &s1, &mut ret_s).unwrap();
+        assert!(ret_s == ref_s);
+
+        ret_s.fill(1);
+        Mantissa::ntt_sqr(&s1, &mut ret_s).unwrap();
+        assert!(ret_s == ref_s);
+
+        let s1 = [0; 7];
+        let s2 = [1, 0, 0];
+        let mut ret_s = [1; 10];
+        Mantissa::ntt_mul(&s1, &s2, &mut ret_s).unwrap();
+        assert!(ret_s.iter().all(|&v| v == 0));
+
+        let mut ret_s = [0; 4];
+        Mantissa::ntt_mul(&[3], &[Word::MAX, 1, 2], &mut ret_s).unwrap();
+        assert!(ret_s == [Word::MAX - 2, 5, 6, 0]);
+
+        // random
+        for _ in 0..100 {
+            let s1 = random_slice(1, 2000);
+            let s2 = random_slice(1, 2000);
+
+            let mut ref_s = WordBuf::new(s1.len() + s2.len()).unwrap();
+            let mut ret_s = WordBuf::new(s1.len() + s2.len()).unwrap();
+
+            Mantissa::mul_unbalanced(&s1, &s2, &mut ref_s).unwrap();
+            Mantissa::ntt_mul(&s1, &s2, &mut ret_s).unwrap();
+            assert!(ret_s[..] == ref_s[..]);
+
+            let mut ref_s = WordBuf::new(2 * s1.len()).unwrap();
+            let mut ret_s = WordBuf::new(2 * s1.len()).unwrap();
+
+            Mantissa::mul_unbalanced(&s1, &s1, &mut ref_s).unwrap();
+            Mantissa::ntt_sqr(&s1, &mut ret_s).unwrap();
+            assert!(ret_s[..] == ref_s[..]);
+        }
+    }
+
+    #[ignore]
+    #[test]
+    #[cfg(feature = "std")]
+    fn ntt_mul_perf() {
+        for sz in [5000, 20000, 100000, 1000000, 8000000] {
+            let s1 = random_slice(sz, sz);
+            let s2 = random_slice(sz, sz);
+
+            let mut ret1 = WordBuf::new(sz + sz).unwrap();
+            let mut ret2 = WordBuf::new(sz + sz).unwrap();
+
+            let l = (2000000 / sz).max(1);
+
+            let start_time = std::time::Instant::now();
+            for _ in 0..l {
+                Mantissa::ntt_mul(&s1, &s2, &mut ret1).unwrap();
+            }
+            let time = start_time.elapsed();
+            println!("{} ntt_mul {}", sz, time.as_millis());
+
+            let start_time = std::time::Instant::now();
+            for _ in 0..l {
+                Mantissa::fft_mul(&s1, &s2, &mut ret2).unwrap();
+            }
+            let time = start_time.elapsed();
+            println!("{} fft_mul {}", sz, time.as_millis());
+
+            assert!(ret1[..] == ret2[..]);
+        }
+    }
+
+    fn random_slice(min_len: usize, max_len: usize) -> Vec<Word> {
+        let mut s1 = Vec::new();
+        let l = if max_len > min_len {
+            random::<usize>() % (max_len - min_len) + min_len
+        } else {
+            min_len
+        };
+        for _ in 0..l {
+            s1.push(random());
+        }
+        s1
+    }
+}
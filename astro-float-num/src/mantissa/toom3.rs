@@ -2,10 +2,13 @@
 
 use crate::common::buf::WordBuf;
 use crate::common::int::SliceWithSign;
+use crate::common::par::par_enabled;
+use crate::common::par::try_for_each;
 use crate::common::util::shift_slice_left_copy;
 use crate::defs::Error;
 use crate::defs::Word;
 use crate::mantissa::Mantissa;
+use crate::tuning::Tuning;
 
 impl Mantissa {
     fn toom3_get_splits(m: &[Word], l: usize) -> (SliceWithSign, SliceWithSign, SliceWithSign) {
@@ -57,8 +60,30 @@ impl Mantissa {
     // Toom-3 multiplication.
     // d1 must contain input number + have reserve of d2.len() positions in addition for the output.
     // The result is placed in d1, and the sign is returned.
-    pub(super) fn toom3(d1: &[Word], d2: &[Word], d3: &mut [Word]) -> Result<(), Error> {
-        let l = (d1.len().max(d2.len()) + 2) / 3;
+    #[inline]
+    pub(super) fn toom3(
+        d1: &[Word],
+        d2: &[Word],
+        d3: &mut [Word],
+        t: &Tuning,
+    ) -> Result<(), Error> {
+        Self::toom3_impl(d1, Some(d2), d3, t)
+    }
+
+    // Toom-3 squaring.
+    #[inline]
+    pub(super) fn toom3_sqr(d1: &[Word], d3: &mut [Word], t: &Tuning) -> Result<(), Error> {
+        Self::toom3_impl(d1, None, d3, t)
+    }
+
+    // Toom-3 multiplication of d1 by d2, or squaring of d1 if d2 is None.
+    fn toom3_impl(
+        d1: &[Word],
+        d2: Option<&[Word]>,
+        d3: &mut [Word],
+        t: &Tuning,
+    ) -> Result<(), Error> {
+        let l = (d1.len().max(d2.map_or(0, |d2| d2.len())) + 2) / 3;
 
         let mut buf = WordBuf::new(25 * (l + 1))?;
 
@@ -91,7 +116,7 @@ impl Mantissa {
         let mut w3 = SliceWithSign::new_mut(w3buf, 1);
 
         let params0 = Self::toom3_get_splits(d1, l);
-        let params1 = Self::toom3_get_splits(d2, l);
+        let params1 = Self::toom3_get_splits(d2.unwrap_or(d1), l);
 
         let mut s0 = SliceWithSign::new_mut(&mut s0buf[..params0.0.len() + params1.0.len()], 1);
         let mut s1 = SliceWithSign::new_mut(s1buf, 1);
@@ -101,21 +126,38 @@ impl Mantissa {
 
         let (p0, p1, p2, p3, p4) = Self::toom3_factors(params0, &mut x1, p1buf, p2buf, p3buf);
 
-        let (q0, q1, q2, q3, q4) = Self::toom3_factors(params1, &mut x1, q1buf, q2buf, q3buf);
+        if d2.is_some() {
+            let (q0, q1, q2, q3, q4) = Self::toom3_factors(params1, &mut x1, q1buf, q2buf, q3buf);
+
+            debug_assert!(p1.len() + q1.len() == s1.len());
+            debug_assert!(p2.len() + q2.len() == s2.len());
+            debug_assert!(p3.len() + q3.len() == s3.len());
 
-        debug_assert!(p1.len() + q1.len() == s1.len());
-        debug_assert!(p2.len() + q2.len() == s2.len());
-        debug_assert!(p3.len() + q3.len() == s3.len());
+            let mut products = [
+                (&p0, &q0, &mut s0),
+                (&p1, &q1, &mut s1),
+                (&p2, &q2, &mut s2),
+                (&p3, &q3, &mut s3),
+                (&p4, &q4, &mut s4),
+            ];
 
-        Self::mul_unbalanced(&p0, &q0, &mut s0)?;
-        Self::mul_unbalanced(&p1, &q1, &mut s1)?;
-        Self::mul_unbalanced(&p2, &q2, &mut s2)?;
-        Self::mul_unbalanced(&p3, &q3, &mut s3)?;
-        Self::mul_unbalanced(&p4, &q4, &mut s4)?;
+            try_for_each(par_enabled(3 * l), &mut products, &|(p, q, s)| {
+                Self::mul_unbalanced_tuned(p, q, s, t)
+            })?;
 
-        s1.set_sign(p1.sign() * q1.sign());
-        s2.set_sign(p2.sign() * q2.sign());
-        s3.set_sign(p3.sign() * q3.sign());
+            s1.set_sign(p1.sign() * q1.sign());
+            s2.set_sign(p2.sign() * q2.sign());
+            s3.set_sign(p3.sign() * q3.sign());
+        } else {
+            debug_assert!(p1.len() * 2 == s1.len());
+
+            let mut products =
+                [(&p0, &mut s0), (&p1, &mut s1), (&p2, &mut s2), (&p3, &mut s3), (&p4, &mut s4)];
+
+            try_for_each(par_enabled(3 * l), &mut products, &|(p, s)| {
+                Self::sqr_tuned(p, s, t)
+            })?;
+        }
 
         s3.sub(&s1, &mut w3);
         w3.div_by_word(3);
@@ -201,7 +243,7 @@ mod tests {
 
             let start_time = std::time::Instant::now();
             for ni in &n {
-                Mantissa::toom3(ni, &f, &mut ret).unwrap();
+                Mantissa::toom3(ni, &f, &mut ret, &Tuning::DEFAULT).unwrap();
             }
             let time = start_time.elapsed();
             println!("toom3 {}", time.as_millis());
@@ -224,7 +266,7 @@ mod tests {
         mul(&s1, &s2, &mut ref_s);
 
         let mut ret_s = [0; 36];
-        Mantissa::toom3(&s1, &s2, &mut ret_s).unwrap();
+        Mantissa::toom3(&s1, &s2, &mut ret_s, &Tuning::DEFAULT).unwrap();
 
         assert!(ret_s == ref_s);
 
@@ -235,7 +277,7 @@ mod tests {
         mul(&s1, &s2, &mut ref_s);
 
         let mut ret_s = [0; 15];
-        Mantissa::toom3(&s1, &s2, &mut ret_s).unwrap();
+        Mantissa::toom3(&s1, &s2, &mut ret_s, &Tuning::DEFAULT).unwrap();
 
         assert!(ret_s == ref_s);
 
@@ -246,7 +288,7 @@ mod tests {
         mul(&s1, &s2, &mut ref_s);
 
         let mut ret_s = [0; 13];
-        Mantissa::toom3(&s1, &s2, &mut ret_s).unwrap();
+        Mantissa::toom3(&s1, &s2, &mut ret_s, &Tuning::DEFAULT).unwrap();
 
         assert!(ret_s == ref_s);
 
@@ -257,7 +299,7 @@ mod tests {
         mul(&s1, &s2, &mut ref_s);
 
         let mut ret_s = [0; 15];
-        Mantissa::toom3(&s1, &s2, &mut ret_s).unwrap();
+        Mantissa::toom3(&s1, &s2, &mut ret_s, &Tuning::DEFAULT).unwrap();
 
         assert!(ret_s == ref_s);
 
@@ -268,7 +310,7 @@ mod tests {
         mul(&s1, &s2, &mut ref_s);
 
         let mut ret_s = [0; 15];
-        Mantissa::toom3(&s1, &s2, &mut ret_s).unwrap();
+        Mantissa::toom3(&s1, &s2, &mut ret_s, &Tuning::DEFAULT).unwrap();
 
         assert!(ret_s == ref_s);
 
@@ -286,7 +328,7 @@ mod tests {
 
             let mut ret_s = Vec::new();
             ret_s.resize(s1.len() + s2.len(), 0);
-            Mantissa::toom3(&s1, &s2, &mut ret_s).unwrap();
+            Mantissa::toom3(&s1, &s2, &mut ret_s, &Tuning::DEFAULT).unwrap();
 
             assert!(ret_s == ref_s);
         }
@@ -305,7 +347,7 @@ mod tests {
 
             let mut ret_s = Vec::new();
             ret_s.resize(s1.len() + s2.len(), 0);
-            Mantissa::toom3(&s1, &s2, &mut ret_s).unwrap();
+            Mantissa::toom3(&s1, &s2, &mut ret_s, &Tuning::DEFAULT).unwrap();
             assert!(ret_s == ref_s);
         }
     }
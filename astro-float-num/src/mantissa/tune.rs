@@ -0,0 +1,252 @@
+//! Measurement of algorithm thresholds on the current machine.
+
+use crate::common::buf::WordBuf;
+use crate::defs::Error;
+use crate::defs::Word;
+use crate::defs::WORD_SIGNIFICANT_BIT;
+use crate::mantissa::Mantissa;
+use crate::tuning::Tuning;
+use std::time::Duration;
+use std::time::Instant;
+
+// Minimum duration of a single measurement.
+const MEASURE_TIME: Duration = Duration::from_millis(2);
+
+// Number of measurements of which the fastest one is taken.
+const MEASURE_CNT: usize = 3;
+
+// Generator of operands.
+struct Rng(u64);
+
+impl Rng {
+    fn new() -> Self {
+        Rng(0x2545f4914f6cdd1d)
+    }
+
+    // xorshift64
+    fn next_word(&mut self) -> Word {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0 as Word
+    }
+
+    fn slice(&mut self, n: usize) -> Result<WordBuf, Error> {
+        let mut buf = WordBuf::new(n)?;
+        for v in buf.iter_mut() {
+            *v = self.next_word();
+        }
+        Ok(buf)
+    }
+
+    fn normalized_slice(&mut self, n: usize) -> Result<WordBuf, Error> {
+        let mut buf = self.slice(n)?;
+        buf[n - 1] |= WORD_SIGNIFICANT_BIT;
+        Ok(buf)
+    }
+}
+
+// Returns the duration of the fastest of several runs of `f`.
+fn measure(mut f: impl FnMut() -> Result<(), Error>) -> Result<Duration, Error> {
+    let mut reps = 1;
+    let mut best = Duration::MAX;
+
+    for _ in 0..MEASURE_CNT {
+        loop {
+            let start = Instant::now();
+            for _ in 0..reps {
+                f()?;
+            }
+            let elapsed = start.elapsed();
+
+            if elapsed >= MEASURE_TIME {
+                best = best.min(elapsed / reps);
+                break;
+            }
+
+            reps *= 2;
+        }
+    }
+
+    Ok(best)
+}
+
+// Returns the largest size for which `f1` is faster than `f2`, or the first size if `f2` is faster from the start,
+// assuming that once `f2` is faster for two consecutive sizes it stays faster for all larger sizes.
+fn crossover(
+    sizes: &[usize],
+    mut f1: impl FnMut(usize) -> Result<Duration, Error>,
+    mut f2: impl FnMut(usize) -> Result<Duration, Error>,
+) -> Result<usize, Error> {
+    let mut ret = sizes[0];
+    let mut wins = 0;
+
+    for &n in sizes {
+        if f2(n)? < f1(n)? {
+            wins += 1;
+            if wins == 2 {
+                break;
+            }
+        } else {
+            wins = 0;
+            ret = n;
+        }
+    }
+
+    Ok(ret)
+}
+
+// Sizes from `from` to `to` growing geometrically.
+fn sizes(from: usize, to: usize) -> Vec<usize> {
+    let mut ret = Vec::new();
+    let mut n = from;
+    while n <= to {
+        ret.push(n);
+        n += (n / 5).max(1);
+    }
+    ret
+}
+
+// Measures multiplication of operands of n1 and n2 words with `f` using tuning `t`.
+fn time_mul(
+    t: &Tuning,
+    n1: usize,
+    n2: usize,
+    f: impl Fn(&[Word], &[Word], &mut [Word], &Tuning) -> Result<(), Error>,
+) -> Result<Duration, Error> {
+    let mut rng = Rng::new();
+    let d1 = rng.slice(n1)?;
+    let d2 = rng.slice(n2)?;
+    let mut d3 = WordBuf::new(n1 + n2)?;
+
+    t.scope(|| {
+        let t = Tuning::current();
+        measure(|| f(&d1, &d2, &mut d3, &t))
+    })
+}
+
+// Measures division of n1 words by n2 words with `f` using tuning `t`.
+fn time_div(
+    t: &Tuning,
+    n1: usize,
+    n2: usize,
+    f: impl Fn(&[Word], &[Word], &Tuning) -> Result<(WordBuf, WordBuf), Error>,
+) -> Result<Duration, Error> {
+    let mut rng = Rng::new();
+    let d1 = rng.slice(n1)?;
+    let d2 = rng.normalized_slice(n2)?;
+
+    t.scope(|| {
+        let t = Tuning::current();
+        measure(|| f(&d1, &d2, &t).map(|_| ()))
+    })
+}
+
+impl Mantissa {
+    // Measures the thresholds on the current machine starting from tuning `t`.
+    pub(crate) fn autotune(mut t: Tuning) -> Result<Tuning, Error> {
+        let mul_basic = |m1: &[Word], m2: &[Word], m3: &mut [Word], _: &Tuning| {
+            Self::mul_basic(m1, m2, m3);
+            Ok(())
+        };
+        let fft_mul =
+            |m1: &[Word], m2: &[Word], m3: &mut [Word], _: &Tuning| Self::fft_mul(m1, m2, m3);
+        let ntt_mul =
+            |m1: &[Word], m2: &[Word], m3: &mut [Word], _: &Tuning| Self::ntt_mul(m1, m2, m3);
+        let div_basic = |m1: &[Word], m2: &[Word], _: &Tuning| Self::div_basic(m1, m2);
+        let div_newton = |m1: &[Word], m2: &[Word], _: &Tuning| Self::div_newton(m1, m2);
+
+        t.mul_basic = crossover(
+            &sizes(8, 128),
+            |n| time_mul(&t, n, n, mul_basic),
+            |n| time_mul(&t, n, n, Self::toom2),
+        )?;
+
+        t.mul_toom2 = crossover(
+            &sizes(t.mul_basic.max(32), 1024),
+            |n| time_mul(&t, n, n, Self::toom2),
+            |n| time_mul(&t, n, n, Self::toom3),
+        )?;
+
+        t.mul_toom3 = crossover(
+            &sizes(t.mul_toom2.max(1000), 16000),
+            |n| time_mul(&t, n, n, Self::toom3),
+            |n| time_mul(&t, n, n, fft_mul),
+        )?;
+
+        t.mul_fft = crossover(
+            &sizes(t.mul_toom3, 64000),
+            |n| time_mul(&t, n, n, fft_mul),
+            |n| time_mul(&t, n, n, ntt_mul),
+        )?;
+
+        let no_balance = Tuning {
+            mul_balance: usize::MAX,
+            ..t
+        };
+        let balance = Tuning {
+            mul_balance: 0,
+            ..t
+        };
+        t.mul_balance = crossover(
+            &sizes(16, 512),
+            |n| time_mul(&no_balance, n, 4 * n, Self::mul_unbalanced_tuned),
+            |n| time_mul(&balance, n, 4 * n, Self::mul_unbalanced_tuned),
+        )?;
+
+        // the quotient of n words is computed by the recursive division with halves computed by the basic division.
+        t.div_recursive = crossover(
+            &sizes(16, 400),
+            |n| time_div(&t, 2 * n, n, div_basic),
+            |n| {
+                let t = Tuning {
+                    div_recursive: n,
+                    ..t
+                };
+                time_div(&t, 2 * n, n, Self::div_recursive)
+            },
+        )?;
+
+        t.div_newton = crossover(
+            &sizes(2000, 24000),
+            |n| time_div(&t, 2 * n, n, Self::div_recursive),
+            |n| time_div(&t, 2 * n, n, div_newton),
+        )? + 1;
+
+        Ok(t)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_crossover() {
+        let s = sizes(10, 100);
+        assert!(s[0] == 10 && *s.last().unwrap() <= 100);
+        assert!(s.windows(2).all(|w| w[0] < w[1]));
+
+        let time = |n: usize| Ok(Duration::from_nanos(n as u64));
+
+        // f2 is faster starting from 50.
+        let ret = crossover(&s, |n| time(n * 2), |n| time(n + 50)).unwrap();
+        assert_eq!(ret, *s.iter().filter(|&&v| v <= 50).last().unwrap());
+
+        // f1 is always faster.
+        let ret = crossover(&s, time, |n| time(n + 1)).unwrap();
+        assert_eq!(ret, *s.last().unwrap());
+
+        // f2 is always faster.
+        let ret = crossover(&s, |n| time(n + 1), time).unwrap();
+        assert_eq!(ret, s[0]);
+    }
+
+    #[ignore]
+    #[test]
+    fn test_autotune() {
+        let t = Mantissa::autotune(Tuning::DEFAULT).unwrap();
+        println!("{}", t);
+    }
+}
@@ -810,6 +810,9 @@ impl BigFloatNumber {
         let mut ret = Self::new(0)?;
 
         if f == 0.0f64 {
+            if f.is_sign_negative() {
+                ret.s = Sign::Neg;
+            }
             return Ok(ret);
         }
 
@@ -838,6 +841,9 @@ impl BigFloatNumber {
             mantissa >>= 1;
             mantissa |= 0x8000000000000000u64;
             exponent += 1;
+        } else {
+            // subnormal f64 has the same scale as the smallest normal f64.
+            exponent = 1;
         }
 
         let (shift, m) = Mantissa::from_u64(p, mantissa)?;
@@ -868,8 +874,8 @@ impl BigFloatNumber {
                 Sign::Pos => f64::INFINITY,
                 Sign::Neg => f64::NEG_INFINITY,
             }
-        } else if e <= 0 {
-            let shift = -e;
+        } else if e <= 1 {
+            let shift = 1 - e;
             if shift < 52 {
                 ret |= mantissa >> (shift + 12);
                 if self.s == Sign::Neg {
@@ -1129,6 +1135,90 @@ impl BigFloatNumber {
         Ok(ret)
     }
 
+    /// Returns the integer part of a number. This function is the same as `int`.
+    ///
+    /// ## Errors
+    ///
+    ///  - MemoryAllocation: failed to allocate memory for mantissa.
+    #[inline]
+    pub fn trunc(&self) -> Result<Self, Error> {
+        self.int()
+    }
+
+    /// Returns `self` rounded to an integer using rounding mode `rm`.
+    /// `RoundingMode::ToEven` and `RoundingMode::ToOdd` round to the nearest integer with the corresponding rule for ties,
+    /// other rounding modes are directed, and `RoundingMode::None` truncates the fractional part.
+    ///
+    /// ## Errors
+    ///
+    ///  - MemoryAllocation: failed to allocate memory for mantissa.
+    ///  - ExponentOverflow: rounding causes exponent overflow.
+    pub fn round_to_integer(&self, rm: RoundingMode) -> Result<Self, Error> {
+        if rm == RoundingMode::None {
+            self.int()
+        } else {
+            self.round(0, rm)
+        }
+    }
+
+    /// Computes the remainder of division of `self` by `d2` as defined by IEEE 754:
+    /// `self` - n * `d2`, where n is the integer nearest to `self` / `d2` with ties rounded to even.
+    /// The remainder is exact, and its absolute value does not exceed |`d2`| / 2.
+    /// If the remainder is zero, its sign is the sign of `self`.
+    /// The quotient n is returned as an integer which has the sign of `self` / `d2`,
+    /// and the absolute value equal to the 31 least significant bits of |n|.
+    ///
+    /// ## Errors
+    ///
+    ///  - MemoryAllocation: failed to allocate memory for mantissa.
+    ///  - InvalidArgument: `d2` is zero.
+    pub fn remquo(&self, d2: &Self) -> Result<(Self, i32), Error> {
+        const QBITS: isize = 31;
+
+        if d2.is_zero() {
+            return Err(Error::InvalidArgument);
+        }
+
+        // the remainder of division by `d2` * 2^QBITS determines the least significant bits of the truncated quotient.
+        let r1 = match d2.ldexp(QBITS, RoundingMode::None) {
+            Ok(d) => self.rem(&d)?,
+            Err(Error::ExponentOverflow(_)) => self.clone()?,
+            Err(e) => return Err(e),
+        };
+
+        let mut r = r1.rem(d2)?;
+
+        // r1 - r is an exact multiple of d2.
+        let t = if r.is_zero() { r1 } else { r1.sub_full_prec(&r)? };
+        let mut q = if t.is_zero() {
+            0
+        } else {
+            t.abs()?
+                .div(&d2.abs()?, WORD_BIT_SIZE * 2, RoundingMode::ToEven)?
+                .int_as_usize()? as u32
+        };
+
+        // round the truncated quotient to the nearest integer.
+        let cmp = match r.abs()?.ldexp(1, RoundingMode::None) {
+            Ok(r2) => r2.abs_cmp(d2),
+            Err(Error::ExponentOverflow(_)) => 1,
+            Err(e) => return Err(e),
+        };
+
+        if cmp > 0 || (cmp == 0 && q & 1 == 1) {
+            r = if r.s == d2.s { r.sub_full_prec(d2) } else { r.add_full_prec(d2) }?;
+            q = q.wrapping_add(1);
+        }
+
+        if r.is_zero() {
+            r.s = self.s;
+        }
+
+        let q = (q & (u32::MAX >> 1)) as i32;
+
+        Ok((r, if self.s == d2.s { q } else { -q }))
+    }
+
     /// Returns true if `self` is odd integer number.
     pub(crate) fn is_odd_int(&self) -> bool {
         if self.e > 0 {
@@ -1142,6 +1232,16 @@ impl BigFloatNumber {
         }
     }
 
+    /// Returns true if `self` multiplied by 2 is an odd integer number.
+    pub(crate) fn is_half_odd_int(&self) -> bool {
+        let p = self.m.max_bit_len();
+        if self.e >= 0 && (self.e as usize) + 1 < p {
+            self.m.is_odd_int(p - self.e as usize - 1)
+        } else {
+            false
+        }
+    }
+
     /// Returns true if `self` is an integer number.
     pub fn is_int(&self) -> bool {
         if self.e > 0 {
@@ -1203,6 +1303,72 @@ impl BigFloatNumber {
         }
     }
 
+    /// Decomposes `self` into a normalized fraction and an exponent.
+    /// The fraction has the same sign and precision as `self`, and its absolute value is greater than or equal to 0.5 and less than 1.
+    /// The exponent is such that `self` equals the fraction multiplied by 2 to the power of the exponent.
+    /// Subnormal numbers are normalized, and their exponent can be less than EXPONENT_MIN.
+    /// If `self` is zero, the fraction is zero and the exponent is 0.
+    ///
+    /// ## Errors
+    ///
+    ///  - MemoryAllocation: failed to allocate memory for mantissa.
+    pub fn frexp(&self) -> Result<(Self, isize), Error> {
+        if self.is_zero() {
+            return Ok((self.clone()?, 0));
+        }
+
+        let (e, m_opt) = self.normalize()?;
+        let m = match m_opt {
+            Some(m) => m,
+            None => self.m.clone()?,
+        };
+
+        Ok((Self::from_raw_unchecked(m, self.s, 0, self.inexact), e))
+    }
+
+    /// Multiplies `self` by 2 to the power of `n`.
+    /// The mantissa is not changed unless the result is subnormal, in which case it is rounded using rounding mode `rm`.
+    /// Unlike `set_exponent`, the exponent is not clamped, and `self` can be subnormal.
+    ///
+    /// ## Errors
+    ///
+    ///  - ExponentOverflow: the result is too large.
+    ///  - MemoryAllocation: failed to allocate memory for mantissa.
+    pub fn ldexp(&self, n: isize, rm: RoundingMode) -> Result<Self, Error> {
+        let (mut ret, e) = self.frexp()?;
+
+        if ret.is_zero() {
+            return Ok(ret);
+        }
+
+        let e = e.saturating_add(n);
+
+        if e > EXPONENT_MAX as isize {
+            return Err(Error::ExponentOverflow(self.s));
+        }
+
+        if e < EXPONENT_MIN as isize {
+            ret.e = EXPONENT_MIN;
+            ret.subnormalize(e, rm);
+        } else {
+            ret.e = e as Exponent;
+        }
+
+        Ok(ret)
+    }
+
+    /// Returns the exponent of `self` as an integer `n` such that the absolute value of `self`
+    /// is greater than or equal to 2^`n` and less than 2^(`n` + 1), or None if `self` is zero.
+    /// Subnormal numbers are taken into account, so `n` can be less than EXPONENT_MIN.
+    pub fn ilogb(&self) -> Option<isize> {
+        if self.is_zero() {
+            None
+        } else {
+            let shift = self.m.max_bit_len() - self.m.bit_len();
+            Some(self.e as isize - shift as isize - 1)
+        }
+    }
+
     /// Returns the maximum mantissa length of `self` in bits regardless of whether `self` is normal or subnormal.
     #[inline]
     pub fn mantissa_max_bit_len(&self) -> usize {
@@ -1254,6 +1420,7 @@ impl BigFloatNumber {
                     // non zero for directed rounding modes,
                     // non zero for rounding to even/odd when msb of self is the rounding bit
                     *ret.m.digits_mut().last_mut().unwrap() = WORD_SIGNIFICANT_BIT;
+                    ret.m.set_bit_len(ret.m.max_bit_len());
 
                     let e = -(n as isize - 1);
                     if e < EXPONENT_MIN as isize {
@@ -1778,6 +1945,8 @@ mod tests {
 
         // 0.0
         assert!(BigFloatNumber::from_f64(p, 0.0).unwrap().to_f64() == 0.0);
+        assert!(BigFloatNumber::from_f64(p, 0.0).unwrap().is_positive());
+        assert!(BigFloatNumber::from_f64(p, -0.0).unwrap().is_negative());
 
         // conversions
         for _ in 0..10000 {
@@ -3446,6 +3615,213 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_f64_subnormal() {
+        let p = 64;
+
+        // the smallest subnormal f64 is 2^-1074
+        let mut d1 = BigFloatNumber::from_word(1, p).unwrap();
+        d1.set_exponent(-1073);
+        let d2 = BigFloatNumber::from_f64(p, f64::from_bits(1)).unwrap();
+        assert_eq!(d1.cmp(&d2), 0);
+        assert_eq!(d1.to_f64().to_bits(), 1);
+
+        // halving the smallest normal f64 gives a subnormal f64
+        let mut d1 = BigFloatNumber::from_f64(p, f64::MIN_POSITIVE).unwrap();
+        d1.set_exponent(d1.exponent() - 1);
+        let d2 = BigFloatNumber::from_f64(p, f64::MIN_POSITIVE / 2.0).unwrap();
+        assert_eq!(d1.cmp(&d2), 0);
+        assert_eq!(d1.to_f64(), f64::MIN_POSITIVE / 2.0);
+
+        for _ in 0..10000 {
+            let mut f = f64::from_bits((random::<u64>() & 0xfffffffffffff).max(1));
+            if random() {
+                f = -f;
+            }
+            let d1 = BigFloatNumber::from_f64(p, f).unwrap();
+            assert_eq!(d1.to_f64().to_bits(), f.to_bits());
+        }
+    }
+
+    #[test]
+    fn test_round_to_nonzero() {
+        // all significant bits are rounded off, and the result is a power of two
+        for p in [WORD_BIT_SIZE, WORD_BIT_SIZE * 3] {
+            let one = BigFloatNumber::from_word(1, p).unwrap();
+
+            for (f, rm, r) in [
+                (0.25, RoundingMode::Up, 1.0),
+                (-0.25, RoundingMode::Down, -1.0),
+                (0.25, RoundingMode::FromZero, 1.0),
+                (0.75, RoundingMode::ToEven, 1.0),
+                (-0.5, RoundingMode::ToOdd, -1.0),
+            ] {
+                let d1 = BigFloatNumber::from_f64(p, f).unwrap();
+                let d2 = d1.round(0, rm).unwrap();
+                assert!(!d2.is_zero());
+                assert_eq!(d2.to_f64(), r);
+                assert_eq!(d2.abs().unwrap().cmp(&one), 0);
+            }
+
+            let d1 = BigFloatNumber::from_f64(p, 0.75).unwrap();
+            let d2 = d1.round(1, RoundingMode::ToZero).unwrap();
+            assert_eq!(d2.to_f64(), 0.5);
+
+            let d1 = BigFloatNumber::from_f64(p, 0.125).unwrap();
+            let d2 = d1.round(1, RoundingMode::Up).unwrap();
+            assert!(!d2.is_zero());
+            assert_eq!(d2.to_f64(), 0.5);
+        }
+    }
+
+    #[test]
+    fn test_frexp_ldexp() {
+        let rm = RoundingMode::ToEven;
+
+        for _ in 0..1000 {
+            let d1 = if random() {
+                random_subnormal(256)
+            } else {
+                BigFloatNumber::random_normal(256, EXPONENT_MIN, EXPONENT_MAX).unwrap()
+            };
+
+            let (f, e) = d1.frexp().unwrap();
+            assert!(!f.is_subnormal());
+            assert_eq!(f.exponent(), 0);
+            assert_eq!(f.sign(), d1.sign());
+            assert_eq!(d1.ilogb(), Some(e - 1));
+            assert_eq!(f.ldexp(e, rm).unwrap().cmp(&d1), 0);
+
+            let n = random::<isize>() % 1000;
+            let d2 = f.ldexp(n, rm).unwrap();
+            assert_eq!(d2.ilogb(), Some(n - 1));
+            assert_eq!(d2.ldexp(-n, rm).unwrap().cmp(&f), 0);
+        }
+
+        // zero
+        let d1 = BigFloatNumber::new(64).unwrap();
+        let (f, e) = d1.frexp().unwrap();
+        assert!(f.is_zero());
+        assert_eq!(e, 0);
+        assert!(d1.ldexp(10, rm).unwrap().is_zero());
+        assert_eq!(d1.ilogb(), None);
+
+        // rounding of subnormal results
+        let d1 = BigFloatNumber::from_f64(64, 0.75).unwrap();
+        let mp = BigFloatNumber::min_positive(64).unwrap();
+        let d2 = d1
+            .ldexp(EXPONENT_MIN as isize - 63, RoundingMode::Down)
+            .unwrap();
+        assert_eq!(d2.cmp(&mp), 0);
+        assert_eq!(d2.ilogb(), Some(EXPONENT_MIN as isize - 64));
+        let d2 = d1
+            .ldexp(EXPONENT_MIN as isize - 63, RoundingMode::Up)
+            .unwrap();
+        assert_eq!(d2.cmp(&mp.ldexp(1, rm).unwrap()), 0);
+        assert_eq!(d2.ilogb(), Some(EXPONENT_MIN as isize - 63));
+        assert!(d1.ldexp(isize::MIN, RoundingMode::Down).unwrap().is_zero());
+
+        // overflow
+        assert!(d1.ldexp(EXPONENT_MAX as isize, rm).is_ok());
+        assert!(
+            d1.ldexp(EXPONENT_MAX as isize + 1, rm).unwrap_err()
+                == Error::ExponentOverflow(Sign::Pos)
+        );
+        assert!(
+            d1.neg().unwrap().ldexp(isize::MAX, rm).unwrap_err()
+                == Error::ExponentOverflow(Sign::Neg)
+        );
+    }
+
+    #[test]
+    fn test_remquo() {
+        // compare with integer arithmetic on multiples of 1/8.
+        for _ in 0..10000 {
+            let i1 = random::<i32>() as i64 >> random::<u32>() % 32;
+            let mut i2 = random::<i32>() as i64 >> random::<u32>() % 32;
+            if i2 == 0 {
+                i2 = 1;
+            }
+
+            let d1 = BigFloatNumber::from_f64(64, i1 as f64 / 8.0).unwrap();
+            let d2 = BigFloatNumber::from_f64(64, i2 as f64 / 8.0).unwrap();
+
+            let mut n = i1.div_euclid(i2.abs());
+            let mut r = i1.rem_euclid(i2.abs());
+            if 2 * r > i2.abs() || (2 * r == i2.abs() && n & 1 != 0) {
+                r -= i2.abs();
+                n += 1;
+            }
+            if i2 < 0 {
+                n = -n;
+            }
+
+            let (rem, q) = d1.remquo(&d2).unwrap();
+            assert_eq!(
+                rem.cmp(&BigFloatNumber::from_f64(64, r as f64 / 8.0).unwrap()),
+                0
+            );
+            assert_eq!(q, n.signum() as i32 * (n.abs() & 0x7fff_ffff) as i32);
+            if rem.is_zero() {
+                assert_eq!(rem.sign(), d1.sign());
+            }
+        }
+
+        // large exponent difference
+        for _ in 0..100 {
+            let d1 = BigFloatNumber::random_normal(256, -300, 300).unwrap();
+            let d2 = BigFloatNumber::random_normal(128, -100, 100).unwrap();
+
+            let (r, q) = d1.remquo(&d2).unwrap();
+
+            // |r| <= |d2| / 2
+            assert!(r.ldexp(1, RoundingMode::None).unwrap().abs_cmp(&d2) <= 0);
+
+            // d1 - r is a multiple of d2 with low bits equal to q
+            let t = if r.is_zero() { d1.clone().unwrap() } else { d1.sub_full_prec(&r).unwrap() };
+            assert!(t.rem(&d2).unwrap().is_zero());
+            if q != 0 {
+                assert_eq!(q < 0, d1.sign() != d2.sign());
+            }
+            let t = t.ldexp(-31, RoundingMode::None).unwrap();
+            let t = t
+                .div(&d2, 640, RoundingMode::ToEven)
+                .unwrap()
+                .abs()
+                .unwrap();
+            let t = t.sub(&t.int().unwrap(), 64, RoundingMode::ToEven).unwrap();
+            let q = BigFloatNumber::from_i32(q.abs(), 64).unwrap();
+            let q = q.ldexp(-31, RoundingMode::None).unwrap();
+            assert_eq!(t.cmp(&q), 0);
+        }
+
+        // ties
+        let d2 = BigFloatNumber::from_f64(64, 2.0).unwrap();
+        for (x, r, q) in [(1.0, 1.0, 0), (3.0, -1.0, 2), (5.0, 1.0, 2), (-3.0, 1.0, -2)] {
+            let d1 = BigFloatNumber::from_f64(64, x).unwrap();
+            let (rem, n) = d1.remquo(&d2).unwrap();
+            assert_eq!(rem.cmp(&BigFloatNumber::from_f64(64, r).unwrap()), 0);
+            assert_eq!(n, q);
+        }
+
+        assert!(d2.remquo(&BigFloatNumber::new(64).unwrap()).is_err());
+
+        // rounding to integers
+        for (x, up, even, odd) in
+            [(1.5, 2.0, 2.0, 1.0), (-2.5, -2.0, -2.0, -3.0), (0.75, 1.0, 1.0, 1.0)]
+        {
+            let d1 = BigFloatNumber::from_f64(64, x).unwrap();
+            for (rm, v) in
+                [(RoundingMode::Up, up), (RoundingMode::ToEven, even), (RoundingMode::ToOdd, odd)]
+            {
+                let d2 = d1.round_to_integer(rm).unwrap();
+                assert_eq!(d2.cmp(&BigFloatNumber::from_f64(64, v).unwrap()), 0);
+            }
+            let d2 = d1.round_to_integer(RoundingMode::None).unwrap();
+            assert_eq!(d2.cmp(&d1.trunc().unwrap()), 0);
+        }
+    }
+
     #[test]
     fn test_inexact() {
         // any arg is inexact
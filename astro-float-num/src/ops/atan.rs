@@ -9,6 +9,7 @@ use crate::common::util::round_p;
 use crate::defs::Error;
 use crate::defs::RoundingMode;
 use crate::num::BigFloatNumber;
+use crate::ops::bsplit::BsplitSeries;
 use crate::ops::consts::Consts;
 use crate::ops::series::series_cost_optimize;
 use crate::ops::series::series_run;
@@ -135,6 +136,10 @@ impl BigFloatNumber {
     pub(super) fn atan_series(mut self, rm: RoundingMode) -> Result<Self, Error> {
         // atan:  x - x^3/3 + x^5/5 - x^7/7 + ...
 
+        if self.bsplit_applicable(BsplitSeries::Atan) {
+            return self.bsplit_series(BsplitSeries::Atan, rm);
+        }
+
         let p = self.mantissa_max_bit_len();
         let mut polycoeff_gen = AtanPolycoeffGen::new(p)?;
         let (mut reduction_times, niter, e_eff) = series_cost_optimize::<AtanArgReductionEstimator>(
@@ -0,0 +1,1532 @@
+//! Bessel functions of the first and the second kind, and modified Bessel functions.
+
+use crate::common::consts::{EIGHT, FOUR, ONE, TWO};
+use crate::common::util::{calc_add_cost, calc_mul_cost, log2_ceil, round_p};
+use crate::defs::Error;
+use crate::defs::RoundingMode;
+use crate::num::BigFloatNumber;
+use crate::ops::consts::Consts;
+use crate::ops::series::{series_run, PolycoeffGen};
+use crate::Sign;
+use crate::WORD_BIT_SIZE;
+
+// Precision used for estimates of the magnitude of series terms.
+const ESTIMATE_PREC: usize = 64;
+
+// Kind of Bessel function.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum BesselKind {
+    // the first kind
+    J,
+    // the second kind
+    Y,
+    // the modified function of the first kind
+    I,
+    // the modified function of the second kind
+    K,
+}
+
+// Polynomial coefficient generator for the ascending series: k! * (nu + 1)_k.
+// If `abs` is true, the absolute values of the factors are used.
+struct BesselPolycoeffGen {
+    nu: BigFloatNumber,
+    k: usize,
+    abs: bool,
+    val: BigFloatNumber,
+    iter_cost: usize,
+}
+
+impl BesselPolycoeffGen {
+    fn new(nu: &BigFloatNumber, p: usize, abs: bool) -> Result<Self, Error> {
+        let val = BigFloatNumber::from_word(1, p)?;
+        let iter_cost = 2 * calc_mul_cost(p) + calc_add_cost(p);
+
+        Ok(BesselPolycoeffGen {
+            nu: nu.clone()?,
+            k: 0,
+            abs,
+            val,
+            iter_cost,
+        })
+    }
+}
+
+impl PolycoeffGen for BesselPolycoeffGen {
+    fn next(&mut self, rm: RoundingMode) -> Result<&BigFloatNumber, Error> {
+        let p = self.val.mantissa_max_bit_len();
+
+        self.k += 1;
+        let k = BigFloatNumber::from_usize(self.k)?;
+
+        let mut t = self.nu.add(&k, p, rm)?;
+        if self.abs {
+            t.set_sign(Sign::Pos);
+        }
+        let t = t.mul(&k, p, rm)?;
+        self.val = self.val.mul(&t, p, rm)?;
+
+        Ok(&self.val)
+    }
+
+    #[inline]
+    fn iter_cost(&self) -> usize {
+        self.iter_cost
+    }
+
+    #[inline]
+    fn is_div(&self) -> bool {
+        true
+    }
+}
+
+// Polynomial coefficient generator for the series of the functions of the second kind of integer order n:
+// k! * (n + k)! / (H(k) + H(n + k)), where H(k) is the k-th harmonic number.
+struct BesselPsiPolycoeffGen {
+    n: usize,
+    k: usize,
+    fct: BigFloatNumber,
+    h: BigFloatNumber,
+    val: BigFloatNumber,
+    iter_cost: usize,
+}
+
+impl BesselPsiPolycoeffGen {
+    fn new(n: usize, hn: &BigFloatNumber, p: usize) -> Result<Self, Error> {
+        let fct = BigFloatNumber::factorial(n, p, RoundingMode::None)?;
+        let val = BigFloatNumber::new(p)?;
+        let iter_cost = 4 * calc_mul_cost(p) + 2 * calc_add_cost(p);
+
+        Ok(BesselPsiPolycoeffGen {
+            n,
+            k: 0,
+            fct,
+            h: hn.clone()?,
+            val,
+            iter_cost,
+        })
+    }
+}
+
+impl PolycoeffGen for BesselPsiPolycoeffGen {
+    fn next(&mut self, rm: RoundingMode) -> Result<&BigFloatNumber, Error> {
+        let p = self.fct.mantissa_max_bit_len();
+
+        self.k += 1;
+        let k = BigFloatNumber::from_usize(self.k)?;
+        let nk = BigFloatNumber::from_usize(self.n + self.k)?;
+
+        self.fct = self.fct.mul(&k, p, rm)?.mul(&nk, p, rm)?;
+
+        let rk = k.reciprocal(p, rm)?;
+        let rnk = nk.reciprocal(p, rm)?;
+        self.h = self.h.add(&rk, p, rm)?.add(&rnk, p, rm)?;
+
+        self.val = self.fct.div(&self.h, p, rm)?;
+
+        Ok(&self.val)
+    }
+
+    #[inline]
+    fn iter_cost(&self) -> usize {
+        self.iter_cost
+    }
+
+    #[inline]
+    fn is_div(&self) -> bool {
+        true
+    }
+}
+
+// Polynomial coefficient generator for the series of the gamma function: v * (v + 1) * ... * (v + k).
+struct GammaPolycoeffGen {
+    v: BigFloatNumber,
+    k: usize,
+    val: BigFloatNumber,
+    iter_cost: usize,
+}
+
+impl GammaPolycoeffGen {
+    fn new(v: &BigFloatNumber, p: usize) -> Result<Self, Error> {
+        let val = v.mul(&ONE, p, RoundingMode::None)?;
+        let iter_cost = calc_mul_cost(p) + calc_add_cost(p);
+
+        Ok(GammaPolycoeffGen {
+            v: v.clone()?,
+            k: 0,
+            val,
+            iter_cost,
+        })
+    }
+}
+
+impl PolycoeffGen for GammaPolycoeffGen {
+    fn next(&mut self, rm: RoundingMode) -> Result<&BigFloatNumber, Error> {
+        let p = self.val.mantissa_max_bit_len();
+
+        self.k += 1;
+        let k = BigFloatNumber::from_usize(self.k)?;
+
+        let t = self.v.add(&k, p, rm)?;
+        self.val = self.val.mul(&t, p, rm)?;
+
+        Ok(&self.val)
+    }
+
+    #[inline]
+    fn iter_cost(&self) -> usize {
+        self.iter_cost
+    }
+
+    #[inline]
+    fn is_div(&self) -> bool {
+        true
+    }
+}
+
+impl BigFloatNumber {
+    /// Computes the Bessel function of the first kind J of order `nu` of a number with precision `p`.
+    /// The result is rounded using the rounding mode `rm`.
+    /// The order can be any real number, but for a negative argument it must be an integer.
+    /// This function requires constants cache `cc` for computing the result.
+    /// Precision is rounded upwards to the word size.
+    ///
+    /// ## Errors
+    ///
+    ///  - ExponentOverflow: the argument is zero and the order is a negative non-integer number.
+    ///  - MemoryAllocation: failed to allocate memory.
+    ///  - InvalidArgument: the argument is negative and the order is not an integer, the order is an integer larger than 2^64,
+    ///    or the precision is incorrect.
+    pub fn bessel_j(
+        &self,
+        nu: &Self,
+        p: usize,
+        rm: RoundingMode,
+        cc: &mut Consts,
+    ) -> Result<Self, Error> {
+        self.bessel(BesselKind::J, nu, p, rm, cc)
+    }
+
+    /// Computes the Bessel function of the second kind Y of order `nu` of a number with precision `p`.
+    /// The result is rounded using the rounding mode `rm`.
+    /// The order can be any real number, and the argument must be positive.
+    /// This function requires constants cache `cc` for computing the result.
+    /// Precision is rounded upwards to the word size.
+    ///
+    /// ## Errors
+    ///
+    ///  - ExponentOverflow: the argument is zero, or the result is too large.
+    ///  - MemoryAllocation: failed to allocate memory.
+    ///  - InvalidArgument: the argument is negative, the order is an integer larger than 2^64, or the precision is incorrect.
+    pub fn bessel_y(
+        &self,
+        nu: &Self,
+        p: usize,
+        rm: RoundingMode,
+        cc: &mut Consts,
+    ) -> Result<Self, Error> {
+        self.bessel(BesselKind::Y, nu, p, rm, cc)
+    }
+
+    /// Computes the modified Bessel function of the first kind I of order `nu` of a number with precision `p`.
+    /// The result is rounded using the rounding mode `rm`.
+    /// The order can be any real number, but for a negative argument it must be an integer.
+    /// This function requires constants cache `cc` for computing the result.
+    /// Precision is rounded upwards to the word size.
+    ///
+    /// ## Errors
+    ///
+    ///  - ExponentOverflow: the result is too large, or the argument is zero and the order is a negative non-integer number.
+    ///  - MemoryAllocation: failed to allocate memory.
+    ///  - InvalidArgument: the argument is negative and the order is not an integer, the order is an integer larger than 2^64,
+    ///    or the precision is incorrect.
+    pub fn bessel_i(
+        &self,
+        nu: &Self,
+        p: usize,
+        rm: RoundingMode,
+        cc: &mut Consts,
+    ) -> Result<Self, Error> {
+        self.bessel(BesselKind::I, nu, p, rm, cc)
+    }
+
+    /// Computes the modified Bessel function of the second kind K of order `nu` of a number with precision `p`.
+    /// The result is rounded using the rounding mode `rm`.
+    /// The order can be any real number, and the argument must be positive.
+    /// This function requires constants cache `cc` for computing the result.
+    /// Precision is rounded upwards to the word size.
+    ///
+    /// ## Errors
+    ///
+    ///  - ExponentOverflow: the argument is zero, or the result is too large.
+    ///  - MemoryAllocation: failed to allocate memory.
+    ///  - InvalidArgument: the argument is negative, the order is an integer larger than 2^64, or the precision is incorrect.
+    pub fn bessel_k(
+        &self,
+        nu: &Self,
+        p: usize,
+        rm: RoundingMode,
+        cc: &mut Consts,
+    ) -> Result<Self, Error> {
+        self.bessel(BesselKind::K, nu, p, rm, cc)
+    }
+
+    fn bessel(
+        &self,
+        kind: BesselKind,
+        nu: &Self,
+        p: usize,
+        rm: RoundingMode,
+        cc: &mut Consts,
+    ) -> Result<Self, Error> {
+        let p = round_p(p);
+        Self::p_assertion(p)?;
+
+        let nu_int = nu.is_int();
+        let n = if nu_int { Some(nu.int_as_usize()?) } else { None };
+        let n_odd = n.is_some_and(|n| n & 1 == 1);
+
+        // J(-n, x) = (-1)^n * J(n, x), Y(-n, x) = (-1)^n * Y(n, x), I(-n, x) = I(n, x), K(-nu, x) = K(nu, x)
+        let mut sign = Sign::Pos;
+        let nu = if nu.is_negative() && (nu_int || kind == BesselKind::K) {
+            if n_odd && (kind == BesselKind::J || kind == BesselKind::Y) {
+                sign = Sign::Neg;
+            }
+            nu.neg()?
+        } else {
+            nu.clone()?
+        };
+
+        // J(n, -x) = (-1)^n * J(n, x), I(n, -x) = (-1)^n * I(n, x)
+        let x = if self.is_negative() {
+            if !nu_int || kind == BesselKind::Y || kind == BesselKind::K {
+                return Err(Error::InvalidArgument);
+            }
+            if n_odd {
+                sign = sign.invert();
+            }
+            self.neg()?
+        } else {
+            self.clone()?
+        };
+
+        if x.is_zero() {
+            return Self::bessel_zero(kind, &nu, sign, p, cc);
+        }
+
+        let mut p_inc = WORD_BIT_SIZE;
+        let mut p_wrk = p + p_inc;
+
+        // the number of bits lost to cancellation is known only after the computation
+        let mut add_p = 8;
+
+        loop {
+            let p_x = p_wrk + add_p;
+
+            let (mut ret, lost) = match x.bessel_approx(kind, &nu, n, p_x, cc) {
+                Ok(v) => v,
+                Err(Error::ExponentOverflow(s)) => {
+                    return Err(Error::ExponentOverflow(if sign.is_negative() {
+                        s.invert()
+                    } else {
+                        s
+                    }))
+                }
+                Err(e) => return Err(e),
+            };
+
+            if ret.is_zero() && lost == 0 {
+                // underflow
+                return Self::new2(p, sign, true);
+            }
+
+            if lost + 4 > add_p {
+                add_p = lost + 8;
+                continue;
+            }
+
+            if sign.is_negative() {
+                ret.inv_sign();
+            }
+
+            if ret.try_set_precision(p, rm, p_wrk)? {
+                ret.set_inexact(ret.inexact() | self.inexact() | nu.inexact());
+                break Ok(ret);
+            }
+
+            p_wrk += p_inc;
+            p_inc = round_p(p_wrk / 5);
+        }
+    }
+
+    // Values at zero argument.
+    fn bessel_zero(
+        kind: BesselKind,
+        nu: &Self,
+        sign: Sign,
+        p: usize,
+        cc: &mut Consts,
+    ) -> Result<Self, Error> {
+        let signed = |s: Sign| if sign.is_negative() { s.invert() } else { s };
+
+        match kind {
+            BesselKind::J | BesselKind::I => {
+                if nu.is_zero() {
+                    let mut ret = Self::from_word(1, p)?;
+                    ret.set_sign(sign);
+                    Ok(ret)
+                } else if nu.is_positive() {
+                    Self::new2(p, sign, false)
+                } else {
+                    // (x/2)^nu / gamma(nu + 1) with the sign of gamma(nu + 1)
+                    let s = Self::bessel_gamma_sign(nu)?;
+                    Err(Error::ExponentOverflow(signed(s)))
+                }
+            }
+            BesselKind::Y => {
+                if nu.is_negative() {
+                    // Y(nu, x) = (J(nu, x)*cos(nu*pi) - J(-nu, x)) / sin(nu*pi), and J(nu, 0) is infinite
+                    let c = nu.cos_pi(ESTIMATE_PREC, RoundingMode::None, cc)?;
+                    let s = nu.sin_pi(ESTIMATE_PREC, RoundingMode::None, cc)?;
+
+                    if c.is_zero() {
+                        return Self::new2(p, signed(s.sign().invert()), false);
+                    }
+
+                    let mut ret_s = Self::bessel_gamma_sign(nu)?;
+                    if c.is_negative() {
+                        ret_s = ret_s.invert();
+                    }
+                    if s.is_negative() {
+                        ret_s = ret_s.invert();
+                    }
+
+                    Err(Error::ExponentOverflow(signed(ret_s)))
+                } else {
+                    Err(Error::ExponentOverflow(signed(Sign::Neg)))
+                }
+            }
+            BesselKind::K => Err(Error::ExponentOverflow(Sign::Pos)),
+        }
+    }
+
+    // Sign of gamma(nu + 1) for a negative non-integer `nu`.
+    fn bessel_gamma_sign(nu: &Self) -> Result<Sign, Error> {
+        if nu.is_negative() && nu.neg()?.int_as_usize()? & 1 == 1 {
+            Ok(Sign::Neg)
+        } else {
+            Ok(Sign::Pos)
+        }
+    }
+
+    // Computes an approximation of the function for positive `self` with working precision `p`.
+    // Returns the value together with the number of lost bits l, i.e. the relative error is below 2^(l - p).
+    // `nu` is non-negative, except for non-integer orders of J, I, and Y.
+    // `n` is the order if it is an integer.
+    fn bessel_approx(
+        &self,
+        kind: BesselKind,
+        nu: &Self,
+        n: Option<usize>,
+        p: usize,
+        cc: &mut Consts,
+    ) -> Result<(Self, usize), Error> {
+        if let Some(ret) = self.bessel_asymptotic(kind, nu, p, cc)? {
+            return Ok(ret);
+        }
+
+        match kind {
+            BesselKind::J | BesselKind::I => {
+                let modified = kind == BesselKind::I;
+                match n {
+                    Some(n) if self.exponent() > 1 => self.bessel_ji_miller(n, modified, p, cc),
+                    _ => self.bessel_ji_series(nu, modified, p, cc),
+                }
+            }
+            BesselKind::Y | BesselKind::K => {
+                let modified = kind == BesselKind::K;
+                match n {
+                    Some(n) => self.bessel_yk_int(n, modified, p, cc),
+                    None => self.bessel_yk_frac(nu, modified, p, cc),
+                }
+            }
+        }
+    }
+
+    // Number of bits lost in the sum `s` of terms with the sum of absolute values `s_abs`,
+    // where `n` is the number of terms and `p` is the working precision.
+    fn bessel_lost(s: &Self, s_abs: &Self, n: usize, p: usize) -> usize {
+        if s.is_zero() {
+            p
+        } else {
+            let d = s_abs.exponent() as isize - s.exponent() as isize;
+            d.max(0) as usize + log2_ceil(n) + 3
+        }
+    }
+
+    // Number of bits lost in the sum `s` of `parts`, where each part is given with its number of lost bits,
+    // and `p` is the working precision.
+    fn bessel_sum_lost(s: &Self, parts: &[(&Self, usize)], p: usize) -> usize {
+        let e_max = parts
+            .iter()
+            .filter(|(v, _)| !v.is_zero())
+            .map(|(v, l)| v.exponent() as isize + *l as isize)
+            .max();
+
+        match e_max {
+            None => 0,
+            Some(_) if s.is_zero() => p,
+            Some(e) => (e - s.exponent() as isize).max(0) as usize + log2_ceil(parts.len()) + 1,
+        }
+    }
+
+    // Estimates the sum of absolute values of the terms of `acc` + sum(`z`^k / d_k), where d_k are produced by `gen`,
+    // and the number of terms required for precision `p`.
+    // The terms following the first `head` terms must decrease after reaching the maximum.
+    fn bessel_series_abs<T: PolycoeffGen>(
+        acc: &Self,
+        z: &Self,
+        gen: &mut T,
+        head: usize,
+        p: usize,
+    ) -> Result<(Self, usize), Error> {
+        let q = ESTIMATE_PREC;
+
+        let za = z.abs()?;
+        let mut sum = acc.abs()?.mul(&ONE, q, RoundingMode::None)?;
+        let mut x_pow = za.mul(&ONE, q, RoundingMode::None)?;
+        let mut prev: Option<Self> = None;
+        let mut k = 0;
+
+        loop {
+            k += 1;
+
+            let d = gen.next(RoundingMode::None)?;
+            let mut t = x_pow.div(d, q, RoundingMode::None)?;
+            t.set_sign(Sign::Pos);
+
+            if t.is_zero() {
+                return Ok((sum, k));
+            }
+
+            sum = sum.add(&t, q, RoundingMode::None)?;
+
+            if k > head
+                && (t.exponent() as isize) + (p as isize) < sum.exponent() as isize
+                && prev.as_ref().is_some_and(|v| t.cmp(v) <= 0)
+            {
+                return Ok((sum, k));
+            }
+
+            x_pow = x_pow.mul(&za, q, RoundingMode::None)?;
+            prev = Some(t);
+        }
+    }
+
+    // J(nu, x) or I(nu, x) for positive x using the ascending series
+    // (x/2)^nu / gamma(nu + 1) * sum((-+x^2/4)^k / (k! * (nu + 1)_k)), where nu is not a negative integer.
+    fn bessel_ji_series(
+        &self,
+        nu: &Self,
+        modified: bool,
+        p: usize,
+        cc: &mut Consts,
+    ) -> Result<(Self, usize), Error> {
+        let mut h = self.clone()?;
+        h.div_by_2(RoundingMode::None);
+
+        let mut z = h.mul(&h, p, RoundingMode::None)?;
+        if !modified {
+            z.inv_sign();
+        }
+
+        // for negative nu the factors nu + k change sign, and the leading terms are not monotonic,
+        // so they are summed directly.
+        let head = if nu.is_negative() { nu.neg()?.int_as_usize()? + 1 } else { 0 };
+
+        let mut gen = BesselPolycoeffGen::new(nu, p, false)?;
+        let mut acc = Self::from_word(1, p)?;
+        let mut x_pow = z.clone()?;
+
+        for _ in 0..head {
+            let d = gen.next(RoundingMode::None)?;
+            let t = x_pow.div(d, p, RoundingMode::None)?;
+            acc = acc.add(&t, p, RoundingMode::None)?;
+            x_pow = x_pow.mul(&z, p, RoundingMode::None)?;
+        }
+
+        let mut gen_abs = BesselPolycoeffGen::new(nu, ESTIMATE_PREC, true)?;
+        let (s_abs, niter) = Self::bessel_series_abs(&ONE, &z, &mut gen_abs, head, p)?;
+
+        let s = series_run(acc, x_pow, z, niter - niter.min(head), &mut gen)?;
+        let lost_s = Self::bessel_lost(&s, &s_abs, niter, p);
+
+        let (f, lost_f) = h.bessel_ji_factor(nu, p, cc)?;
+        if f.is_zero() {
+            // underflow
+            return Ok((f, 0));
+        }
+
+        let ret = s.mul(&f, p, RoundingMode::None)?;
+
+        Ok((ret, lost_s + lost_f + 1))
+    }
+
+    // Computes (x/2)^nu / gamma(nu + 1), where `self` is x/2.
+    fn bessel_ji_factor(
+        &self,
+        nu: &Self,
+        p: usize,
+        cc: &mut Consts,
+    ) -> Result<(Self, usize), Error> {
+        if nu.is_zero() {
+            return Ok((Self::from_word(1, p)?, 0));
+        }
+
+        if nu.is_int() && nu.is_positive() {
+            let n = nu.int_as_usize()?;
+            let t = self.powi(n, p, RoundingMode::None)?;
+            let f = Self::factorial(n, p, RoundingMode::None)?;
+            return Ok((t.div(&f, p, RoundingMode::None)?, 3));
+        }
+
+        let l = self.ln(p, RoundingMode::None, cc)?;
+        let t = l.mul(nu, p, RoundingMode::None)?;
+        let lost_t = (t.exponent() as isize).max(0) as usize + 2;
+        let et = t.exp(p, RoundingMode::None, cc)?;
+
+        let (g, lost_g) = Self::bessel_gamma1(nu, p, cc)?;
+        let ret = et.div(&g, p, RoundingMode::None)?;
+
+        Ok((ret, lost_t + lost_g + 1))
+    }
+
+    // Computes gamma(nu + 1) for `nu` which is not a negative integer.
+    // gamma(nu + 1) = gamma(v) * (v)_m, or gamma(nu + 1) = gamma(v) / ((nu + 1) * ... * (nu + m)), where 1 <= v < 2.
+    fn bessel_gamma1(nu: &Self, p: usize, cc: &mut Consts) -> Result<(Self, usize), Error> {
+        // gamma(nu + 1) = 1 - gamma*nu + ...
+        if nu.is_zero() || (nu.exponent() as isize) < -(p as isize) - 4 {
+            return Ok((Self::from_word(1, p)?, 1));
+        }
+
+        // v is computed exactly
+        let fl = nu.floor()?;
+        let p_v =
+            round_p(nu.mantissa_max_bit_len() + (-(nu.exponent() as isize)).max(0) as usize + 2);
+        let v = nu.sub(&fl, p_v, RoundingMode::None)?;
+        let v = v.add(&ONE, p_v, RoundingMode::None)?;
+
+        let (gv, lost_v) = if v.cmp(&ONE) == 0 {
+            (Self::from_word(1, p)?, 0)
+        } else {
+            Self::bessel_gamma_reduced(&v, p, cc)?
+        };
+
+        let m = fl.int_as_usize()?;
+
+        if m == 0 {
+            Ok((gv, lost_v))
+        } else if fl.is_positive() {
+            let r = v.rising_factorial(m, p, RoundingMode::None)?;
+            Ok((gv.mul(&r, p, RoundingMode::None)?, lost_v + 3))
+        } else {
+            let mut r = Self::from_word(1, p)?;
+            for j in 1..=m {
+                let t = nu.add(&Self::from_usize(j)?, p, RoundingMode::None)?;
+                r = r.mul(&t, p, RoundingMode::None)?;
+            }
+            Ok((
+                gv.div(&r, p, RoundingMode::None)?,
+                lost_v + log2_ceil(m) + 3,
+            ))
+        }
+    }
+
+    // Computes gamma(v) for 1 < v < 2 using the series
+    // gamma(v) = N^v * e^(-N) * sum(N^k / (v * (v + 1) * ... * (v + k))) + gamma(v, N),
+    // where the incomplete gamma function gamma(v, N) < N^(v - 1) * e^(-N) is negligible.
+    fn bessel_gamma_reduced(v: &Self, p: usize, cc: &mut Consts) -> Result<(Self, usize), Error> {
+        let nn = (p + 2 * log2_ceil(p) + 8) * 7 / 10;
+        let niter = 3 * nn;
+
+        let n = Self::from_usize(nn)?;
+        let n = n.mul(&ONE, p, RoundingMode::None)?;
+
+        let acc = v.reciprocal(p, RoundingMode::None)?;
+        let mut gen = GammaPolycoeffGen::new(v, p)?;
+        let s = series_run(acc, n.clone()?, n.clone()?, niter, &mut gen)?;
+
+        // N^v * e^(-N) = e^(v*ln(N) - N)
+        let l = n.ln(p, RoundingMode::None, cc)?;
+        let t = l.mul(v, p, RoundingMode::None)?;
+        let t = t.sub(&n, p, RoundingMode::None)?;
+        let lost_t = (t.exponent() as isize).max(0) as usize + 3;
+        let et = t.exp(p, RoundingMode::None, cc)?;
+
+        let ret = s.mul(&et, p, RoundingMode::None)?;
+
+        Ok((ret, lost_t + log2_ceil(niter) + 2))
+    }
+
+    // Computes the Euler-Mascheroni constant with the relative error below 2^(2 - p) using the Brent-McMillan algorithm:
+    // gamma = U / V, where U = sum(A_k), V = sum(B_k), A_0 = -ln(N), B_0 = 1,
+    // B_k = B_(k-1) * N^2 / k^2, A_k = (A_(k-1) * N^2 / k + B_k) / k, and the error is below e^(-4*N).
+    fn bessel_euler_gamma(p: usize, cc: &mut Consts) -> Result<Self, Error> {
+        let p_x = p + 2 * log2_ceil(p) + 8;
+        let nn = p_x * 18 / 100 + 1;
+
+        let n = Self::from_usize(nn)?;
+        let n2 = Self::from_usize(nn * nn)?;
+
+        let mut a = n.ln(p_x, RoundingMode::None, cc)?;
+        a.inv_sign();
+        let mut b = Self::from_word(1, p_x)?;
+        let mut u = a.clone()?;
+        let mut v = b.clone()?;
+        let mut k = 0;
+
+        loop {
+            k += 1;
+
+            let kn = Self::from_usize(k)?;
+            let kk = Self::from_usize(k * k)?;
+
+            b = b.mul(&n2, p_x, RoundingMode::None)?;
+            b = b.div(&kk, p_x, RoundingMode::None)?;
+
+            a = a.mul(&n2, p_x, RoundingMode::None)?;
+            a = a.div(&kn, p_x, RoundingMode::None)?;
+            a = a.add(&b, p_x, RoundingMode::None)?;
+            a = a.div(&kn, p_x, RoundingMode::None)?;
+
+            u = u.add(&a, p_x, RoundingMode::None)?;
+            v = v.add(&b, p_x, RoundingMode::None)?;
+
+            if k > nn
+                && (b.exponent() as isize) < v.exponent() as isize - p_x as isize
+                && (a.exponent() as isize) < u.exponent() as isize - p_x as isize
+            {
+                break;
+            }
+        }
+
+        u.div(&v, p, RoundingMode::None)
+    }
+
+    // Y(n, x) or K(n, x) for positive x and integer n >= 0:
+    // Y(n, x) = (-(x/2)^(-n)*F + 2*(ln(x/2) + gamma)*J(n, x) - (x/2)^n*G) / pi,
+    // K(n, x) = (x/2)^(-n)*F/2 + (-1)^(n+1)*(ln(x/2) + gamma)*I(n, x) + (-1)^n*(x/2)^n*G/2,
+    // where F = sum((n-k-1)!/k! * (+-x^2/4)^k) for k < n, G = sum((H(k) + H(n+k)) * (-+x^2/4)^k / (k! * (n+k)!)),
+    // and H(k) is the k-th harmonic number.
+    fn bessel_yk_int(
+        &self,
+        n: usize,
+        modified: bool,
+        p: usize,
+        cc: &mut Consts,
+    ) -> Result<(Self, usize), Error> {
+        let mut h = self.clone()?;
+        h.div_by_2(RoundingMode::None);
+
+        let z = h.mul(&h, p, RoundingMode::None)?;
+        let hn = if n > 0 {
+            let hn = h.powi(n, p, RoundingMode::None)?;
+            if hn.is_zero() {
+                let s = if modified { Sign::Pos } else { Sign::Neg };
+                return Err(Error::ExponentOverflow(s));
+            }
+            hn
+        } else {
+            Self::from_word(1, p)?
+        };
+
+        // (x/2)^(-n) * F
+        let (a, lost_a) = if n > 0 {
+            let mut zf = z.clone()?;
+            if modified {
+                zf.inv_sign();
+            }
+
+            let mut t = Self::factorial(n - 1, p, RoundingMode::None)?;
+            let mut s = t.clone()?;
+            let mut s_abs = t.clone()?;
+
+            for k in 1..n {
+                let d = Self::from_usize(k * (n - k))?;
+                t = t.mul(&zf, p, RoundingMode::None)?;
+                t = t.div(&d, p, RoundingMode::None)?;
+                s = s.add(&t, p, RoundingMode::None)?;
+                s_abs = s_abs.add(&t.abs()?, p, RoundingMode::None)?;
+            }
+
+            let lost = Self::bessel_lost(&s, &s_abs, n, p);
+
+            (s.div(&hn, p, RoundingMode::None)?, lost + 2)
+        } else {
+            (Self::new(p)?, 0)
+        };
+
+        // (x/2)^n * G
+        let (c, lost_c) = {
+            let mut zg = z.clone()?;
+            if !modified {
+                zg.inv_sign();
+            }
+
+            let mut h_n = Self::new(p)?;
+            for j in 1..=n {
+                let r = Self::from_usize(j)?.reciprocal(p, RoundingMode::None)?;
+                h_n = h_n.add(&r, p, RoundingMode::None)?;
+            }
+
+            let f = Self::factorial(n, p, RoundingMode::None)?;
+            let acc = h_n.div(&f, p, RoundingMode::None)?;
+
+            let h_n_est = h_n.mul(&ONE, ESTIMATE_PREC, RoundingMode::None)?;
+            let mut gen_abs = BesselPsiPolycoeffGen::new(n, &h_n_est, ESTIMATE_PREC)?;
+            let (s_abs, niter) = Self::bessel_series_abs(&acc, &zg, &mut gen_abs, 0, p)?;
+
+            let mut gen = BesselPsiPolycoeffGen::new(n, &h_n, p)?;
+            let s = series_run(acc, zg.clone()?, zg, niter, &mut gen)?;
+            let lost = Self::bessel_lost(&s, &s_abs, niter, p);
+
+            (s.mul(&hn, p, RoundingMode::None)?, lost + 1)
+        };
+
+        // (ln(x/2) + gamma) * J(n, x) or (ln(x/2) + gamma) * I(n, x)
+        let (b, lost_b) = {
+            let (j, lost_j) = self.bessel_ji_series(&Self::from_usize(n)?, modified, p, cc)?;
+
+            let lh = h.ln(p, RoundingMode::None, cc)?;
+            let g = Self::bessel_euler_gamma(p, cc)?;
+            let l = lh.add(&g, p, RoundingMode::None)?;
+            let lost_l = Self::bessel_sum_lost(&l, &[(&lh, 2), (&g, 2)], p);
+
+            let mut b = l.mul(&j, p, RoundingMode::None)?;
+            b.set_exponent(b.exponent() + 1);
+
+            (b, lost_l + lost_j + 1)
+        };
+
+        if modified {
+            // (F*(x/2)^(-n) + (-1)^n*((x/2)^n*G - 2*(ln(x/2) + gamma)*I(n, x))) / 2
+            let mut u = c.sub(&b, p, RoundingMode::None)?;
+            let lost_u = Self::bessel_sum_lost(&u, &[(&c, lost_c), (&b, lost_b)], p);
+            if n & 1 == 1 {
+                u.inv_sign();
+            }
+
+            let mut ret = a.add(&u, p, RoundingMode::None)?;
+            let lost = Self::bessel_sum_lost(&ret, &[(&a, lost_a), (&u, lost_u)], p);
+            ret.div_by_2(RoundingMode::None);
+
+            Ok((ret, lost))
+        } else {
+            let ret = b.sub(&a, p, RoundingMode::None)?;
+            let ret = ret.sub(&c, p, RoundingMode::None)?;
+            let lost = Self::bessel_sum_lost(&ret, &[(&b, lost_b), (&a, lost_a), (&c, lost_c)], p);
+
+            let pi = cc.pi_num(p, RoundingMode::None)?;
+            let ret = ret.div(&pi, p, RoundingMode::None)?;
+
+            Ok((ret, lost + 2))
+        }
+    }
+
+    // Y(nu, x) or K(nu, x) for positive x and non-integer nu:
+    // Y(nu, x) = (J(nu, x)*cos(nu*pi) - J(-nu, x)) / sin(nu*pi),
+    // K(nu, x) = pi/2 * (I(-nu, x) - I(nu, x)) / sin(nu*pi).
+    fn bessel_yk_frac(
+        &self,
+        nu: &Self,
+        modified: bool,
+        p: usize,
+        cc: &mut Consts,
+    ) -> Result<(Self, usize), Error> {
+        let (a, lost_a) = self.bessel_ji_series(nu, modified, p, cc)?;
+        let (b, lost_b) = self.bessel_ji_series(&nu.neg()?, modified, p, cc)?;
+        let s = nu.sin_pi(p, RoundingMode::None, cc)?;
+
+        if modified {
+            let d = b.sub(&a, p, RoundingMode::None)?;
+            let lost = Self::bessel_sum_lost(&d, &[(&b, lost_b), (&a, lost_a)], p);
+
+            let pi = cc.pi_num(p, RoundingMode::None)?;
+            let mut ret = d.mul(&pi, p, RoundingMode::None)?;
+            ret = ret.div(&s, p, RoundingMode::None)?;
+            ret.div_by_2(RoundingMode::None);
+
+            Ok((ret, lost + 3))
+        } else {
+            let c = nu.cos_pi(p, RoundingMode::None, cc)?;
+            let ac = a.mul(&c, p, RoundingMode::None)?;
+            let d = ac.sub(&b, p, RoundingMode::None)?;
+            let lost = Self::bessel_sum_lost(&d, &[(&ac, lost_a + 1), (&b, lost_b)], p);
+
+            let ret = d.div(&s, p, RoundingMode::None)?;
+
+            Ok((ret, lost + 2))
+        }
+    }
+
+    // J(n, x) or I(n, x) for x >= 2 and integer n >= 0 using Miller's backward recurrence
+    // y(k-1) = 2*k/x * y(k) -+ y(k+1), normalized with J(0, x) + 2*sum(J(2*k, x)) = 1, or I(0, x) + 2*sum(I(k, x)) = e^x.
+    fn bessel_ji_miller(
+        &self,
+        n: usize,
+        modified: bool,
+        p: usize,
+        cc: &mut Consts,
+    ) -> Result<(Self, usize), Error> {
+        let m = self.bessel_miller_start(n, modified, p)?;
+
+        let r = TWO.div(self, p, RoundingMode::None)?;
+
+        let mut y1 = Self::new(p)?;
+        let mut y0 = Self::from_word(1, p)?;
+        let mut norm = Self::new(p)?;
+        let mut e_norm = y0.exponent() as isize;
+        let mut yn = None;
+
+        for k in (1..=m).rev() {
+            if modified || k & 1 == 0 {
+                let mut t = y0.clone()?;
+                t.set_exponent(t.exponent() + 1);
+                e_norm = e_norm.max(t.exponent() as isize);
+                norm = norm.add(&t, p, RoundingMode::None)?;
+            }
+
+            let t = r.mul(&Self::from_usize(k)?, p, RoundingMode::None)?;
+            let t = t.mul(&y0, p, RoundingMode::None)?;
+            let y = if modified {
+                t.add(&y1, p, RoundingMode::None)
+            } else {
+                t.sub(&y1, p, RoundingMode::None)
+            }?;
+
+            if k - 1 == n {
+                let lost = Self::bessel_sum_lost(&y, &[(&t, 2), (&y1, 2)], p);
+                yn = Some((y.clone()?, lost));
+            }
+
+            y1 = y0;
+            y0 = y;
+        }
+
+        e_norm = e_norm.max(y0.exponent() as isize);
+        norm = norm.add(&y0, p, RoundingMode::None)?;
+
+        let lost_norm = if norm.is_zero() {
+            p
+        } else {
+            (e_norm - norm.exponent() as isize).max(0) as usize + log2_ceil(m) + 3
+        };
+
+        // m > n, so yn is always set
+        let (y, lost_y) = yn.ok_or(Error::InvalidArgument)?;
+
+        let mut ret = y.div(&norm, p, RoundingMode::None)?;
+        let mut lost = lost_y + lost_norm + log2_ceil(m) + 1;
+
+        if modified {
+            let ex = self.exp(p, RoundingMode::None, cc)?;
+            ret = ret.mul(&ex, p, RoundingMode::None)?;
+            lost += 2;
+        }
+
+        Ok((ret, lost))
+    }
+
+    // Determines the starting index of the backward recurrence:
+    // the trial forward recurrence started from max(n, x) must grow above 2^(p + 16).
+    fn bessel_miller_start(&self, n: usize, modified: bool, p: usize) -> Result<usize, Error> {
+        let q = ESTIMATE_PREC;
+
+        let r = TWO.div(self, q, RoundingMode::None)?;
+        let target = (p + 16) as isize;
+
+        let mut k = n.max(self.int_as_usize()?) + 1;
+        let mut y0 = Self::new(q)?;
+        let mut y1 = Self::from_word(1, q)?;
+
+        while (y1.exponent() as isize) <= target {
+            let t = r.mul(&Self::from_usize(k)?, q, RoundingMode::None)?;
+            let t = t.mul(&y1, q, RoundingMode::None)?;
+            let y = if modified {
+                t.add(&y0, q, RoundingMode::None)
+            } else {
+                t.sub(&y0, q, RoundingMode::None)
+            }?;
+
+            y0 = y1;
+            y1 = y;
+            k += 1;
+        }
+
+        Ok(k + (k & 1))
+    }
+
+    // Computes the function for large x using the asymptotic expansions:
+    // J(nu, x) = sqrt(2/(pi*x)) * (P*cos(w) - Q*sin(w)), Y(nu, x) = sqrt(2/(pi*x)) * (P*sin(w) + Q*cos(w)),
+    // I(nu, x) = e^x / sqrt(2*pi*x) * sum((-1)^k * a_k / x^k), K(nu, x) = sqrt(pi/(2*x)) * e^(-x) * sum(a_k / x^k),
+    // where w = x - (nu/2 + 1/4)*pi, P = sum((-1)^k * a_2k / x^2k), Q = sum((-1)^k * a_(2k+1) / x^(2k+1)),
+    // and a_k / x^k = a_(k-1) / x^(k-1) * (4*nu^2 - (2*k - 1)^2) / (8*k*x).
+    // Returns None if the expansion does not give the precision `p`.
+    fn bessel_asymptotic(
+        &self,
+        kind: BesselKind,
+        nu: &Self,
+        p: usize,
+        cc: &mut Consts,
+    ) -> Result<Option<(Self, usize)>, Error> {
+        // the smallest term of the expansion is about e^(-2*x)
+        let x_min = Self::from_usize((p + 16) * 35 / 100 + 1)?;
+        if self.cmp(&x_min) < 0 {
+            return Ok(None);
+        }
+
+        let mu = nu.mul(nu, p, RoundingMode::None)?;
+        let mu = mu.mul(&FOUR, p, RoundingMode::None)?;
+        let x8 = self.mul(&EIGHT, p, RoundingMode::None)?;
+
+        // for large orders the terms grow before they start to decrease
+        if mu.cmp(self) > 0 {
+            return Ok(None);
+        }
+
+        let two_sums = kind == BesselKind::J || kind == BesselKind::Y;
+
+        let mut t = Self::from_word(1, p)?;
+        let mut s = [t.clone()?, Self::new(p)?];
+        let mut e_max = [t.exponent() as isize, isize::MIN];
+        let mut e_prev = t.exponent() as isize;
+        let mut k = 0;
+
+        // the exponent of the truncation error
+        let e_tail = loop {
+            k += 1;
+
+            let d = Self::from_usize((2 * k - 1) * (2 * k - 1))?;
+            let c = mu.sub(&d, p, RoundingMode::None)?;
+
+            if c.is_zero() {
+                // the expansion terminates for half-integer orders
+                break None;
+            }
+
+            t = t.mul(&c, p, RoundingMode::None)?;
+            t = t.div(&Self::from_usize(k)?, p, RoundingMode::None)?;
+            t = t.div(&x8, p, RoundingMode::None)?;
+
+            let e_t = t.exponent() as isize;
+
+            if c.is_negative() {
+                // the remainder is bounded by the first neglected term when the terms decrease
+                if e_t < -(p as isize) - 4 && e_t <= e_prev {
+                    break Some(e_t + 2);
+                }
+
+                if e_t > e_prev {
+                    return Ok(None);
+                }
+            }
+
+            let (i, neg) = match kind {
+                BesselKind::J | BesselKind::Y => (k & 1, (k / 2) & 1 == 1),
+                BesselKind::I => (0, k & 1 == 1),
+                BesselKind::K => (0, false),
+            };
+
+            if neg {
+                s[i] = s[i].sub(&t, p, RoundingMode::None)?;
+            } else {
+                s[i] = s[i].add(&t, p, RoundingMode::None)?;
+            }
+            e_max[i] = e_max[i].max(e_t);
+            e_prev = e_t;
+        };
+
+        // the number of lost bits for each of the sums
+        let mut lost_s = [0, 0];
+        for i in 0..2 {
+            if e_max[i] == isize::MIN {
+                continue;
+            }
+
+            let mut e_err = e_max[i] + log2_ceil(k) as isize + 1 - p as isize;
+            if let Some(e) = e_tail {
+                e_err = e_err.max(e);
+            }
+
+            lost_s[i] = if s[i].is_zero() {
+                p
+            } else {
+                (e_err + p as isize - s[i].exponent() as isize).max(0) as usize + 1
+            };
+        }
+
+        let pi = cc.pi_num(p, RoundingMode::None)?;
+
+        let ret = if two_sums {
+            let (sx, cx) = self.sin_cos(p, RoundingMode::None, cc)?;
+
+            // nu/2 + 1/4 is computed exactly
+            let p_t = nu.mantissa_max_bit_len() + (nu.exponent() as isize).unsigned_abs() + 4;
+            let mut nu2 = nu.clone()?;
+            nu2.div_by_2(RoundingMode::None);
+            let mut quarter = Self::from_word(1, WORD_BIT_SIZE)?;
+            quarter.set_exponent(-1);
+            let tw = nu2.add(&quarter, p_t, RoundingMode::None)?;
+
+            let st = tw.sin_pi(p, RoundingMode::None, cc)?;
+            let ct = tw.cos_pi(p, RoundingMode::None, cc)?;
+
+            // cos(w) = cos(x)*cos(t*pi) + sin(x)*sin(t*pi), sin(w) = sin(x)*cos(t*pi) - cos(x)*sin(t*pi)
+            let c1 = cx.mul(&ct, p, RoundingMode::None)?;
+            let c2 = sx.mul(&st, p, RoundingMode::None)?;
+            let cw = c1.add(&c2, p, RoundingMode::None)?;
+            let lost_cw = Self::bessel_sum_lost(&cw, &[(&c1, 3), (&c2, 3)], p);
+
+            let s1 = sx.mul(&ct, p, RoundingMode::None)?;
+            let s2 = cx.mul(&st, p, RoundingMode::None)?;
+            let sw = s1.sub(&s2, p, RoundingMode::None)?;
+            let lost_sw = Self::bessel_sum_lost(&sw, &[(&s1, 3), (&s2, 3)], p);
+
+            let (u, lost_u) = if kind == BesselKind::J {
+                let u1 = s[0].mul(&cw, p, RoundingMode::None)?;
+                let u2 = s[1].mul(&sw, p, RoundingMode::None)?;
+                let u = u1.sub(&u2, p, RoundingMode::None)?;
+                let lost = Self::bessel_sum_lost(
+                    &u,
+                    &[(&u1, lost_s[0] + lost_cw + 1), (&u2, lost_s[1] + lost_sw + 1)],
+                    p,
+                );
+                (u, lost)
+            } else {
+                let u1 = s[0].mul(&sw, p, RoundingMode::None)?;
+                let u2 = s[1].mul(&cw, p, RoundingMode::None)?;
+                let u = u1.add(&u2, p, RoundingMode::None)?;
+                let lost = Self::bessel_sum_lost(
+                    &u,
+                    &[(&u1, lost_s[0] + lost_sw + 1), (&u2, lost_s[1] + lost_cw + 1)],
+                    p,
+                );
+                (u, lost)
+            };
+
+            // sqrt(2 / (pi*x))
+            let f = pi.mul(self, p, RoundingMode::None)?;
+            let f = TWO.div(&f, p, RoundingMode::None)?;
+            let f = f.sqrt(p, RoundingMode::None)?;
+
+            (u.mul(&f, p, RoundingMode::None)?, lost_u + 4)
+        } else if kind == BesselKind::I {
+            // e^x / sqrt(2*pi*x)
+            let ex = self.exp(p, RoundingMode::None, cc)?;
+            let f = pi.mul(self, p, RoundingMode::None)?;
+            let f = f.mul(&TWO, p, RoundingMode::None)?;
+            let f = f.sqrt(p, RoundingMode::None)?;
+            let ret = s[0].mul(&ex, p, RoundingMode::None)?;
+
+            (ret.div(&f, p, RoundingMode::None)?, lost_s[0] + 5)
+        } else {
+            // sqrt(pi / (2*x)) * e^(-x)
+            let ex = self.neg()?.exp(p, RoundingMode::None, cc)?;
+            if ex.is_zero() {
+                return Ok(Some((ex, 0)));
+            }
+            let f = self.mul(&TWO, p, RoundingMode::None)?;
+            let f = pi.div(&f, p, RoundingMode::None)?;
+            let f = f.sqrt(p, RoundingMode::None)?;
+            let ret = s[0].mul(&ex, p, RoundingMode::None)?;
+
+            (ret.mul(&f, p, RoundingMode::None)?, lost_s[0] + 5)
+        };
+
+        Ok(Some(ret))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::EXPONENT_MIN;
+
+    type BesselFn = fn(
+        &BigFloatNumber,
+        &BigFloatNumber,
+        usize,
+        RoundingMode,
+        &mut Consts,
+    ) -> Result<BigFloatNumber, Error>;
+
+    fn num(s: &str, p: usize, cc: &mut Consts) -> BigFloatNumber {
+        BigFloatNumber::parse(s, crate::Radix::Dec, p, RoundingMode::None, cc).unwrap()
+    }
+
+    // checks that |a - b| < |b| * 2^(-p)
+    fn assert_close(a: &BigFloatNumber, b: &BigFloatNumber, p: usize) {
+        let d = a.sub(b, p + WORD_BIT_SIZE, RoundingMode::None).unwrap();
+        assert!(d.is_zero() || (d.exponent() as isize) < b.exponent() as isize - p as isize);
+    }
+
+    #[test]
+    fn test_bessel() {
+        let mut cc = Consts::new().unwrap();
+        let rm = RoundingMode::ToEven;
+
+        // known values
+        let p = 192;
+        let cases: [(BesselFn, &str, &str, &str); 11] = [
+            (
+                BigFloatNumber::bessel_j,
+                "0",
+                "1",
+                "0.765197686557966551449717526102663220909274289755325241861548",
+            ),
+            (
+                BigFloatNumber::bessel_y,
+                "0",
+                "1",
+                "0.0882569642156769579829267660235151628278175230906755467110438",
+            ),
+            (
+                BigFloatNumber::bessel_i,
+                "0",
+                "1",
+                "1.26606587775200833559824462521471753760767031135496220680814",
+            ),
+            (
+                BigFloatNumber::bessel_k,
+                "0",
+                "1",
+                "0.42102443824070833333562737921260903613621974822666047229897",
+            ),
+            (
+                BigFloatNumber::bessel_j,
+                "1",
+                "10",
+                "0.0434727461688614366697487680258592883062728671185942081359143",
+            ),
+            (
+                BigFloatNumber::bessel_y,
+                "1",
+                "10",
+                "0.249015424206953883923283474663222803260416543069658461246944",
+            ),
+            (
+                BigFloatNumber::bessel_j,
+                "2.25",
+                "3.5",
+                "0.467892324515833152488973365879241040633606205184569537781321",
+            ),
+            (
+                BigFloatNumber::bessel_y,
+                "-1.75",
+                "0.75",
+                "-1.45858786409020140506448553544685161062349794954707100900122",
+            ),
+            (
+                BigFloatNumber::bessel_i,
+                "3",
+                "40",
+                "13291455664733659.5100885836866835154159031082198518825572679",
+            ),
+            (
+                BigFloatNumber::bessel_k,
+                "0.375",
+                "100",
+                "4.65988736074854817156844899204943590598467439530317181051078e-45",
+            ),
+            (
+                BigFloatNumber::bessel_j,
+                "5",
+                "200",
+                "-0.055132678944014677613881610657670259235746988617144411252287",
+            ),
+        ];
+
+        for (f, nu, x, val) in cases {
+            let nu = num(nu, p, &mut cc);
+            let x = num(x, p, &mut cc);
+            let y = f(&x, &nu, p, rm, &mut cc).unwrap();
+            assert_close(&y, &num(val, p + WORD_BIT_SIZE, &mut cc), p - 8);
+        }
+
+        // half-integer orders: J(1/2, x) = sqrt(2/(pi*x))*sin(x), Y(1/2, x) = -sqrt(2/(pi*x))*cos(x),
+        // I(1/2, x) = sqrt(2/(pi*x))*sinh(x), K(1/2, x) = sqrt(pi/(2*x))*e^(-x)
+        let half = num("0.5", WORD_BIT_SIZE, &mut cc);
+        for _ in 0..20 {
+            let p = (rand::random::<usize>() % 4 + 1) * WORD_BIT_SIZE;
+            let p_wrk = p + 256;
+            let x = BigFloatNumber::random_normal(p, -4, 8)
+                .unwrap()
+                .abs()
+                .unwrap();
+
+            let pi = cc.pi_num(p_wrk, RoundingMode::None).unwrap();
+            let px = pi.mul(&x, p_wrk, RoundingMode::None).unwrap();
+            let f = TWO
+                .div(&px, p_wrk, RoundingMode::None)
+                .unwrap()
+                .sqrt(p_wrk, RoundingMode::None)
+                .unwrap();
+            let (s, c) = x.sin_cos(p_wrk, RoundingMode::None, &mut cc).unwrap();
+            let sh = x.sinh(p_wrk, RoundingMode::None, &mut cc).unwrap();
+            let ex = x
+                .neg()
+                .unwrap()
+                .exp(p_wrk, RoundingMode::None, &mut cc)
+                .unwrap();
+            let fk = f.mul(&pi, p_wrk, RoundingMode::None).unwrap();
+            let mut fk = fk.mul(&ex, p_wrk, RoundingMode::None).unwrap();
+            fk.div_by_2(RoundingMode::None);
+
+            let expected = [
+                f.mul(&s, p_wrk, RoundingMode::None).unwrap(),
+                f.mul(&c, p_wrk, RoundingMode::None).unwrap().neg().unwrap(),
+                f.mul(&sh, p_wrk, RoundingMode::None).unwrap(),
+                fk,
+            ];
+            let funcs: [BesselFn; 4] = [
+                BigFloatNumber::bessel_j,
+                BigFloatNumber::bessel_y,
+                BigFloatNumber::bessel_i,
+                BigFloatNumber::bessel_k,
+            ];
+
+            for (f, mut v) in funcs.into_iter().zip(expected) {
+                let y = f(&x, &half, p, rm, &mut cc).unwrap();
+                v.set_precision(p, rm).unwrap();
+                assert!(y.cmp(&v) == 0);
+            }
+        }
+
+        // Wronskians: J(nu+1, x)*Y(nu, x) - J(nu, x)*Y(nu+1, x) = 2/(pi*x), I(nu, x)*K(nu+1, x) + I(nu+1, x)*K(nu, x) = 1/x
+        for i in 0..40 {
+            let p = 128;
+            let p_wrk = p + WORD_BIT_SIZE;
+            let nu = if i & 1 == 0 {
+                BigFloatNumber::from_usize(rand::random::<usize>() % 10).unwrap()
+            } else {
+                BigFloatNumber::random_normal(WORD_BIT_SIZE, -4, 3).unwrap()
+            };
+            let nu1 = nu.add(&ONE, p_wrk, RoundingMode::None).unwrap();
+            let x = BigFloatNumber::random_normal(WORD_BIT_SIZE, -3, 7)
+                .unwrap()
+                .abs()
+                .unwrap();
+
+            let j0 = x.bessel_j(&nu, p_wrk, rm, &mut cc).unwrap();
+            let j1 = x.bessel_j(&nu1, p_wrk, rm, &mut cc).unwrap();
+            let y0 = x.bessel_y(&nu, p_wrk, rm, &mut cc).unwrap();
+            let y1 = x.bessel_y(&nu1, p_wrk, rm, &mut cc).unwrap();
+            let w = j1
+                .mul(&y0, p_wrk, RoundingMode::None)
+                .unwrap()
+                .sub(
+                    &j0.mul(&y1, p_wrk, RoundingMode::None).unwrap(),
+                    p_wrk,
+                    RoundingMode::None,
+                )
+                .unwrap();
+            let pi = cc.pi_num(p_wrk, RoundingMode::None).unwrap();
+            let e = TWO
+                .div(
+                    &pi.mul(&x, p_wrk, RoundingMode::None).unwrap(),
+                    p_wrk,
+                    RoundingMode::None,
+                )
+                .unwrap();
+            // the products can be much larger than the Wronskian
+            let m =
+                j1.exponent().max(j0.exponent()) + y1.exponent().max(y0.exponent()) - e.exponent();
+            assert_close(&w, &e, p - 8 - m.max(0) as usize);
+
+            let i0 = x.bessel_i(&nu, p_wrk, rm, &mut cc).unwrap();
+            let i1 = x.bessel_i(&nu1, p_wrk, rm, &mut cc).unwrap();
+            let k0 = x.bessel_k(&nu, p_wrk, rm, &mut cc).unwrap();
+            let k1 = x.bessel_k(&nu1, p_wrk, rm, &mut cc).unwrap();
+            let w = i0
+                .mul(&k1, p_wrk, RoundingMode::None)
+                .unwrap()
+                .add(
+                    &i1.mul(&k0, p_wrk, RoundingMode::None).unwrap(),
+                    p_wrk,
+                    RoundingMode::None,
+                )
+                .unwrap();
+            let e = x.reciprocal(p_wrk, RoundingMode::None).unwrap();
+            assert_close(&w, &e, p - 8);
+        }
+
+        // correct rounding
+        let funcs: [BesselFn; 4] = [
+            BigFloatNumber::bessel_j,
+            BigFloatNumber::bessel_y,
+            BigFloatNumber::bessel_i,
+            BigFloatNumber::bessel_k,
+        ];
+        for i in 0..80 {
+            let p = (rand::random::<usize>() % 4 + 1) * WORD_BIT_SIZE;
+            let nu = if i & 1 == 0 {
+                BigFloatNumber::from_i8(rand::random::<i8>() % 8, WORD_BIT_SIZE).unwrap()
+            } else {
+                BigFloatNumber::random_normal(WORD_BIT_SIZE, -3, 3).unwrap()
+            };
+            let x = BigFloatNumber::random_normal(p, -5, 7)
+                .unwrap()
+                .abs()
+                .unwrap();
+            let f = funcs[rand::random::<usize>() % 4];
+
+            let y1 = f(&x, &nu, p, RoundingMode::Up, &mut cc).unwrap();
+            let mut y2 = f(&x, &nu, p + 256, RoundingMode::None, &mut cc).unwrap();
+            y2.set_precision(p, RoundingMode::Up).unwrap();
+            assert!(y1.cmp(&y2) == 0);
+        }
+
+        // the asymptotic expansion and the backward recurrence agree
+        let x = BigFloatNumber::from_word(60, WORD_BIT_SIZE).unwrap();
+        let n3 = BigFloatNumber::from_word(3, WORD_BIT_SIZE).unwrap();
+        for f in funcs {
+            let y1 = f(&x, &n3, 64, rm, &mut cc).unwrap();
+            let mut y2 = f(&x, &n3, 640, RoundingMode::None, &mut cc).unwrap();
+            y2.set_precision(64, rm).unwrap();
+            assert!(y1.cmp(&y2) == 0);
+        }
+
+        // negative orders and arguments
+        let x = num("2.5", p, &mut cc);
+        let n = BigFloatNumber::from_word(3, p).unwrap();
+        let nn = n.neg().unwrap();
+        let xn = x.neg().unwrap();
+        let j = x.bessel_j(&n, p, rm, &mut cc).unwrap();
+        assert!(
+            x.bessel_j(&nn, p, rm, &mut cc)
+                .unwrap()
+                .cmp(&j.neg().unwrap())
+                == 0
+        );
+        assert!(
+            xn.bessel_j(&n, p, rm, &mut cc)
+                .unwrap()
+                .cmp(&j.neg().unwrap())
+                == 0
+        );
+        let y = x.bessel_y(&n, p, rm, &mut cc).unwrap();
+        assert!(
+            x.bessel_y(&nn, p, rm, &mut cc)
+                .unwrap()
+                .cmp(&y.neg().unwrap())
+                == 0
+        );
+        let i = x.bessel_i(&n, p, rm, &mut cc).unwrap();
+        assert!(x.bessel_i(&nn, p, rm, &mut cc).unwrap().cmp(&i) == 0);
+        assert!(
+            xn.bessel_i(&n, p, rm, &mut cc)
+                .unwrap()
+                .cmp(&i.neg().unwrap())
+                == 0
+        );
+        let nu = num("1.3", p, &mut cc);
+        let k = x.bessel_k(&nu, p, rm, &mut cc).unwrap();
+        assert!(
+            x.bessel_k(&nu.neg().unwrap(), p, rm, &mut cc)
+                .unwrap()
+                .cmp(&k)
+                == 0
+        );
+
+        // recurrence: J(nu-1, x) + J(nu+1, x) = 2*nu/x * J(nu, x)
+        let nu0 = num("0.3", p, &mut cc);
+        let nu1 = num("1.3", p, &mut cc);
+        let nu2 = num("2.3", p, &mut cc);
+        let p_wrk = p + WORD_BIT_SIZE;
+        let j0 = x.bessel_j(&nu0, p_wrk, rm, &mut cc).unwrap();
+        let j1 = x.bessel_j(&nu1, p_wrk, rm, &mut cc).unwrap();
+        let j2 = x.bessel_j(&nu2, p_wrk, rm, &mut cc).unwrap();
+        let s = j0.add(&j2, p_wrk, RoundingMode::None).unwrap();
+        let t = j1
+            .mul(&nu1, p_wrk, RoundingMode::None)
+            .unwrap()
+            .mul(&TWO, p_wrk, RoundingMode::None)
+            .unwrap()
+            .div(&x, p_wrk, RoundingMode::None)
+            .unwrap();
+        assert_close(&s, &t, p - 8);
+
+        // special cases
+        let zero = BigFloatNumber::new(p).unwrap();
+        let n0 = BigFloatNumber::new(p).unwrap();
+        assert!(zero.bessel_j(&n0, p, rm, &mut cc).unwrap().cmp(&ONE) == 0);
+        assert!(zero.bessel_i(&n0, p, rm, &mut cc).unwrap().cmp(&ONE) == 0);
+        assert!(zero.bessel_j(&n, p, rm, &mut cc).unwrap().is_zero());
+        assert!(zero.bessel_j(&nn, p, rm, &mut cc).unwrap().is_zero());
+        assert!(
+            zero.bessel_j(&num("-0.5", p, &mut cc), p, rm, &mut cc)
+                .unwrap_err()
+                == Error::ExponentOverflow(Sign::Pos)
+        );
+        assert!(
+            zero.bessel_j(&num("-1.5", p, &mut cc), p, rm, &mut cc)
+                .unwrap_err()
+                == Error::ExponentOverflow(Sign::Neg)
+        );
+        assert!(
+            zero.bessel_y(&n, p, rm, &mut cc).unwrap_err() == Error::ExponentOverflow(Sign::Neg)
+        );
+        assert!(
+            zero.bessel_y(&nn, p, rm, &mut cc).unwrap_err() == Error::ExponentOverflow(Sign::Pos)
+        );
+        assert!(
+            zero.bessel_k(&nu, p, rm, &mut cc).unwrap_err() == Error::ExponentOverflow(Sign::Pos)
+        );
+        assert!(xn.bessel_j(&nu, p, rm, &mut cc).unwrap_err() == Error::InvalidArgument);
+        assert!(xn.bessel_y(&n, p, rm, &mut cc).unwrap_err() == Error::InvalidArgument);
+        assert!(xn.bessel_k(&n, p, rm, &mut cc).unwrap_err() == Error::InvalidArgument);
+
+        // underflow and overflow
+        let x = BigFloatNumber::from_word(1, p).unwrap();
+        let mut big = x.clone().unwrap();
+        big.set_exponent(40);
+        assert!(big.bessel_k(&n, p, rm, &mut cc).unwrap().is_zero());
+        assert!(
+            big.bessel_i(&n, p, rm, &mut cc).unwrap_err() == Error::ExponentOverflow(Sign::Pos)
+        );
+        let mut tiny = x.clone().unwrap();
+        tiny.set_exponent(EXPONENT_MIN / 2);
+        let y = tiny
+            .bessel_j(&BigFloatNumber::from_word(8, p).unwrap(), p, rm, &mut cc)
+            .unwrap();
+        assert!(y.is_zero());
+    }
+
+    #[ignore]
+    #[test]
+    #[cfg(feature = "std")]
+    fn bessel_perf() {
+        let p = 320;
+        let mut cc = Consts::new().unwrap();
+        let nu = BigFloatNumber::parse("1.25", crate::Radix::Dec, p, RoundingMode::None, &mut cc)
+            .unwrap();
+        let mut n = vec![];
+        for _ in 0..1000 {
+            n.push(
+                BigFloatNumber::random_normal(p, -5, 5)
+                    .unwrap()
+                    .abs()
+                    .unwrap(),
+            );
+        }
+
+        for _ in 0..5 {
+            let start_time = std::time::Instant::now();
+            for ni in n.iter() {
+                let _f = ni.bessel_j(&nu, p, RoundingMode::ToEven, &mut cc).unwrap();
+            }
+            let time = start_time.elapsed();
+            println!("{}", time.as_millis());
+        }
+    }
+}
@@ -0,0 +1,400 @@
+//! Binary splitting evaluation of series for arguments with a short mantissa.
+
+use crate::common::consts::ONE;
+use crate::common::par::{join, PAR_SPLIT_THRESHOLD};
+use crate::common::util::{log2_ceil, log2_floor, round_p};
+use crate::defs::Error;
+use crate::defs::RoundingMode;
+use crate::num::BigFloatNumber;
+use crate::tuning::Tuning;
+use crate::Sign;
+use crate::WORD_BIT_SIZE;
+
+/// Binary splitting is used if the size of the numbers it produces in bits
+/// does not exceed the precision multiplied by this value.
+const BSPLIT_SIZE_RATIO: usize = 8;
+
+/// A series which can be evaluated using binary splitting.
+/// Each series is `S = 1 + a(1) + a(2) + ...`, where `a(n) = a(n-1) * p(n) / q(n)`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum BsplitSeries {
+    /// exp(x) = S, p(n) = x, q(n) = n, 0 < x < 1.
+    Exp,
+    /// sin(x) = x * S, p(n) = -x^2, q(n) = 2n * (2n + 1), |x| < 4.
+    Sin,
+    /// cos(x) = S, p(n) = -x^2, q(n) = (2n - 1) * 2n, |x| < 4.
+    Cos,
+    /// atan(x) = x * S, p(n) = -x^2 * (2n - 1), q(n) = 2n + 1, |x| < 0.5.
+    Atan,
+}
+
+impl BsplitSeries {
+    // The largest exponent of the argument for which the series is evaluated.
+    fn max_exponent(&self) -> isize {
+        match self {
+            BsplitSeries::Exp => 0,
+            BsplitSeries::Sin | BsplitSeries::Cos => 2,
+            BsplitSeries::Atan => -1,
+        }
+    }
+
+    // Returns the number of the first term which is smaller than 2^(-p) for the argument below 2^e.
+    // All subsequent terms are smaller and decrease at least geometrically with the ratio 1/2.
+    fn terms_cnt(&self, e: isize, p: usize) -> usize {
+        let mut b = 0; // lower bound of -log2(|a(n)|)
+        let mut n = 0;
+
+        loop {
+            n += 1;
+
+            let inc = match self {
+                BsplitSeries::Exp => log2_floor(n) as isize - e,
+                BsplitSeries::Sin => (log2_floor(2 * n) + log2_floor(2 * n + 1)) as isize - 2 * e,
+                BsplitSeries::Cos => (log2_floor(2 * n - 1) + log2_floor(2 * n)) as isize - 2 * e,
+                BsplitSeries::Atan => -2 * e,
+            };
+
+            b += inc;
+
+            if inc > 0 && b >= p as isize {
+                return n;
+            }
+        }
+    }
+
+    // Returns the multipliers of the argument length and the log2 of the number of terms
+    // giving an estimate of the size of p(n) and q(n) in bits.
+    fn term_size(&self) -> (usize, usize) {
+        match self {
+            BsplitSeries::Exp => (1, 1),
+            BsplitSeries::Sin | BsplitSeries::Cos | BsplitSeries::Atan => (2, 2),
+        }
+    }
+}
+
+// Terms of a series for a specific argument.
+struct BsplitTerms {
+    kind: BsplitSeries,
+    x: BigFloatNumber,
+    xx: BigFloatNumber,
+}
+
+impl BsplitTerms {
+    fn new(x: &BigFloatNumber, kind: BsplitSeries) -> Result<Self, Error> {
+        let mut x = x.clone()?;
+        x.set_precision(round_p(x.significant_bits()), RoundingMode::None)?;
+
+        let mut xx = x.mul_full_prec(&x)?;
+        xx.set_sign(Sign::Neg);
+
+        Ok(BsplitTerms { kind, x, xx })
+    }
+
+    // p(n) and q(n)
+    fn pq(&self, n: usize) -> Result<(BigFloatNumber, BigFloatNumber), Error> {
+        match self.kind {
+            BsplitSeries::Exp => Ok((self.x.clone()?, BigFloatNumber::from_usize(n)?)),
+            BsplitSeries::Sin => {
+                let q0 = BigFloatNumber::from_usize(2 * n)?;
+                let q1 = BigFloatNumber::from_usize(2 * n + 1)?;
+                Ok((self.xx.clone()?, q0.mul_full_prec(&q1)?))
+            }
+            BsplitSeries::Cos => {
+                let q0 = BigFloatNumber::from_usize(2 * n - 1)?;
+                let q1 = BigFloatNumber::from_usize(2 * n)?;
+                Ok((self.xx.clone()?, q0.mul_full_prec(&q1)?))
+            }
+            BsplitSeries::Atan => {
+                let p0 = BigFloatNumber::from_usize(2 * n - 1)?;
+                let q = BigFloatNumber::from_usize(2 * n + 1)?;
+                Ok((self.xx.mul_full_prec(&p0)?, q))
+            }
+        }
+    }
+
+    // Returns P = p(a)*...*p(b-1), Q = q(a)*...*q(b-1), and T such that
+    // T / Q = a(a)/a(a-1) + a(a+1)/a(a-1) + ... + a(b-1)/a(a-1).
+    #[allow(clippy::type_complexity)]
+    fn pqt(
+        &self,
+        a: usize,
+        b: usize,
+    ) -> Result<(BigFloatNumber, BigFloatNumber, BigFloatNumber), Error> {
+        if a == b - 1 {
+            let (p, q) = self.pq(a)?;
+            let t = p.clone()?;
+
+            Ok((p, q, t))
+        } else {
+            let m = (a + b) / 2;
+
+            let (ret_a, ret_b) = join(
+                b - a >= PAR_SPLIT_THRESHOLD,
+                || self.pqt(a, m),
+                || self.pqt(m, b),
+            );
+
+            let (pa, qa, ta) = ret_a?;
+            let (pb, qb, tb) = ret_b?;
+
+            let p = pa.mul_full_prec(&pb)?;
+            let q = qa.mul_full_prec(&qb)?;
+            let n0 = ta.mul_full_prec(&qb)?;
+            let n1 = pa.mul_full_prec(&tb)?;
+            let t = n0.add_full_prec(&n1)?;
+
+            Ok((p, q, t))
+        }
+    }
+}
+
+impl BigFloatNumber {
+    // Number of bits of the mantissa excluding trailing zeroes.
+    fn significant_bits(&self) -> usize {
+        let mut tz = 0;
+
+        for &d in self.mantissa().digits() {
+            if d == 0 {
+                tz += WORD_BIT_SIZE;
+            } else {
+                tz += d.trailing_zeros() as usize;
+                break;
+            }
+        }
+
+        self.mantissa_max_bit_len().saturating_sub(tz).max(1)
+    }
+
+    /// Returns true if the series `kind` for `self` with the precision of `self`
+    /// is evaluated faster using binary splitting than using the generic series evaluation.
+    pub(super) fn bsplit_applicable(&self, kind: BsplitSeries) -> bool {
+        let p = self.mantissa_max_bit_len();
+        let e = self.exponent() as isize;
+
+        if self.is_zero() || p < Tuning::current().series_bsplit || e > kind.max_exponent() {
+            return false;
+        }
+
+        let s = self.significant_bits();
+        let n = kind.terms_cnt(e, p);
+        let (k, m) = kind.term_size();
+
+        n * (k * s + m * log2_ceil(n)) <= BSPLIT_SIZE_RATIO * p
+    }
+
+    /// Evaluates the series `kind` for `self` with the precision of `self` using binary splitting.
+    /// The result is rounded using rounding mode `rm`. The argument must be in the range of the series.
+    pub(super) fn bsplit_series(
+        &self,
+        kind: BsplitSeries,
+        rm: RoundingMode,
+    ) -> Result<Self, Error> {
+        debug_assert!(!self.is_zero() && self.exponent() as isize <= kind.max_exponent());
+
+        let p = self.mantissa_max_bit_len();
+        let terms = BsplitTerms::new(self, kind)?;
+
+        // The sum of absolute values of the terms is below 2^6.
+        let mut p_ext = p + 8;
+
+        loop {
+            let n = kind.terms_cnt(self.exponent() as isize, p_ext).max(2);
+
+            let (_, q, t) = terms.pqt(1, n)?;
+
+            let p_wrk = round_p(p_ext);
+            let s0 = t.div(&q, p_wrk, RoundingMode::None)?;
+            let mut ret = s0.add(&ONE, p_wrk, RoundingMode::None)?;
+
+            // cos(x) and sin(x)/x can be small for |x| near pi/2 and pi.
+            let lost = -(ret.exponent() as isize);
+            if lost > 0 && p_ext < p + 8 + lost as usize {
+                p_ext = p + 8 + lost as usize;
+                continue;
+            }
+
+            if matches!(kind, BsplitSeries::Sin | BsplitSeries::Atan) {
+                ret = ret.mul(self, p_wrk, RoundingMode::None)?;
+            }
+
+            ret.set_precision(p, rm)?;
+
+            return Ok(ret);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::ops::consts::Consts;
+
+    fn assert_close(d1: &BigFloatNumber, d2: &BigFloatNumber, p: usize) {
+        let d = d1.sub(d2, p, RoundingMode::None).unwrap();
+        assert!(
+            d.is_zero() || d2.exponent() as isize - d.exponent() as isize >= p as isize - 8,
+            "{:?} {:?}",
+            d1,
+            d2
+        );
+    }
+
+    #[test]
+    fn test_bsplit_series() {
+        let p = 640;
+
+        for _ in 0..100 {
+            // short argument
+            let u = BigFloatNumber::random_normal(64, 0, 0).unwrap();
+            let mut x = u.clone().unwrap();
+            x.set_precision(round_p(16), RoundingMode::ToZero).unwrap();
+            x.set_precision(p, RoundingMode::None).unwrap();
+
+            x.set_sign(Sign::Pos);
+            let d1 = x
+                .bsplit_series(BsplitSeries::Exp, RoundingMode::None)
+                .unwrap();
+            let d2 = x.clone().unwrap().exp_series().unwrap();
+            assert_close(&d1, &d2, p);
+
+            x.set_exponent(2);
+            x.set_sign(u.sign());
+            let d1 = x
+                .bsplit_series(BsplitSeries::Sin, RoundingMode::None)
+                .unwrap();
+            let d2 = x.clone().unwrap().sin_series(RoundingMode::None).unwrap();
+            assert_close(&d1, &d2, p);
+
+            let d1 = x
+                .bsplit_series(BsplitSeries::Cos, RoundingMode::None)
+                .unwrap();
+            let d2 = x.clone().unwrap().cos_series(RoundingMode::None).unwrap();
+            assert_close(&d1, &d2, p);
+
+            x.set_exponent(-3);
+            let d1 = x
+                .bsplit_series(BsplitSeries::Atan, RoundingMode::None)
+                .unwrap();
+            let d2 = x.clone().unwrap().atan_series(RoundingMode::None).unwrap();
+            assert_close(&d1, &d2, p);
+        }
+
+        // cancellation near pi
+        let x = BigFloatNumber::from_word(355, p).unwrap();
+        let x = x
+            .div(
+                &BigFloatNumber::from_word(113, 1).unwrap(),
+                16,
+                RoundingMode::None,
+            )
+            .unwrap();
+        let mut x2 = x.clone().unwrap();
+        x2.set_precision(p, RoundingMode::None).unwrap();
+        let d1 = x2
+            .bsplit_series(BsplitSeries::Sin, RoundingMode::None)
+            .unwrap();
+        x2.set_precision(p + 64, RoundingMode::None).unwrap();
+        let d2 = x2.sin_series(RoundingMode::None).unwrap();
+        assert_close(&d1, &d2, p);
+
+        // the rounding mode is applied to the result
+        let p = round_p(Tuning::current().series_bsplit);
+        let mut x = BigFloatNumber::from_word(5, p).unwrap();
+        x.set_exponent(0);
+        assert!(x.bsplit_applicable(BsplitSeries::Sin));
+
+        let lo = x.clone().unwrap().sin_series(RoundingMode::ToZero).unwrap();
+        let hi = x.sin_series(RoundingMode::FromZero).unwrap();
+        let d = hi.sub(&lo, p, RoundingMode::None).unwrap();
+        assert!(d.is_positive());
+        assert_eq!(
+            d.exponent() as isize,
+            lo.exponent() as isize - p as isize + 1
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_bsplit_dispatch() {
+        let mut cc = Consts::new().unwrap();
+        let p = 8000;
+        let rm = RoundingMode::ToEven;
+
+        let no_bsplit = Tuning {
+            series_bsplit: usize::MAX,
+            ..Tuning::DEFAULT
+        };
+
+        for (w, e) in [(1, 0), (3, 2), (201, 1), (7, -5), (1, -3)] {
+            let mut x = BigFloatNumber::from_word(w, 64).unwrap();
+            x.set_exponent(e);
+
+            for f in [
+                BigFloatNumber::exp,
+                BigFloatNumber::sin,
+                BigFloatNumber::cos,
+                BigFloatNumber::atan,
+            ] {
+                let d1 = f(&x, p, rm, &mut cc).unwrap();
+                let d2 = no_bsplit.scope(|| f(&x, p, rm, &mut cc)).unwrap();
+                assert!(d1.cmp(&d2) == 0);
+            }
+        }
+    }
+
+    #[test]
+    fn test_bsplit_applicable() {
+        let mut x = BigFloatNumber::from_word(3, 100000).unwrap();
+        x.set_exponent(0);
+        assert!(x.bsplit_applicable(BsplitSeries::Exp));
+        assert!(!x.bsplit_applicable(BsplitSeries::Atan));
+
+        let x = BigFloatNumber::random_normal(100000, -1, -1).unwrap();
+        assert!(!x.bsplit_applicable(BsplitSeries::Exp));
+    }
+
+    #[ignore]
+    #[test]
+    #[cfg(feature = "std")]
+    fn bsplit_perf() {
+        for p in [2000, 4000, 8000, 16000, 32000] {
+            for s in [64, 256, 1024] {
+                let mut x = BigFloatNumber::random_normal(round_p(s), -1, -1).unwrap();
+                x.set_precision(p, RoundingMode::None).unwrap();
+                x.set_sign(Sign::Pos);
+
+                let start_time = std::time::Instant::now();
+                let _f = x
+                    .bsplit_series(BsplitSeries::Exp, RoundingMode::None)
+                    .unwrap();
+                let t1 = start_time.elapsed();
+
+                let start_time = std::time::Instant::now();
+                let _f = x.clone().unwrap().exp_series().unwrap();
+                let t2 = start_time.elapsed();
+
+                let start_time = std::time::Instant::now();
+                let _f = x
+                    .bsplit_series(BsplitSeries::Sin, RoundingMode::None)
+                    .unwrap();
+                let t3 = start_time.elapsed();
+
+                let start_time = std::time::Instant::now();
+                let _f = x.clone().unwrap().sin_series(RoundingMode::None).unwrap();
+                let t4 = start_time.elapsed();
+
+                println!(
+                    "{} {} {} exp {} {} sin {} {}",
+                    p,
+                    s,
+                    x.bsplit_applicable(BsplitSeries::Exp),
+                    t1.as_micros(),
+                    t2.as_micros(),
+                    t3.as_micros(),
+                    t4.as_micros()
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,446 @@
+//! Factorial, binomial coefficients, rising and falling factorials.
+
+use crate::common::util::log2_ceil;
+use crate::common::util::round_p;
+use crate::defs::Error;
+use crate::defs::RoundingMode;
+use crate::num::BigFloatNumber;
+use crate::Sign;
+use crate::WORD_BIT_SIZE;
+
+// Number of factors below which a product of integers is accumulated in a machine word.
+const PRODUCT_LEAF_THRESHOLD: usize = 16;
+
+// Multiplies `x` by `y` exactly if the product fits precision `p`, or with precision `p` otherwise.
+fn mul_trunc(x: &BigFloatNumber, y: &BigFloatNumber, p: usize) -> Result<BigFloatNumber, Error> {
+    if x.mantissa_max_bit_len() + y.mantissa_max_bit_len() <= p {
+        x.mul_full_prec(y)
+    } else {
+        x.mul(y, p, RoundingMode::None)
+    }
+}
+
+// Computes the product of integers from a + 1 to b inclusive using binary splitting.
+// Partial products are truncated to precision `p` once they exceed it,
+// so the relative error of the result is less than 2*(b - a)*2^(-p).
+fn int_product(a: usize, b: usize, p: usize) -> Result<BigFloatNumber, Error> {
+    if b - a <= PRODUCT_LEAF_THRESHOLD {
+        let mut ret = BigFloatNumber::from_usize(1)?;
+        let mut acc: usize = 1;
+
+        for i in a + 1..=b {
+            match acc.checked_mul(i) {
+                Some(v) => acc = v,
+                None => {
+                    ret = mul_trunc(&ret, &BigFloatNumber::from_usize(acc)?, p)?;
+                    acc = i;
+                }
+            }
+        }
+
+        mul_trunc(&ret, &BigFloatNumber::from_usize(acc)?, p)
+    } else {
+        let m = (a + b) / 2;
+
+        let p1 = int_product(a, m, p)?;
+        let p2 = int_product(m, b, p)?;
+
+        mul_trunc(&p1, &p2, p)
+    }
+}
+
+// Computes the product of (x + i), or (x - i) if `falling` is true, for i from a to b - 1 with precision p.
+fn pochhammer_product(
+    x: &BigFloatNumber,
+    a: usize,
+    b: usize,
+    falling: bool,
+    p: usize,
+) -> Result<BigFloatNumber, Error> {
+    if b - a == 1 {
+        let i = BigFloatNumber::from_usize(a)?;
+
+        if falling {
+            x.sub(&i, p, RoundingMode::None)
+        } else {
+            x.add(&i, p, RoundingMode::None)
+        }
+    } else {
+        let m = (a + b) / 2;
+
+        let p1 = pochhammer_product(x, a, m, falling, p)?;
+        let p2 = pochhammer_product(x, m, b, falling, p)?;
+
+        p1.mul(&p2, p, RoundingMode::None)
+    }
+}
+
+impl BigFloatNumber {
+    /// Computes the factorial of `n` with precision `p`. The result is rounded using the rounding mode `rm`.
+    /// Precision is rounded upwards to the word size.
+    ///
+    /// ## Errors
+    ///
+    ///  - ExponentOverflow: the result is too large.
+    ///  - MemoryAllocation: failed to allocate memory.
+    ///  - InvalidArgument: the precision is incorrect.
+    pub fn factorial(n: usize, p: usize, rm: RoundingMode) -> Result<Self, Error> {
+        let p = round_p(p);
+        Self::p_assertion(p)?;
+
+        let n = n.max(1);
+
+        // less than n multiplications, each gives an error of at most 1 ulp.
+        let err = log2_ceil(n) + 2;
+
+        let mut p_inc = WORD_BIT_SIZE;
+        let mut p_wrk = p + err + p_inc;
+
+        loop {
+            let mut ret = int_product(1, n, p_wrk)?;
+
+            // if the result is exact, rounding always succeeds.
+            if ret.try_set_precision(p, rm, p_wrk - err)? {
+                break Ok(ret);
+            }
+
+            p_wrk += p_inc;
+            p_inc = round_p(p_wrk / 5);
+        }
+    }
+
+    /// Computes the binomial coefficient of `n` and `k` with precision `p`. The result is rounded using the rounding mode `rm`.
+    /// The result is zero if `k` is greater than `n`.
+    /// Precision is rounded upwards to the word size.
+    ///
+    /// ## Errors
+    ///
+    ///  - ExponentOverflow: the result is too large.
+    ///  - MemoryAllocation: failed to allocate memory.
+    ///  - InvalidArgument: the precision is incorrect.
+    pub fn binomial(n: usize, k: usize, p: usize, rm: RoundingMode) -> Result<Self, Error> {
+        let p = round_p(p);
+        Self::p_assertion(p)?;
+
+        if k > n {
+            return Self::new(p);
+        }
+
+        let k = k.min(n - k);
+
+        if k == 0 {
+            return Self::from_word(1, p);
+        }
+
+        // less than 2*k multiplications and one division, each gives an error of at most 1 ulp.
+        let err = log2_ceil(2 * k) + 2;
+
+        let mut p_inc = WORD_BIT_SIZE;
+        let mut p_wrk = p + err + p_inc;
+
+        loop {
+            // n * (n - 1) * ... * (n - k + 1) / k!
+            let num = int_product(n - k, n, p_wrk)?;
+            let den = int_product(1, k, p_wrk)?;
+
+            let mut ret = num.div(&den, p_wrk, RoundingMode::None)?;
+
+            // if the result is exact, rounding always succeeds.
+            if ret.try_set_precision(p, rm, p_wrk - err)? {
+                break Ok(ret);
+            }
+
+            p_wrk += p_inc;
+            p_inc = round_p(p_wrk / 5);
+        }
+    }
+
+    /// Computes the rising factorial (the Pochhammer symbol) `self` * (`self` + 1) * ... * (`self` + `n` - 1)
+    /// with precision `p`. The result is rounded using the rounding mode `rm`.
+    /// If the exact result fits the precision, it is returned without rounding.
+    /// Precision is rounded upwards to the word size.
+    ///
+    /// ## Errors
+    ///
+    ///  - ExponentOverflow: the result is too large.
+    ///  - MemoryAllocation: failed to allocate memory.
+    ///  - InvalidArgument: the precision is incorrect.
+    pub fn rising_factorial(&self, n: usize, p: usize, rm: RoundingMode) -> Result<Self, Error> {
+        self.pochhammer(n, p, rm, false)
+    }
+
+    /// Computes the falling factorial `self` * (`self` - 1) * ... * (`self` - `n` + 1)
+    /// with precision `p`. The result is rounded using the rounding mode `rm`.
+    /// If the exact result fits the precision, it is returned without rounding.
+    /// Precision is rounded upwards to the word size.
+    ///
+    /// ## Errors
+    ///
+    ///  - ExponentOverflow: the result is too large.
+    ///  - MemoryAllocation: failed to allocate memory.
+    ///  - InvalidArgument: the precision is incorrect.
+    pub fn falling_factorial(&self, n: usize, p: usize, rm: RoundingMode) -> Result<Self, Error> {
+        self.pochhammer(n, p, rm, true)
+    }
+
+    fn pochhammer(
+        &self,
+        n: usize,
+        p: usize,
+        rm: RoundingMode,
+        falling: bool,
+    ) -> Result<Self, Error> {
+        let p = round_p(p);
+        Self::p_assertion(p)?;
+
+        if n == 0 {
+            return Self::from_word(1, p);
+        }
+
+        // The falling factorial of x equals (-1)^n times the rising factorial of -x.
+        // Determine the sign of the result, and whether one of the factors is zero.
+        let y = if falling { self.neg()? } else { self.clone()? };
+
+        let mut s = if y.is_negative() {
+            let ny = y.neg()?;
+
+            if ny.cmp(&Self::from_usize(n)?) >= 0 {
+                // all factors are negative
+                if n & 1 == 0 {
+                    Sign::Pos
+                } else {
+                    Sign::Neg
+                }
+            } else if ny.is_int() {
+                return Self::new2(p, Sign::Pos, self.inexact());
+            } else if ny.ceil()?.int_as_usize()? & 1 == 0 {
+                Sign::Pos
+            } else {
+                Sign::Neg
+            }
+        } else {
+            Sign::Pos
+        };
+
+        if falling && n & 1 == 1 {
+            s = s.invert();
+        }
+
+        // each operation gives an error of at most 1 ulp, and there are less than 2*n operations.
+        let err = round_p(log2_ceil(n) + 2);
+
+        let mut p_inc = WORD_BIT_SIZE;
+        let mut p_wrk = p + err + p_inc;
+
+        let mut x = self.clone()?;
+        x.set_inexact(false);
+
+        loop {
+            let mut ret = pochhammer_product(&x, 0, n, falling, p_wrk).map_err(|e| {
+                if let Error::ExponentOverflow(_) = e {
+                    Error::ExponentOverflow(s)
+                } else {
+                    e
+                }
+            })?;
+
+            // if the result is exact, rounding always succeeds.
+            if ret.try_set_precision(p, rm, p_wrk - err)? {
+                ret.set_inexact(ret.inexact() | self.inexact());
+                break Ok(ret);
+            }
+
+            p_wrk += p_inc;
+            p_inc = round_p(p_wrk / 5);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::common::consts::ONE;
+    use rand::random;
+
+    #[test]
+    fn test_factorial() {
+        let rm = RoundingMode::ToEven;
+
+        // small values
+        let mut f: u128 = 1;
+        for n in 0..34 {
+            if n > 0 {
+                f *= n as u128;
+            }
+
+            let d1 = BigFloatNumber::factorial(n, 128, rm).unwrap();
+            assert_eq!(d1.cmp(&BigFloatNumber::from_u128(f, 128).unwrap()), 0);
+            assert!(!d1.inexact());
+        }
+
+        // n! = 1 * 2 * ... * n
+        for n in [100, 1000, 5000] {
+            let d1 = BigFloatNumber::factorial(n, 2000, rm).unwrap();
+            let d2 = ONE.rising_factorial(n, 2000, rm).unwrap();
+            assert_eq!(d1.cmp(&d2), 0);
+            assert_eq!(d1.inexact(), d2.inexact());
+        }
+
+        // binomial coefficients
+        let mut row: [u128; 100] = [0; 100];
+        row[0] = 1;
+        for n in 0..100 {
+            for k in (1..=n).rev() {
+                row[k] += row[k - 1];
+            }
+
+            for k in 0..=n + 1 {
+                let d1 = BigFloatNumber::binomial(n, k, 128, rm).unwrap();
+                let v = if k <= n { row[k] } else { 0 };
+                assert_eq!(d1.cmp(&BigFloatNumber::from_u128(v, 128).unwrap()), 0);
+            }
+        }
+
+        // C(n, k) = n! / (k! * (n - k)!)
+        for _ in 0..10 {
+            let n = random::<usize>() % 3000 + 1;
+            let k = random::<usize>() % n;
+            let p = 320;
+
+            let d1 = BigFloatNumber::binomial(n, k, p, rm).unwrap();
+            let f1 = BigFloatNumber::factorial(n, 50000, rm).unwrap();
+            let f2 = BigFloatNumber::factorial(k, 50000, rm).unwrap();
+            let f3 = BigFloatNumber::factorial(n - k, 50000, rm).unwrap();
+            let d2 = f1.div(&f2.mul_full_prec(&f3).unwrap(), p, rm).unwrap();
+            assert_eq!(d1.cmp(&d2), 0);
+        }
+
+        // large arguments at a small precision are correctly rounded
+        let rms = [
+            RoundingMode::ToEven,
+            RoundingMode::ToOdd,
+            RoundingMode::ToZero,
+            RoundingMode::FromZero,
+            RoundingMode::Up,
+            RoundingMode::Down,
+        ];
+
+        for (n, k) in [(3000, 1000), (10000, 77), (123456, 5)] {
+            let p = 128;
+            let f = int_product(1, n, usize::MAX).unwrap();
+            let num = int_product(n - k, n, usize::MAX).unwrap();
+            let den = int_product(1, k, usize::MAX).unwrap();
+
+            for rm in rms {
+                let mut d2 = f.clone().unwrap();
+                d2.set_precision(p, rm).unwrap();
+
+                let d1 = BigFloatNumber::factorial(n, p, rm).unwrap();
+                assert_eq!(d1.cmp(&d2), 0);
+                assert!(d1.inexact());
+
+                let d2 = num.div(&den, p, rm).unwrap();
+
+                let d1 = BigFloatNumber::binomial(n, k, p, rm).unwrap();
+                assert_eq!(d1.cmp(&d2), 0);
+                assert_eq!(d1.inexact(), d2.inexact());
+            }
+        }
+
+        // exact results which need the full product
+        for rm in rms {
+            let d1 = BigFloatNumber::binomial(100, 50, 128, rm).unwrap();
+            let d2 = BigFloatNumber::from_u128(100891344545564193334812497256, 128).unwrap();
+            assert_eq!(d1.cmp(&d2), 0);
+            assert!(!d1.inexact());
+        }
+
+        assert!(BigFloatNumber::factorial(10, usize::MAX, rm).is_err());
+        assert!(BigFloatNumber::binomial(10, 3, usize::MAX, rm).is_err());
+    }
+
+    #[ignore]
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_factorial_perf() {
+        for n in [10000, 100000, 1000000] {
+            let start_time = std::time::Instant::now();
+            let _ = BigFloatNumber::factorial(n, 256, RoundingMode::ToEven).unwrap();
+            let time = start_time.elapsed();
+            println!("{} factorial {}", n, time.as_millis());
+
+            let start_time = std::time::Instant::now();
+            let _ = int_product(1, n, usize::MAX).unwrap();
+            let time = start_time.elapsed();
+            println!("{} exact product {}", n, time.as_millis());
+        }
+    }
+
+    #[test]
+    fn test_pochhammer() {
+        let rm = RoundingMode::ToEven;
+
+        // exact results
+        let x = BigFloatNumber::from_f64(64, 0.5).unwrap();
+        let d1 = x.rising_factorial(5, 64, rm).unwrap();
+        assert_eq!(d1.cmp(&BigFloatNumber::from_f64(64, 29.53125).unwrap()), 0);
+        assert!(!d1.inexact());
+
+        let d1 = x.falling_factorial(3, 64, rm).unwrap();
+        assert_eq!(d1.cmp(&BigFloatNumber::from_f64(64, 0.375).unwrap()), 0);
+        assert!(!d1.inexact());
+
+        let x = BigFloatNumber::from_f64(64, -2.5).unwrap();
+        let d1 = x.rising_factorial(4, 64, rm).unwrap();
+        assert_eq!(d1.cmp(&BigFloatNumber::from_f64(64, -0.9375).unwrap()), 0);
+
+        // zero factor
+        let x = BigFloatNumber::from_f64(64, -3.0).unwrap();
+        assert!(x.rising_factorial(4, 64, rm).unwrap().is_zero());
+        assert!(!x.rising_factorial(3, 64, rm).unwrap().is_zero());
+        let x = BigFloatNumber::from_f64(64, 3.0).unwrap();
+        assert!(x.falling_factorial(4, 64, rm).unwrap().is_zero());
+        assert!(x.falling_factorial(0, 64, rm).unwrap().cmp(&ONE) == 0);
+
+        // compare with a high precision product
+        for _ in 0..20 {
+            let p = round_p(random::<usize>() % 1000 + 64);
+            let n = random::<usize>() % 200 + 1;
+            let x = BigFloatNumber::random_normal(p, -5, 10).unwrap();
+
+            for falling in [false, true] {
+                let d1 = if falling {
+                    x.falling_factorial(n, p, rm)
+                } else {
+                    x.rising_factorial(n, p, rm)
+                }
+                .unwrap();
+
+                let mut d2 = ONE.clone().unwrap();
+                for i in 0..n {
+                    let i = BigFloatNumber::from_usize(i).unwrap();
+                    let t = if i.is_zero() {
+                        x.clone()
+                    } else if falling {
+                        x.sub_full_prec(&i)
+                    } else {
+                        x.add_full_prec(&i)
+                    };
+                    d2 = d2.mul_full_prec(&t.unwrap()).unwrap();
+                }
+                d2.set_precision(p, rm).unwrap();
+
+                assert_eq!(d1.cmp(&d2), 0);
+            }
+        }
+
+        // overflow
+        let x = BigFloatNumber::max_value(64).unwrap();
+        assert!(
+            x.neg().unwrap().rising_factorial(3, 64, rm).unwrap_err()
+                == Error::ExponentOverflow(Sign::Neg)
+        );
+        assert!(x.falling_factorial(2, 64, rm).unwrap_err() == Error::ExponentOverflow(Sign::Pos));
+    }
+}
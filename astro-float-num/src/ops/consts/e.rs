@@ -1,6 +1,7 @@
 //! Euler's number
 
 use crate::common::consts::ONE;
+use crate::common::par::{join, PAR_SPLIT_THRESHOLD};
 use crate::common::util::{log2_floor, round_p};
 use crate::defs::Error;
 use crate::num::BigFloatNumber;
@@ -15,8 +16,10 @@ fn pq(a: usize, b: usize) -> Result<(BigFloatNumber, BigFloatNumber), Error> {
     } else {
         let m = (a + b) / 2;
 
-        let (pa, qa) = pq(a, m)?;
-        let (pb, qb) = pq(m, b)?;
+        let (ret_a, ret_b) = join(b - a >= PAR_SPLIT_THRESHOLD, || pq(a, m), || pq(m, b));
+
+        let (pa, qa) = ret_a?;
+        let (pb, qb) = ret_b?;
 
         let q = qa.mul_full_prec(&qb)?;
         let n0 = pa.mul_full_prec(&qb)?;
@@ -1,6 +1,7 @@
 //! ln(10)
 
 use crate::common::consts::ONE;
+use crate::common::par::{join, PAR_SPLIT_THRESHOLD};
 use crate::common::util::round_p;
 use crate::defs::Error;
 use crate::num::BigFloatNumber;
@@ -16,8 +17,10 @@ fn pqr(a: usize, b: usize) -> Result<(BigFloatNumber, BigFloatNumber, BigFloatNu
     } else {
         let m = (a + b) / 2;
 
-        let (pa, qa, ra) = pqr(a, m)?;
-        let (pb, qb, rb) = pqr(m, b)?;
+        let (ret_a, ret_b) = join(b - a >= PAR_SPLIT_THRESHOLD, || pqr(a, m), || pqr(m, b));
+
+        let (pa, qa, ra) = ret_a?;
+        let (pb, qb, rb) = ret_b?;
 
         let pq = pa.mul_full_prec(&qb)?;
         let pr = pb.mul_full_prec(&ra)?;
@@ -4,6 +4,7 @@ mod ln2;
 mod pi;
 
 use crate::common::buf::WordBuf;
+use crate::common::consts::ONE;
 use crate::common::util::round_p;
 use crate::mantissa::Mantissa;
 use crate::num::BigFloatNumber;
@@ -14,10 +15,16 @@ use crate::ops::consts::pi::PiCache;
 use crate::BigFloat;
 use crate::Error;
 use crate::RoundingMode;
+use crate::Sign;
+use crate::Word;
+use crate::WORD_BIT_SIZE;
 
 #[cfg(not(feature = "std"))]
 use alloc::vec::Vec;
 
+// Cached powers of a radix and their normalized forms.
+type RdxPowers = Vec<(WordBuf, WordBuf, usize)>;
+
 /// Constants cache contains arbitrary-precision mathematical constants.
 #[derive(Debug)]
 pub struct Consts {
@@ -25,7 +32,8 @@ pub struct Consts {
     e: ECache,
     ln2: Ln2Cache,
     ln10: Ln10Cache,
-    tenpowers: Vec<(WordBuf, WordBuf, usize)>,
+    frac_1_pi: Option<BigFloatNumber>,
+    rdxpowers: Vec<(Word, RdxPowers)>,
 }
 
 /// In an ideal situation, the `Consts` structure is initialized with `Consts::new` only once,
@@ -42,7 +50,8 @@ impl Consts {
             e: ECache::new()?,
             ln2: Ln2Cache::new()?,
             ln10: Ln10Cache::new()?,
-            tenpowers: Vec::new(),
+            frac_1_pi: None,
+            rdxpowers: Vec::new(),
         })
     }
 
@@ -98,6 +107,37 @@ impl Consts {
         self.ln10.for_prec(p, rm)
     }
 
+    /// Returns the value of 1/pi with precision `p`.
+    /// The value is not rounded: its relative error is below 2^(-p+1).
+    /// Precision is rounded upwards to the word size.
+    /// The value is cached with precision a bit larger than `p`, and the result is made of the leading words of the cached value.
+    /// A call with precision larger than the cached one computes pi with the new precision and divides 1 by it.
+    ///
+    /// ## Errors
+    ///
+    ///  - MemoryAllocation: failed to allocate memory for mantissa.
+    ///  - InvalidArgument: the precision is incorrect.
+    pub(crate) fn frac_1_pi_num(&mut self, p: usize) -> Result<BigFloatNumber, Error> {
+        let p = round_p(p);
+        BigFloatNumber::p_assertion(p)?;
+
+        if !matches!(&self.frac_1_pi, Some(v) if v.mantissa_max_bit_len() > p) {
+            // extra precision to avoid recomputation for slowly increasing p
+            let p_wrk = round_p(p + p / 8) + WORD_BIT_SIZE;
+            let pi = self.pi_num(p_wrk + WORD_BIT_SIZE, RoundingMode::None)?;
+            self.frac_1_pi = Some(ONE.div(&pi, p_wrk, RoundingMode::None)?);
+        }
+
+        let v = self.frac_1_pi.as_ref().unwrap(); // the value is computed above
+        let m = v.mantissa().digits();
+
+        let mut ret =
+            BigFloatNumber::from_words(&m[m.len() - p / WORD_BIT_SIZE..], Sign::Pos, v.exponent())?;
+        ret.set_inexact(true);
+
+        Ok(ret)
+    }
+
     /// Returns the value of the pi number with precision `p` using rounding mode `rm`.
     /// Precision is rounded upwards to the word size.
     pub fn pi(&mut self, p: usize, rm: RoundingMode) -> BigFloat {
@@ -134,12 +174,51 @@ impl Consts {
         }
     }
 
-    /// Return powers of 10: 100, 10000, 100000000, ...
-    pub(crate) fn tenpowers(&mut self, p: usize) -> Result<&[(WordBuf, WordBuf, usize)], Error> {
-        if p >= self.tenpowers.len() {
-            Mantissa::compute_tenpowers(&mut self.tenpowers, p)?;
+    /// Return powers of `rdx`: `rdx`^2, `rdx`^4, `rdx`^8, ...
+    pub(crate) fn rdxpowers(
+        &mut self,
+        rdx: Word,
+        p: usize,
+    ) -> Result<&[(WordBuf, WordBuf, usize)], Error> {
+        let idx = match self.rdxpowers.iter().position(|(r, _)| *r == rdx) {
+            Some(idx) => idx,
+            None => {
+                self.rdxpowers.try_reserve(1)?;
+                self.rdxpowers.push((rdx, Vec::new()));
+                self.rdxpowers.len() - 1
+            }
+        };
+
+        let rdxpowers = &mut self.rdxpowers[idx].1;
+
+        if p >= rdxpowers.len() {
+            Mantissa::compute_rdxpowers(rdxpowers, rdx, p)?;
         }
 
-        Ok(&self.tenpowers)
+        Ok(rdxpowers)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_frac_1_pi() {
+        let mut cc = Consts::new().unwrap();
+
+        // the first call caches a longer value, and the following calls take its leading words.
+        for p in [1024, 64, 640, 1088, 128] {
+            let v = cc.frac_1_pi_num(p).unwrap();
+            assert_eq!(v.mantissa_max_bit_len(), p);
+            assert!(v.inexact());
+
+            let pi = cc.pi_num(p + 128, RoundingMode::None).unwrap();
+            let r = ONE.div(&pi, p + 64, RoundingMode::None).unwrap();
+            let d = r.sub(&v, p + 64, RoundingMode::None).unwrap();
+            assert!(d.is_positive());
+            assert!((r.exponent() as isize - d.exponent() as isize) >= p as isize - 1);
+        }
     }
 }
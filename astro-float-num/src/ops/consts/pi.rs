@@ -1,5 +1,6 @@
 //! π number
 
+use crate::common::par::{join, PAR_SPLIT_THRESHOLD};
 use crate::common::util::round_p;
 use crate::defs::{Error, WORD_BIT_SIZE};
 use crate::num::BigFloatNumber;
@@ -31,8 +32,14 @@ fn pqr(a: u64, b: u64) -> Result<(BigFloatNumber, BigFloatNumber, BigFloatNumber
     } else {
         let m = (a + b) / 2;
 
-        let (pa, qa, ra) = pqr(a, m)?;
-        let (pb, qb, rb) = pqr(m, b)?;
+        let (ret_a, ret_b) = join(
+            b - a >= PAR_SPLIT_THRESHOLD as u64,
+            || pqr(a, m),
+            || pqr(m, b),
+        );
+
+        let (pa, qa, ra) = ret_a?;
+        let (pb, qb, rb) = ret_b?;
 
         let r = ra.mul_full_prec(&rb)?;
         let q = qa.mul_full_prec(&qb)?;
@@ -10,6 +10,7 @@ use crate::defs::RoundingMode;
 use crate::defs::EXPONENT_MIN;
 use crate::defs::WORD_BIT_SIZE;
 use crate::num::BigFloatNumber;
+use crate::ops::bsplit::BsplitSeries;
 use crate::ops::consts::Consts;
 use crate::ops::series::series_cost_optimize;
 use crate::ops::series::series_run;
@@ -130,10 +131,14 @@ impl BigFloatNumber {
             let p_x = p_wrk + add_p;
             x.set_precision(p_x, RoundingMode::None)?;
 
-            x = x.reduce_trig_arg(cc, RoundingMode::None)?;
+            let (x, odd) = x.reduce_trig_arg(cc, RoundingMode::None)?;
 
             let mut ret = x.cos_series(RoundingMode::None)?;
 
+            if odd {
+                ret.inv_sign();
+            }
+
             let t = ret.exponent().unsigned_abs() as usize + 1; // avoid cancellation when x near pi / 2
             if add_p < t {
                 add_p = t;
@@ -153,6 +158,10 @@ impl BigFloatNumber {
     pub(super) fn cos_series(mut self, rm: RoundingMode) -> Result<Self, Error> {
         // cos:  1 - x^2/2! + x^4/4! - x^6/6! + ...
 
+        if self.bsplit_applicable(BsplitSeries::Cos) {
+            return self.bsplit_series(BsplitSeries::Cos, rm);
+        }
+
         let p = self.mantissa_max_bit_len();
         let mut polycoeff_gen = CosPolycoeffGen::new(p)?;
         let (reduction_times, niter, e_eff) = series_cost_optimize::<CosArgReductionEstimator>(
@@ -0,0 +1,336 @@
+//! Exponents base 2 and base 10.
+
+use crate::common::buf::WordBuf;
+use crate::common::consts::{ONE, TEN};
+use crate::common::util::round_p;
+use crate::defs::{Error, EXPONENT_MAX, EXPONENT_MIN, WORD_BIT_SIZE};
+use crate::mantissa::Mantissa;
+use crate::num::BigFloatNumber;
+use crate::ops::consts::Consts;
+use crate::ops::util::compute_small_exp;
+use crate::Exponent;
+use crate::RoundingMode;
+use crate::Sign;
+
+impl BigFloatNumber {
+    /// Computes 2 to the power of `self` with precision `p`. The result is rounded using the rounding mode `rm`.
+    /// If `self` is an integer number, the result is exact.
+    /// This function requires constants cache `cc` for computing the result.
+    /// Precision is rounded upwards to the word size.
+    ///
+    /// ## Errors
+    ///
+    ///  - ExponentOverflow: the result is too large.
+    ///  - MemoryAllocation: failed to allocate memory.
+    ///  - InvalidArgument: the precision is incorrect.
+    pub fn exp2(&self, p: usize, rm: RoundingMode, cc: &mut Consts) -> Result<Self, Error> {
+        let p = round_p(p);
+        Self::p_assertion(p)?;
+
+        if self.is_zero() {
+            let mut ret = Self::from_word(1, p)?;
+            ret.set_inexact(self.inexact());
+            return Ok(ret);
+        }
+
+        // 2^x - 1 < x for 0 < x < 1.
+        compute_small_exp!(ONE, self.exponent() as isize, self.is_negative(), p, rm);
+
+        // 2^self = 2^n * 2^f, where n is the integer part of self, and |f| < 1.
+        let n = match self.int_as_usize() {
+            Ok(n) if !self.exp2_out_of_range(n, p) => n as isize,
+            Ok(_) | Err(Error::InvalidArgument) => return self.exp_out_of_range(p),
+            Err(e) => return Err(e),
+        };
+        let n = if self.is_negative() { -n } else { n };
+
+        let f = self.fract()?;
+
+        if f.is_zero() {
+            let mut ret = Self::from_word(1, p)?;
+            ret.set_inexact(self.inexact());
+            return ret.scale_pow2(n, rm);
+        }
+
+        let mut p_inc = WORD_BIT_SIZE;
+        let mut p_wrk = p + p_inc;
+
+        loop {
+            let p_x = p_wrk + 4;
+
+            let ln2 = cc.ln_2_num(p_x, RoundingMode::None)?;
+            let t = f.mul(&ln2, p_x, RoundingMode::None)?;
+            let mut ret = t.exp(p_x, RoundingMode::None, cc)?;
+
+            if ret.try_set_precision(p, rm, p_wrk)? {
+                ret.set_inexact(ret.inexact() | self.inexact());
+                break ret.scale_pow2(n, rm);
+            }
+
+            p_wrk += p_inc;
+            p_inc = round_p(p_wrk / 5);
+        }
+    }
+
+    /// Computes 10 to the power of `self` with precision `p`. The result is rounded using the rounding mode `rm`.
+    /// If `self` is an integer number, and the result is exactly representable with precision `p`, the exact value is returned.
+    /// This function requires constants cache `cc` for computing the result.
+    /// Precision is rounded upwards to the word size.
+    ///
+    /// ## Errors
+    ///
+    ///  - ExponentOverflow: the result is too large.
+    ///  - MemoryAllocation: failed to allocate memory.
+    ///  - InvalidArgument: the precision is incorrect.
+    pub fn exp10(&self, p: usize, rm: RoundingMode, cc: &mut Consts) -> Result<Self, Error> {
+        let p = round_p(p);
+        Self::p_assertion(p)?;
+
+        if self.is_zero() {
+            let mut ret = Self::from_word(1, p)?;
+            ret.set_inexact(self.inexact());
+            return Ok(ret);
+        }
+
+        // 10^x - 1 < 4*x for 0 < x < 1.
+        compute_small_exp!(ONE, self.exponent() as isize + 2, self.is_negative(), p, rm);
+
+        // 10^self = 10^n * 10^f, where n is the integer part of self, and |f| < 1.
+        // 10^n > 2^(3*n), and any larger n gives a result out of the range of representable numbers.
+        let n = match self.int_as_usize() {
+            Ok(n) if !self.exp2_out_of_range(n / 3, p) => n,
+            Ok(_) | Err(Error::InvalidArgument) => return self.exp_out_of_range(p),
+            Err(e) => return Err(e),
+        };
+
+        let f = self.fract()?;
+
+        if f.is_zero() && self.is_positive() && Self::pow10_is_exact(n, p) {
+            let mut ret = match Self::pow10_exact(n, cc) {
+                Err(Error::ExponentOverflow(_)) => return self.exp_out_of_range(p),
+                r => r,
+            }?;
+            ret.set_precision(p, rm)?;
+            ret.set_inexact(self.inexact());
+            return Ok(ret);
+        }
+
+        let mut p_inc = WORD_BIT_SIZE;
+        let mut p_wrk = p + p_inc;
+
+        loop {
+            let p_x = p_wrk + 4;
+
+            let mut ret = match self.exp10_int_fract(n, &f, p_x, cc) {
+                Err(Error::ExponentOverflow(_)) => return self.exp_out_of_range(p),
+                r => r,
+            }?;
+
+            if ret.try_set_precision(p, rm, p_wrk)? {
+                ret.set_inexact(ret.inexact() | self.inexact());
+                break Ok(ret);
+            }
+
+            p_wrk += p_inc;
+            p_inc = round_p(p_wrk / 5);
+        }
+    }
+
+    // Computes 10^n * 10^f, or 10^f / 10^n if self is negative, with precision p.
+    // 10^n is exact if the precision is sufficient, otherwise the relative error of the result is below 2^(-p+3).
+    fn exp10_int_fract(
+        &self,
+        n: usize,
+        f: &Self,
+        p: usize,
+        cc: &mut Consts,
+    ) -> Result<Self, Error> {
+        let d = if Self::pow10_is_exact(n, p) {
+            Self::pow10_exact(n, cc)
+        } else {
+            TEN.powi(n, p, RoundingMode::None)
+        }?;
+
+        let v = if f.is_zero() {
+            ONE.clone()
+        } else {
+            let ln10 = cc.ln_10_num(p, RoundingMode::None)?;
+            let t = f.mul(&ln10, p, RoundingMode::None)?;
+            t.exp(p, RoundingMode::None, cc)
+        }?;
+
+        if self.is_negative() {
+            v.div(&d, p, RoundingMode::None)
+        } else {
+            v.mul(&d, p, RoundingMode::None)
+        }
+    }
+
+    // Returns true if 10^n = 5^n * 2^n fits in `p` bits.
+    fn pow10_is_exact(n: usize, p: usize) -> bool {
+        // log2(5) < 2.3220
+        (n as u64).saturating_mul(23220) < (p as u64).saturating_mul(10000)
+    }
+
+    // Computes 10^n exactly using the cached powers 10^(2^i).
+    fn pow10_exact(n: usize, cc: &mut Consts) -> Result<Self, Error> {
+        let k = (usize::BITS - n.leading_zeros()) as usize;
+        let powers = cc.rdxpowers(10, k)?;
+
+        let mut ret = Self::from_word(1, WORD_BIT_SIZE)?;
+
+        for (i, (wb, _, shift)) in powers.iter().enumerate().take(k) {
+            if n & (1 << i) != 0 {
+                let mut m = WordBuf::new(wb.len())?;
+                m.copy_from_slice(wb);
+
+                let e = (wb.len() * WORD_BIT_SIZE - shift) as Exponent;
+                let pw = Self::from_raw_unchecked(Mantissa::from_word_buf(m), Sign::Pos, e, false);
+
+                ret = ret.mul_full_prec(&pw)?;
+            }
+        }
+
+        Ok(ret)
+    }
+
+    // Returns true if 2^n or 2^(-n) multiplied by a number in the range [1/2, 2] overflows or underflows.
+    fn exp2_out_of_range(&self, n: usize, p: usize) -> bool {
+        if self.is_positive() {
+            n > EXPONENT_MAX as usize
+        } else {
+            n > (EXPONENT_MIN as isize).unsigned_abs() + p + 2
+        }
+    }
+
+    // Exponent of an argument with large magnitude: overflow for a positive argument, and zero for a negative argument.
+    fn exp_out_of_range(&self, p: usize) -> Result<Self, Error> {
+        if self.is_positive() {
+            Err(Error::ExponentOverflow(Sign::Pos))
+        } else {
+            Self::new2(p, Sign::Pos, self.inexact())
+        }
+    }
+
+    // Multiplies `self` by 2^n. The result becomes subnormal or zero if it is too small.
+    fn scale_pow2(mut self, n: isize, rm: RoundingMode) -> Result<Self, Error> {
+        let e = self.exponent() as isize + n;
+
+        if e > EXPONENT_MAX as isize {
+            Err(Error::ExponentOverflow(self.sign()))
+        } else if e < EXPONENT_MIN as isize {
+            self.set_exponent(EXPONENT_MIN);
+            self.subnormalize(e, rm);
+            Ok(self)
+        } else {
+            self.set_exponent(e as Exponent);
+            Ok(self)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_exp2_exp10() {
+        let mut cc = Consts::new().unwrap();
+        let rm = RoundingMode::ToEven;
+
+        // exact results for integer arguments
+        for n in [-100000i64, -1000, -1, 1, 2, 63, 64, 1000, 100000] {
+            let x = BigFloatNumber::from_i64(n, 64).unwrap();
+            let y = x.exp2(128, rm, &mut cc).unwrap();
+            let mut z = BigFloatNumber::from_word(1, 128).unwrap();
+            z.set_exponent(n as Exponent + 1);
+            assert!(y.cmp(&z) == 0);
+            assert!(!y.inexact());
+        }
+
+        for n in 1..=55 {
+            let x = BigFloatNumber::from_word(n, 64).unwrap();
+            let y = x.exp10(128, rm, &mut cc).unwrap();
+            let z = TEN.powi(n as usize, 256, RoundingMode::None).unwrap();
+            assert!(y.cmp(&z) == 0);
+            assert!(!y.inexact());
+        }
+
+        // 10^55 = 5^55 * 2^55, where 5^55 has 128 bits, and 5^56 has 131 bits
+        let x = BigFloatNumber::from_word(56, 64).unwrap();
+        let y = x.exp10(128, rm, &mut cc).unwrap();
+        assert!(y.inexact());
+        let mut z = TEN.powi(56, 256, RoundingMode::None).unwrap();
+        z.set_precision(128, rm).unwrap();
+        assert!(y.cmp(&z) == 0);
+
+        // correct rounding
+        for _ in 0..200 {
+            let p = (rand::random::<usize>() % 5 + 1) * WORD_BIT_SIZE;
+            let x = BigFloatNumber::random_normal(p, -20, 12).unwrap();
+
+            let y1 = x.exp2(p, rm, &mut cc).unwrap();
+            let mut y2 = x.exp2(p + 256, RoundingMode::None, &mut cc).unwrap();
+            y2.set_precision(p, rm).unwrap();
+            assert!(y1.cmp(&y2) == 0);
+
+            let ln2 = cc.ln_2_num(p + 256, RoundingMode::None).unwrap();
+            let mut y3 = x
+                .mul(&ln2, p + 256, RoundingMode::None)
+                .unwrap()
+                .exp(p + 256, RoundingMode::None, &mut cc)
+                .unwrap();
+            y3.set_precision(p, rm).unwrap();
+            assert!(y1.cmp(&y3) == 0);
+
+            let y1 = x.exp10(p, rm, &mut cc).unwrap();
+            let mut y2 = x.exp10(p + 256, RoundingMode::None, &mut cc).unwrap();
+            y2.set_precision(p, rm).unwrap();
+            assert!(y1.cmp(&y2) == 0);
+
+            let ln10 = cc.ln_10_num(p + 256, RoundingMode::None).unwrap();
+            let mut y3 = x
+                .mul(&ln10, p + 256, RoundingMode::None)
+                .unwrap()
+                .exp(p + 256, RoundingMode::None, &mut cc)
+                .unwrap();
+            y3.set_precision(p, rm).unwrap();
+            assert!(y1.cmp(&y3) == 0);
+        }
+
+        // negative integer powers of 10
+        let x = BigFloatNumber::from_i8(-3, 64).unwrap();
+        let y = x.exp10(128, rm, &mut cc).unwrap();
+        assert!(y.inexact());
+        let z = ONE
+            .div(&BigFloatNumber::from_word(1000, 64).unwrap(), 128, rm)
+            .unwrap();
+        assert!(y.cmp(&z) == 0);
+
+        // large arguments
+        let x = BigFloatNumber::from_u64(1 << 40, 64).unwrap();
+        assert!(x.exp2(128, rm, &mut cc).unwrap_err() == Error::ExponentOverflow(Sign::Pos));
+        assert!(x.exp10(128, rm, &mut cc).unwrap_err() == Error::ExponentOverflow(Sign::Pos));
+        let x = x.neg().unwrap();
+        assert!(x.exp2(128, rm, &mut cc).unwrap().is_zero());
+        assert!(x.exp10(128, rm, &mut cc).unwrap().is_zero());
+
+        // subnormal result
+        let x = BigFloatNumber::from_i64(EXPONENT_MIN as i64 - 10, 64).unwrap();
+        let y = x.exp2(128, rm, &mut cc).unwrap();
+        assert!(y.is_subnormal());
+        assert!(!y.inexact());
+        let mut s = BigFloatNumber::from_word(1, 128).unwrap();
+        s.set_exponent(21);
+        let mut z = BigFloatNumber::from_word(1, 128).unwrap();
+        z.set_exponent(EXPONENT_MIN + 11);
+        assert!(y.mul(&s, 128, RoundingMode::None).unwrap().cmp(&z) == 0);
+
+        // small arguments
+        let mut x = BigFloatNumber::from_word(1, 128).unwrap();
+        x.set_exponent(-200);
+        assert!(x.exp2(128, rm, &mut cc).unwrap().cmp(&ONE) == 0);
+        assert!(x.exp10(128, rm, &mut cc).unwrap().cmp(&ONE) == 0);
+    }
+}
@@ -0,0 +1,434 @@
+//! Lambert W function.
+
+use crate::common::consts::{ONE, THREE};
+use crate::common::util::round_p;
+use crate::defs::Error;
+use crate::defs::RoundingMode;
+use crate::num::BigFloatNumber;
+use crate::ops::consts::Consts;
+use crate::ops::util::compute_small_exp;
+use crate::Sign;
+use crate::WORD_BIT_SIZE;
+
+// Precision of the initial estimate.
+const SEED_PREC: usize = 64;
+
+// Maximum number of iterations at the working precision before the precision is increased.
+const MAX_ITER: usize = 64;
+
+impl BigFloatNumber {
+    /// Computes the principal branch of the Lambert W function of a number with precision `p`. The result is rounded using the rounding mode `rm`.
+    /// The principal branch is the solution `w` >= -1 of the equation `w*e^w = self`, and it is defined for `self` >= -1/e.
+    /// This function requires constants cache `cc` for computing the result.
+    /// Precision is rounded upwards to the word size.
+    ///
+    /// ## Errors
+    ///
+    ///  - MemoryAllocation: failed to allocate memory.
+    ///  - InvalidArgument: the argument is less than -1/e, or the precision is incorrect.
+    pub fn lambert_w0(&self, p: usize, rm: RoundingMode, cc: &mut Consts) -> Result<Self, Error> {
+        let p = round_p(p);
+        Self::p_assertion(p)?;
+
+        if self.is_zero() {
+            return Self::new2(p, self.sign(), self.inexact());
+        }
+
+        // W0(x) = x - x^2 + 3/2*x^3 - ...
+        compute_small_exp!(self, self.exponent() as isize, self.is_positive(), p, rm);
+
+        self.lambert_w(false, p, rm, cc)
+    }
+
+    /// Computes the lower branch of the Lambert W function of a number with precision `p`. The result is rounded using the rounding mode `rm`.
+    /// The lower branch is the solution `w` <= -1 of the equation `w*e^w = self`, and it is defined for -1/e <= `self` < 0.
+    /// This function requires constants cache `cc` for computing the result.
+    /// Precision is rounded upwards to the word size.
+    ///
+    /// ## Errors
+    ///
+    ///  - ExponentOverflow: the argument is zero.
+    ///  - MemoryAllocation: failed to allocate memory.
+    ///  - InvalidArgument: the argument is positive, or less than -1/e, or the precision is incorrect.
+    pub fn lambert_wm1(&self, p: usize, rm: RoundingMode, cc: &mut Consts) -> Result<Self, Error> {
+        let p = round_p(p);
+        Self::p_assertion(p)?;
+
+        if self.is_zero() {
+            return Err(Error::ExponentOverflow(Sign::Neg));
+        }
+
+        if self.is_positive() {
+            return Err(Error::InvalidArgument);
+        }
+
+        self.lambert_w(true, p, rm, cc)
+    }
+
+    fn lambert_w(
+        &self,
+        wm1: bool,
+        p: usize,
+        rm: RoundingMode,
+        cc: &mut Consts,
+    ) -> Result<Self, Error> {
+        // Near the branch point -1/e the function is ill-conditioned: the error of w is amplified by 1/|1+w|,
+        // and 1 + w ~ sqrt(2*(1 + e*x)). k bounds the number of bits lost to the cancellation in 1 + w.
+        let (seed, k) = if self.is_negative() {
+            let q = self.lambert_w_branch_dist(cc)?;
+
+            if q.is_negative() {
+                return Err(Error::InvalidArgument);
+            }
+
+            let mut s = q.mul(&ONE, SEED_PREC, RoundingMode::None)?;
+            s.set_exponent(s.exponent() + 1);
+            let s = s.sqrt(SEED_PREC, RoundingMode::None)?;
+            let k = (-(s.exponent() as isize)).max(0) as usize + 1;
+
+            (self.lambert_w_seed(wm1, &q, k, cc)?, k)
+        } else {
+            (self.lambert_w_seed(wm1, self, 0, cc)?, 0)
+        };
+
+        let mut p_inc = WORD_BIT_SIZE;
+        let mut p_wrk = p.max(self.mantissa_max_bit_len()) + p_inc;
+
+        loop {
+            let p_x = p_wrk + 2 * k + 6;
+
+            if let Some(mut ret) = self.lambert_w_halley(wm1, &seed, k, p_x, cc)? {
+                if ret.try_set_precision(p, rm, p_wrk)? {
+                    ret.set_inexact(ret.inexact() | self.inexact());
+                    break Ok(ret);
+                }
+            }
+
+            p_wrk += p_inc;
+            p_inc = round_p(p_wrk / 5);
+        }
+    }
+
+    // Computes 1 + e*x for negative x using the cached e, with at least 64 correct bits.
+    fn lambert_w_branch_dist(&self, cc: &mut Consts) -> Result<Self, Error> {
+        let mut p_q = self.mantissa_max_bit_len() + WORD_BIT_SIZE;
+
+        loop {
+            let e = cc.e_num(p_q, RoundingMode::None)?;
+            let q = e.mul(self, p_q, RoundingMode::None)?;
+            let q = q.add(&ONE, p_q, RoundingMode::None)?;
+
+            // the absolute error of q is below 2^(-p_q+2)
+            if !q.is_zero() && q.exponent() as isize > WORD_BIT_SIZE as isize + 3 - p_q as isize {
+                return Ok(q);
+            }
+
+            // x is exact, and -1/e is irrational, so q is not zero
+            p_q += p_q / 2;
+        }
+    }
+
+    // Initial estimate of W(x) with precision SEED_PREC.
+    // For negative x, `q` = 1 + e*x, otherwise `q` is x itself.
+    fn lambert_w_seed(
+        &self,
+        wm1: bool,
+        q: &Self,
+        k: usize,
+        cc: &mut Consts,
+    ) -> Result<Self, Error> {
+        let p = SEED_PREC;
+        let quarter = Self::from_f64(p, -0.25)?;
+
+        if self.is_negative() && self.cmp(&quarter) < 0 {
+            // near the branch point: W(x) = -1 + s - s^2/3 + 11/72*s^3 - ..., s = +-sqrt(2*(1 + e*x))
+            let p = p + 2 * k;
+            let mut s = q.mul(&ONE, p, RoundingMode::None)?;
+            s.set_exponent(s.exponent() + 1);
+            let mut s = s.sqrt(p, RoundingMode::None)?;
+            if wm1 {
+                s.inv_sign();
+            }
+
+            let c3 =
+                Self::from_word(11, p)?.div(&Self::from_word(72, p)?, p, RoundingMode::None)?;
+            let c2 = ONE.div(&THREE, p, RoundingMode::None)?;
+
+            let t = s.mul(&c3, p, RoundingMode::None)?;
+            let t = t.sub(&c2, p, RoundingMode::None)?;
+            let t = t.mul(&s, p, RoundingMode::None)?;
+            let t = t.add(&ONE, p, RoundingMode::None)?;
+            let t = t.mul(&s, p, RoundingMode::None)?;
+            t.sub(&ONE, p, RoundingMode::None)
+        } else if wm1 || self.cmp(&THREE) > 0 {
+            // far from the branch point: W(x) = L1 - L2 + L2/L1 + ..., L1 = ln(|x|), L2 = ln(|L1|)
+            let x = self.abs()?;
+            let l1 = x.ln(p, RoundingMode::None, cc)?;
+            let l2 = l1.abs()?.ln(p, RoundingMode::None, cc)?;
+            let t = l2.div(&l1, p, RoundingMode::None)?;
+            let t = t.sub(&l2, p, RoundingMode::None)?;
+            t.add(&l1, p, RoundingMode::None)
+        } else {
+            // near zero: W0(x) = x/(1 + x) + O(x^3)
+            let d = self.add(&ONE, p, RoundingMode::None)?;
+            self.div(&d, p, RoundingMode::None)
+        }
+    }
+
+    // Halley's iteration for g(w) = w + ln(w/x) starting from `seed`.
+    // Returns None if the iteration did not converge with precision `p`.
+    fn lambert_w_halley(
+        &self,
+        wm1: bool,
+        seed: &Self,
+        k: usize,
+        p: usize,
+        cc: &mut Consts,
+    ) -> Result<Option<Self>, Error> {
+        let mut w = seed.clone()?;
+
+        // the precision must exceed the number of bits lost to the cancellation in 1 + w
+        let p_start = 2 * k + 2 * WORD_BIT_SIZE;
+        let mut p_iter = p.min(p_start);
+
+        for _ in 0..MAX_ITER {
+            // g'(w) = (w + 1)/w, g''(w) = -1/w^2, and Halley's correction is g*(w + 1)*w / ((w + 1)^2 + g/2)
+            let t = w.div(self, p_iter, RoundingMode::None)?;
+            let t = t.ln(p_iter, RoundingMode::None, cc)?;
+            let g = w.add(&t, p_iter, RoundingMode::None)?;
+
+            if g.is_zero() {
+                return Ok(if p_iter == p { Some(w) } else { None });
+            }
+
+            let a = w.add(&ONE, p_iter, RoundingMode::None)?;
+            let mut g2 = g.clone()?;
+            g2.div_by_2(RoundingMode::None);
+            let d = a.mul(&a, p_iter, RoundingMode::None)?;
+            let d = d.add(&g2, p_iter, RoundingMode::None)?;
+            let n = g.mul(&a, p_iter, RoundingMode::None)?;
+            let n = n.mul(&w, p_iter, RoundingMode::None)?;
+            let delta = n.div(&d, p_iter, RoundingMode::None)?;
+
+            let w_new = w.sub(&delta, p_iter, RoundingMode::None)?;
+
+            // correct bits of w before the step
+            let bits = (w.exponent() as isize - delta.exponent() as isize).max(0) as usize;
+
+            // at the noise level of precision p the correction does not decrease anymore
+            let done = p_iter == p && bits + 2 * k + 4 >= p;
+
+            w = self.lambert_w_bound(wm1, w, w_new, p_iter)?;
+
+            if done {
+                return Ok(Some(w));
+            }
+
+            // Halley's iteration triples the number of correct bits
+            p_iter = round_p((3 * bits + p_start).max(p_iter)).min(p);
+        }
+
+        Ok(None)
+    }
+
+    // Keeps the next iterate `w_new` in the range of the branch by halving the distance from `w` to the bound of the range.
+    fn lambert_w_bound(&self, wm1: bool, w: Self, w_new: Self, p: usize) -> Result<Self, Error> {
+        let m1 = ONE.neg()?;
+
+        // W0(x) > 0 for x > 0, -1 < W0(x) < 0 for x < 0, and W-1(x) < -1
+        let bound = if wm1 {
+            (w_new.cmp(&m1) >= 0).then_some(m1)
+        } else if w_new.cmp(&m1) <= 0 {
+            Some(m1)
+        } else if (self.is_positive() && !w_new.is_positive())
+            || (self.is_negative() && !w_new.is_negative())
+        {
+            Some(Self::new(p)?)
+        } else {
+            None
+        };
+
+        match bound {
+            Some(b) => {
+                let mut t = w.add(&b, p, RoundingMode::None)?;
+                t.div_by_2(RoundingMode::None);
+                Ok(t)
+            }
+            None => Ok(w_new),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::Exponent;
+
+    // Checks that w*e^w = x, where the error of w*e^w is amplified by |1 + w|, and that w is in the range of the branch.
+    fn check_w(w: &BigFloatNumber, x: &BigFloatNumber, wm1: bool, p: usize, cc: &mut Consts) {
+        let p_wrk = p + 4 * WORD_BIT_SIZE;
+        let y = w
+            .exp(p_wrk, RoundingMode::None, cc)
+            .unwrap()
+            .mul(w, p_wrk, RoundingMode::None)
+            .unwrap();
+
+        let a = w.add(&ONE, p_wrk, RoundingMode::None).unwrap();
+        let mut eps = BigFloatNumber::from_word(1, p).unwrap();
+        eps.set_exponent(x.exponent() - p as Exponent + 2);
+        let eps = eps.mul(&a.abs().unwrap(), p, RoundingMode::None).unwrap();
+
+        let d = y.sub(x, p_wrk, RoundingMode::None).unwrap();
+        assert!(d.abs().unwrap().cmp(&eps) <= 0);
+
+        if wm1 {
+            assert!(a.is_negative());
+        } else {
+            assert!(a.is_positive());
+        }
+    }
+
+    #[test]
+    fn test_lambert_w() {
+        let mut cc = Consts::new().unwrap();
+        let rm = RoundingMode::ToEven;
+
+        // W0(1) = omega constant
+        let p = 320;
+        let w = ONE.lambert_w0(p, rm, &mut cc).unwrap();
+        check_w(&w, &ONE, false, p, &mut cc);
+        let omega = BigFloatNumber::parse(
+            "5.67143290409783872999968662210355549753815787186512508135131e-1",
+            crate::Radix::Dec,
+            p,
+            RoundingMode::None,
+            &mut cc,
+        )
+        .unwrap();
+        let d = w.sub(&omega, p, RoundingMode::None).unwrap();
+        assert!(d.is_zero() || d.exponent() < -190);
+
+        // random arguments
+        for _ in 0..200 {
+            let p = (rand::random::<usize>() % 5 + 1) * WORD_BIT_SIZE;
+            let x = BigFloatNumber::random_normal(p, -20, 20).unwrap();
+            let x = if x.is_negative() {
+                // -1/e < x < 0
+                let mut t = x.abs().unwrap();
+                t.set_exponent(t.exponent().min(-2));
+                t.neg().unwrap()
+            } else {
+                x
+            };
+
+            let w = x.lambert_w0(p, rm, &mut cc).unwrap();
+            check_w(&w, &x, false, p, &mut cc);
+
+            // correct rounding
+            let mut w2 = x.lambert_w0(p + 256, RoundingMode::None, &mut cc).unwrap();
+            w2.set_precision(p, rm).unwrap();
+            assert!(w.cmp(&w2) == 0);
+
+            if x.is_negative() {
+                let w = x.lambert_wm1(p, rm, &mut cc).unwrap();
+                check_w(&w, &x, true, p, &mut cc);
+
+                let mut w2 = x.lambert_wm1(p + 256, RoundingMode::None, &mut cc).unwrap();
+                w2.set_precision(p, rm).unwrap();
+                assert!(w.cmp(&w2) == 0);
+            }
+        }
+
+        // near the branch point
+        let p = 256;
+        let e = cc.e_num(p * 4, RoundingMode::None).unwrap();
+        let b = ONE
+            .div(&e, p * 4, RoundingMode::None)
+            .unwrap()
+            .neg()
+            .unwrap();
+        for prec in [64, 128, 256, 512] {
+            // x_in > -1/e > x_out, and x_in - x_out is ulp
+            let mut x = b.clone().unwrap();
+            x.set_precision(prec, RoundingMode::ToEven).unwrap();
+            let mut ulp = BigFloatNumber::from_word(1, prec).unwrap();
+            ulp.set_exponent(x.exponent() - prec as Exponent + 1);
+            let (x_in, x_out) = if x.cmp(&b) > 0 {
+                (
+                    x.clone().unwrap(),
+                    x.sub(&ulp, prec, RoundingMode::None).unwrap(),
+                )
+            } else {
+                (x.add(&ulp, prec, RoundingMode::None).unwrap(), x)
+            };
+
+            let w0 = x_in.lambert_w0(p, rm, &mut cc).unwrap();
+            let wm1 = x_in.lambert_wm1(p, rm, &mut cc).unwrap();
+            check_w(&w0, &x_in, false, p, &mut cc);
+            check_w(&wm1, &x_in, true, p, &mut cc);
+
+            assert!(x_out.lambert_w0(p, rm, &mut cc).unwrap_err() == Error::InvalidArgument);
+            assert!(x_out.lambert_wm1(p, rm, &mut cc).unwrap_err() == Error::InvalidArgument);
+        }
+
+        // large and small arguments
+        let p = 128;
+        let x = BigFloatNumber::max_value(p).unwrap();
+        let w = x.lambert_w0(p, rm, &mut cc).unwrap();
+        check_w(&w, &x, false, p, &mut cc);
+
+        let x = BigFloatNumber::random_normal(p, -1000000, -1000)
+            .unwrap()
+            .abs()
+            .unwrap()
+            .neg()
+            .unwrap();
+        let w = x.lambert_wm1(p, rm, &mut cc).unwrap();
+        check_w(&w, &x, true, p, &mut cc);
+
+        let mut x = BigFloatNumber::from_word(1, p).unwrap();
+        x.set_exponent(-200);
+        let w = x.lambert_w0(p, RoundingMode::Down, &mut cc).unwrap();
+        assert!(w.cmp(&x) < 0);
+        let w = x.lambert_w0(p, RoundingMode::ToEven, &mut cc).unwrap();
+        assert!(w.cmp(&x) == 0);
+
+        // special cases
+        let zero = BigFloatNumber::new(p).unwrap();
+        assert!(zero.lambert_w0(p, rm, &mut cc).unwrap().is_zero());
+        assert!(
+            zero.lambert_wm1(p, rm, &mut cc).unwrap_err() == Error::ExponentOverflow(Sign::Neg)
+        );
+        assert!(ONE.lambert_wm1(p, rm, &mut cc).unwrap_err() == Error::InvalidArgument);
+        assert!(
+            ONE.neg().unwrap().lambert_w0(p, rm, &mut cc).unwrap_err() == Error::InvalidArgument
+        );
+    }
+
+    #[ignore]
+    #[test]
+    #[cfg(feature = "std")]
+    fn lambert_w_perf() {
+        let p = 320;
+        let mut cc = Consts::new().unwrap();
+        let mut n = vec![];
+        for _ in 0..10000 {
+            n.push(
+                BigFloatNumber::random_normal(p, -5, 5)
+                    .unwrap()
+                    .abs()
+                    .unwrap(),
+            );
+        }
+
+        for _ in 0..5 {
+            let start_time = std::time::Instant::now();
+            for ni in n.iter() {
+                let _f = ni.lambert_w0(p, RoundingMode::ToEven, &mut cc).unwrap();
+            }
+            let time = start_time.elapsed();
+            println!("{}", time.as_millis());
+        }
+    }
+}
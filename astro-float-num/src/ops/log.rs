@@ -8,6 +8,7 @@ use crate::common::util::calc_mul_cost;
 use crate::common::util::calc_sqrt_cost;
 use crate::common::util::count_leading_ones;
 use crate::common::util::count_leading_zeroes_skip_first;
+use crate::common::util::log2_ceil;
 use crate::common::util::round_p;
 use crate::defs::Error;
 use crate::defs::RoundingMode;
@@ -18,6 +19,7 @@ use crate::ops::series::series_cost_optimize;
 use crate::ops::series::series_run;
 use crate::ops::series::ArgReductionEstimator;
 use crate::ops::series::PolycoeffGen;
+use crate::tuning::Tuning;
 use crate::Exponent;
 use crate::WORD_BIT_SIZE;
 
@@ -133,7 +135,7 @@ impl BigFloatNumber {
             let p_x = p_wrk + additional_prec;
             x.set_precision(p_x, RoundingMode::None)?;
 
-            let p1 = Self::ln_series(x, RoundingMode::None)?;
+            let p1 = Self::ln_reduced(x, cc)?;
 
             let mut ret = if e == 0 {
                 p1
@@ -159,6 +161,62 @@ impl BigFloatNumber {
         }
     }
 
+    // Computes ln(x) for 0.5 <= x < 1 with the precision of x.
+    fn ln_reduced(x: Self, cc: &mut Consts) -> Result<Self, Error> {
+        if x.mantissa_max_bit_len() >= Tuning::current().ln_agm {
+            Self::ln_agm(x, cc)
+        } else {
+            Self::ln_series(x, RoundingMode::None)
+        }
+    }
+
+    // ln(x) = pi / (2 * AGM(1, 4/s)) - m*ln(2) + O(ln(s)/s^2), where s = x*2^m, 0.5 <= x < 1.
+    fn ln_agm(x: Self, cc: &mut Consts) -> Result<Self, Error> {
+        let p = x.mantissa_max_bit_len();
+
+        // ln(s) is about m*ln(2), and the subtraction of m*ln(2) cancels about log2(m) bits.
+        let p_wrk = round_p(p + 2 * log2_ceil(p) + WORD_BIT_SIZE);
+        let m = p_wrk / 2 + log2_ceil(p_wrk) + 4;
+
+        // 4/s = 2^(2-m) / x
+        let mut z = ONE.div(&x, p_wrk, RoundingMode::None)?;
+        z.set_exponent(z.exponent() + 2 - m as Exponent);
+
+        let agm = Self::agm_one(z, p_wrk)?;
+
+        let pi = cc.pi_num(p_wrk, RoundingMode::None)?;
+        let mut ln_s = pi.div(&agm, p_wrk, RoundingMode::None)?;
+        ln_s.set_exponent(ln_s.exponent() - 1);
+
+        let ln2 = cc.ln_2_num(p_wrk, RoundingMode::None)?;
+        let mln2 = ln2.mul(&Self::from_usize(m)?, p_wrk, RoundingMode::None)?;
+
+        let mut ret = ln_s.sub(&mln2, p_wrk, RoundingMode::None)?;
+        ret.set_precision(p, RoundingMode::None)?;
+
+        Ok(ret)
+    }
+
+    // Arithmetic-geometric mean of 1 and b, 0 < b < 1, with precision p.
+    fn agm_one(mut b: Self, p: usize) -> Result<Self, Error> {
+        let mut a = Self::from_word(1, p)?;
+
+        loop {
+            let mut an = a.add(&b, p, RoundingMode::None)?;
+            an.set_exponent(an.exponent() - 1);
+
+            // when a and b agree in half of the bits, (a+b)/2 agrees with the limit in all bits.
+            let d = a.sub(&b, p, RoundingMode::None)?;
+            if d.is_zero() || (d.exponent() as isize) < a.exponent() as isize - (p / 2) as isize {
+                return Ok(an);
+            }
+
+            let ab = a.mul(&b, p, RoundingMode::None)?;
+            b = ab.sqrt(p, RoundingMode::None)?;
+            a = an;
+        }
+    }
+
     fn ln_series(mut x: Self, rm: RoundingMode) -> Result<Self, Error> {
         let p = x.mantissa_max_bit_len();
         let mut polycoeff_gen = AtanhPolycoeffGen::new(p)?;
@@ -268,7 +326,7 @@ impl BigFloatNumber {
             let p_x = p_wrk + additional_prec;
             x.set_precision(p_x, RoundingMode::None)?;
 
-            let p1 = Self::ln_series(x, RoundingMode::None)?;
+            let p1 = Self::ln_reduced(x, cc)?;
 
             let p2 = cc.ln_2_num(p_x, RoundingMode::None)?;
 
@@ -808,6 +866,84 @@ mod tests {
         assert!(d1.cmp(&refn) == 0);
     }
 
+    #[test]
+    fn test_ln_agm() {
+        let mut cc = Consts::new().unwrap();
+
+        for p in [320, 1024, 4992] {
+            for _ in 0..20 {
+                let mut x = BigFloatNumber::random_normal(p, 0, 0).unwrap();
+                x.set_sign(Sign::Pos);
+
+                let d1 = BigFloatNumber::ln_agm(x.clone().unwrap(), &mut cc).unwrap();
+                let d2 = BigFloatNumber::ln_series(x, RoundingMode::None).unwrap();
+
+                let d = d1.sub(&d2, p, RoundingMode::None).unwrap();
+                assert!(
+                    d.is_zero() || d2.exponent() as isize - d.exponent() as isize >= p as isize - 4
+                );
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_ln_agm_rounding() {
+        // ln computed with the AGM is correctly rounded: compare with the series at a much higher precision.
+        let mut cc = Consts::new().unwrap();
+        let series = Tuning {
+            ln_agm: usize::MAX,
+            ..Tuning::DEFAULT
+        };
+        let rms = [
+            RoundingMode::ToEven,
+            RoundingMode::ToOdd,
+            RoundingMode::ToZero,
+            RoundingMode::FromZero,
+            RoundingMode::Up,
+            RoundingMode::Down,
+        ];
+
+        for p in [Tuning::DEFAULT.ln_agm, 2560] {
+            let mut args = vec![];
+
+            // large and tiny exponents
+            for e in [crate::EXPONENT_MAX, crate::EXPONENT_MAX / 2, 100, -100, crate::EXPONENT_MIN]
+            {
+                let mut x = BigFloatNumber::random_normal(p, e, e).unwrap();
+                x.set_sign(Sign::Pos);
+                args.push(x);
+            }
+            args.push(random_subnormal(p).abs().unwrap());
+
+            // near 1
+            for k in [1, 5, 64, p / 2, p - 1] {
+                let mut eps = BigFloatNumber::random_normal(p, 0, 0).unwrap();
+                eps.set_sign(Sign::Pos);
+                eps.set_exponent(1 - k as Exponent);
+
+                args.push(ONE.add(&eps, p, RoundingMode::None).unwrap());
+                args.push(ONE.sub(&eps, p, RoundingMode::None).unwrap());
+            }
+
+            for x in args {
+                let d2 = series
+                    .scope(|| x.ln(3 * p, RoundingMode::None, &mut cc))
+                    .unwrap();
+
+                for rm in rms {
+                    let d1 = Tuning::DEFAULT.scope(|| x.ln(p, rm, &mut cc)).unwrap();
+
+                    let mut d3 = d2.clone().unwrap();
+                    d3.set_precision(p, rm).unwrap();
+
+                    assert_eq!(d1.cmp(&d3), 0);
+                    assert!(d1.inexact());
+                }
+            }
+        }
+    }
+
     #[ignore]
     #[test]
     #[cfg(feature = "std")]
@@ -830,4 +966,49 @@ mod tests {
             println!("{}", time.as_millis());
         }
     }
+
+    #[ignore]
+    #[test]
+    #[cfg(feature = "std")]
+    fn ln_agm_perf() {
+        // Crossover of the series and the AGM logarithm: time of ln in microseconds with each of them.
+        let mut cc = Consts::new().unwrap();
+        let series = Tuning {
+            ln_agm: usize::MAX,
+            ..Tuning::DEFAULT
+        };
+        let agm = Tuning {
+            ln_agm: 0,
+            ..Tuning::DEFAULT
+        };
+
+        for p in [1024, 1280, 1536, 1792, 2048, 2560, 3072, 4096, 8192] {
+            let mut n = vec![];
+            for _ in 0..10 {
+                let mut x = BigFloatNumber::random_normal(p, -100, 100).unwrap();
+                x.set_sign(Sign::Pos);
+                n.push(x);
+            }
+
+            // cache constants
+            let _f = agm
+                .scope(|| n[0].ln(p, RoundingMode::ToEven, &mut cc))
+                .unwrap();
+
+            let mut t = [u128::MAX; 2];
+            for _ in 0..5 {
+                for (ti, tuning) in t.iter_mut().zip([series, agm]) {
+                    let start_time = std::time::Instant::now();
+                    for x in n.iter() {
+                        let _f = tuning
+                            .scope(|| x.ln(p, RoundingMode::ToEven, &mut cc))
+                            .unwrap();
+                    }
+                    *ti = (*ti).min(start_time.elapsed().as_micros() / n.len() as u128);
+                }
+            }
+
+            println!("{} {} {}", p, t[0], t[1]);
+        }
+    }
 }
@@ -6,18 +6,26 @@ mod asin;
 mod asinh;
 mod atan;
 mod atanh;
+mod bessel;
+mod bsplit;
 mod cbrt;
+mod comb;
 pub mod consts;
 mod cos;
 mod cosh;
+mod exp2;
+mod lambert;
 mod log;
 mod pow;
+mod recip;
+mod root;
 mod series;
 mod sin;
 mod sinh;
 mod sqrt;
 mod tan;
 mod tanh;
+mod trigpi;
 mod util;
 
 #[cfg(test)]
@@ -2,8 +2,10 @@
 
 use crate::common::consts::{FOUR, THREE};
 use crate::common::util::{calc_add_cost, calc_mul_cost, round_p};
+use crate::ops::bsplit::BsplitSeries;
 use crate::ops::consts::Consts;
 use crate::ops::util::compute_small_exp;
+use crate::tuning::Tuning;
 use crate::EXPONENT_MIN;
 use crate::{
     common::consts::ONE,
@@ -168,7 +170,7 @@ impl BigFloatNumber {
             fract.set_inexact(false);
             fract.set_precision(p_work, RoundingMode::None)?;
             fract.set_sign(Sign::Pos);
-            fract.expf()
+            fract.expf(cc)
         } else {
             ONE.clone()
         }?;
@@ -342,11 +344,24 @@ impl BigFloatNumber {
     }
 
     // e^self for |self| < 1.
-    fn expf(self) -> Result<Self, Error> {
+    fn expf(self, cc: &mut Consts) -> Result<Self, Error> {
         debug_assert!(!self.is_zero());
 
         let p = self.mantissa_max_bit_len();
 
+        if self.bsplit_applicable(BsplitSeries::Exp) {
+            self.bsplit_series(BsplitSeries::Exp, RoundingMode::None)
+        } else if p >= Tuning::current().exp_newton {
+            self.exp_newton(p, cc)
+        } else {
+            self.exp_series()
+        }
+    }
+
+    // e^self for |self| < 1 using series.
+    pub(super) fn exp_series(self) -> Result<Self, Error> {
+        let p = self.mantissa_max_bit_len();
+
         let sh = self.sinh_series(p, RoundingMode::None)?; // faster convergence than direct series
 
         // e = sh + sqrt(sh^2 + 1)
@@ -356,6 +371,33 @@ impl BigFloatNumber {
         sq3.add(&sh, p, RoundingMode::FromZero)
     }
 
+    // e^self for |self| < 1 with precision p using Newton's iteration y = y * (1 + self - ln(y)).
+    // Each step doubles the number of correct bits, so the initial value is computed recursively
+    // with half the precision, and the final step costs about one logarithm.
+    fn exp_newton(&self, p: usize, cc: &mut Consts) -> Result<Self, Error> {
+        // If y = e^self * (1 + d), then the step gives e^self * (1 - d^2) plus the rounding error.
+        let p_half = round_p(p / 2 + WORD_BIT_SIZE);
+
+        let y = if p_half >= Tuning::current().exp_newton && p_half < p {
+            self.exp_newton(p_half, cc)?
+        } else {
+            let mut x = self.clone()?;
+            x.set_precision(p_half, RoundingMode::None)?;
+            x.exp_series()?
+        };
+
+        let p_wrk = p + WORD_BIT_SIZE;
+
+        let ln_y = y.ln(p_wrk, RoundingMode::None, cc)?;
+        let d = self.sub(&ln_y, p_wrk, RoundingMode::None)?;
+        let yd = y.mul(&d, p_wrk, RoundingMode::None)?;
+        let mut ret = y.add(&yd, p_wrk, RoundingMode::None)?;
+
+        ret.set_precision(p, RoundingMode::None)?;
+
+        Ok(ret)
+    }
+
     /// sinh using series, for |x| < 1
     pub fn sinh_series(mut self, p: usize, rm: RoundingMode) -> Result<Self, Error> {
         // sinh:  x + x^3/3! + x^5/5! + x^7/7! + ...
@@ -767,6 +809,25 @@ mod test {
         assert!(d1.cmp(&d2) == 0);
     }
 
+    #[test]
+    fn test_exp_newton() {
+        let mut cc = Consts::new().unwrap();
+
+        for p in [320, 1024, 4992] {
+            for _ in 0..20 {
+                let x = BigFloatNumber::random_normal(p, -5, 0).unwrap();
+
+                let d1 = x.exp_newton(p, &mut cc).unwrap();
+                let d2 = x.clone().unwrap().exp_series().unwrap();
+
+                let d = d1.sub(&d2, p, RoundingMode::None).unwrap();
+                assert!(
+                    d.is_zero() || d2.exponent() as isize - d.exponent() as isize >= p as isize - 4
+                );
+            }
+        }
+    }
+
     #[ignore]
     #[test]
     #[cfg(feature = "std")]
@@ -787,6 +848,51 @@ mod test {
         }
     }
 
+    #[ignore]
+    #[test]
+    #[cfg(feature = "std")]
+    fn exp_newton_perf() {
+        // Crossover of the series and Newton's iteration: time of exp in microseconds with each of them.
+        let mut cc = Consts::new().unwrap();
+        let series = Tuning {
+            exp_newton: usize::MAX,
+            ..Tuning::DEFAULT
+        };
+
+        for p in [2048, 2560, 3072, 3584, 4096, 5120, 6144, 8192, 16384] {
+            // the initial value of the iteration is computed with the series
+            let newton = Tuning {
+                exp_newton: p,
+                ..Tuning::DEFAULT
+            };
+
+            let mut n = vec![];
+            for _ in 0..10 {
+                n.push(BigFloatNumber::random_normal(p, -20, 20).unwrap());
+            }
+
+            // cache constants
+            let _f = newton
+                .scope(|| n[0].exp(p, RoundingMode::ToEven, &mut cc))
+                .unwrap();
+
+            let mut t = [u128::MAX; 2];
+            for _ in 0..5 {
+                for (ti, tuning) in t.iter_mut().zip([series, newton]) {
+                    let start_time = std::time::Instant::now();
+                    for x in n.iter() {
+                        let _f = tuning
+                            .scope(|| x.exp(p, RoundingMode::ToEven, &mut cc))
+                            .unwrap();
+                    }
+                    *ti = (*ti).min(start_time.elapsed().as_micros() / n.len() as u128);
+                }
+            }
+
+            println!("{} {} {}", p, t[0], t[1]);
+        }
+    }
+
     /* test the polynimial generator error
     #[test]
     fn poly_sinh() {
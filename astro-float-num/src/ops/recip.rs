@@ -0,0 +1,572 @@
+//! Reciprocal trigonometric and hyperbolic functions, and their inverses.
+
+use crate::common::consts::ONE;
+use crate::common::util::round_p;
+use crate::defs::Error;
+use crate::defs::RoundingMode;
+use crate::num::BigFloatNumber;
+use crate::ops::consts::Consts;
+use crate::ops::util::compute_small_exp;
+use crate::Sign;
+use crate::WORD_BIT_SIZE;
+
+impl BigFloatNumber {
+    /// Computes the secant of a number with precision `p`. The result is rounded using the rounding mode `rm`.
+    /// This function requires constants cache `cc` for computing the result.
+    /// Precision is rounded upwards to the word size.
+    ///
+    /// ## Errors
+    ///
+    ///  - ExponentOverflow: the result is too large or too small number.
+    ///  - MemoryAllocation: failed to allocate memory.
+    ///  - InvalidArgument: the precision is incorrect.
+    pub fn sec(&self, p: usize, rm: RoundingMode, cc: &mut Consts) -> Result<Self, Error> {
+        let p = round_p(p);
+
+        if self.is_zero() {
+            let mut ret = Self::from_word(1, p)?;
+            ret.set_inexact(self.inexact());
+            return Ok(ret);
+        }
+
+        compute_small_exp!(ONE, self.exponent() as isize * 2 - 1, false, p, rm);
+
+        self.recip_fn_loop(p, rm, |x, p_x| {
+            x.cos(p_x, RoundingMode::None, cc)?
+                .reciprocal(p_x, RoundingMode::None)
+        })
+    }
+
+    /// Computes the cosecant of a number with precision `p`. The result is rounded using the rounding mode `rm`.
+    /// This function requires constants cache `cc` for computing the result.
+    /// Precision is rounded upwards to the word size.
+    ///
+    /// ## Errors
+    ///
+    ///  - ExponentOverflow: the result is too large or too small number, or `self` is zero.
+    ///  - MemoryAllocation: failed to allocate memory.
+    ///  - InvalidArgument: the precision is incorrect.
+    pub fn csc(&self, p: usize, rm: RoundingMode, cc: &mut Consts) -> Result<Self, Error> {
+        let p = round_p(p);
+
+        if self.is_zero() {
+            return Err(Error::ExponentOverflow(self.sign()));
+        }
+
+        self.recip_fn_loop(p, rm, |x, p_x| {
+            x.sin(p_x, RoundingMode::None, cc)?
+                .reciprocal(p_x, RoundingMode::None)
+        })
+    }
+
+    /// Computes the cotangent of a number with precision `p`. The result is rounded using the rounding mode `rm`.
+    /// This function requires constants cache `cc` for computing the result.
+    /// Precision is rounded upwards to the word size.
+    ///
+    /// ## Errors
+    ///
+    ///  - ExponentOverflow: the result is too large or too small number, or `self` is zero.
+    ///  - MemoryAllocation: failed to allocate memory.
+    ///  - InvalidArgument: the precision is incorrect.
+    pub fn cot(&self, p: usize, rm: RoundingMode, cc: &mut Consts) -> Result<Self, Error> {
+        let p = round_p(p);
+
+        if self.is_zero() {
+            return Err(Error::ExponentOverflow(self.sign()));
+        }
+
+        self.recip_fn_loop(p, rm, |x, p_x| {
+            x.tan(p_x, RoundingMode::None, cc)?
+                .reciprocal(p_x, RoundingMode::None)
+        })
+    }
+
+    /// Computes the hyperbolic secant of a number with precision `p`. The result is rounded using the rounding mode `rm`.
+    /// This function requires constants cache `cc` for computing the result.
+    /// Precision is rounded upwards to the word size.
+    ///
+    /// ## Errors
+    ///
+    ///  - MemoryAllocation: failed to allocate memory.
+    ///  - InvalidArgument: the precision is incorrect.
+    pub fn sech(&self, p: usize, rm: RoundingMode, cc: &mut Consts) -> Result<Self, Error> {
+        let p = round_p(p);
+
+        if self.is_zero() {
+            let mut ret = Self::from_word(1, p)?;
+            ret.set_inexact(self.inexact());
+            return Ok(ret);
+        }
+
+        compute_small_exp!(ONE, self.exponent() as isize * 2 - 1, true, p, rm);
+
+        let ret = self.recip_fn_loop(p, rm, |x, p_x| {
+            x.cosh(p_x, RoundingMode::None, cc)?
+                .reciprocal(p_x, RoundingMode::None)
+        });
+
+        match ret {
+            // underflow
+            Err(Error::ExponentOverflow(_)) => Self::new2(p, Sign::Pos, true),
+            ret => ret,
+        }
+    }
+
+    /// Computes the hyperbolic cosecant of a number with precision `p`. The result is rounded using the rounding mode `rm`.
+    /// This function requires constants cache `cc` for computing the result.
+    /// Precision is rounded upwards to the word size.
+    ///
+    /// ## Errors
+    ///
+    ///  - ExponentOverflow: the result is too large number, or `self` is zero.
+    ///  - MemoryAllocation: failed to allocate memory.
+    ///  - InvalidArgument: the precision is incorrect.
+    pub fn csch(&self, p: usize, rm: RoundingMode, cc: &mut Consts) -> Result<Self, Error> {
+        let p = round_p(p);
+
+        if self.is_zero() {
+            return Err(Error::ExponentOverflow(self.sign()));
+        }
+
+        let ret = self.recip_fn_loop(p, rm, |x, p_x| {
+            x.sinh(p_x, RoundingMode::None, cc)?
+                .reciprocal(p_x, RoundingMode::None)
+        });
+
+        match ret {
+            // underflow
+            Err(Error::ExponentOverflow(s)) if self.exponent() > 0 => Self::new2(p, s, true),
+            ret => ret,
+        }
+    }
+
+    /// Computes the hyperbolic cotangent of a number with precision `p`. The result is rounded using the rounding mode `rm`.
+    /// This function requires constants cache `cc` for computing the result.
+    /// Precision is rounded upwards to the word size.
+    ///
+    /// ## Errors
+    ///
+    ///  - ExponentOverflow: the result is too large number, or `self` is zero.
+    ///  - MemoryAllocation: failed to allocate memory.
+    ///  - InvalidArgument: the precision is incorrect.
+    pub fn coth(&self, p: usize, rm: RoundingMode, cc: &mut Consts) -> Result<Self, Error> {
+        let p = round_p(p);
+
+        if self.is_zero() {
+            return Err(Error::ExponentOverflow(self.sign()));
+        }
+
+        // coth(x) = 1 + 2*e^(-2*x) + ..., and e^(-2*x) < 2^(-p-2) when |x| > p
+        if self.exponent() as isize > (usize::BITS - p.leading_zeros()) as isize + 1 {
+            let mut ret = Self::from_i8(self.sign().to_int(), p)?;
+            ret = ret.add_correction(false)?;
+            ret.set_precision(p, rm)?;
+            return Ok(ret);
+        }
+
+        self.recip_fn_loop(p, rm, |x, p_x| {
+            x.tanh(p_x, RoundingMode::None, cc)?
+                .reciprocal(p_x, RoundingMode::None)
+        })
+    }
+
+    /// Computes the arcsecant of a number with precision `p`. The result is rounded using the rounding mode `rm`.
+    /// This function requires constants cache `cc` for computing the result.
+    /// Precision is rounded upwards to the word size.
+    ///
+    /// ## Errors
+    ///
+    ///  - MemoryAllocation: failed to allocate memory.
+    ///  - InvalidArgument: when |`self`| < 1, or the precision is incorrect.
+    pub fn asec(&self, p: usize, rm: RoundingMode, cc: &mut Consts) -> Result<Self, Error> {
+        let p = round_p(p);
+
+        if self.abs_cmp(&ONE) < 0 {
+            return Err(Error::InvalidArgument);
+        }
+
+        self.recip_fn_loop(p, rm, |x, p_x| {
+            if x.exponent() > 1 {
+                // acos(1/x)
+                x.reciprocal(p_x, RoundingMode::None)?
+                    .acos(p_x, RoundingMode::None, cc)
+            } else {
+                // acos(1/x) is ill-conditioned near 1, use atan(sqrt(x^2 - 1)) instead.
+                let t = Self::sqrt_sq_sub_one(x, p_x)?;
+                let a = t.atan(p_x, RoundingMode::None, cc)?;
+                if x.is_negative() {
+                    let pi = cc.pi_num(p_x, RoundingMode::None)?;
+                    pi.sub(&a, p_x, RoundingMode::None)
+                } else {
+                    Ok(a)
+                }
+            }
+        })
+    }
+
+    /// Computes the arccosecant of a number with precision `p`. The result is rounded using the rounding mode `rm`.
+    /// This function requires constants cache `cc` for computing the result.
+    /// Precision is rounded upwards to the word size.
+    ///
+    /// ## Errors
+    ///
+    ///  - MemoryAllocation: failed to allocate memory.
+    ///  - InvalidArgument: when |`self`| < 1, or the precision is incorrect.
+    pub fn acsc(&self, p: usize, rm: RoundingMode, cc: &mut Consts) -> Result<Self, Error> {
+        let p = round_p(p);
+
+        if self.abs_cmp(&ONE) < 0 {
+            return Err(Error::InvalidArgument);
+        }
+
+        self.recip_fn_loop(p, rm, |x, p_x| {
+            if x.exponent() > 1 {
+                // asin(1/x)
+                x.reciprocal(p_x, RoundingMode::None)?
+                    .asin(p_x, RoundingMode::None, cc)
+            } else {
+                // asin(1/x) is ill-conditioned near 1, use atan(1/sqrt(x^2 - 1)) instead.
+                let t = Self::sqrt_sq_sub_one(x, p_x)?;
+                let mut ret = if t.is_zero() {
+                    let mut half_pi = cc.pi_num(p_x, RoundingMode::None)?;
+                    half_pi.set_exponent(1);
+                    half_pi
+                } else {
+                    t.reciprocal(p_x, RoundingMode::None)?
+                        .atan(p_x, RoundingMode::None, cc)?
+                };
+                ret.set_sign(x.sign());
+                Ok(ret)
+            }
+        })
+    }
+
+    /// Computes the arccotangent of a number with precision `p`. The result is rounded using the rounding mode `rm`.
+    /// The result is in the range (-pi/2, pi/2], the arccotangent of zero is pi/2 with the sign of zero.
+    /// This function requires constants cache `cc` for computing the result.
+    /// Precision is rounded upwards to the word size.
+    ///
+    /// ## Errors
+    ///
+    ///  - MemoryAllocation: failed to allocate memory.
+    ///  - InvalidArgument: the precision is incorrect.
+    pub fn acot(&self, p: usize, rm: RoundingMode, cc: &mut Consts) -> Result<Self, Error> {
+        let p = round_p(p);
+
+        self.recip_fn_loop(p, rm, |x, p_x| {
+            match x.reciprocal(p_x, RoundingMode::None) {
+                Ok(r) => r.atan(p_x, RoundingMode::None, cc),
+                Err(Error::ExponentOverflow(_)) | Err(Error::DivisionByZero) => {
+                    let mut half_pi = cc.pi_num(p_x, RoundingMode::None)?;
+                    half_pi.set_exponent(1);
+                    half_pi.set_sign(x.sign());
+                    Ok(half_pi)
+                }
+                Err(e) => Err(e),
+            }
+        })
+    }
+
+    /// Computes the hyperbolic arcsecant of a number with precision `p`. The result is rounded using the rounding mode `rm`.
+    /// This function requires constants cache `cc` for computing the result.
+    /// Precision is rounded upwards to the word size.
+    ///
+    /// ## Errors
+    ///
+    ///  - ExponentOverflow: the result is too large number, or `self` is zero.
+    ///  - MemoryAllocation: failed to allocate memory.
+    ///  - InvalidArgument: when `self` < 0 or `self` > 1, or the precision is incorrect.
+    pub fn asech(&self, p: usize, rm: RoundingMode, cc: &mut Consts) -> Result<Self, Error> {
+        let p = round_p(p);
+
+        if self.is_zero() {
+            return Err(Error::ExponentOverflow(Sign::Pos));
+        }
+
+        if self.is_negative() || self.cmp(&ONE) > 0 {
+            return Err(Error::InvalidArgument);
+        }
+
+        self.recip_fn_loop(p, rm, |x, p_x| {
+            if x.exponent() < 0 {
+                // ln(1/x + sqrt(1/x^2 - 1))
+                let y = x.reciprocal(p_x, RoundingMode::None)?;
+                let t = Self::sqrt_sq_sub_one(&y, p_x)?;
+                y.add(&t, p_x, RoundingMode::None)?
+                    .ln(p_x, RoundingMode::None, cc)
+            } else {
+                // acosh(1/x) is ill-conditioned near 1, use atanh(sqrt(1 - x^2)) instead.
+                let mut t = Self::sqrt_sq_sub_one(x, p_x)?;
+                t.set_sign(Sign::Pos);
+                t.atanh(p_x, RoundingMode::None, cc)
+            }
+        })
+    }
+
+    /// Computes the hyperbolic arccosecant of a number with precision `p`. The result is rounded using the rounding mode `rm`.
+    /// This function requires constants cache `cc` for computing the result.
+    /// Precision is rounded upwards to the word size.
+    ///
+    /// ## Errors
+    ///
+    ///  - ExponentOverflow: `self` is zero.
+    ///  - MemoryAllocation: failed to allocate memory.
+    ///  - InvalidArgument: the precision is incorrect.
+    pub fn acsch(&self, p: usize, rm: RoundingMode, cc: &mut Consts) -> Result<Self, Error> {
+        let p = round_p(p);
+
+        if self.is_zero() {
+            return Err(Error::ExponentOverflow(self.sign()));
+        }
+
+        self.recip_fn_loop(p, rm, |x, p_x| {
+            x.reciprocal(p_x, RoundingMode::None)?
+                .asinh(p_x, RoundingMode::None, cc)
+        })
+    }
+
+    /// Computes the hyperbolic arccotangent of a number with precision `p`. The result is rounded using the rounding mode `rm`.
+    /// This function requires constants cache `cc` for computing the result.
+    /// Precision is rounded upwards to the word size.
+    ///
+    /// ## Errors
+    ///
+    ///  - ExponentOverflow: the result is too large number, or |`self`| = 1.
+    ///  - MemoryAllocation: failed to allocate memory.
+    ///  - InvalidArgument: when |`self`| < 1, or the precision is incorrect.
+    pub fn acoth(&self, p: usize, rm: RoundingMode, cc: &mut Consts) -> Result<Self, Error> {
+        let p = round_p(p);
+
+        match self.abs_cmp(&ONE) {
+            0 => return Err(Error::ExponentOverflow(self.sign())),
+            c if c < 0 => return Err(Error::InvalidArgument),
+            _ => {}
+        }
+
+        self.recip_fn_loop(p, rm, |x, p_x| {
+            if x.exponent() > 1 {
+                // atanh(1/x)
+                x.reciprocal(p_x, RoundingMode::None)?
+                    .atanh(p_x, RoundingMode::None, cc)
+            } else {
+                // atanh(1/x) is ill-conditioned near 1, use ln((x + 1) / (x - 1)) / 2 instead.
+                let d1 = x.add(&ONE, p_x, RoundingMode::None)?;
+                let d2 = x.sub(&ONE, p_x, RoundingMode::None)?;
+                let mut ret =
+                    d1.div(&d2, p_x, RoundingMode::None)?
+                        .ln(p_x, RoundingMode::None, cc)?;
+                ret.div_by_2(RoundingMode::None);
+                Ok(ret)
+            }
+        })
+    }
+
+    // Computes sqrt(x^2 - 1) for |x| >= 1, or -sqrt(1 - x^2) for |x| < 1, with the relative error
+    // not depending on the closeness of |x| to 1.
+    fn sqrt_sq_sub_one(x: &Self, p: usize) -> Result<Self, Error> {
+        let d1 = x.sub(&ONE, p, RoundingMode::None)?;
+        let d2 = x.add(&ONE, p, RoundingMode::None)?;
+        let mut t = d1.mul(&d2, p, RoundingMode::None)?;
+
+        let neg = t.is_negative();
+        t.set_sign(Sign::Pos);
+
+        let mut ret = t.sqrt(p, RoundingMode::None)?;
+        if neg {
+            ret.set_sign(Sign::Neg);
+        }
+
+        Ok(ret)
+    }
+
+    // Computes `f(x, p_x)` increasing the working precision until the result can be correctly rounded to precision `p`.
+    // The relative error of the value returned by `f` must be below 2^(-p_x + 4).
+    fn recip_fn_loop<F>(&self, p: usize, rm: RoundingMode, mut f: F) -> Result<Self, Error>
+    where
+        F: FnMut(&Self, usize) -> Result<Self, Error>,
+    {
+        let mut p_inc = WORD_BIT_SIZE;
+        let mut p_wrk = p.max(self.mantissa_max_bit_len()) + p_inc;
+
+        let mut x = self.clone()?;
+        x.set_inexact(false);
+
+        loop {
+            let p_x = p_wrk + 4;
+
+            let mut ret = f(&x, p_x)?;
+
+            if ret.try_set_precision(p, rm, p_wrk)? {
+                ret.set_inexact(ret.inexact() | self.inexact());
+                break Ok(ret);
+            }
+
+            p_wrk += p_inc;
+            p_inc = round_p(p_wrk / 5);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    // Checks that `a` and `b` differ by at most 1 ulp of precision `p`.
+    fn assert_close(a: &BigFloatNumber, b: &BigFloatNumber, p: usize) {
+        let d = a.sub(b, p + WORD_BIT_SIZE, RoundingMode::None).unwrap();
+        assert!(
+            d.is_zero() || (d.exponent() as isize) < a.exponent() as isize - p as isize + 1,
+            "{:?} {:?}",
+            a,
+            b
+        );
+    }
+
+    // Computes the reference value of function `f` using the definition with a much larger precision.
+    fn reference(x: &BigFloatNumber, f: &str, p: usize, cc: &mut Consts) -> BigFloatNumber {
+        let p = p + 512;
+        let rm = RoundingMode::None;
+        let r = |v: &BigFloatNumber| v.reciprocal(p, rm).unwrap();
+        match f {
+            "sec" => r(&x.cos(p, rm, cc).unwrap()),
+            "csc" => r(&x.sin(p, rm, cc).unwrap()),
+            "cot" => r(&x.tan(p, rm, cc).unwrap()),
+            "sech" => r(&x.cosh(p, rm, cc).unwrap()),
+            "csch" => r(&x.sinh(p, rm, cc).unwrap()),
+            "coth" => r(&x.tanh(p, rm, cc).unwrap()),
+            "asec" => r(x).acos(p, rm, cc).unwrap(),
+            "acsc" => r(x).asin(p, rm, cc).unwrap(),
+            "acot" => r(x).atan(p, rm, cc).unwrap(),
+            "asech" => r(x).acosh(p, rm, cc).unwrap(),
+            "acsch" => r(x).asinh(p, rm, cc).unwrap(),
+            "acoth" => r(x).atanh(p, rm, cc).unwrap(),
+            _ => unreachable!(),
+        }
+    }
+
+    fn compute(
+        x: &BigFloatNumber,
+        f: &str,
+        p: usize,
+        rm: RoundingMode,
+        cc: &mut Consts,
+    ) -> BigFloatNumber {
+        match f {
+            "sec" => x.sec(p, rm, cc),
+            "csc" => x.csc(p, rm, cc),
+            "cot" => x.cot(p, rm, cc),
+            "sech" => x.sech(p, rm, cc),
+            "csch" => x.csch(p, rm, cc),
+            "coth" => x.coth(p, rm, cc),
+            "asec" => x.asec(p, rm, cc),
+            "acsc" => x.acsc(p, rm, cc),
+            "acot" => x.acot(p, rm, cc),
+            "asech" => x.asech(p, rm, cc),
+            "acsch" => x.acsch(p, rm, cc),
+            "acoth" => x.acoth(p, rm, cc),
+            _ => unreachable!(),
+        }
+        .unwrap_or_else(|e| panic!("{} {:?} {:?}", f, x, e))
+    }
+
+    #[test]
+    fn test_recip_trig() {
+        let mut cc = Consts::new().unwrap();
+        let rm = RoundingMode::ToEven;
+        let p = 192;
+
+        // functions defined for any argument
+        for f in ["sec", "csc", "cot", "sech", "csch", "coth", "acot", "acsch"] {
+            for _ in 0..20 {
+                let mut x = BigFloatNumber::random_normal(p, -10, 10).unwrap();
+                if rand::random::<bool>() {
+                    x.inv_sign();
+                }
+                assert_close(
+                    &compute(&x, f, p, rm, &mut cc),
+                    &reference(&x, f, p, &mut cc),
+                    p,
+                );
+            }
+        }
+
+        // functions defined for |x| >= 1, including arguments close to 1
+        for f in ["asec", "acsc", "acoth"] {
+            for i in 0..20 {
+                let mut x = if i < 10 {
+                    BigFloatNumber::random_normal(p, 1, 10).unwrap()
+                } else {
+                    let mut d = ONE.clone().unwrap();
+                    d.set_exponent(-10 * i);
+                    ONE.add(&d, p, RoundingMode::None).unwrap()
+                };
+                if rand::random::<bool>() {
+                    x.inv_sign();
+                }
+                assert_close(
+                    &compute(&x, f, p, rm, &mut cc),
+                    &reference(&x, f, p, &mut cc),
+                    p,
+                );
+            }
+        }
+
+        // asech is defined for 0 < x <= 1
+        for i in 0..20 {
+            let x = if i < 10 {
+                BigFloatNumber::random_normal(p, -10, 0)
+                    .unwrap()
+                    .abs()
+                    .unwrap()
+            } else {
+                let mut d = ONE.clone().unwrap();
+                d.set_exponent(-10 * i);
+                ONE.sub(&d, p, RoundingMode::None).unwrap()
+            };
+            assert_close(
+                &compute(&x, "asech", p, rm, &mut cc),
+                &reference(&x, "asech", p, &mut cc),
+                p,
+            );
+        }
+
+        // special values
+        let zero = BigFloatNumber::new(p).unwrap();
+        let one = BigFloatNumber::from_word(1, p).unwrap();
+        let mone = one.neg().unwrap();
+        let half = BigFloatNumber::from_f64(p, 0.5).unwrap();
+        let pi = cc.pi_num(p, rm).unwrap();
+
+        assert!(zero.sec(p, rm, &mut cc).unwrap().cmp(&one) == 0);
+        assert!(zero.sech(p, rm, &mut cc).unwrap().cmp(&one) == 0);
+        assert!(zero.csc(p, rm, &mut cc).unwrap_err() == Error::ExponentOverflow(Sign::Pos));
+        assert!(zero.cot(p, rm, &mut cc).unwrap_err() == Error::ExponentOverflow(Sign::Pos));
+        assert!(zero.csch(p, rm, &mut cc).unwrap_err() == Error::ExponentOverflow(Sign::Pos));
+        assert!(zero.coth(p, rm, &mut cc).unwrap_err() == Error::ExponentOverflow(Sign::Pos));
+        assert!(zero.asech(p, rm, &mut cc).unwrap_err() == Error::ExponentOverflow(Sign::Pos));
+        assert!(mone.acoth(p, rm, &mut cc).unwrap_err() == Error::ExponentOverflow(Sign::Neg));
+        assert!(half.asec(p, rm, &mut cc).unwrap_err() == Error::InvalidArgument);
+        assert!(half.acsc(p, rm, &mut cc).unwrap_err() == Error::InvalidArgument);
+        assert!(half.acoth(p, rm, &mut cc).unwrap_err() == Error::InvalidArgument);
+        assert!(mone.asech(p, rm, &mut cc).unwrap_err() == Error::InvalidArgument);
+
+        let r = one.asec(p, rm, &mut cc).unwrap();
+        assert!(r.is_zero() && !r.inexact());
+        let r = one.asech(p, rm, &mut cc).unwrap();
+        assert!(r.is_zero() && !r.inexact());
+        assert!(mone.asec(p, rm, &mut cc).unwrap().cmp(&pi) == 0);
+        let mut half_pi = pi.clone().unwrap();
+        half_pi.set_exponent(1);
+        assert!(one.acsc(p, rm, &mut cc).unwrap().cmp(&half_pi) == 0);
+        assert!(zero.acot(p, rm, &mut cc).unwrap().cmp(&half_pi) == 0);
+
+        // large arguments
+        let mut x = BigFloatNumber::from_word(1, p).unwrap();
+        x.set_exponent(40);
+        assert!(x.sech(p, rm, &mut cc).unwrap().is_zero());
+        assert!(x.neg().unwrap().csch(p, rm, &mut cc).unwrap().is_zero());
+        assert!(x.coth(p, rm, &mut cc).unwrap().cmp(&one) == 0);
+        assert!(x.neg().unwrap().coth(p, rm, &mut cc).unwrap().cmp(&mone) == 0);
+    }
+}
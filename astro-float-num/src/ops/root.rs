@@ -0,0 +1,300 @@
+//! Root of an arbitrary degree.
+
+use crate::common::util::invert_rm_for_sign;
+use crate::common::util::round_p;
+use crate::defs::Error;
+use crate::defs::RoundingMode;
+use crate::num::BigFloatNumber;
+use crate::ops::consts::Consts;
+use crate::Exponent;
+use crate::Sign;
+use crate::WORD_BIT_SIZE;
+
+impl BigFloatNumber {
+    /// Computes the root of degree `n` of a number with precision `p`. The result is rounded using the rounding mode `rm`.
+    /// Roots of odd degree of negative numbers are negative.
+    /// If the root is exactly representable with precision `p`, the exact value is returned.
+    /// This function requires constants cache `cc` for computing the result.
+    /// Precision is rounded upwards to the word size.
+    ///
+    /// ## Errors
+    ///
+    ///  - MemoryAllocation: failed to allocate memory.
+    ///  - InvalidArgument: `n` is zero, `n` is even and the argument is negative, or the precision is incorrect.
+    pub fn root(
+        &self,
+        n: usize,
+        p: usize,
+        rm: RoundingMode,
+        cc: &mut Consts,
+    ) -> Result<Self, Error> {
+        let p = round_p(p);
+        Self::p_assertion(p)?;
+
+        match n {
+            0 => return Err(Error::InvalidArgument),
+            1 => {
+                let mut ret = self.clone()?;
+                ret.set_precision(p, rm)?;
+                return Ok(ret);
+            }
+            2 => return self.sqrt(p, rm),
+            3 => return self.cbrt(p, rm),
+            _ => {}
+        }
+
+        if self.is_zero() {
+            let s = if n & 1 == 1 { self.sign() } else { Sign::Pos };
+            return Self::new2(p, s, self.inexact());
+        }
+
+        if self.is_negative() && n & 1 == 0 {
+            return Err(Error::InvalidArgument);
+        }
+
+        // |self| = z * 2^(q*n), where 2^(r-1) <= z < 2^r, 0 <= r < n,
+        // then root(|self|, n) = root(z, n) * 2^q, and |ln(z) / n| < ln(2).
+        let (e1, m1_opt) = self.normalize()?;
+        let m1 = match m1_opt {
+            Some(m) => m,
+            None => self.mantissa().clone()?,
+        };
+
+        let q = e1.div_euclid(n as isize);
+        let r = e1.rem_euclid(n as isize);
+        let z = Self::from_raw_unchecked(m1, Sign::Pos, r as Exponent, false);
+
+        let d = Self::from_usize(n)?;
+
+        // the root of |self| is computed, and the sign is restored at the end.
+        let rm = if self.is_negative() { invert_rm_for_sign(rm) } else { rm };
+
+        let mut p_inc = WORD_BIT_SIZE;
+        let mut p_wrk = p + p_inc;
+
+        loop {
+            let p_x = p_wrk + 4;
+
+            let t = z.ln(p_x, RoundingMode::None, cc)?;
+            let t = t.div(&d, p_x, RoundingMode::None)?;
+            let t = t.exp(p_x, RoundingMode::None, cc)?;
+
+            // ret is modified even if rounding fails, so the exactness is checked using t
+            let mut ret = t.clone()?;
+
+            let done = if ret.try_set_precision(p, rm, p_wrk)? {
+                true
+            } else if let Some(y) = Self::root_exact(&t, &z, n, p, rm)? {
+                // the root is exactly representable with precision p + 1, and the approximation can't be rounded
+                ret = y;
+                true
+            } else {
+                false
+            };
+
+            if done {
+                ret.set_exponent(ret.exponent() + q as Exponent);
+                ret.set_sign(self.sign());
+                ret.set_inexact(ret.inexact() | self.inexact());
+                break Ok(ret);
+            }
+
+            p_wrk += p_inc;
+            p_inc = round_p(p_wrk / 5);
+        }
+    }
+
+    // Returns the root of degree `n` of `z` rounded to precision `p` using rounding mode `rm`
+    // if the root is exactly representable with precision `p` + 1, where `y` is an approximation of the root.
+    // Such roots are either representable with precision `p`, or lie exactly at the midpoint of two such numbers,
+    // and their approximation can't be rounded.
+    // The exact root can only have up to m/n significant bits, where m is the number of bits of `z`,
+    // so the check requires computations with precision of `z`.
+    fn root_exact(
+        y: &Self,
+        z: &Self,
+        n: usize,
+        p: usize,
+        rm: RoundingMode,
+    ) -> Result<Option<Self>, Error> {
+        let mut c = y.clone()?;
+        c.set_precision(p + WORD_BIT_SIZE, RoundingMode::ToEven)?;
+        c.set_inexact(false);
+
+        let v = c.powi(n, z.mantissa_max_bit_len(), RoundingMode::None)?;
+
+        if !v.inexact() && v.cmp(z) == 0 {
+            c.set_precision(p, rm)?;
+            Ok(Some(c))
+        } else {
+            Ok(None)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::common::util::random_subnormal;
+    use crate::EXPONENT_MAX;
+    use crate::EXPONENT_MIN;
+
+    #[test]
+    fn test_root() {
+        let mut cc = Consts::new().unwrap();
+        let rm = RoundingMode::ToEven;
+
+        // compare with the power
+        for _ in 0..100 {
+            let p = (rand::random::<usize>() % 5 + 1) * WORD_BIT_SIZE;
+            let n = rand::random::<usize>() % 30 + 1;
+            let mut x =
+                BigFloatNumber::random_normal(p, EXPONENT_MIN + 1000, EXPONENT_MAX).unwrap();
+            if n & 1 == 0 {
+                x.set_sign(Sign::Pos);
+            }
+
+            let y = x.root(n, p + WORD_BIT_SIZE, rm, &mut cc).unwrap();
+            let z = y.powi(n, p + WORD_BIT_SIZE, rm).unwrap();
+
+            let mut eps = BigFloatNumber::from_word(1, p).unwrap();
+            eps.set_exponent(x.exponent() - p as Exponent + 1);
+            let d = x.sub(&z, p + WORD_BIT_SIZE, rm).unwrap();
+            assert!(d.abs().unwrap().cmp(&eps) < 0);
+            assert!(y.sign() == x.sign());
+        }
+
+        // correct rounding
+        for _ in 0..100 {
+            let p = 128;
+            let n = rand::random::<usize>() % 1000 + 4;
+            let mut x = BigFloatNumber::random_normal(p, -100, 100).unwrap();
+            if n & 1 == 0 {
+                x.set_sign(Sign::Pos);
+            }
+
+            let y2 = x.root(n, p + 256, RoundingMode::None, &mut cc).unwrap();
+
+            for rm in [
+                RoundingMode::Up,
+                RoundingMode::Down,
+                RoundingMode::ToZero,
+                RoundingMode::FromZero,
+            ] {
+                let y1 = x.root(n, p, rm, &mut cc).unwrap();
+                let mut y3 = y2.clone().unwrap();
+                y3.set_precision(p, rm).unwrap();
+                assert!(y1.cmp(&y3) == 0);
+            }
+        }
+
+        // negative arguments with directed rounding
+        let x = BigFloatNumber::from_f64(128, -39.0398988346711348640383221209049224854).unwrap();
+        for n in [3, 5, 7] {
+            let y2 = x.root(n, 384, RoundingMode::None, &mut cc).unwrap();
+            for rm in [RoundingMode::Up, RoundingMode::Down] {
+                let y1 = x.root(n, 128, rm, &mut cc).unwrap();
+                let mut y3 = y2.clone().unwrap();
+                y3.set_precision(128, rm).unwrap();
+                assert!(y1.cmp(&y3) == 0);
+            }
+        }
+
+        // exact roots
+        let x = BigFloatNumber::from_u64(3u64.pow(40), 64).unwrap();
+        let y = x.root(5, 64, rm, &mut cc).unwrap();
+        assert!(y.cmp(&BigFloatNumber::from_word(6561, 64).unwrap()) == 0);
+        assert!(!y.inexact());
+
+        let mut x = BigFloatNumber::from_i8(-1, 128).unwrap();
+        x.set_exponent(-699);
+        let y = x.root(7, 64, rm, &mut cc).unwrap();
+        let mut z = BigFloatNumber::from_i8(-1, 64).unwrap();
+        z.set_exponent(-99);
+        assert!(y.cmp(&z) == 0);
+        assert!(!y.inexact());
+
+        // the root is exactly the midpoint: root(y^4, 4) = y = 1 + 2^-64
+        let mut y = BigFloatNumber::from_word(1, 128).unwrap();
+        y.set_exponent(-63);
+        let y = y
+            .add(&BigFloatNumber::from_word(1, 128).unwrap(), 128, rm)
+            .unwrap();
+        let x = y.powi(4, 512, RoundingMode::None).unwrap();
+        assert!(!x.inexact());
+
+        let r = x.root(4, 64, RoundingMode::ToEven, &mut cc).unwrap();
+        assert!(r.cmp(&BigFloatNumber::from_word(1, 64).unwrap()) == 0);
+        assert!(r.inexact());
+
+        let r = x.root(4, 64, RoundingMode::ToOdd, &mut cc).unwrap();
+        let mut ulp = BigFloatNumber::from_word(1, 64).unwrap();
+        ulp.set_exponent(-62);
+        let one_ulp = BigFloatNumber::from_word(1, 64)
+            .unwrap()
+            .add(&ulp, 64, RoundingMode::None)
+            .unwrap();
+        assert!(r.cmp(&one_ulp) == 0);
+
+        let r = x.root(4, 128, RoundingMode::ToEven, &mut cc).unwrap();
+        assert!(r.cmp(&y) == 0);
+        assert!(!r.inexact());
+
+        // special cases
+        let zero = BigFloatNumber::new(64).unwrap();
+        assert!(zero.root(6, 64, rm, &mut cc).unwrap().is_zero());
+        assert!(zero
+            .neg()
+            .unwrap()
+            .root(5, 64, rm, &mut cc)
+            .unwrap()
+            .is_negative());
+        assert!(zero.root(0, 64, rm, &mut cc).unwrap_err() == Error::InvalidArgument);
+        assert!(
+            BigFloatNumber::from_i8(-2, 64)
+                .unwrap()
+                .root(4, 64, rm, &mut cc)
+                .unwrap_err()
+                == Error::InvalidArgument
+        );
+
+        // subnormal argument: root(x, 4) = root(x * 2^400, 4) * 2^-100
+        let x = random_subnormal(128).abs().unwrap();
+        let y1 = x.root(4, 128, rm, &mut cc).unwrap();
+        let mut s = BigFloatNumber::from_word(1, 128).unwrap();
+        s.set_exponent(401);
+        let x2 = x
+            .mul(&s, x.mantissa_max_bit_len(), RoundingMode::None)
+            .unwrap();
+        let mut y2 = x2.root(4, 128, rm, &mut cc).unwrap();
+        y2.set_exponent(y2.exponent() - 100);
+        assert!(y1.cmp(&y2) == 0);
+    }
+
+    #[ignore]
+    #[test]
+    #[cfg(feature = "std")]
+    fn root_perf() {
+        let p = 320;
+        let mut cc = Consts::new().unwrap();
+        let mut n = vec![];
+        for _ in 0..10000 {
+            n.push(
+                BigFloatNumber::random_normal(p, -20, 20)
+                    .unwrap()
+                    .abs()
+                    .unwrap(),
+            );
+        }
+
+        for _ in 0..5 {
+            let start_time = std::time::Instant::now();
+            for ni in n.iter() {
+                let _f = ni.root(7, p, RoundingMode::ToEven, &mut cc).unwrap();
+            }
+            let time = start_time.elapsed();
+            println!("{}", time.as_millis());
+        }
+    }
+}
@@ -8,12 +8,12 @@ use crate::common::util::sqrt_int;
 use crate::defs::Error;
 use crate::defs::RoundingMode;
 use crate::num::BigFloatNumber;
+use crate::tuning::Tuning;
 
 #[cfg(not(feature = "std"))]
 use alloc::vec::Vec;
 
 const MAX_CACHE: usize = 128;
-const RECT_ITER_THRESHOLD: usize = MAX_CACHE / 10 * 9;
 
 //
 // Public part
@@ -92,7 +92,7 @@ pub(crate) fn series_run<T: PolycoeffGen>(
 ) -> Result<BigFloatNumber, Error> {
     if x_first.is_zero() || x_step.is_zero() {
         series_compute_fast(acc, x_first, polycoeff_gen)
-    } else if niter >= RECT_ITER_THRESHOLD {
+    } else if niter >= Tuning::current().series_rect {
         series_rectangular(niter, acc, x_first, x_step, polycoeff_gen)
     } else if polycoeff_gen.is_div() {
         series_linear(acc, x_first, x_step, polycoeff_gen)
@@ -123,7 +123,7 @@ fn series_cost<T: PolycoeffGen>(niter: usize, p: usize, polycoeff_gen: &T) -> u6
     let cost_add = calc_add_cost(p);
     let cost = niter as u64 * (cost_mul + cost_add + polycoeff_gen.iter_cost()) as u64;
 
-    if niter >= RECT_ITER_THRESHOLD {
+    if niter >= Tuning::current().series_rect {
         // niter * (cost(mul) + cost(add) + cost(polcoeff_gen.next)) + sqrt(niter) * cost(mul)
         // + niter / 10 * (2 * cost(mul) + cost(add) + cost(polcoeff_gen.next))
         cost + sqrt_int(niter as u32) as u64 * cost_mul as u64
@@ -9,6 +9,7 @@ use crate::common::util::round_p;
 use crate::defs::Error;
 use crate::defs::RoundingMode;
 use crate::num::BigFloatNumber;
+use crate::ops::bsplit::BsplitSeries;
 use crate::ops::consts::Consts;
 use crate::ops::series::series_cost_optimize;
 use crate::ops::series::series_run;
@@ -125,10 +126,14 @@ impl BigFloatNumber {
             let p_x = p_wrk + 3;
             x.set_precision(p_x, RoundingMode::None)?;
 
-            x = x.reduce_trig_arg(cc, RoundingMode::None)?;
+            let (x, odd) = x.reduce_trig_arg(cc, RoundingMode::None)?;
 
             let mut ret = x.sin_series(RoundingMode::None)?;
 
+            if odd {
+                ret.inv_sign();
+            }
+
             if ret.try_set_precision(p, rm, p_wrk)? {
                 ret.set_inexact(ret.inexact() | self.inexact());
                 break Ok(ret);
@@ -139,10 +144,96 @@ impl BigFloatNumber {
         }
     }
 
+    /// Computes the sine and the cosine of a number with precision `p`. The results are rounded using the rounding mode `rm`.
+    /// The argument reduction and the series evaluation are shared by both results.
+    /// This function requires constants cache `cc` for computing the result.
+    /// Precision is rounded upwards to the word size.
+    ///
+    /// ## Errors
+    ///
+    ///  - MemoryAllocation: failed to allocate memory.
+    ///  - InvalidArgument: the precision is incorrect.
+    pub fn sin_cos(
+        &self,
+        p: usize,
+        rm: RoundingMode,
+        cc: &mut Consts,
+    ) -> Result<(Self, Self), Error> {
+        let p = round_p(p);
+
+        if self.is_zero() || -(self.exponent() as isize) * 2 > p as isize {
+            // both results are computed directly from the argument
+            return Ok((self.sin(p, rm, cc)?, self.cos(p, rm, cc)?));
+        }
+
+        let mut p_inc = WORD_BIT_SIZE;
+        let mut p_wrk = p.max(self.mantissa_max_bit_len()) + p_inc;
+
+        let mut add_p = 0;
+        loop {
+            let mut x = self.clone()?;
+            x.set_inexact(false);
+
+            let p_x = p_wrk + add_p + 4;
+            x.set_precision(p_x, RoundingMode::None)?;
+
+            let (mut x, mut odd) = x.reduce_trig_arg(cc, RoundingMode::None)?;
+
+            // x/2 must be in [-pi/4; pi/4]
+            if x.exponent() == 2 {
+                let mut pi = cc.pi_num(p_x + WORD_BIT_SIZE, RoundingMode::None)?;
+                pi.set_sign(x.sign());
+                x = x.sub(&pi, p_x, RoundingMode::None)?;
+                odd = !odd;
+            }
+
+            // sin(x) = 2 * sin(x/2) * sqrt(1 - sin(x/2)^2), cos(x) = 1 - 2 * sin(x/2)^2
+            x.set_exponent(x.exponent() - 1);
+            let sh = x.sin_series(RoundingMode::None)?;
+
+            let mut sh2 = sh.mul(&sh, p_x, RoundingMode::None)?;
+            sh2.set_exponent(sh2.exponent() + 1);
+            let mut c = ONE.sub(&sh2, p_x, RoundingMode::None)?;
+
+            sh2.set_exponent(sh2.exponent() - 1);
+            let ch = ONE
+                .sub(&sh2, p_x, RoundingMode::None)?
+                .sqrt(p_x, RoundingMode::None)?;
+            let mut s = sh.mul(&ch, p_x, RoundingMode::None)?;
+            s.set_exponent(s.exponent() + 1);
+
+            // avoid cancellation when x is near pi / 2 or pi
+            let t = (c.exponent().unsigned_abs() as usize + 1)
+                .max(s.exponent().unsigned_abs() as usize * 2 + 1);
+            if add_p < t {
+                add_p = t;
+                continue;
+            }
+
+            if odd {
+                s.inv_sign();
+                c.inv_sign();
+            }
+
+            if s.try_set_precision(p, rm, p_wrk)? && c.try_set_precision(p, rm, p_wrk)? {
+                s.set_inexact(s.inexact() | self.inexact());
+                c.set_inexact(c.inexact() | self.inexact());
+                break Ok((s, c));
+            }
+
+            p_wrk += p_inc;
+            p_inc = round_p(p_wrk / 5);
+        }
+    }
+
     /// sine using series
     pub fn sin_series(mut self, rm: RoundingMode) -> Result<Self, Error> {
         // sin:  x - x^3/3! + x^5/5! - x^7/7! + ...
 
+        if self.bsplit_applicable(BsplitSeries::Sin) {
+            return self.bsplit_series(BsplitSeries::Sin, rm);
+        }
+
         let p = self.mantissa_max_bit_len();
 
         let mut polycoeff_gen = SinPolycoeffGen::new(p)?;
@@ -281,6 +372,52 @@ mod tests {
         assert!(n1.sin(p, rm, &mut cc).unwrap().cmp(&n1) == 0);
     }
 
+    #[test]
+    fn test_sin_cos() {
+        let mut cc = Consts::new().unwrap();
+        let rm = RoundingMode::ToEven;
+
+        for p in [64, 320, 1024] {
+            for e in [-40, -3, 0, 1, 2, 3, 10, 200] {
+                for _ in 0..20 {
+                    let mut x = BigFloatNumber::random_normal(p, e, e).unwrap();
+                    if rand::random::<bool>() {
+                        x.inv_sign();
+                    }
+
+                    let (s, c) = x.sin_cos(p, rm, &mut cc).unwrap();
+
+                    assert!(s.cmp(&x.sin(p, rm, &mut cc).unwrap()) == 0);
+                    assert!(c.cmp(&x.cos(p, rm, &mut cc).unwrap()) == 0);
+                }
+            }
+        }
+
+        // near pi and pi/2
+        let p = 320;
+        let mut pi = cc.pi_num(p, RoundingMode::ToEven).unwrap();
+        for _ in 0..2 {
+            let (s, c) = pi.sin_cos(p, rm, &mut cc).unwrap();
+            assert!(s.cmp(&pi.sin(p, rm, &mut cc).unwrap()) == 0);
+            assert!(c.cmp(&pi.cos(p, rm, &mut cc).unwrap()) == 0);
+            pi.set_exponent(pi.exponent() - 1);
+        }
+
+        // tiny and zero
+        let mut x = BigFloatNumber::from_word(1, p).unwrap();
+        x.set_exponent(-(p as crate::Exponent));
+        let (s, c) = x.sin_cos(p, rm, &mut cc).unwrap();
+        assert!(s.cmp(&x.sin(p, rm, &mut cc).unwrap()) == 0);
+        assert!(c.cmp(&x.cos(p, rm, &mut cc).unwrap()) == 0);
+
+        let (s, c) = BigFloatNumber::new(p)
+            .unwrap()
+            .sin_cos(p, rm, &mut cc)
+            .unwrap();
+        assert!(s.is_zero());
+        assert!(c.cmp(&ONE) == 0);
+    }
+
     #[ignore]
     #[test]
     #[cfg(feature = "std")]
@@ -74,6 +74,81 @@ impl BigFloatNumber {
             p_inc = round_p(p_wrk / 5);
         }
     }
+
+    /// Computes the hyperbolic sine and the hyperbolic cosine of a number with precision `p`.
+    /// The results are rounded using the rounding mode `rm`. The exponent is computed only once for both results.
+    /// This function requires constants cache cc for computing the result.
+    /// Precision is rounded upwards to the word size.
+    ///
+    /// ## Errors
+    ///
+    ///  - ExponentOverflow: the results are too large; the sign of the error is the sign of the hyperbolic sine.
+    ///  - MemoryAllocation: failed to allocate memory.
+    ///  - InvalidArgument: the precision is incorrect.
+    pub fn sinh_cosh(
+        &self,
+        p: usize,
+        rm: RoundingMode,
+        cc: &mut Consts,
+    ) -> Result<(Self, Self), Error> {
+        let p = round_p(p);
+
+        if self.is_zero() || -(self.exponent() as isize) * 2 > p as isize {
+            // both results are computed directly from the argument
+            return Ok((self.sinh(p, rm, cc)?, self.cosh(p, rm, cc)?));
+        }
+
+        let mut p_inc = WORD_BIT_SIZE;
+        let mut p_wrk = p.max(self.mantissa_max_bit_len()) + p_inc;
+
+        // e^x - e^(-x) loses about -exponent(x) bits for small x
+        let add_p = if self.exponent() < 0 { self.exponent().unsigned_abs() as usize } else { 0 };
+
+        let mut x = self.clone()?;
+        x.set_inexact(false);
+        x.set_sign(Sign::Pos);
+
+        loop {
+            let p_x = p_wrk + add_p + 4;
+            x.set_precision(p_x, RoundingMode::None)?;
+
+            let ex = x.exp(p_x, RoundingMode::None, cc).map_err(|e| -> Error {
+                if let Error::ExponentOverflow(_) = e {
+                    Error::ExponentOverflow(self.sign())
+                } else {
+                    e
+                }
+            })?;
+
+            let (mut s, mut c) =
+                if (x.exponent() as isize - 1) * 2 > x.mantissa_max_bit_len() as isize + 2 {
+                    // e^x / 2
+                    (ex.clone()?, ex)
+                } else {
+                    // (e^x - e^(-x)) / 2, (e^x + e^(-x)) / 2
+                    let xe = ex.reciprocal(p_x, RoundingMode::None)?;
+
+                    (
+                        ex.sub(&xe, p_x, RoundingMode::None)?,
+                        ex.add(&xe, p_x, RoundingMode::None)?,
+                    )
+                };
+
+            s.div_by_2(RoundingMode::None);
+            c.div_by_2(RoundingMode::None);
+
+            s.set_sign(self.sign());
+
+            if s.try_set_precision(p, rm, p_wrk)? && c.try_set_precision(p, rm, p_wrk)? {
+                s.set_inexact(s.inexact() | self.inexact());
+                c.set_inexact(c.inexact() | self.inexact());
+                break Ok((s, c));
+            }
+
+            p_wrk += p_inc;
+            p_inc = round_p(p_wrk / 5);
+        }
+    }
 }
 
 #[cfg(test)]
@@ -107,4 +182,34 @@ mod tests {
         assert!(zero.sinh(p, rm, &mut cc).unwrap().is_zero());
         assert!(n1.sinh(p, rm, &mut cc).unwrap().cmp(&n1) == 0);
     }
+
+    #[test]
+    fn test_sinh_cosh() {
+        let mut cc = Consts::new().unwrap();
+        let rm = RoundingMode::ToEven;
+
+        for p in [64, 320, 1024] {
+            for e in [-40, -3, 0, 1, 5, 10] {
+                for _ in 0..20 {
+                    let mut x = BigFloatNumber::random_normal(p, e, e).unwrap();
+                    if rand::random::<bool>() {
+                        x.inv_sign();
+                    }
+
+                    let (s, c) = x.sinh_cosh(p, rm, &mut cc).unwrap();
+
+                    assert!(s.cmp(&x.sinh(p, rm, &mut cc).unwrap()) == 0);
+                    assert!(c.cmp(&x.cosh(p, rm, &mut cc).unwrap()) == 0);
+                }
+            }
+        }
+
+        let mut x = BigFloatNumber::from_word(1, 64).unwrap();
+        x.set_exponent(40);
+        x.inv_sign();
+        assert!(matches!(
+            x.sinh_cosh(64, rm, &mut cc),
+            Err(Error::ExponentOverflow(Sign::Neg))
+        ));
+    }
 }
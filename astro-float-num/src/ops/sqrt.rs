@@ -1,7 +1,9 @@
 //! Sqrt computation.
 
+use crate::common::consts::ONE;
 use crate::common::util::round_p;
 use crate::Sign;
+use crate::WORD_BIT_SIZE;
 use crate::{
     defs::{Error, EXPONENT_MIN},
     num::BigFloatNumber,
@@ -52,6 +54,60 @@ impl BigFloatNumber {
             ))
         }
     }
+
+    /// Computes the reciprocal of the square root of a number with precision `p`. The result is rounded using the rounding mode `rm`.
+    /// Precision is rounded upwards to the word size.
+    ///
+    /// ## Errors
+    ///
+    ///  - ExponentOverflow: the argument is zero.
+    ///  - InvalidArgument: argument is negative, or the precision is incorrect.
+    ///  - MemoryAllocation: failed to allocate memory.
+    pub fn rec_sqrt(&self, p: usize, rm: RoundingMode) -> Result<Self, Error> {
+        let p = round_p(p);
+        Self::p_assertion(p)?;
+
+        if self.is_zero() {
+            return Err(Error::ExponentOverflow(self.sign()));
+        }
+
+        if self.is_negative() {
+            return Err(Error::InvalidArgument);
+        }
+
+        // the result is exact only for even powers of 2
+        let (e1, m1_opt) = self.normalize()?;
+        let m1 = match m1_opt {
+            Some(m) => m,
+            None => self.mantissa().clone()?,
+        };
+
+        if e1 & 1 == 1 && Self::from_raw_unchecked(m1, Sign::Pos, 1, false).cmp(&ONE) == 0 {
+            // self = 2^(e1 - 1)
+            let mut ret = Self::from_word(1, p)?;
+            ret.set_exponent(((1 - e1) / 2 + 1) as Exponent);
+            ret.set_inexact(self.inexact());
+            return Ok(ret);
+        }
+
+        let mut p_inc = WORD_BIT_SIZE;
+        let mut p_wrk = p + p_inc;
+
+        loop {
+            let p_x = p_wrk + 2;
+
+            let s = self.sqrt(p_x, RoundingMode::None)?;
+            let mut ret = s.reciprocal(p_x, RoundingMode::None)?;
+
+            if ret.try_set_precision(p, rm, p_wrk)? {
+                ret.set_inexact(ret.inexact() | self.inexact());
+                break Ok(ret);
+            }
+
+            p_wrk += p_inc;
+            p_inc = round_p(p_wrk / 5);
+        }
+    }
 }
 
 #[cfg(test)]
@@ -60,7 +116,7 @@ mod tests {
     use super::*;
     use crate::common::consts::ONE;
     use crate::{common::util::random_subnormal, defs::WORD_BIT_SIZE, Exponent};
-    use crate::{Consts, Sign, EXPONENT_MAX};
+    use crate::{Consts, Sign, EXPONENT_MAX, EXPONENT_MIN};
 
     #[test]
     fn test_sqrt() {
@@ -203,6 +259,60 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_rec_sqrt() {
+        for _ in 0..1000 {
+            let p = (rand::random::<usize>() % 5 + 1) * WORD_BIT_SIZE;
+            let mut d1 =
+                BigFloatNumber::random_normal(p, EXPONENT_MIN + 1000, EXPONENT_MAX - 1000).unwrap();
+            d1.set_sign(Sign::Pos);
+
+            // correct rounding
+            let d2 = d1.rec_sqrt(p, RoundingMode::ToEven).unwrap();
+            let mut d3 = d1
+                .sqrt(p + 256, RoundingMode::None)
+                .unwrap()
+                .reciprocal(p + 256, RoundingMode::None)
+                .unwrap();
+            d3.set_precision(p, RoundingMode::ToEven).unwrap();
+            assert!(d2.cmp(&d3) == 0);
+            assert!(d2.inexact());
+        }
+
+        // exact results
+        for e in [-1000, -2, 0, 2, 1000] {
+            let mut d1 = BigFloatNumber::from_word(1, 128).unwrap();
+            d1.set_exponent(e + 1);
+            let d2 = d1.rec_sqrt(128, RoundingMode::ToEven).unwrap();
+            assert!(!d2.inexact());
+            assert!(d2.exponent() == -e / 2 + 1);
+            assert!(
+                d2.mul(&d2, 128, RoundingMode::None)
+                    .unwrap()
+                    .mul(&d1, 128, RoundingMode::None)
+                    .unwrap()
+                    .cmp(&ONE)
+                    == 0
+            );
+        }
+
+        let d1 = random_subnormal(128).abs().unwrap();
+        assert!(d1.rec_sqrt(128, RoundingMode::ToEven).unwrap().exponent() > 0);
+
+        let zero = BigFloatNumber::new(128).unwrap();
+        assert!(
+            zero.rec_sqrt(128, RoundingMode::ToEven).unwrap_err()
+                == Error::ExponentOverflow(Sign::Pos)
+        );
+        assert!(
+            ONE.neg()
+                .unwrap()
+                .rec_sqrt(128, RoundingMode::ToEven)
+                .unwrap_err()
+                == Error::InvalidArgument
+        );
+    }
+
     #[ignore]
     #[test]
     #[cfg(feature = "std")]
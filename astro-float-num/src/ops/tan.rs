@@ -88,7 +88,7 @@ impl BigFloatNumber {
             let p_x = p_wrk + 3;
             x.set_precision(p_x, RoundingMode::None)?;
 
-            x = x.reduce_trig_arg(cc, RoundingMode::None)?;
+            (x, _) = x.reduce_trig_arg(cc, RoundingMode::None)?;
 
             let mut ret = x.tan_series(RoundingMode::None)?;
 
@@ -102,7 +102,7 @@ impl BigFloatNumber {
         }
     }
 
-    fn tan_series(mut self, rm: RoundingMode) -> Result<Self, Error> {
+    pub(super) fn tan_series(mut self, rm: RoundingMode) -> Result<Self, Error> {
         let p = self.mantissa_max_bit_len();
 
         let polycoeff_gen = TanPolycoeffGen::new(p)?;
@@ -0,0 +1,938 @@
+//! Trigonometric functions of arguments measured in half-turns and in degrees.
+
+use crate::common::consts::ONE;
+use crate::common::util::round_p;
+use crate::defs::Error;
+use crate::defs::RoundingMode;
+use crate::defs::WORD_BIT_SIZE;
+use crate::num::BigFloatNumber;
+use crate::ops::consts::Consts;
+use crate::ops::util::compute_small_exp;
+use crate::Sign;
+
+// Unit of angle.
+#[derive(Clone, Copy, PartialEq)]
+enum AngleUnit {
+    // pi radians
+    HalfTurn,
+    // pi/180 radians
+    Degree,
+}
+
+impl AngleUnit {
+    // Returns the angle of `deg` degrees measured in the unit.
+    // The result is exact for all the angles used below.
+    fn angle(self, deg: u16) -> Result<BigFloatNumber, Error> {
+        let d = BigFloatNumber::from_u16(deg, WORD_BIT_SIZE)?;
+        match self {
+            AngleUnit::HalfTurn => {
+                let h = BigFloatNumber::from_u8(180, WORD_BIT_SIZE)?;
+                d.div(&h, WORD_BIT_SIZE, RoundingMode::None)
+            }
+            AngleUnit::Degree => Ok(d),
+        }
+    }
+
+    // Converts the angle `a` measured in the unit to radians with precision `p`.
+    fn unit_to_rad(
+        self,
+        a: &BigFloatNumber,
+        p: usize,
+        cc: &mut Consts,
+    ) -> Result<BigFloatNumber, Error> {
+        let pi = cc.pi_num(p, RoundingMode::None)?;
+        let y = a.mul(&pi, p, RoundingMode::None)?;
+        match self {
+            AngleUnit::HalfTurn => Ok(y),
+            AngleUnit::Degree => {
+                let h = BigFloatNumber::from_u8(180, WORD_BIT_SIZE)?;
+                y.div(&h, p, RoundingMode::None)
+            }
+        }
+    }
+
+    // Converts the angle `a` measured in radians to the unit with precision `p`.
+    fn rad_to_unit(
+        self,
+        a: &BigFloatNumber,
+        p: usize,
+        cc: &mut Consts,
+    ) -> Result<BigFloatNumber, Error> {
+        let pi = cc.pi_num(p, RoundingMode::None)?;
+        match self {
+            AngleUnit::HalfTurn => a.div(&pi, p, RoundingMode::None),
+            AngleUnit::Degree => {
+                let h = BigFloatNumber::from_u8(180, WORD_BIT_SIZE)?;
+                let y = a.mul(&h, p, RoundingMode::None)?;
+                y.div(&pi, p, RoundingMode::None)
+            }
+        }
+    }
+}
+
+macro_rules! gen_trig_unit {
+    ($comment:literal, $fname:ident, $impl_fname:ident, $unit:expr) => {
+        #[doc=$comment]
+        pub fn $fname(&self, p: usize, rm: RoundingMode, cc: &mut Consts) -> Result<Self, Error> {
+            self.$impl_fname($unit, p, rm, cc)
+        }
+    };
+}
+
+impl BigFloatNumber {
+    gen_trig_unit!(
+        "Computes sin(pi * `self`) with precision `p`. The result is rounded using the rounding mode `rm`.
+The argument is reduced exactly, so the result is exact for integer and half-integer arguments,
+and arguments with large exponent do not require a high-precision value of pi.
+This function requires constants cache `cc` for computing the result.
+Precision is rounded upwards to the word size.
+
+## Errors
+
+ - MemoryAllocation: failed to allocate memory.
+ - InvalidArgument: the precision is incorrect.",
+        sin_pi,
+        sin_unit,
+        AngleUnit::HalfTurn
+    );
+
+    gen_trig_unit!(
+        "Computes cos(pi * `self`) with precision `p`. The result is rounded using the rounding mode `rm`.
+The argument is reduced exactly, so the result is exact for integer and half-integer arguments,
+and arguments with large exponent do not require a high-precision value of pi.
+This function requires constants cache `cc` for computing the result.
+Precision is rounded upwards to the word size.
+
+## Errors
+
+ - MemoryAllocation: failed to allocate memory.
+ - InvalidArgument: the precision is incorrect.",
+        cos_pi,
+        cos_unit,
+        AngleUnit::HalfTurn
+    );
+
+    gen_trig_unit!(
+        "Computes tan(pi * `self`) with precision `p`. The result is rounded using the rounding mode `rm`.
+The argument is reduced exactly, so the result is exact for integer arguments and arguments of the form n + 1/4.
+This function requires constants cache `cc` for computing the result.
+Precision is rounded upwards to the word size.
+
+## Errors
+
+ - ExponentOverflow: `self` is a half-integer, or the result is too large or too small number.
+ - MemoryAllocation: failed to allocate memory.
+ - InvalidArgument: the precision is incorrect.",
+        tan_pi,
+        tan_unit,
+        AngleUnit::HalfTurn
+    );
+
+    gen_trig_unit!(
+        "Computes asin(`self`) / pi with precision `p`. The result is rounded using the rounding mode `rm`.
+This function requires constants cache `cc` for computing the result.
+Precision is rounded upwards to the word size.
+
+## Errors
+
+ - MemoryAllocation: failed to allocate memory.
+ - InvalidArgument: the precision is incorrect, or |`self`| > 1.",
+        asin_pi,
+        asin_unit,
+        AngleUnit::HalfTurn
+    );
+
+    gen_trig_unit!(
+        "Computes acos(`self`) / pi with precision `p`. The result is rounded using the rounding mode `rm`.
+This function requires constants cache `cc` for computing the result.
+Precision is rounded upwards to the word size.
+
+## Errors
+
+ - MemoryAllocation: failed to allocate memory.
+ - InvalidArgument: the precision is incorrect, or |`self`| > 1.",
+        acos_pi,
+        acos_unit,
+        AngleUnit::HalfTurn
+    );
+
+    gen_trig_unit!(
+        "Computes atan(`self`) / pi with precision `p`. The result is rounded using the rounding mode `rm`.
+This function requires constants cache `cc` for computing the result.
+Precision is rounded upwards to the word size.
+
+## Errors
+
+ - MemoryAllocation: failed to allocate memory.
+ - InvalidArgument: the precision is incorrect.",
+        atan_pi,
+        atan_unit,
+        AngleUnit::HalfTurn
+    );
+
+    gen_trig_unit!(
+        "Computes the sine of `self` degrees with precision `p`. The result is rounded using the rounding mode `rm`.
+The argument is reduced exactly, so the result is exact for multiples of 30 degrees.
+This function requires constants cache `cc` for computing the result.
+Precision is rounded upwards to the word size.
+
+## Errors
+
+ - MemoryAllocation: failed to allocate memory.
+ - InvalidArgument: the precision is incorrect.",
+        sin_deg,
+        sin_unit,
+        AngleUnit::Degree
+    );
+
+    gen_trig_unit!(
+        "Computes the cosine of `self` degrees with precision `p`. The result is rounded using the rounding mode `rm`.
+The argument is reduced exactly, so the result is exact for multiples of 30 degrees.
+This function requires constants cache `cc` for computing the result.
+Precision is rounded upwards to the word size.
+
+## Errors
+
+ - MemoryAllocation: failed to allocate memory.
+ - InvalidArgument: the precision is incorrect.",
+        cos_deg,
+        cos_unit,
+        AngleUnit::Degree
+    );
+
+    gen_trig_unit!(
+        "Computes the tangent of `self` degrees with precision `p`. The result is rounded using the rounding mode `rm`.
+The argument is reduced exactly, so the result is exact for multiples of 45 degrees.
+This function requires constants cache `cc` for computing the result.
+Precision is rounded upwards to the word size.
+
+## Errors
+
+ - ExponentOverflow: `self` is an odd multiple of 90, or the result is too large or too small number.
+ - MemoryAllocation: failed to allocate memory.
+ - InvalidArgument: the precision is incorrect.",
+        tan_deg,
+        tan_unit,
+        AngleUnit::Degree
+    );
+
+    gen_trig_unit!(
+        "Computes the arcsine of `self` in degrees with precision `p`. The result is rounded using the rounding mode `rm`.
+This function requires constants cache `cc` for computing the result.
+Precision is rounded upwards to the word size.
+
+## Errors
+
+ - MemoryAllocation: failed to allocate memory.
+ - InvalidArgument: the precision is incorrect, or |`self`| > 1.",
+        asin_deg,
+        asin_unit,
+        AngleUnit::Degree
+    );
+
+    gen_trig_unit!(
+        "Computes the arccosine of `self` in degrees with precision `p`. The result is rounded using the rounding mode `rm`.
+This function requires constants cache `cc` for computing the result.
+Precision is rounded upwards to the word size.
+
+## Errors
+
+ - MemoryAllocation: failed to allocate memory.
+ - InvalidArgument: the precision is incorrect, or |`self`| > 1.",
+        acos_deg,
+        acos_unit,
+        AngleUnit::Degree
+    );
+
+    gen_trig_unit!(
+        "Computes the arctangent of `self` in degrees with precision `p`. The result is rounded using the rounding mode `rm`.
+This function requires constants cache `cc` for computing the result.
+Precision is rounded upwards to the word size.
+
+## Errors
+
+ - MemoryAllocation: failed to allocate memory.
+ - InvalidArgument: the precision is incorrect.",
+        atan_deg,
+        atan_unit,
+        AngleUnit::Degree
+    );
+
+    // Returns exact `a` from [0, h/2], where h is the half-turn, and the signs `s` and `c`
+    // such that sin(self) = s*sin(a) and cos(self) = c*cos(a).
+    fn reduce_half_turn(&self, unit: AngleUnit) -> Result<(Self, bool, bool), Error> {
+        let h = unit.angle(180)?;
+        let h2 = unit.angle(90)?;
+
+        // the remainder is exact
+        let mut a = self.rem(&unit.angle(360)?)?;
+        let mut sin_neg = a.is_negative();
+        let mut cos_neg = false;
+        a.set_sign(Sign::Pos);
+
+        let p = a.mantissa_max_bit_len() + WORD_BIT_SIZE;
+
+        if a.cmp(&h) >= 0 {
+            a = a.sub(&h, p, RoundingMode::None)?;
+            sin_neg = !sin_neg;
+            cos_neg = !cos_neg;
+        }
+
+        if a.cmp(&h2) > 0 {
+            a = h.sub(&a, p, RoundingMode::None)?;
+            cos_neg = !cos_neg;
+        }
+
+        Ok((a, sin_neg, cos_neg))
+    }
+
+    // Computes sin(a), or cos(a) if `cos` is true, for nonzero `a` from [0, h/2] with precision `p`.
+    fn sin_cos_unit_reduced(
+        a: &Self,
+        mut cos: bool,
+        unit: AngleUnit,
+        p: usize,
+        cc: &mut Consts,
+    ) -> Result<Self, Error> {
+        let a = if a.cmp(&unit.angle(45)?) > 0 {
+            // sin(a) = cos(h/2 - a)
+            cos = !cos;
+            unit.angle(90)?.sub(
+                a,
+                a.mantissa_max_bit_len() + WORD_BIT_SIZE,
+                RoundingMode::None,
+            )?
+        } else {
+            a.clone()?
+        };
+
+        let y = unit.unit_to_rad(&a, p, cc)?;
+
+        if cos {
+            y.cos_series(RoundingMode::None)
+        } else if 2 * (y.exponent() as isize) < -(p as isize) - 2 {
+            // sin(y) = y * (1 - y^2/6 + ...)
+            Ok(y)
+        } else {
+            y.sin_series(RoundingMode::None)
+        }
+    }
+
+    // Computes tan(a) for nonzero `a` from (0, h/2) with precision `p`.
+    fn tan_unit_reduced(
+        a: &Self,
+        unit: AngleUnit,
+        p: usize,
+        cc: &mut Consts,
+    ) -> Result<Self, Error> {
+        let (a, inv) = if a.cmp(&unit.angle(45)?) > 0 {
+            // tan(a) = 1 / tan(h/2 - a)
+            let b = unit.angle(90)?.sub(
+                a,
+                a.mantissa_max_bit_len() + WORD_BIT_SIZE,
+                RoundingMode::None,
+            )?;
+            (b, true)
+        } else {
+            (a.clone()?, false)
+        };
+
+        let y = unit.unit_to_rad(&a, p, cc)?;
+
+        let ret = if 2 * (y.exponent() as isize) < -(p as isize) - 2 {
+            // tan(y) = y * (1 + y^2/3 + ...)
+            y
+        } else {
+            y.tan_series(RoundingMode::None)?
+        };
+
+        if inv {
+            ONE.div(&ret, p, RoundingMode::None)
+        } else {
+            Ok(ret)
+        }
+    }
+
+    // Returns 1 with precision `p`, or 1/2 if `half` is true, with the sign `s`.
+    fn exact_one(p: usize, half: bool, s: bool, inexact: bool) -> Result<Self, Error> {
+        let mut ret = Self::from_word(1, p)?;
+        if half {
+            ret.set_exponent(0);
+        }
+        if s {
+            ret.set_sign(Sign::Neg);
+        }
+        ret.set_inexact(inexact);
+        Ok(ret)
+    }
+
+    // Converts exact `a` to the result with precision `p` and the sign `s`.
+    fn exact_angle(mut a: Self, p: usize, s: bool, inexact: bool) -> Result<Self, Error> {
+        a.set_precision(p, RoundingMode::None)?;
+        if s {
+            a.set_sign(Sign::Neg);
+        }
+        a.set_inexact(inexact);
+        Ok(a)
+    }
+
+    fn sin_unit(
+        &self,
+        unit: AngleUnit,
+        p: usize,
+        rm: RoundingMode,
+        cc: &mut Consts,
+    ) -> Result<Self, Error> {
+        let p = round_p(p);
+
+        if self.is_zero() {
+            return Self::new2(p, self.sign(), self.inexact());
+        }
+
+        let (a, neg, _) = self.reduce_half_turn(unit)?;
+
+        if a.is_zero() {
+            // sin(n*h) has the sign of n
+            return Self::new2(p, self.sign(), self.inexact());
+        }
+
+        if a.cmp(&unit.angle(90)?) == 0 {
+            return Self::exact_one(p, false, neg, self.inexact());
+        }
+
+        if unit == AngleUnit::Degree && a.cmp(&unit.angle(30)?) == 0 {
+            return Self::exact_one(p, true, neg, self.inexact());
+        }
+
+        let mut p_inc = WORD_BIT_SIZE;
+        let mut p_wrk = p + p_inc;
+
+        loop {
+            let mut ret = Self::sin_cos_unit_reduced(&a, false, unit, p_wrk + 4, cc)?;
+
+            if neg {
+                ret.inv_sign();
+            }
+
+            if ret.try_set_precision(p, rm, p_wrk)? {
+                ret.set_inexact(ret.inexact() | self.inexact());
+                break Ok(ret);
+            }
+
+            p_wrk += p_inc;
+            p_inc = round_p(p_wrk / 5);
+        }
+    }
+
+    fn cos_unit(
+        &self,
+        unit: AngleUnit,
+        p: usize,
+        rm: RoundingMode,
+        cc: &mut Consts,
+    ) -> Result<Self, Error> {
+        let p = round_p(p);
+
+        if self.is_zero() {
+            return Self::exact_one(p, false, false, self.inexact());
+        }
+
+        // 1 - cos(pi*x) < 2^(2*e + 3)
+        compute_small_exp!(ONE, self.exponent() as isize * 2 + 3, true, p, rm);
+
+        let (a, _, neg) = self.reduce_half_turn(unit)?;
+
+        if a.is_zero() {
+            return Self::exact_one(p, false, neg, self.inexact());
+        }
+
+        if a.cmp(&unit.angle(90)?) == 0 {
+            // cos(n*h + h/2) is +0
+            return Self::new2(p, Sign::Pos, self.inexact());
+        }
+
+        if unit == AngleUnit::Degree && a.cmp(&unit.angle(60)?) == 0 {
+            return Self::exact_one(p, true, neg, self.inexact());
+        }
+
+        let mut p_inc = WORD_BIT_SIZE;
+        let mut p_wrk = p + p_inc;
+
+        loop {
+            let mut ret = Self::sin_cos_unit_reduced(&a, true, unit, p_wrk + 4, cc)?;
+
+            if neg {
+                ret.inv_sign();
+            }
+
+            if ret.try_set_precision(p, rm, p_wrk)? {
+                ret.set_inexact(ret.inexact() | self.inexact());
+                break Ok(ret);
+            }
+
+            p_wrk += p_inc;
+            p_inc = round_p(p_wrk / 5);
+        }
+    }
+
+    fn tan_unit(
+        &self,
+        unit: AngleUnit,
+        p: usize,
+        rm: RoundingMode,
+        cc: &mut Consts,
+    ) -> Result<Self, Error> {
+        let p = round_p(p);
+
+        if self.is_zero() {
+            return Self::new2(p, self.sign(), self.inexact());
+        }
+
+        let (a, sin_neg, cos_neg) = self.reduce_half_turn(unit)?;
+
+        if a.is_zero() {
+            // the sign of sin(n*h) / cos(n*h)
+            let s = if self.is_negative() != cos_neg { Sign::Neg } else { Sign::Pos };
+            return Self::new2(p, s, self.inexact());
+        }
+
+        if a.cmp(&unit.angle(90)?) == 0 {
+            // tan(n*h + h/2) is +inf for even n, and -inf for odd n
+            let s = if sin_neg { Sign::Neg } else { Sign::Pos };
+            return Err(Error::ExponentOverflow(s));
+        }
+
+        let neg = sin_neg != cos_neg;
+
+        if a.cmp(&unit.angle(45)?) == 0 {
+            return Self::exact_one(p, false, neg, self.inexact());
+        }
+
+        let mut p_inc = WORD_BIT_SIZE;
+        let mut p_wrk = p + p_inc;
+
+        loop {
+            let mut ret = Self::tan_unit_reduced(&a, unit, p_wrk + 4, cc)?;
+
+            if neg {
+                ret.inv_sign();
+            }
+
+            if ret.try_set_precision(p, rm, p_wrk)? {
+                ret.set_inexact(ret.inexact() | self.inexact());
+                break Ok(ret);
+            }
+
+            p_wrk += p_inc;
+            p_inc = round_p(p_wrk / 5);
+        }
+    }
+
+    fn asin_unit(
+        &self,
+        unit: AngleUnit,
+        p: usize,
+        rm: RoundingMode,
+        cc: &mut Consts,
+    ) -> Result<Self, Error> {
+        let p = round_p(p);
+
+        if self.is_zero() {
+            return Self::new2(p, self.sign(), self.inexact());
+        }
+
+        let neg = self.is_negative();
+        let mut half = ONE.clone()?;
+        half.set_exponent(0);
+
+        if self.abs_cmp(&ONE) == 0 {
+            return Self::exact_angle(unit.angle(90)?, p, neg, self.inexact());
+        }
+
+        if unit == AngleUnit::Degree && self.abs_cmp(&half) == 0 {
+            return Self::exact_angle(unit.angle(30)?, p, neg, self.inexact());
+        }
+
+        let mut p_inc = WORD_BIT_SIZE;
+        let mut p_wrk = p + p_inc;
+
+        let mut x = self.clone()?;
+        x.set_inexact(false);
+
+        loop {
+            let r = x.asin(p_wrk + 4, RoundingMode::None, cc)?;
+            let mut ret = unit.rad_to_unit(&r, p_wrk + 4, cc)?;
+
+            if ret.try_set_precision(p, rm, p_wrk)? {
+                ret.set_inexact(ret.inexact() | self.inexact());
+                break Ok(ret);
+            }
+
+            p_wrk += p_inc;
+            p_inc = round_p(p_wrk / 5);
+        }
+    }
+
+    fn acos_unit(
+        &self,
+        unit: AngleUnit,
+        p: usize,
+        rm: RoundingMode,
+        cc: &mut Consts,
+    ) -> Result<Self, Error> {
+        let p = round_p(p);
+
+        if self.is_zero() {
+            return Self::exact_angle(unit.angle(90)?, p, false, self.inexact());
+        }
+
+        let mut half = ONE.clone()?;
+        half.set_exponent(0);
+
+        if self.cmp(&ONE) == 0 {
+            return Self::new2(p, Sign::Pos, self.inexact());
+        }
+
+        if self.abs_cmp(&ONE) == 0 {
+            return Self::exact_angle(unit.angle(180)?, p, false, self.inexact());
+        }
+
+        if unit == AngleUnit::Degree && self.abs_cmp(&half) == 0 {
+            let a = if self.is_negative() { unit.angle(120)? } else { unit.angle(60)? };
+            return Self::exact_angle(a, p, false, self.inexact());
+        }
+
+        let mut p_inc = WORD_BIT_SIZE;
+        let mut p_wrk = p + p_inc;
+
+        let mut x = self.clone()?;
+        x.set_inexact(false);
+
+        loop {
+            let r = x.acos(p_wrk + 4, RoundingMode::None, cc)?;
+            let mut ret = unit.rad_to_unit(&r, p_wrk + 4, cc)?;
+
+            if ret.try_set_precision(p, rm, p_wrk)? {
+                ret.set_inexact(ret.inexact() | self.inexact());
+                break Ok(ret);
+            }
+
+            p_wrk += p_inc;
+            p_inc = round_p(p_wrk / 5);
+        }
+    }
+
+    fn atan_unit(
+        &self,
+        unit: AngleUnit,
+        p: usize,
+        rm: RoundingMode,
+        cc: &mut Consts,
+    ) -> Result<Self, Error> {
+        let p = round_p(p);
+
+        if self.is_zero() {
+            return Self::new2(p, self.sign(), self.inexact());
+        }
+
+        if self.abs_cmp(&ONE) == 0 {
+            return Self::exact_angle(unit.angle(45)?, p, self.is_negative(), self.inexact());
+        }
+
+        let mut p_inc = WORD_BIT_SIZE;
+        let mut p_wrk = p + p_inc;
+
+        let mut x = self.clone()?;
+        x.set_inexact(false);
+
+        loop {
+            let r = x.atan(p_wrk + 4, RoundingMode::None, cc)?;
+            let mut ret = unit.rad_to_unit(&r, p_wrk + 4, cc)?;
+
+            if ret.try_set_precision(p, rm, p_wrk)? {
+                ret.set_inexact(ret.inexact() | self.inexact());
+                break Ok(ret);
+            }
+
+            p_wrk += p_inc;
+            p_inc = round_p(p_wrk / 5);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::common::util::random_subnormal;
+    use crate::Exponent;
+
+    // Checks that `a` and `b` differ by at most 1 ulp of precision `p`.
+    fn assert_close(a: &BigFloatNumber, b: &BigFloatNumber, p: usize) {
+        let d = a.sub(b, p + WORD_BIT_SIZE, RoundingMode::None).unwrap();
+        assert!(
+            d.is_zero() || (d.exponent() as isize) < a.exponent() as isize - p as isize + 1,
+            "{:?} {:?}",
+            a,
+            b
+        );
+    }
+
+    fn num(f: f64, p: usize) -> BigFloatNumber {
+        BigFloatNumber::from_f64(p, f).unwrap()
+    }
+
+    #[test]
+    fn test_trig_pi() {
+        let mut cc = Consts::new().unwrap();
+        let rm = RoundingMode::ToEven;
+        let p = 192;
+
+        // exact values at integers and half-integers
+        for n in -6i32..6 {
+            let x = num(n as f64, p);
+            let s = x.sin_pi(p, rm, &mut cc).unwrap();
+            assert!(s.is_zero() && s.sign() == x.sign() && !s.inexact());
+            let c = x.cos_pi(p, rm, &mut cc).unwrap();
+            assert!(c.cmp(&num(if n % 2 == 0 { 1.0 } else { -1.0 }, p)) == 0 && !c.inexact());
+            let t = x.tan_pi(p, rm, &mut cc).unwrap();
+            assert!(t.is_zero() && t.is_negative() == ((n % 2 != 0) != (n < 0)));
+
+            let x = num(n as f64 + 0.5, p);
+            let s = x.sin_pi(p, rm, &mut cc).unwrap();
+            assert!(s.cmp(&num(if n.rem_euclid(2) == 0 { 1.0 } else { -1.0 }, p)) == 0);
+            let c = x.cos_pi(p, rm, &mut cc).unwrap();
+            assert!(c.is_zero() && c.is_positive());
+            let sign = if n.rem_euclid(2) == 0 { Sign::Pos } else { Sign::Neg };
+            assert!(x.tan_pi(p, rm, &mut cc).unwrap_err() == Error::ExponentOverflow(sign));
+
+            let x = num(n as f64 + 0.25, p);
+            let t = x.tan_pi(p, rm, &mut cc).unwrap();
+            assert!(t.cmp(&ONE) == 0 && !t.inexact());
+        }
+
+        // degrees
+        for n in -3i32..3 {
+            let d = 360.0 * n as f64;
+            let half = num(0.5, p);
+            assert!(num(d + 30.0, p).sin_deg(p, rm, &mut cc).unwrap().cmp(&half) == 0);
+            assert!(
+                num(d + 150.0, p)
+                    .sin_deg(p, rm, &mut cc)
+                    .unwrap()
+                    .cmp(&half)
+                    == 0
+            );
+            assert!(
+                num(d - 30.0, p)
+                    .sin_deg(p, rm, &mut cc)
+                    .unwrap()
+                    .cmp(&half.neg().unwrap())
+                    == 0
+            );
+            assert!(num(d + 60.0, p).cos_deg(p, rm, &mut cc).unwrap().cmp(&half) == 0);
+            assert!(
+                num(d + 240.0, p)
+                    .cos_deg(p, rm, &mut cc)
+                    .unwrap()
+                    .cmp(&half.neg().unwrap())
+                    == 0
+            );
+            assert!(
+                num(d + 135.0, p)
+                    .tan_deg(p, rm, &mut cc)
+                    .unwrap()
+                    .cmp(&ONE.neg().unwrap())
+                    == 0
+            );
+            assert!(num(d + 90.0, p).cos_deg(p, rm, &mut cc).unwrap().is_zero());
+        }
+
+        // comparison with the functions of the argument in radians
+        for _ in 0..100 {
+            let x = BigFloatNumber::random_normal(p, -5, 5).unwrap();
+
+            let p_wrk = p + 2 * WORD_BIT_SIZE;
+            let pi = cc.pi_num(p_wrk, RoundingMode::None).unwrap();
+            let y = x.mul(&pi, p_wrk, RoundingMode::None).unwrap();
+            let deg = BigFloatNumber::from_word(180, WORD_BIT_SIZE).unwrap();
+            let z = y.div(&deg, p_wrk, RoundingMode::None).unwrap();
+
+            assert_close(
+                &x.sin_pi(p, rm, &mut cc).unwrap(),
+                &y.sin(p, rm, &mut cc).unwrap(),
+                p,
+            );
+            assert_close(
+                &x.cos_pi(p, rm, &mut cc).unwrap(),
+                &y.cos(p, rm, &mut cc).unwrap(),
+                p,
+            );
+            assert_close(
+                &x.tan_pi(p, rm, &mut cc).unwrap(),
+                &y.tan(p, rm, &mut cc).unwrap(),
+                p,
+            );
+            assert_close(
+                &x.sin_deg(p, rm, &mut cc).unwrap(),
+                &z.sin(p, rm, &mut cc).unwrap(),
+                p,
+            );
+            assert_close(
+                &x.cos_deg(p, rm, &mut cc).unwrap(),
+                &z.cos(p, rm, &mut cc).unwrap(),
+                p,
+            );
+            assert_close(
+                &x.tan_deg(p, rm, &mut cc).unwrap(),
+                &z.tan(p, rm, &mut cc).unwrap(),
+                p,
+            );
+        }
+
+        // large arguments are reduced exactly
+        let p = 1088;
+        let mut x = ONE.clone().unwrap();
+        x.set_exponent(1001);
+        let x = x
+            .mul(&num(360.0, p), p, RoundingMode::None)
+            .unwrap()
+            .add(&num(30.0, p), p, RoundingMode::None)
+            .unwrap();
+        assert!(!x.inexact());
+        let s = x.sin_deg(p, rm, &mut cc).unwrap();
+        assert!(s.cmp(&num(0.5, p)) == 0 && !s.inexact());
+
+        let mut x = num(3.0, 128);
+        x.set_exponent(1000000);
+        assert!(x.sin_pi(128, rm, &mut cc).unwrap().is_zero());
+        assert!(x.cos_pi(128, rm, &mut cc).unwrap().cmp(&ONE) == 0);
+
+        let mut x = num(0.25, 1024);
+        x = x
+            .add(&num(1048576.0, 1024), 1024, RoundingMode::None)
+            .unwrap();
+        let mut d = ONE.clone().unwrap();
+        d.set_exponent(-1000);
+        let x = x.add(&d, 1024, RoundingMode::None).unwrap();
+        let t = x.tan_pi(128, rm, &mut cc).unwrap();
+        // tan(pi/4 + pi*2^-1001) = 1 + 2*pi*2^-1001 + ...
+        let mut r = cc.pi_num(128, RoundingMode::None).unwrap();
+        r.set_exponent(r.exponent() - 1000);
+        let r = ONE.add(&r, 128, RoundingMode::None).unwrap();
+        assert!(t.cmp(&r) == 0);
+
+        // small arguments
+        let p = 128;
+        let x = random_subnormal(p);
+        let pi = cc.pi_num(p, RoundingMode::None).unwrap();
+        assert!(x.cos_pi(p, rm, &mut cc).unwrap().cmp(&ONE) == 0);
+        assert!(
+            x.sin_pi(p, rm, &mut cc)
+                .unwrap()
+                .cmp(&x.mul(&pi, p, rm).unwrap())
+                == 0
+        );
+        let mut x = num(1.0, p);
+        x.set_exponent(-(p as Exponent) * 2);
+        assert_close(
+            &x.tan_pi(p, rm, &mut cc).unwrap(),
+            &x.mul(&pi, p, rm).unwrap(),
+            p,
+        );
+    }
+
+    #[test]
+    fn test_arc_trig_pi() {
+        let mut cc = Consts::new().unwrap();
+        let rm = RoundingMode::ToEven;
+        let p = 192;
+
+        let one = num(1.0, p);
+        let half = num(0.5, p);
+
+        assert!(one.asin_pi(p, rm, &mut cc).unwrap().cmp(&half) == 0);
+        assert!(
+            one.neg()
+                .unwrap()
+                .asin_deg(p, rm, &mut cc)
+                .unwrap()
+                .cmp(&num(-90.0, p))
+                == 0
+        );
+        assert!(half.asin_deg(p, rm, &mut cc).unwrap().cmp(&num(30.0, p)) == 0);
+        assert!(one.acos_pi(p, rm, &mut cc).unwrap().is_zero());
+        assert!(
+            one.neg()
+                .unwrap()
+                .acos_pi(p, rm, &mut cc)
+                .unwrap()
+                .cmp(&one)
+                == 0
+        );
+        assert!(num(0.0, p).acos_pi(p, rm, &mut cc).unwrap().cmp(&half) == 0);
+        assert!(half.acos_deg(p, rm, &mut cc).unwrap().cmp(&num(60.0, p)) == 0);
+        assert!(
+            half.neg()
+                .unwrap()
+                .acos_deg(p, rm, &mut cc)
+                .unwrap()
+                .cmp(&num(120.0, p))
+                == 0
+        );
+        assert!(one.atan_pi(p, rm, &mut cc).unwrap().cmp(&num(0.25, p)) == 0);
+        assert!(
+            one.neg()
+                .unwrap()
+                .atan_deg(p, rm, &mut cc)
+                .unwrap()
+                .cmp(&num(-45.0, p))
+                == 0
+        );
+        assert!(num(2.0, p).asin_pi(p, rm, &mut cc).unwrap_err() == Error::InvalidArgument);
+
+        // comparison with the functions returning radians
+        for _ in 0..100 {
+            let x = BigFloatNumber::random_normal(p, -5, 0).unwrap();
+
+            let p_wrk = p + 2 * WORD_BIT_SIZE;
+            let pi = cc.pi_num(p_wrk, RoundingMode::None).unwrap();
+            let deg = BigFloatNumber::from_word(180, WORD_BIT_SIZE).unwrap();
+            let to_pi = |y: BigFloatNumber| y.div(&pi, p, rm).unwrap();
+            let to_deg = |y: BigFloatNumber| {
+                y.mul(&deg, p_wrk, RoundingMode::None)
+                    .unwrap()
+                    .div(&pi, p, rm)
+                    .unwrap()
+            };
+
+            let r = x.asin(p_wrk, RoundingMode::None, &mut cc).unwrap();
+            assert_close(
+                &x.asin_pi(p, rm, &mut cc).unwrap(),
+                &to_pi(r.clone().unwrap()),
+                p,
+            );
+            assert_close(&x.asin_deg(p, rm, &mut cc).unwrap(), &to_deg(r), p);
+
+            let r = x.acos(p_wrk, RoundingMode::None, &mut cc).unwrap();
+            assert_close(
+                &x.acos_pi(p, rm, &mut cc).unwrap(),
+                &to_pi(r.clone().unwrap()),
+                p,
+            );
+            assert_close(&x.acos_deg(p, rm, &mut cc).unwrap(), &to_deg(r), p);
+
+            let r = x.atan(p_wrk, RoundingMode::None, &mut cc).unwrap();
+            assert_close(
+                &x.atan_pi(p, rm, &mut cc).unwrap(),
+                &to_pi(r.clone().unwrap()),
+                p,
+            );
+            assert_close(&x.atan_deg(p, rm, &mut cc).unwrap(), &to_deg(r), p);
+
+            // round trip
+            let a = x.asin_deg(p + WORD_BIT_SIZE, rm, &mut cc).unwrap();
+            assert_close(&a.sin_deg(p, rm, &mut cc).unwrap(), &x, p);
+        }
+    }
+}
@@ -1,18 +1,94 @@
 //! Auxiliary items.
 
-use crate::{num::BigFloatNumber, Consts, Error, RoundingMode};
+use crate::common::consts::ONE;
+use crate::common::util::round_p;
+use crate::{num::BigFloatNumber, Consts, Error, Exponent, RoundingMode, WORD_BIT_SIZE};
 
 impl BigFloatNumber {
-    /// Reduce `self` to interval (-2*pi; 2*pi)
-    pub(crate) fn reduce_trig_arg(self, cc: &mut Consts, rm: RoundingMode) -> Result<Self, Error> {
-        if self.exponent() > 2 {
-            let mut pi = cc.pi_num(self.mantissa_max_bit_len() + self.exponent() as usize, rm)?;
+    /// Reduces `self` to the interval [-pi/2; pi/2] if the exponent of `self` is greater than 2.
+    /// Returns the reduced value `r` and true if `self` = `r` + `k`*pi for an odd `k`.
+    /// The relative error of `r` is below 2^(-p+2), where p is the precision of `self`,
+    /// regardless of how close `self` is to a multiple of pi.
+    ///
+    /// The reduction uses the Payne-Hanek method. Only the bits of 1/pi from the position e-p
+    /// to the position e+p+g matter, where e is the exponent of `self` and g is the number of guard bits.
+    /// The value of 1/pi with e+p+g bits is computed once and cached in `cc`,
+    /// and each reduction then costs O(e) to extract the window of 2p+g bits,
+    /// and a multiplication of numbers with p and 2p+g bits.
+    /// The number of guard bits g starts from two words and is increased only when `self` is close
+    /// to a multiple of pi, in which case g exceeds the number of leading bits lost due to cancellation by two words.
+    pub(crate) fn reduce_trig_arg(
+        self,
+        cc: &mut Consts,
+        rm: RoundingMode,
+    ) -> Result<(Self, bool), Error> {
+        if self.exponent() <= 2 {
+            return Ok((self, false));
+        }
+
+        let p = self.mantissa_max_bit_len();
+        let e = self.exponent() as isize;
+
+        // self = m * 2^(e - p), where m is an integer with p bits
+        let mut m = self.abs()?;
+        m.set_exponent(p as Exponent);
+
+        let mut g = 2 * WORD_BIT_SIZE;
+
+        loop {
+            // self / pi = 2 * m * (2^(e - p - 1) / pi),
+            // and the integer part of 2^(e - p - 1) / pi adds an even number to self / pi.
+            let mut w = cc.frac_1_pi_num(e as usize + p + g)?;
+            w.set_exponent((w.exponent() as isize + e - p as isize - 1) as Exponent);
+
+            let q = round_p(2 * p + g);
+            let mut w = w.fract()?;
+            if w.mantissa_max_bit_len() > q {
+                w.set_precision(q, RoundingMode::None)?;
+            }
+
+            // f = (self / pi mod 2) / 2, the absolute error is below 2^(-p - g + 2).
+            let t = m.mul(&w, p + w.mantissa_max_bit_len(), RoundingMode::None)?;
+            let f = t.fract()?;
+
+            // v = f - k/2, |v| <= 1/4
+            let (mut v, odd) = if f.is_zero() || f.exponent() < -1 {
+                (f, false)
+            } else {
+                let mut half = ONE.clone()?;
+                half.set_exponent(0);
 
-            pi.set_exponent(pi.exponent() + 1);
+                let d = f.sub(&half, f.mantissa_max_bit_len(), RoundingMode::None)?;
+
+                if d.is_zero() || d.exponent() < -1 || d.is_negative() && d.exponent() == -1 {
+                    (d, true)
+                } else {
+                    (
+                        f.sub(&ONE, f.mantissa_max_bit_len(), RoundingMode::None)?,
+                        false,
+                    )
+                }
+            };
+
+            // v must retain at least p + 2 correct bits.
+            let lost = if v.is_zero() { g } else { -(v.exponent() as isize) as usize };
+            if lost + 4 > g {
+                g = lost + 4 + 2 * WORD_BIT_SIZE;
+                continue;
+            }
 
-            self.rem(&pi)
-        } else {
-            Ok(self)
+            // r = 2 * pi * v
+            let pi = cc.pi_num(p + WORD_BIT_SIZE, RoundingMode::None)?;
+            v.set_exponent(v.exponent() + 1);
+            let mut r = v.mul(&pi, p + WORD_BIT_SIZE, RoundingMode::None)?;
+
+            r.set_precision(p, rm)?;
+
+            if self.is_negative() {
+                r.inv_sign();
+            }
+
+            return Ok((r, odd));
         }
     }
 }
@@ -33,3 +109,109 @@ macro_rules! compute_small_exp {
 }
 
 pub(super) use compute_small_exp;
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::Sign;
+
+    // sin, cos, and tan using the reduction by the remainder of the division by 2*pi.
+    fn trig_ref(
+        x: &BigFloatNumber,
+        p: usize,
+        rm: RoundingMode,
+        cc: &mut Consts,
+    ) -> [BigFloatNumber; 3] {
+        let p_wrk = p + x.mantissa_max_bit_len() + x.exponent() as usize + 4 * WORD_BIT_SIZE;
+
+        let mut pi2 = cc.pi_num(p_wrk, RoundingMode::None).unwrap();
+        pi2.set_exponent(pi2.exponent() + 1);
+
+        let mut r = x.clone().unwrap();
+        r.set_precision(p_wrk, RoundingMode::None).unwrap();
+        let mut r = r.rem(&pi2).unwrap();
+        r.set_precision(p + 4 * WORD_BIT_SIZE, RoundingMode::None)
+            .unwrap();
+
+        [r.sin(p, rm, cc).unwrap(), r.cos(p, rm, cc).unwrap(), r.tan(p, rm, cc).unwrap()]
+    }
+
+    fn assert_trig(x: &BigFloatNumber, p: usize, cc: &mut Consts) {
+        let rm = RoundingMode::ToEven;
+        let [s, c, t] = trig_ref(x, p, rm, cc);
+
+        assert!(x.sin(p, rm, cc).unwrap().cmp(&s) == 0);
+        assert!(x.cos(p, rm, cc).unwrap().cmp(&c) == 0);
+        assert!(x.tan(p, rm, cc).unwrap().cmp(&t) == 0);
+    }
+
+    #[test]
+    fn test_reduce_trig_arg() {
+        let mut cc = Consts::new().unwrap();
+        let p = 128;
+
+        // random arguments
+        for e in [3, 4, 10, 64, 200, 1000, 10000] {
+            for _ in 0..10 {
+                let mut x = BigFloatNumber::random_normal(p, e, e).unwrap();
+                if rand::random::<bool>() {
+                    x.set_sign(Sign::Neg);
+                }
+
+                assert_trig(&x, p, &mut cc);
+            }
+        }
+
+        // close to multiples of pi
+        for e in [10, 64, 100] {
+            let mut pi = cc.pi_num(p + e, RoundingMode::None).unwrap();
+            pi.set_exponent(pi.exponent() + e as Exponent);
+            for k in [1, 3, 4, 7] {
+                let mut x = pi
+                    .mul(
+                        &BigFloatNumber::from_word(k, 64).unwrap(),
+                        p,
+                        RoundingMode::ToEven,
+                    )
+                    .unwrap();
+                x.set_precision(p, RoundingMode::None).unwrap();
+
+                let (r, odd) = x
+                    .clone()
+                    .unwrap()
+                    .reduce_trig_arg(&mut cc, RoundingMode::None)
+                    .unwrap();
+                assert!(r.exponent() < -(p as Exponent) + e as Exponent + 8);
+                assert!(!odd);
+
+                assert_trig(&x, p, &mut cc);
+            }
+        }
+
+        // huge argument
+        let mut x = BigFloatNumber::from_word(1, p).unwrap();
+        x.set_exponent(100001);
+        assert_trig(&x, p, &mut cc);
+    }
+
+    #[ignore]
+    #[test]
+    #[cfg(feature = "std")]
+    fn reduce_trig_arg_perf() {
+        let mut cc = Consts::new().unwrap();
+        let p = 128;
+
+        for e in [10000, 100000, 1000000] {
+            let mut x = BigFloatNumber::from_word(1, p).unwrap();
+            x.set_exponent(e + 1);
+
+            for _ in 0..3 {
+                let start_time = std::time::Instant::now();
+                let _f = x.sin(p, RoundingMode::ToEven, &mut cc).unwrap();
+                let time = start_time.elapsed();
+                println!("{} {}", e, time.as_micros());
+            }
+        }
+    }
+}
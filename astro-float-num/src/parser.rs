@@ -1,45 +1,57 @@
 //! Parser parses numbers represented in scientific format.
 
-use crate::defs::Exponent;
+use crate::conv::PackedDigits;
+use crate::defs::check_radix;
 use crate::defs::Sign;
+use crate::defs::Word;
 use crate::defs::EXPONENT_MAX;
 use crate::Error;
 use crate::Radix;
 use crate::EXPONENT_MIN;
-use core::str::Chars;
 
-#[cfg(not(feature = "std"))]
-use alloc::vec::Vec;
-
-pub struct ParserState<'a> {
-    chars: Chars<'a>,
+pub struct ParserState<I: Iterator<Item = u8>> {
+    bytes: I,
+    ahead: [u8; 3],
+    ahead_len: usize,
     cur_ch: Option<char>,
-    s_len: usize,
     sign: Sign,
-    mantissa_bytes: Vec<u8>,
+    digits: PackedDigits,
     e: isize,
     inf: bool,
     nan: bool,
 }
 
-impl<'a> ParserState<'a> {
-    fn new(s: &'a str) -> Self {
-        ParserState {
-            chars: s.chars(),
-            s_len: s.len(),
+impl<I: Iterator<Item = u8>> ParserState<I> {
+    fn new(bytes: I, rdx: u32) -> Result<Self, Error> {
+        let mut digits = PackedDigits::new(rdx as Word);
+        digits.reserve(bytes.size_hint().0)?;
+
+        Ok(ParserState {
+            bytes,
+            ahead: [0; 3],
+            ahead_len: 0,
             cur_ch: None,
             sign: Sign::Pos,
-            mantissa_bytes: Vec::new(),
+            digits,
             e: 0,
             inf: false,
             nan: true,
-        }
+        })
     }
 
-    // Returns next character of a string in lower case,
-    // or None if string end reached.
+    // Returns next character of the input in lower case,
+    // or None if the end of the input is reached.
     fn next_char(&mut self) -> Option<char> {
-        self.cur_ch = self.chars.next().map(|c| c.to_ascii_lowercase());
+        let b = if self.ahead_len > 0 {
+            let b = self.ahead[0];
+            self.ahead.copy_within(1.., 0);
+            self.ahead_len -= 1;
+            Some(b)
+        } else {
+            self.bytes.next()
+        };
+
+        self.cur_ch = b.map(|b| (b as char).to_ascii_lowercase());
         self.cur_ch
     }
 
@@ -47,6 +59,27 @@ impl<'a> ParserState<'a> {
         self.cur_ch
     }
 
+    // Reads up to `n` characters following the current character without consuming them.
+    fn look_ahead(&mut self, n: usize) -> &[u8] {
+        while self.ahead_len < n {
+            match self.bytes.next() {
+                Some(b) => {
+                    self.ahead[self.ahead_len] = b;
+                    self.ahead_len += 1;
+                }
+                None => break,
+            }
+        }
+
+        &self.ahead[..self.ahead_len.min(n)]
+    }
+
+    // Returns true if the rest of the input after the current character is equal to `s` ignoring case.
+    fn rest_eq(&mut self, s: &str) -> bool {
+        let ahead = self.look_ahead(s.len() + 1);
+        ahead.eq_ignore_ascii_case(s.as_bytes())
+    }
+
     pub fn is_inf(&self) -> bool {
         self.inf
     }
@@ -59,15 +92,63 @@ impl<'a> ParserState<'a> {
         self.sign
     }
 
-    /// Returns mantissa digits, mantissa length, sign, exponent.
-    pub fn raw_parts(&self) -> (&[u8], Sign, Exponent) {
-        (&self.mantissa_bytes, self.sign, self.e as Exponent)
+    /// Returns mantissa digits, sign, exponent.
+    /// For hexadecimal floating-point literals the exponent is binary.
+    pub fn into_raw_parts(self) -> (PackedDigits, Sign, isize) {
+        (self.digits, self.sign, self.e)
     }
 }
 
 /// Parse BigFloat.
-pub fn parse(s: &str, rdx: Radix) -> Result<ParserState, Error> {
-    let mut parser_state = ParserState::new(s);
+#[cfg(test)]
+pub fn parse(
+    s: &str,
+    rdx: impl Into<u32>,
+) -> Result<ParserState<impl Iterator<Item = u8> + '_>, Error> {
+    parse_iter(s.bytes(), rdx.into())
+}
+
+/// Parse BigFloat from a sequence of bytes.
+pub fn parse_iter<I: Iterator<Item = u8>>(bytes: I, rdx: u32) -> Result<ParserState<I>, Error> {
+    check_radix(rdx)?;
+
+    let mut parser_state = ParserState::new(bytes, rdx)?;
+    let mut ch = parser_state.next_char();
+
+    // sign
+    if let Some(c) = ch {
+        match c {
+            '+' => ch = parser_state.next_char(),
+            '-' => {
+                parser_state.sign = Sign::Neg;
+                ch = parser_state.next_char()
+            }
+            _ => {}
+        };
+    }
+
+    if let Some(c) = ch {
+        // in large radixes "inf" and "nan" are recognized only as whole words.
+        match c {
+            'i' if !is_radix_digit(c, rdx) || parser_state.rest_eq("nf") => {
+                parse_inf(&mut parser_state)
+            }
+            'n' if !is_radix_digit(c, rdx) || parser_state.rest_eq("an") => {
+                parse_nan(&mut parser_state)
+            }
+            '.' => parse_num(&mut parser_state, rdx)?,
+            c if is_radix_digit(c, rdx) => parse_num(&mut parser_state, rdx)?,
+            _ => {}
+        };
+    }
+
+    Ok(parser_state)
+}
+
+/// Parse hexadecimal floating-point literal, e.g. `-0x1.921fb54442d18p+1`.
+/// The prefix `0x` is optional, the exponent is a power of 2 written in decimal.
+pub fn parse_hex_float(s: &str) -> Result<ParserState<impl Iterator<Item = u8> + '_>, Error> {
+    let mut parser_state = ParserState::new(s.bytes(), Radix::Hex as u32)?;
     let mut ch = parser_state.next_char();
 
     // sign
@@ -82,14 +163,18 @@ pub fn parse(s: &str, rdx: Radix) -> Result<ParserState, Error> {
         };
     }
 
+    // prefix
+    if Some('0') == ch && parser_state.look_ahead(1).eq_ignore_ascii_case(b"x") {
+        parser_state.next_char();
+        ch = parser_state.next_char();
+    }
+
     if let Some(c) = ch {
-        match (c, rdx) {
-            ('i', _) => parse_inf(&mut parser_state),
-            ('n', _) => parse_nan(&mut parser_state),
-            ('.' | '0' | '1', Radix::Bin) => parse_num(&mut parser_state, rdx)?,
-            ('.' | '0'..='7', Radix::Oct) => parse_num(&mut parser_state, rdx)?,
-            ('.' | '0'..='9', Radix::Dec) => parse_num(&mut parser_state, rdx)?,
-            ('.' | '0'..='9' | 'a'..='f', Radix::Hex) => parse_num(&mut parser_state, rdx)?,
+        match c {
+            'i' => parse_inf(&mut parser_state),
+            'n' => parse_nan(&mut parser_state),
+            '.' => parse_hex_num(&mut parser_state)?,
+            c if is_radix_digit(c, Radix::Hex as u32) => parse_hex_num(&mut parser_state)?,
             _ => {}
         };
     }
@@ -97,7 +182,7 @@ pub fn parse(s: &str, rdx: Radix) -> Result<ParserState, Error> {
     Ok(parser_state)
 }
 
-fn parse_inf(parser_state: &mut ParserState) {
+fn parse_inf<I: Iterator<Item = u8>>(parser_state: &mut ParserState<I>) {
     let n = parser_state.next_char();
     let f = parser_state.next_char();
     if Some('n') == n && Some('f') == f {
@@ -106,7 +191,7 @@ fn parse_inf(parser_state: &mut ParserState) {
     }
 }
 
-fn parse_nan(parser_state: &mut ParserState) {
+fn parse_nan<I: Iterator<Item = u8>>(parser_state: &mut ParserState<I>) {
     let a = parser_state.next_char();
     let n = parser_state.next_char();
     if Some('n') == n && Some('a') == a {
@@ -114,7 +199,10 @@ fn parse_nan(parser_state: &mut ParserState) {
     }
 }
 
-fn parse_num(parser_state: &mut ParserState, rdx: Radix) -> Result<(), Error> {
+fn parse_num<I: Iterator<Item = u8>>(
+    parser_state: &mut ParserState<I>,
+    rdx: u32,
+) -> Result<(), Error> {
     let (int_len, skip_cnt1) = parse_digits(parser_state, true, true, rdx)?;
     if Some('.') == parser_state.cur_char() {
         parser_state.next_char();
@@ -122,7 +210,7 @@ fn parse_num(parser_state: &mut ParserState, rdx: Radix) -> Result<(), Error> {
     let (frac_len, _) = parse_digits(parser_state, false, false, rdx)?;
     if frac_len > 0 || int_len > 0 {
         parser_state.nan = false;
-        if rdx == Radix::Hex {
+        if is_radix_digit('e', rdx) {
             if Some('_') == parser_state.cur_char() {
                 parser_state.next_char();
                 if Some('e') == parser_state.cur_char() {
@@ -139,10 +227,7 @@ fn parse_num(parser_state: &mut ParserState, rdx: Radix) -> Result<(), Error> {
         }
 
         if parser_state.e < EXPONENT_MIN as isize {
-            let mut zero = Vec::new();
-            zero.try_reserve_exact(1)?;
-            zero.push(0);
-            parser_state.mantissa_bytes = zero;
+            parser_state.digits.clear();
             parser_state.e = 0;
         } else if parser_state.e > EXPONENT_MAX as isize {
             parser_state.inf = true;
@@ -155,11 +240,36 @@ fn parse_num(parser_state: &mut ParserState, rdx: Radix) -> Result<(), Error> {
     Ok(())
 }
 
-fn parse_digits(
-    parser_state: &mut ParserState,
+fn parse_hex_num<I: Iterator<Item = u8>>(parser_state: &mut ParserState<I>) -> Result<(), Error> {
+    let (int_len, skip_cnt1) = parse_digits(parser_state, true, true, Radix::Hex as u32)?;
+    if Some('.') == parser_state.cur_char() {
+        parser_state.next_char();
+    }
+    let (frac_len, _) = parse_digits(parser_state, false, false, Radix::Hex as u32)?;
+    if frac_len > 0 || int_len > 0 {
+        parser_state.nan = false;
+        if Some('p') == parser_state.cur_char() {
+            parser_state.next_char();
+            parse_exp(parser_state, Radix::Dec as u32);
+        }
+
+        // the exponent is in bits, and each hexadecimal digit of the integer part adds 4 bits.
+        parser_state.e = parser_state
+            .e
+            .saturating_add((int_len as isize).saturating_mul(4));
+    } else if skip_cnt1 > 0 {
+        // just zeroes
+        parser_state.nan = false;
+    }
+
+    Ok(())
+}
+
+fn parse_digits<I: Iterator<Item = u8>>(
+    parser_state: &mut ParserState<I>,
     skip_zeroes: bool,
     int: bool,
-    rdx: Radix,
+    rdx: u32,
 ) -> Result<(usize, usize), Error> {
     let mut ch = parser_state.cur_char();
     let mut len = 0;
@@ -168,7 +278,7 @@ fn parse_digits(
     if skip_zeroes {
         // skip leading zeroes
         while let Some(c) = ch {
-            if is_radix_digit(c, rdx) && c.to_digit(rdx as u32).unwrap() == 0 {
+            if is_radix_digit(c, rdx) && c.to_digit(rdx).unwrap() == 0 {
                 // call to unwrap() is unreachable, because c is surely a digit.
                 skip_cnt += 1;
                 if !int {
@@ -182,15 +292,9 @@ fn parse_digits(
     }
 
     if ch.is_some() && is_radix_digit(ch.unwrap(), rdx) {
-        parser_state
-            .mantissa_bytes
-            .try_reserve_exact(parser_state.s_len)?;
-
         while let Some(c) = ch {
             if is_radix_digit(c, rdx) {
-                parser_state
-                    .mantissa_bytes
-                    .push(c.to_digit(rdx as u32).unwrap() as u8); // call to unwrap() is unreachable, because c is surely a digit.
+                parser_state.digits.push(c.to_digit(rdx).unwrap() as u8)?; // call to unwrap() is unreachable, because c is surely a digit.
                 len += 1;
             } else {
                 break;
@@ -207,23 +311,11 @@ fn parse_digits(
     Ok((len, skip_cnt))
 }
 
-fn is_radix_digit(c: char, rdx: Radix) -> bool {
-    matches!(
-        (rdx, c),
-        (Radix::Bin, '0' | '1')
-            | (Radix::Oct, '0'..='7')
-            | (
-                Radix::Dec,
-                '0'..='9'
-            )
-            | (
-                Radix::Hex,
-                '0'..='9' | 'a'..='f'
-            )
-    )
+fn is_radix_digit(c: char, rdx: u32) -> bool {
+    c.is_digit(rdx)
 }
 
-fn parse_exp(parser_state: &mut ParserState, rdx: Radix) {
+fn parse_exp<I: Iterator<Item = u8>>(parser_state: &mut ParserState<I>, rdx: u32) {
     let mut neg = false;
     let mut ch = parser_state.cur_char();
     if let Some(c) = ch {
@@ -245,7 +337,7 @@ fn parse_exp(parser_state: &mut ParserState, rdx: Radix) {
                 break;
             }
             parser_state.e = parser_state.e.saturating_mul(rdx as isize);
-            let digit = c.to_digit(rdx as u32).unwrap(); // call to unwrap() is unreachable, because c is surely a digit.
+            let digit = c.to_digit(rdx).unwrap(); // call to unwrap() is unreachable, because c is surely a digit.
             parser_state.e = parser_state.e.saturating_add(digit as isize);
         } else {
             break;
@@ -262,8 +354,13 @@ mod tests {
 
     use super::*;
 
+    fn parts<I: Iterator<Item = u8>>(ps: ParserState<I>) -> (Vec<u8>, Sign, isize) {
+        let (m, s, e) = ps.into_raw_parts();
+        (m.to_digits(), s, e)
+    }
+
     #[cfg(not(feature = "std"))]
-    use {alloc::format, alloc::string::String, alloc::vec};
+    use {alloc::format, alloc::string::String, alloc::vec, alloc::vec::Vec};
 
     #[test]
     pub fn test_parser() {
@@ -306,7 +403,7 @@ mod tests {
                     assert!(!ps.is_inf());
                     assert!(!ps.is_nan());
 
-                    let (m, s, e) = ps.raw_parts();
+                    let (m, s, e) = parts(ps);
                     assert!(s == expected_signs[i]);
                     assert!(m == expected_mantissas[j]);
                     assert!(m.len() == expected_mantissa_len[j]);
@@ -342,21 +439,21 @@ mod tests {
 
         // bin
         let ps = parse("101.00101e+1101", Radix::Bin).unwrap();
-        let (m, s, e) = ps.raw_parts();
+        let (m, s, e) = parts(ps);
         assert!(m == [1, 0, 1, 0, 0, 1, 0, 1]);
         assert!(s == Sign::Pos);
         assert!(e == 16);
 
         // oct
         let ps = parse("2670.343e+703", Radix::Oct).unwrap();
-        let (m, s, e) = ps.raw_parts();
+        let (m, s, e) = parts(ps);
         assert!(m == [2, 6, 7, 0, 3, 4, 3]);
         assert!(s == Sign::Pos);
         assert!(e == 0o707);
 
         // hex
         let ps = parse("abc.def09123e_e-1fa", Radix::Hex).unwrap();
-        let (m, s, e) = ps.raw_parts();
+        let (m, s, e) = parts(ps);
         assert!(m == [10, 11, 12, 13, 14, 15, 0, 9, 1, 2, 3, 14]);
         assert!(s == Sign::Pos);
         assert!(e == -0x1f7);
@@ -402,9 +499,9 @@ mod tests {
         let ps = parse(&numstr, Radix::Hex).unwrap();
         assert!(!ps.is_inf());
         assert!(!ps.is_nan());
-        let (m, _s, e) = ps.raw_parts();
+        let (m, _s, e) = parts(ps);
         assert_eq!(m, [0, 0, 0, 0, 0xa, 0xb, 0xc]);
-        assert_eq!(e, EXPONENT_MAX);
+        assert_eq!(e, EXPONENT_MAX as isize);
 
         // small exp
         let numstr = format!(
@@ -414,7 +511,7 @@ mod tests {
         let ps = parse(&numstr, Radix::Hex).unwrap();
         assert!(!ps.is_inf());
         assert!(!ps.is_nan());
-        let (m, _s, e) = ps.raw_parts();
+        let (m, _s, e) = parts(ps);
         assert_eq!(m.iter().filter(|&&x| x != 0).count(), 0);
         assert!(e == 0);
 
@@ -425,12 +522,12 @@ mod tests {
         let ps = parse(&numstr, Radix::Hex).unwrap();
         assert!(!ps.is_inf());
         assert!(!ps.is_nan());
-        let (m, _s, e) = ps.raw_parts();
+        let (m, _s, e) = parts(ps);
         assert_eq!(
             m,
             [0, 0, 0, 0, 0xa, 0xb, 0xc, 0xd, 0xe, 0xf, 0x0, 0x9, 0x1, 0x2, 0x3, 0xe]
         );
-        assert_eq!(e, EXPONENT_MIN);
+        assert_eq!(e, EXPONENT_MIN as isize);
 
         let ps = parse(
             "abc.def09123e_e-ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff",
@@ -439,8 +536,29 @@ mod tests {
         .unwrap();
         assert!(!ps.is_inf());
         assert!(!ps.is_nan());
-        let (m, _s, e) = ps.raw_parts();
+        let (m, _s, e) = parts(ps);
         assert_eq!(m.iter().filter(|&&x| x != 0).count(), 0);
         assert!(e == 0);
+
+        // hexadecimal floating-point literals
+        let ps = parse_hex_float("-0x1a.3Fp-17").unwrap();
+        let (m, s, e) = parts(ps);
+        assert!(m == [1, 10, 3, 15]);
+        assert!(s == Sign::Neg);
+        assert!(e == -9);
+
+        let ps = parse_hex_float("0X.00c").unwrap();
+        let (m, s, e) = parts(ps);
+        assert!(m == [0, 0, 12]);
+        assert!(s == Sign::Pos);
+        assert!(e == 0);
+
+        let ps = parse_hex_float("0x0p+5").unwrap();
+        assert!(!ps.is_nan());
+        assert!(parts(ps).0.is_empty());
+
+        assert!(parse_hex_float("Inf").unwrap().is_inf());
+        assert!(parse_hex_float("nan").unwrap().is_nan());
+        assert!(parse_hex_float("0xp1").unwrap().is_nan());
     }
 }
@@ -0,0 +1,554 @@
+//! Exact rational numbers.
+
+use crate::defs::Error;
+use crate::defs::Radix;
+use crate::defs::RoundingMode;
+use crate::defs::Sign;
+use crate::defs::WORD_BIT_SIZE;
+use crate::ext::BigFloat;
+use crate::int::BigInt;
+use crate::int::BigUint;
+use core::cmp::Ordering;
+use core::fmt::Display;
+use core::fmt::Formatter;
+use core::hash::Hash;
+use core::hash::Hasher;
+use core::str::FromStr;
+
+#[cfg(not(feature = "std"))]
+use alloc::string::String;
+
+/// Exact rational number represented by an integer numerator and a positive integer denominator.
+/// The numerator and the denominator are always coprime, and zero is represented as 0/1.
+#[derive(Debug)]
+pub struct BigRational {
+    num: BigInt,
+    den: BigUint,
+}
+
+impl BigRational {
+    /// Constructs the number `num`/`den` and reduces the fraction.
+    ///
+    /// ## Errors
+    ///
+    ///  - MemoryAllocation: failed to allocate memory.
+    ///  - DivisionByZero: `den` is zero.
+    pub fn new(num: BigInt, den: BigUint) -> Result<Self, Error> {
+        if den.is_zero() {
+            return Err(Error::DivisionByZero);
+        }
+
+        let g = num.magnitude().gcd(&den)?;
+
+        if g.to_u64() == Some(1) {
+            Ok(BigRational { num, den })
+        } else {
+            let (s, m) = num.into_parts();
+
+            Ok(BigRational {
+                num: BigInt::from_parts(s, m.div(&g)?),
+                den: den.div(&g)?,
+            })
+        }
+    }
+
+    /// Constructs the number from the integer `num`.
+    ///
+    /// ## Errors
+    ///
+    ///  - MemoryAllocation: failed to allocate memory.
+    pub fn from_int(num: BigInt) -> Result<Self, Error> {
+        Ok(BigRational {
+            num,
+            den: BigUint::from_u64(1)?,
+        })
+    }
+
+    /// Constructs the number `num`/`den` from primitive values and reduces the fraction.
+    ///
+    /// ## Errors
+    ///
+    ///  - MemoryAllocation: failed to allocate memory.
+    ///  - DivisionByZero: `den` is zero.
+    pub fn from_i64(num: i64, den: u64) -> Result<Self, Error> {
+        Self::new(BigInt::from_i64(num)?, BigUint::from_u64(den)?)
+    }
+
+    /// Returns zero.
+    ///
+    /// ## Errors
+    ///
+    ///  - MemoryAllocation: failed to allocate memory.
+    pub fn zero() -> Result<Self, Error> {
+        Self::from_int(BigInt::zero())
+    }
+
+    /// Returns the numerator.
+    pub fn numer(&self) -> &BigInt {
+        &self.num
+    }
+
+    /// Returns the denominator.
+    pub fn denom(&self) -> &BigUint {
+        &self.den
+    }
+
+    /// Decomposes `self` into the numerator and the denominator.
+    pub fn into_parts(self) -> (BigInt, BigUint) {
+        (self.num, self.den)
+    }
+
+    /// Returns true if `self` is zero.
+    pub fn is_zero(&self) -> bool {
+        self.num.is_zero()
+    }
+
+    /// Returns true if `self` is an integer.
+    pub fn is_int(&self) -> bool {
+        self.den.to_u64() == Some(1)
+    }
+
+    /// Returns the sign of `self`. The sign of zero is positive.
+    pub fn sign(&self) -> Sign {
+        self.num.sign()
+    }
+
+    /// Returns true if `self` is negative.
+    pub fn is_negative(&self) -> bool {
+        self.num.is_negative()
+    }
+
+    /// Returns a copy of `self`.
+    ///
+    /// ## Errors
+    ///
+    ///  - MemoryAllocation: failed to allocate memory.
+    #[allow(clippy::should_implement_trait)]
+    pub fn clone(&self) -> Result<Self, Error> {
+        Ok(BigRational {
+            num: self.num.clone()?,
+            den: self.den.clone()?,
+        })
+    }
+
+    /// Returns the negated value of `self`.
+    ///
+    /// ## Errors
+    ///
+    ///  - MemoryAllocation: failed to allocate memory.
+    pub fn neg(&self) -> Result<Self, Error> {
+        Ok(BigRational {
+            num: self.num.neg()?,
+            den: self.den.clone()?,
+        })
+    }
+
+    /// Returns the absolute value of `self`.
+    ///
+    /// ## Errors
+    ///
+    ///  - MemoryAllocation: failed to allocate memory.
+    pub fn abs(&self) -> Result<Self, Error> {
+        Ok(BigRational {
+            num: self.num.abs()?,
+            den: self.den.clone()?,
+        })
+    }
+
+    /// Returns the reciprocal of `self`.
+    ///
+    /// ## Errors
+    ///
+    ///  - MemoryAllocation: failed to allocate memory.
+    ///  - DivisionByZero: `self` is zero.
+    pub fn recip(&self) -> Result<Self, Error> {
+        if self.is_zero() {
+            return Err(Error::DivisionByZero);
+        }
+
+        // the fraction stays reduced.
+        Ok(BigRational {
+            num: BigInt::from_parts(self.num.sign(), self.den.clone()?),
+            den: self.num.magnitude().clone()?,
+        })
+    }
+
+    // Returns `a` multiplied by `b`.
+    fn mul_int(a: &BigInt, b: &BigUint) -> Result<BigInt, Error> {
+        Ok(BigInt::from_parts(a.sign(), a.magnitude().mul(b)?))
+    }
+
+    /// Adds `d2` to `self` and returns the result of the operation.
+    ///
+    /// ## Errors
+    ///
+    ///  - MemoryAllocation: failed to allocate memory.
+    pub fn add(&self, d2: &Self) -> Result<Self, Error> {
+        let a = Self::mul_int(&self.num, &d2.den)?;
+        let b = Self::mul_int(&d2.num, &self.den)?;
+
+        Self::new(a.add(&b)?, self.den.mul(&d2.den)?)
+    }
+
+    /// Subtracts `d2` from `self` and returns the result of the operation.
+    ///
+    /// ## Errors
+    ///
+    ///  - MemoryAllocation: failed to allocate memory.
+    pub fn sub(&self, d2: &Self) -> Result<Self, Error> {
+        let a = Self::mul_int(&self.num, &d2.den)?;
+        let b = Self::mul_int(&d2.num, &self.den)?;
+
+        Self::new(a.sub(&b)?, self.den.mul(&d2.den)?)
+    }
+
+    /// Multiplies `self` by `d2` and returns the result of the operation.
+    ///
+    /// ## Errors
+    ///
+    ///  - MemoryAllocation: failed to allocate memory.
+    pub fn mul(&self, d2: &Self) -> Result<Self, Error> {
+        Self::new(self.num.mul(&d2.num)?, self.den.mul(&d2.den)?)
+    }
+
+    /// Divides `self` by `d2` and returns the result of the operation.
+    ///
+    /// ## Errors
+    ///
+    ///  - MemoryAllocation: failed to allocate memory.
+    ///  - DivisionByZero: `d2` is zero.
+    pub fn div(&self, d2: &Self) -> Result<Self, Error> {
+        if d2.is_zero() {
+            return Err(Error::DivisionByZero);
+        }
+
+        let num = Self::mul_int(&self.num, &d2.den)?;
+        let num = BigInt::from_parts(
+            if num.sign() == d2.num.sign() { Sign::Pos } else { Sign::Neg },
+            num.into_parts().1,
+        );
+
+        Self::new(num, self.den.mul(d2.num.magnitude())?)
+    }
+
+    /// Computes `self` to the power of `n`.
+    ///
+    /// ## Errors
+    ///
+    ///  - MemoryAllocation: failed to allocate memory.
+    pub fn pow(&self, n: usize) -> Result<Self, Error> {
+        // powers of coprime numbers are coprime.
+        Ok(BigRational {
+            num: self.num.pow(n)?,
+            den: self.den.pow(n)?,
+        })
+    }
+
+    /// Converts `self` to BigFloat with precision `p` using rounding mode `rm`.
+    /// The result is correctly rounded.
+    /// Precision is rounded upwards to the word size.
+    /// The function returns NaN if the precision is incorrect,
+    /// and Inf if the result is too large.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// # use astro_float_num::BigFloat;
+    /// # use astro_float_num::BigRational;
+    /// # use astro_float_num::RoundingMode;
+    /// let r = BigRational::from_i64(-1, 3).unwrap();
+    /// let f = r.to_big_float(64, RoundingMode::ToEven);
+    /// let third = BigFloat::from_i8(-1, 64).div(&BigFloat::from_i8(3, 64), 64, RoundingMode::ToEven);
+    /// assert_eq!(f, third);
+    ///
+    /// let r = BigRational::from_big_float(&BigFloat::from_f64(0.375, 64)).unwrap();
+    /// assert_eq!(r, BigRational::from_i64(3, 8).unwrap());
+    /// ```
+    pub fn to_big_float(&self, p: usize, rm: RoundingMode) -> BigFloat {
+        let num = self.num.to_big_float(usize::MAX, RoundingMode::None);
+        let den = self.den.to_big_float(usize::MAX, RoundingMode::None);
+
+        if self.is_int() {
+            let mut ret = num;
+            if let Err(e) = ret.set_precision(p, rm) {
+                return BigFloat::nan(Some(e));
+            }
+            ret
+        } else {
+            num.div(&den, p, rm)
+        }
+    }
+
+    /// Converts `x` to a rational number exactly.
+    ///
+    /// ## Errors
+    ///
+    ///  - MemoryAllocation: failed to allocate memory.
+    ///  - InvalidArgument: `x` is Inf or NaN.
+    pub fn from_big_float(x: &BigFloat) -> Result<Self, Error> {
+        let (m, n, s, e, _) = x.as_raw_parts().ok_or(Error::InvalidArgument)?;
+
+        if n == 0 {
+            return Self::zero();
+        }
+
+        // the value is `m` * 2^(e - bits in m).
+        let l = m.len() * WORD_BIT_SIZE;
+        let e = e as isize - l as isize;
+        let m = BigUint::from_words(m)?;
+
+        if e >= 0 {
+            Self::from_int(BigInt::from_parts(s, m.shl(e as usize)?))
+        } else {
+            // the denominator is a power of 2, and the fraction is reduced by removing common factors of 2.
+            let k = m.trailing_zeros().unwrap_or(0).min(e.unsigned_abs()); // m is not zero
+            Ok(BigRational {
+                num: BigInt::from_parts(s, m.shr(k)?),
+                den: BigUint::from_u64(1)?.shl(e.unsigned_abs() - k)?,
+            })
+        }
+    }
+
+    /// Parses the number from the string `s` in radix `rdx`.
+    /// The string contains an integer numerator optionally followed by "/" and a positive integer denominator.
+    ///
+    /// ## Errors
+    ///
+    ///  - MemoryAllocation: failed to allocate memory.
+    ///  - InvalidArgument: the string has incorrect format, or the radix is not in the range from 2 to 36.
+    ///  - DivisionByZero: the denominator is zero.
+    pub fn parse(s: &str, rdx: impl Into<u32>) -> Result<Self, Error> {
+        let rdx = rdx.into();
+
+        match s.split_once('/') {
+            Some((num, den)) => {
+                if den.starts_with('+') {
+                    return Err(Error::InvalidArgument);
+                }
+
+                Self::new(BigInt::parse(num, rdx)?, BigUint::parse(den, rdx)?)
+            }
+            None => Self::from_int(BigInt::parse(s, rdx)?),
+        }
+    }
+
+    /// Formats the number using radix `rdx` as "numerator/denominator",
+    /// or as "numerator" if the denominator is 1.
+    ///
+    /// ## Errors
+    ///
+    ///  - MemoryAllocation: failed to allocate memory.
+    ///  - InvalidArgument: the radix is not in the range from 2 to 36.
+    pub fn format(&self, rdx: impl Into<u32>) -> Result<String, Error> {
+        let rdx = rdx.into();
+        let mut ret = self.num.format(rdx)?;
+
+        if !self.is_int() {
+            let den = self.den.format(rdx)?;
+            ret.try_reserve_exact(den.len() + 1)?;
+            ret.push('/');
+            ret.push_str(&den);
+        }
+
+        Ok(ret)
+    }
+}
+
+impl PartialEq for BigRational {
+    fn eq(&self, other: &Self) -> bool {
+        // the representation is unique.
+        self.num == other.num && self.den == other.den
+    }
+}
+
+impl Eq for BigRational {}
+
+impl Hash for BigRational {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.num.hash(state);
+        self.den.hash(state);
+    }
+}
+
+impl PartialOrd for BigRational {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        if self.sign() != other.sign() {
+            return Some(self.num.cmp(&other.num));
+        }
+
+        // compare a*d with c*b; on allocation failure numbers are not comparable.
+        let a = Self::mul_int(&self.num, &other.den).ok()?;
+        let b = Self::mul_int(&other.num, &self.den).ok()?;
+
+        Some(a.cmp(&b))
+    }
+}
+
+impl Display for BigRational {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        let s = self.format(Radix::Dec).map_err(|_| core::fmt::Error)?;
+        f.pad(&s)
+    }
+}
+
+impl FromStr for BigRational {
+    type Err = Error;
+
+    /// Parses a decimal number.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s, Radix::Dec)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::int::BigInt;
+    use crate::Word;
+    use crate::INF_POS;
+    use crate::NAN;
+    use rand::random;
+
+    #[cfg(not(feature = "std"))]
+    use {alloc::format, alloc::vec::Vec};
+
+    fn rat(n: i64, d: u64) -> BigRational {
+        BigRational::from_i64(n, d).unwrap()
+    }
+
+    fn random_rat(max_words: usize) -> BigRational {
+        let random_uint = |min| {
+            let l = random::<usize>() % max_words + min;
+            let m: Vec<Word> = (0..l).map(|_| random()).collect();
+            BigUint::from_words(&m).unwrap()
+        };
+
+        let s = if random::<bool>() { Sign::Pos } else { Sign::Neg };
+        let num = BigInt::from_parts(s, random_uint(0));
+        let mut den = random_uint(1);
+        if den.is_zero() {
+            den = BigUint::from_u64(1).unwrap();
+        }
+
+        BigRational::new(num, den).unwrap()
+    }
+
+    #[test]
+    fn test_rational() {
+        // normalization
+        let r = rat(-6, 4);
+        assert_eq!(r.numer().to_i64(), Some(-3));
+        assert_eq!(r.denom().to_u64(), Some(2));
+        assert_eq!(r, rat(3, 1).div(&rat(-2, 1)).unwrap());
+        assert_eq!(rat(0, 5).denom().to_u64(), Some(1));
+        assert!(BigRational::from_i64(1, 0) == Err(Error::DivisionByZero));
+        assert!(rat(1, 2).div(&rat(0, 1)) == Err(Error::DivisionByZero));
+        assert!(rat(0, 2).recip() == Err(Error::DivisionByZero));
+
+        // arithmetic
+        assert_eq!(rat(1, 2).add(&rat(1, 3)).unwrap(), rat(5, 6));
+        assert_eq!(rat(1, 2).sub(&rat(1, 3)).unwrap(), rat(1, 6));
+        assert_eq!(rat(1, 2).sub(&rat(1, 2)).unwrap(), rat(0, 1));
+        assert_eq!(rat(-2, 3).mul(&rat(9, 4)).unwrap(), rat(-3, 2));
+        assert_eq!(rat(-2, 3).div(&rat(-4, 9)).unwrap(), rat(3, 2));
+        assert_eq!(rat(-2, 3).recip().unwrap(), rat(-3, 2));
+        assert_eq!(rat(-2, 3).pow(3).unwrap(), rat(-8, 27));
+
+        // comparison
+        assert!(rat(1, 3) < rat(1, 2));
+        assert!(rat(-1, 2) < rat(-1, 3));
+        assert!(rat(-1, 2) < rat(0, 1));
+        assert!(rat(7, 3) > rat(2, 1));
+
+        // formatting and parsing
+        assert_eq!(rat(-6, 4).to_string(), "-3/2");
+        assert_eq!(rat(8, 4).to_string(), "2");
+        assert_eq!(format!("{:>5}", rat(1, 2)), "  1/2");
+        assert_eq!(BigRational::from_str("-10/4").unwrap(), rat(-5, 2));
+        assert_eq!(BigRational::from_str("+7").unwrap(), rat(7, 1));
+        assert_eq!(BigRational::parse("-a/c", Radix::Hex).unwrap(), rat(-5, 6));
+        assert!(BigRational::from_str("1/0") == Err(Error::DivisionByZero));
+        assert!(BigRational::from_str("1/-2").is_err());
+        assert!(BigRational::from_str("1/+2").is_err());
+        assert!(BigRational::from_str("1/").is_err());
+        assert!(BigRational::from_str("/2").is_err());
+
+        for _ in 0..200 {
+            let a = random_rat(10);
+            let b = random_rat(10);
+
+            // (a + b) - b = a, (a * b) / b = a
+            assert_eq!(a.add(&b).unwrap().sub(&b).unwrap(), a);
+            if !b.is_zero() {
+                assert_eq!(a.mul(&b).unwrap().div(&b).unwrap(), a);
+            }
+
+            // reduced form
+            assert_eq!(
+                a.numer().magnitude().gcd(a.denom()).unwrap().to_u64(),
+                Some(1)
+            );
+
+            assert_eq!(BigRational::from_str(&a.to_string()).unwrap(), a);
+
+            // a < b iff a - b < 0
+            assert_eq!(
+                a.partial_cmp(&b),
+                a.sub(&b).unwrap().numer().cmp(&BigInt::zero()).into()
+            );
+        }
+    }
+
+    #[test]
+    fn test_rational_conv() {
+        // exact conversion from BigFloat and back
+        for _ in 0..1000 {
+            let f = BigFloat::from_f64(
+                f64::from_bits(
+                    random::<u64>() & !(0x7ff << 52) | ((random::<u64>() % 2046 + 1) << 52),
+                ),
+                64,
+            );
+            let r = BigRational::from_big_float(&f).unwrap();
+            assert_eq!(r.to_big_float(64, RoundingMode::None), f);
+        }
+
+        let r = BigRational::from_big_float(&BigFloat::from_f64(-0.375, 64)).unwrap();
+        assert_eq!(r, rat(-3, 8));
+        let r = BigRational::from_big_float(&BigFloat::from_f64(1536.0, 64)).unwrap();
+        assert_eq!(r, rat(1536, 1));
+        let r = BigRational::from_big_float(&BigFloat::from_f64(0.0, 64)).unwrap();
+        assert!(r.is_zero());
+        assert!(BigRational::from_big_float(&INF_POS) == Err(Error::InvalidArgument));
+        assert!(BigRational::from_big_float(&NAN) == Err(Error::InvalidArgument));
+
+        // correct rounding
+        let p = 128;
+        for _ in 0..1000 {
+            let r = random_rat(4);
+            if r.is_zero() {
+                continue;
+            }
+
+            for rm in [RoundingMode::Up, RoundingMode::Down] {
+                let f = r.to_big_float(p, rm);
+                let fr = BigRational::from_big_float(&f).unwrap();
+
+                // the result is on the correct side of the exact value,
+                // and the exact value is less than 1 ulp away.
+                let d = fr.sub(&r).unwrap();
+                if rm == RoundingMode::Up {
+                    assert!(!d.is_negative());
+                } else {
+                    assert!(d.is_negative() || d.is_zero());
+                }
+
+                let mut ulp = BigFloat::from_word(1, p);
+                ulp.set_exponent(f.exponent().unwrap() - p as crate::Exponent + 1);
+                let ulp = BigRational::from_big_float(&ulp).unwrap();
+                assert!(d.abs().unwrap() < ulp);
+            }
+        }
+    }
+}
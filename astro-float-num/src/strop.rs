@@ -1,21 +1,106 @@
 //! BigFloatNumber formatting.
 
+use crate::common::util::log2_ceil;
+use crate::common::util::log2_floor;
+use crate::conv::LOGRDX_2;
+use crate::defs::check_radix;
 use crate::defs::Error;
-use crate::defs::Radix;
 use crate::defs::RoundingMode;
+use crate::defs::Word;
+use crate::defs::EXPONENT_MAX;
+use crate::defs::WORD_BIT_SIZE;
+use crate::mantissa::Mantissa;
 use crate::num::BigFloatNumber;
 use crate::Consts;
 use crate::Exponent;
 use crate::Sign;
 
-#[cfg(feature = "std")]
-use std::fmt::Write;
-
 #[cfg(not(feature = "std"))]
-use {alloc::string::String, core::fmt::Write};
+use {alloc::string::String, alloc::vec::Vec};
+
+pub(crate) const DIGIT_CHARS: [char; 36] = [
+    '0', '1', '2', '3', '4', '5', '6', '7', '8', '9', 'A', 'B', 'C', 'D', 'E', 'F', 'G', 'H', 'I',
+    'J', 'K', 'L', 'M', 'N', 'O', 'P', 'Q', 'R', 'S', 'T', 'U', 'V', 'W', 'X', 'Y', 'Z',
+];
+
+/// Parameters of the fixed-point (positional) formatting of a number.
+///
+/// By default, the number is formatted with exactly the specified number of digits after the radix point,
+/// without grouping of the digits of the integer part, and with "." as the radix point.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FixedFormat {
+    frac_digits: usize,
+    pad_zeroes: bool,
+    group_sep: Option<char>,
+    group_len: usize,
+    decimal_sep: char,
+}
+
+impl FixedFormat {
+    /// Creates formatting parameters with at most `frac_digits` digits after the radix point.
+    pub fn new(frac_digits: usize) -> Self {
+        FixedFormat {
+            frac_digits,
+            pad_zeroes: true,
+            group_sep: None,
+            group_len: 3,
+            decimal_sep: '.',
+        }
+    }
+
+    /// Sets the maximum number of digits after the radix point.
+    pub fn set_frac_digits(&mut self, frac_digits: usize) {
+        self.frac_digits = frac_digits;
+    }
+
+    /// Sets whether the fractional part is padded with trailing zeroes up to the maximum number of digits.
+    /// If `pad_zeroes` is false, trailing zeroes are removed, and the radix point is omitted for integer values.
+    pub fn set_pad_zeroes(&mut self, pad_zeroes: bool) {
+        self.pad_zeroes = pad_zeroes;
+    }
 
-const DIGIT_CHARS: [char; 16] =
-    ['0', '1', '2', '3', '4', '5', '6', '7', '8', '9', 'A', 'B', 'C', 'D', 'E', 'F'];
+    /// Sets the separator of the groups of digits in the integer part.
+    /// If `group_sep` is None, the digits are not grouped.
+    pub fn set_group_separator(&mut self, group_sep: Option<char>) {
+        self.group_sep = group_sep;
+    }
+
+    /// Sets the number of digits in a group of the integer part.
+    /// Digits are not grouped if `group_len` is 0.
+    pub fn set_group_len(&mut self, group_len: usize) {
+        self.group_len = group_len;
+    }
+
+    /// Sets the character used as the radix point.
+    pub fn set_decimal_separator(&mut self, decimal_sep: char) {
+        self.decimal_sep = decimal_sep;
+    }
+
+    /// Returns the maximum number of digits after the radix point.
+    pub fn frac_digits(&self) -> usize {
+        self.frac_digits
+    }
+
+    /// Returns true if the fractional part is padded with trailing zeroes.
+    pub fn pad_zeroes(&self) -> bool {
+        self.pad_zeroes
+    }
+
+    /// Returns the separator of the groups of digits in the integer part.
+    pub fn group_separator(&self) -> Option<char> {
+        self.group_sep
+    }
+
+    /// Returns the number of digits in a group of the integer part.
+    pub fn group_len(&self) -> usize {
+        self.group_len
+    }
+
+    /// Returns the character used as the radix point.
+    pub fn decimal_separator(&self) -> char {
+        self.decimal_sep
+    }
+}
 
 impl BigFloatNumber {
     /// Parses the number from the string `s` using radix `rdx`, precision `p`, and rounding mode `rm`.
@@ -31,7 +116,7 @@ impl BigFloatNumber {
     #[cfg(test)]
     pub fn parse(
         s: &str,
-        rdx: Radix,
+        rdx: impl Into<u32>,
         p: usize,
         rm: RoundingMode,
         cc: &mut Consts,
@@ -41,8 +126,8 @@ impl BigFloatNumber {
         if ps.is_nan() || ps.is_inf() {
             Err(Error::InvalidArgument)
         } else {
-            let (m, s, e) = ps.raw_parts();
-            BigFloatNumber::convert_from_radix(s, m, e, rdx, p, rm, cc)
+            let (m, s, e) = ps.into_raw_parts();
+            BigFloatNumber::convert_from_packed(s, m, e, p, rm, cc)
         }
     }
 
@@ -55,69 +140,560 @@ impl BigFloatNumber {
     ///
     ///  - MemoryAllocation: failed to allocate memory for mantissa.
     ///  - ExponentOverflow: the resulting exponent becomes greater than the maximum allowed value for the exponent.
-    pub fn format(&self, rdx: Radix, rm: RoundingMode, cc: &mut Consts) -> Result<String, Error> {
-        let (s, m, e) = self.convert_to_radix(rdx, rm, cc)?;
+    ///  - InvalidArgument: the radix is not in the range from 2 to 36.
+    pub fn format(
+        &self,
+        rdx: impl Into<u32>,
+        rm: RoundingMode,
+        cc: &mut Consts,
+    ) -> Result<String, Error> {
+        self.format_sci(rdx, None, rm, cc)
+    }
+
+    /// Formats the number in scientific notation using radix `rdx` and rounding mode `rm`.
+    /// If `n` is specified, the mantissa is rounded to `n` digits after the radix point,
+    /// otherwise all significant digits are formatted.
+    ///
+    /// ## Errors
+    ///
+    ///  - MemoryAllocation: failed to allocate memory for mantissa.
+    ///  - ExponentOverflow: the resulting exponent becomes greater than the maximum allowed value for the exponent.
+    ///  - InvalidArgument: the radix is not in the range from 2 to 36.
+    pub fn format_sci(
+        &self,
+        rdx: impl Into<u32>,
+        n: Option<usize>,
+        rm: RoundingMode,
+        cc: &mut Consts,
+    ) -> Result<String, Error> {
+        let rdx = check_radix(rdx.into())?;
+        let b = rdx as Word;
 
         let mut mstr = String::new();
         let mstr_sz = 8
+            + n.unwrap_or(0)
             + (self.mantissa_max_bit_len() + core::mem::size_of::<Exponent>() * 8)
-                / match rdx {
-                    Radix::Bin => 1,
-                    Radix::Oct => 3,
-                    Radix::Dec => 3,
-                    Radix::Hex => 4,
-                };
+                / log2_floor(b as usize);
 
         mstr.try_reserve_exact(mstr_sz)?;
 
+        match self.write_sci(&mut mstr, rdx, n, rm, cc) {
+            Ok(()) => Ok(mstr),
+            Err(WriteError::Num(e)) => Err(e),
+            Err(WriteError::Fmt) => Err(Error::MemoryAllocation), // writing to a string fails only if memory can't be allocated
+        }
+    }
+
+    /// Writes the number in scientific notation using radix `rdx` and rounding mode `rm` to `w`.
+    /// The output is identical to the output of `format_sci`.
+    /// The number is rounded before anything is written, and then the digits are produced
+    /// and written to `w` in small blocks, so neither the digits nor the output are collected in memory.
+    pub(crate) fn write_sci<W: core::fmt::Write>(
+        &self,
+        w: &mut W,
+        rdx: u32,
+        n: Option<usize>,
+        rm: RoundingMode,
+        cc: &mut Consts,
+    ) -> Result<(), WriteError> {
+        let b = check_radix(rdx)? as Word;
+
+        // subnormal numbers are formatted as `0.ddd`, normal numbers as `d.ddd`.
+        let n = if self.is_subnormal() { n.map(|n| n.max(1)) } else { n };
+        let sig = n.map(|n| if self.is_subnormal() { n } else { n + 1 });
+
+        // "e" is a digit in large radixes, and the exponent is separated by "_".
+        let exp_sep = if 'e'.is_digit(b as u32) { "_e" } else { "e" };
+
+        let mut out = ChunkWriter::new(w);
+
+        if self.is_zero() {
+            if self.is_negative() {
+                out.push('-')?;
+            }
+
+            match n {
+                Some(n) => {
+                    out.push('0')?;
+                    if n > 0 {
+                        out.push('.')?;
+                        for _ in 0..n {
+                            out.push('0')?;
+                        }
+                    }
+                    out.push_str(exp_sep)?;
+                    out.push_str("+0")?;
+                }
+                None => out.push_str("0.0")?,
+            }
+
+            out.flush()?;
+
+            return Ok(());
+        }
+
+        let e = if b.is_power_of_two() {
+            // digits are exact for radixes of power of 2.
+            let shift = b.trailing_zeros() as usize;
+
+            let rounded;
+            let (x, e) = match sig {
+                Some(sig) => {
+                    let (y, e) = self.round_commensurable(shift, sig, rm)?;
+                    rounded = y;
+                    (&rounded, e)
+                }
+                None => (self, self.commensurable_exponent(shift).0 as isize),
+            };
+
+            let mut digits =
+                DigitWriter::new(&mut out, self.is_negative(), self.is_subnormal(), n, sig)?;
+            x.write_commensurable(shift, &mut |d: &[u8]| digits.push(d))?;
+            digits.finish()?;
+
+            e
+        } else {
+            let nd = sig.unwrap_or(
+                (self.mantissa_max_bit_len() as u64 * LOGRDX_2[b as usize] / 1000000000) as usize
+                    + 1,
+            );
+
+            let (_, m, e) = self.conv_to_rdx_int(b, nd, rm)?;
+
+            let k = log2_ceil(nd).max(1);
+            let rdxpowers = cc.rdxpowers(b, k)?;
+
+            let mut digits =
+                DigitWriter::new(&mut out, self.is_negative(), self.is_subnormal(), n, sig)?;
+            Mantissa::from_word_buf(m.into_word_buf()).write_rdx(
+                b,
+                nd,
+                rdxpowers,
+                k - 1,
+                &mut |d: &[u8]| digits.push(d),
+            )?;
+            digits.finish()?;
+
+            e
+        };
+
+        out.push_str(exp_sep)?;
+
+        let (es, val) = if e < 1 {
+            let val = if self.is_subnormal() { e.unsigned_abs() } else { (e - 1).unsigned_abs() };
+
+            ('-', val)
+        } else {
+            ('+', (e - 1) as usize)
+        };
+
+        let mut buf = [0u8; usize::BITS as usize];
+        out.push(es)?;
+        out.push_str(exponent_str(&mut buf, val, b))?;
+
+        out.flush()?;
+
+        Ok(())
+    }
+
+    // Rounds `self` to `n` significant digits in radix 2^`shift` using rounding mode `rm`.
+    // For subnormal numbers leading zero digits are counted as significant.
+    // Returns the rounded number, and the exponent of its digits in radix 2^`shift`.
+    fn round_commensurable(
+        &self,
+        shift: usize,
+        n: usize,
+        rm: RoundingMode,
+    ) -> Result<(Self, isize), Error> {
+        let (e, e_shift) = self.commensurable_exponent(shift);
+
+        // number of bits of the mantissa in n digits
+        let nb = n * shift - e_shift;
+
+        if self.is_subnormal() {
+            let y = self.round((nb as isize - self.exponent() as isize) as usize, rm)?;
+            let e = if y.is_zero() { e } else { y.commensurable_exponent(shift).0 } as isize;
+
+            Ok((y, e))
+        } else {
+            // the exponent is moved close to 0, so that the number of bits after the point is not negative.
+            let e0 = (shift - e_shift) as Exponent;
+
+            let mut x = self.clone()?;
+            x.set_exponent(e0);
+
+            let y = x.round(nb - e0 as usize, rm)?;
+            let e = e as isize + y.commensurable_exponent(shift).0 as isize - 1;
+
+            if e > EXPONENT_MAX as isize {
+                return Err(Error::ExponentOverflow(self.sign()));
+            }
+
+            Ok((y, e))
+        }
+    }
+    /// Formats the number in fixed-point notation using radix `rdx` and formatting parameters `ff`.
+    /// The result is rounded using rounding mode `rm`.
+    ///
+    /// ## Errors
+    ///
+    ///  - MemoryAllocation: failed to allocate memory for mantissa.
+    ///  - ExponentOverflow: the resulting exponent becomes greater than the maximum allowed value for the exponent.
+    ///  - InvalidArgument: the number of digits after the radix point is too large, or the radix is not in the range from 2 to 36.
+    pub fn format_fixed(
+        &self,
+        rdx: impl Into<u32>,
+        ff: &FixedFormat,
+        rm: RoundingMode,
+        cc: &mut Consts,
+    ) -> Result<String, Error> {
+        let (s, int, mut frac) = self.fixed_digits(rdx.into(), ff.frac_digits, rm, cc)?;
+
+        if !ff.pad_zeroes {
+            let nzr = frac.iter().rev().take_while(|&&x| x == 0).count();
+            frac.truncate(frac.len() - nzr);
+        }
+
+        let group_len = if ff.group_sep.is_some() { ff.group_len } else { 0 };
+        let group_cnt = int
+            .len()
+            .saturating_sub(1)
+            .checked_div(group_len)
+            .unwrap_or(0);
+
+        let mut mstr = String::new();
+        mstr.try_reserve_exact(int.len() + frac.len() + (group_cnt + 2) * 4)?;
+
         if s == Sign::Neg {
             mstr.push('-');
         }
 
-        if m.is_empty() {
-            mstr.push_str("0.0");
+        if int.is_empty() {
+            mstr.push('0');
         } else {
-            let mut iter = m.iter();
-
-            if self.is_subnormal() {
-                mstr.push('0');
-            } else {
-                mstr.push(DIGIT_CHARS[*iter.next().unwrap() as usize]); // m is not empty as checked above, hence unwrap
+            for (i, &d) in int.iter().enumerate() {
+                if group_len > 0 && i > 0 && (int.len() - i) % group_len == 0 {
+                    mstr.push(ff.group_sep.unwrap()); // group_len is 0 if there is no separator
+                }
+                mstr.push(DIGIT_CHARS[d as usize]);
             }
+        }
+
+        if !frac.is_empty() {
+            mstr.push(ff.decimal_sep);
+            frac.iter()
+                .for_each(|&d| mstr.push(DIGIT_CHARS[d as usize]));
+        }
+
+        Ok(mstr)
+    }
 
+    /// Formats the number exactly as a hexadecimal floating-point literal with a binary exponent, e.g. `0x1.921fb54442d18p+1`.
+    /// The first hexadecimal digit of a nonzero number is always 1, and trailing zeroes of the fractional part are omitted.
+    ///
+    /// ## Errors
+    ///
+    ///  - MemoryAllocation: failed to allocate memory for the string.
+    pub fn format_hex_float(&self) -> Result<String, Error> {
+        let (m, n, s, e, _) = self.as_raw_parts();
+
+        let mut mstr = String::new();
+        mstr.try_reserve_exact(n / 4 + 32)?;
+
+        if s == Sign::Neg {
+            mstr.push('-');
+        }
+
+        mstr.push_str("0x");
+
+        if n == 0 {
+            mstr.push_str("0p+0");
+            return Ok(mstr);
+        }
+
+        let bit = |i: usize| (m[i / WORD_BIT_SIZE] >> (i % WORD_BIT_SIZE)) & 1;
+
+        // the most significant bit is at position n - 1, and the least significant nonzero bit at position tz.
+        let tz = (0..n).find(|&i| bit(i) != 0).unwrap_or(0);
+
+        mstr.push('1');
+
+        let frac_bits = n - 1 - tz;
+        if frac_bits > 0 {
             mstr.push('.');
 
-            iter.map(|&d| DIGIT_CHARS[d as usize])
-                .for_each(|v| mstr.push(v));
+            let mut i = n - 1;
+            for _ in 0..frac_bits.div_ceil(4) {
+                let mut d = 0;
+                for _ in 0..4 {
+                    d <<= 1;
+                    if i > 0 {
+                        i -= 1;
+                        d |= bit(i);
+                    }
+                }
+                mstr.push(DIGIT_CHARS[d as usize].to_ascii_lowercase());
+            }
+        }
+
+        // the value of the most significant bit is 2^(e - 1 - number of leading zeroes).
+        let e = e as isize - 1 - (m.len() * WORD_BIT_SIZE - n) as isize;
+
+        mstr.push('p');
+        mstr.push(if e < 0 { '-' } else { '+' });
+        push_exponent(&mut mstr, e.unsigned_abs(), 10);
+
+        Ok(mstr)
+    }
+
+    /// Returns the sign, the digits of the integer part without leading zeroes,
+    /// and exactly `n` digits of the fractional part of `self` in radix `rdx` rounded using rounding mode `rm`.
+    /// The sign is the sign of `self` even if the rounded value is zero.
+    pub(crate) fn fixed_digits(
+        &self,
+        rdx: u32,
+        n: usize,
+        rm: RoundingMode,
+        cc: &mut Consts,
+    ) -> Result<(Sign, Vec<u8>, Vec<u8>), Error> {
+        let b = check_radix(rdx)? as Word;
 
-            if rdx == Radix::Hex {
-                let _ = write!(mstr, "_");
+        // scale the number so that the fractional digits become integer digits,
+        // then round to an integer: both operations are exact, except for the final rounding.
+        let (x, scaled, odd_tie) = if self.is_int() {
+            (self.clone()?, false, false)
+        } else {
+            let p = n
+                .checked_mul(log2_ceil(b as usize))
+                .and_then(|v| v.checked_add(WORD_BIT_SIZE))
+                .ok_or(Error::InvalidArgument)?;
+            Self::p_assertion(p)?;
+
+            let r = Self::from_word(b, p)?.powi(n, p, RoundingMode::None)?;
+            let x = self.mul_full_prec(&r)?;
+
+            // in odd radixes a tie is resolved by the parity of the last digit, and not of the integer value.
+            let odd_tie = b % 2 == 1
+                && (rm == RoundingMode::ToEven || rm == RoundingMode::ToOdd)
+                && x.is_half_odd_int();
+
+            (
+                x.round(0, if odd_tie { RoundingMode::ToZero } else { rm })?,
+                true,
+                odd_tie,
+            )
+        };
+
+        // integer value: conversion is exact given the number of digits is sufficient.
+        let (_, mut digits, e) = if !x.is_zero() && !b.is_power_of_two() {
+            let n = (x.exponent() as u64 * LOGRDX_2[b as usize] / 1000000000) as usize + 1;
+            x.conv_to_rdx(b, n, RoundingMode::ToEven, cc)?
+        } else {
+            x.convert_to_radix(rdx, RoundingMode::ToEven, cc)?
+        };
+
+        let l = if digits.is_empty() { 0 } else { e as usize };
+        digits.try_reserve_exact(l.saturating_sub(digits.len()) + n)?;
+        digits.resize(l, 0);
+
+        if odd_tie && (rm == RoundingMode::ToEven) != (digits.last().copied().unwrap_or(0) % 2 == 0)
+        {
+            let mut c = true;
+            for d in digits.iter_mut().rev() {
+                if *d + 1 < b as u8 {
+                    *d += 1;
+                    c = false;
+                    break;
+                }
+                *d = 0;
             }
 
-            if e < 1 {
-                let val = if self.is_subnormal() {
-                    e.unsigned_abs() as usize
-                } else {
-                    (e as isize - 1).unsigned_abs()
-                };
+            if c {
+                digits.insert(0, 1);
+            }
+        }
 
-                let _ = match rdx {
-                    Radix::Bin => write!(mstr, "e-{:b}", val),
-                    Radix::Oct => write!(mstr, "e-{:o}", val),
-                    Radix::Dec => write!(mstr, "e-{}", val),
-                    Radix::Hex => write!(mstr, "e-{:x}", val),
-                };
+        let mut frac = Vec::new();
+        frac.try_reserve_exact(n)?;
+
+        if scaled {
+            let int_len = digits.len().saturating_sub(n);
+            frac.resize(n - (digits.len() - int_len), 0);
+            frac.extend_from_slice(&digits[int_len..]);
+            digits.truncate(int_len);
+        } else {
+            frac.resize(n, 0);
+        }
+
+        Ok((self.sign(), digits, frac))
+    }
+}
+
+// Append the absolute value of the exponent `val` in radix `rdx` to `s`.
+fn push_exponent(s: &mut String, val: usize, rdx: Word) {
+    let mut buf = [0u8; usize::BITS as usize];
+    s.push_str(exponent_str(&mut buf, val, rdx));
+}
+
+// Write the absolute value of the exponent `val` in radix `rdx` to the end of `buf`, and return the written part.
+fn exponent_str(buf: &mut [u8; usize::BITS as usize], mut val: usize, rdx: Word) -> &str {
+    let mut i = buf.len();
+
+    loop {
+        i -= 1;
+        buf[i] = DIGIT_CHARS[val % rdx as usize].to_ascii_lowercase() as u8;
+        val /= rdx as usize;
+        if val == 0 {
+            break;
+        }
+    }
+
+    core::str::from_utf8(&buf[i..]).unwrap() // digits are ASCII characters
+}
+
+/// Error of writing a formatted number.
+#[derive(Debug)]
+pub(crate) enum WriteError {
+    /// The number could not be formatted.
+    Num(Error),
+
+    /// The writer returned an error.
+    Fmt,
+}
+
+impl From<Error> for WriteError {
+    fn from(e: Error) -> Self {
+        WriteError::Num(e)
+    }
+}
+
+impl From<core::fmt::Error> for WriteError {
+    fn from(_: core::fmt::Error) -> Self {
+        WriteError::Fmt
+    }
+}
+
+// Writes the sign and the digits of the mantissa in scientific notation.
+// The digits are received in blocks, the most significant digit first.
+struct DigitWriter<'a, 'b, W: core::fmt::Write> {
+    out: &'a mut ChunkWriter<'b, W>,
+    point: bool,
+    subnormal: bool,
+    sig: Option<usize>,
+    cnt: usize,
+    zeroes: usize,
+}
+
+impl<'a, 'b, W: core::fmt::Write> DigitWriter<'a, 'b, W> {
+    // If `sig` is None, trailing zeroes are omitted, otherwise exactly `sig` digits are written.
+    fn new(
+        out: &'a mut ChunkWriter<'b, W>,
+        neg: bool,
+        subnormal: bool,
+        n: Option<usize>,
+        sig: Option<usize>,
+    ) -> Result<Self, WriteError> {
+        if neg {
+            out.push('-')?;
+        }
+
+        if subnormal {
+            out.push_str("0.")?;
+        }
+
+        Ok(DigitWriter {
+            out,
+            point: n != Some(0),
+            subnormal,
+            sig,
+            cnt: 0,
+            zeroes: 0,
+        })
+    }
+
+    fn push(&mut self, digits: &[u8]) -> Result<(), WriteError> {
+        for &d in digits {
+            if self.sig == Some(self.cnt) {
+                // the remaining digits are zeroes
+                break;
+            }
+
+            if self.cnt == 0 && !self.subnormal {
+                self.out.push(DIGIT_CHARS[d as usize])?;
+                if self.point {
+                    self.out.push('.')?;
+                }
+            } else if d == 0 && self.sig.is_none() {
+                self.zeroes += 1;
             } else {
-                let _ = match rdx {
-                    Radix::Bin => write!(mstr, "e+{:b}", e as isize - 1),
-                    Radix::Oct => write!(mstr, "e+{:o}", e as isize - 1),
-                    Radix::Dec => write!(mstr, "e+{}", e as isize - 1),
-                    Radix::Hex => write!(mstr, "e+{:x}", e as isize - 1),
-                };
-            };
+                for _ in 0..self.zeroes {
+                    self.out.push('0')?;
+                }
+                self.zeroes = 0;
+
+                self.out.push(DIGIT_CHARS[d as usize])?;
+            }
+
+            self.cnt += 1;
         }
 
-        Ok(mstr)
+        Ok(())
+    }
+
+    fn finish(self) -> Result<(), WriteError> {
+        if let Some(sig) = self.sig {
+            for _ in self.cnt..sig {
+                self.out.push('0')?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+const CHUNK_SIZE: usize = 256;
+
+// Collects ASCII characters in a small buffer and passes them to the writer in chunks.
+struct ChunkWriter<'a, W: core::fmt::Write> {
+    w: &'a mut W,
+    buf: [u8; CHUNK_SIZE],
+    len: usize,
+}
+
+impl<'a, W: core::fmt::Write> ChunkWriter<'a, W> {
+    fn new(w: &'a mut W) -> Self {
+        ChunkWriter {
+            w,
+            buf: [0; CHUNK_SIZE],
+            len: 0,
+        }
+    }
+
+    fn push(&mut self, c: char) -> core::fmt::Result {
+        debug_assert!(c.is_ascii());
+
+        if self.len == CHUNK_SIZE {
+            self.flush()?;
+        }
+
+        self.buf[self.len] = c as u8;
+        self.len += 1;
+
+        Ok(())
+    }
+
+    fn push_str(&mut self, s: &str) -> core::fmt::Result {
+        for c in s.chars() {
+            self.push(c)?;
+        }
+
+        Ok(())
+    }
+
+    fn flush(&mut self) -> core::fmt::Result {
+        let s = core::str::from_utf8(&self.buf[..self.len]).map_err(|_| core::fmt::Error)?; // buf contains only ASCII characters
+        self.len = 0;
+        self.w.write_str(s)
     }
 }
 
@@ -127,11 +703,690 @@ mod tests {
     use rand::random;
 
     use crate::{
-        common::util::random_subnormal, Exponent, EXPONENT_MAX, EXPONENT_MIN, WORD_BIT_SIZE,
+        common::util::random_subnormal, Exponent, Radix, EXPONENT_MAX, EXPONENT_MIN, WORD_BIT_SIZE,
     };
 
     use super::*;
 
+    #[test]
+    fn test_format_prec() {
+        let mut cc = Consts::new().unwrap();
+        let p = 128;
+
+        let n = BigFloatNumber::from_f64(p, 1234.5678).unwrap();
+
+        // fixed-point
+        for (d, rm, s) in [
+            (0, RoundingMode::ToEven, "1235"),
+            (2, RoundingMode::ToEven, "1234.57"),
+            (2, RoundingMode::ToZero, "1234.56"),
+            (2, RoundingMode::Down, "1234.56"),
+            (2, RoundingMode::Up, "1234.57"),
+            (6, RoundingMode::ToEven, "1234.567800"),
+        ] {
+            assert_eq!(
+                n.format_fixed(Radix::Dec, &FixedFormat::new(d), rm, &mut cc)
+                    .unwrap(),
+                s
+            );
+        }
+
+        let n = n.neg().unwrap();
+        assert_eq!(
+            n.format_fixed(Radix::Dec, &FixedFormat::new(2), RoundingMode::Up, &mut cc)
+                .unwrap(),
+            "-1234.56"
+        );
+        assert_eq!(
+            n.format_fixed(
+                Radix::Dec,
+                &FixedFormat::new(2),
+                RoundingMode::Down,
+                &mut cc
+            )
+            .unwrap(),
+            "-1234.57"
+        );
+
+        // ties
+        for (f, d, s_even, s_odd) in [
+            (0.5, 0, "0", "1"),
+            (1.5, 0, "2", "1"),
+            (2.5, 0, "2", "3"),
+            (0.125, 2, "0.12", "0.13"),
+            (0.375, 2, "0.38", "0.37"),
+        ] {
+            let n = BigFloatNumber::from_f64(p, f).unwrap();
+            assert_eq!(
+                n.format_fixed(
+                    Radix::Dec,
+                    &FixedFormat::new(d),
+                    RoundingMode::ToEven,
+                    &mut cc
+                )
+                .unwrap(),
+                s_even
+            );
+            assert_eq!(
+                n.format_fixed(
+                    Radix::Dec,
+                    &FixedFormat::new(d),
+                    RoundingMode::ToOdd,
+                    &mut cc
+                )
+                .unwrap(),
+                s_odd
+            );
+        }
+
+        // small numbers
+        let n = BigFloatNumber::from_f64(p, 0.000123).unwrap();
+        assert_eq!(
+            n.format_fixed(
+                Radix::Dec,
+                &FixedFormat::new(3),
+                RoundingMode::ToEven,
+                &mut cc
+            )
+            .unwrap(),
+            "0.000"
+        );
+        assert_eq!(
+            n.format_fixed(
+                Radix::Dec,
+                &FixedFormat::new(3),
+                RoundingMode::FromZero,
+                &mut cc
+            )
+            .unwrap(),
+            "0.001"
+        );
+        assert_eq!(
+            n.format_fixed(
+                Radix::Dec,
+                &FixedFormat::new(4),
+                RoundingMode::ToEven,
+                &mut cc
+            )
+            .unwrap(),
+            "0.0001"
+        );
+        assert_eq!(
+            n.format_fixed(
+                Radix::Dec,
+                &FixedFormat::new(0),
+                RoundingMode::ToEven,
+                &mut cc
+            )
+            .unwrap(),
+            "0"
+        );
+
+        let n = BigFloatNumber::from_f64(p, 0.96).unwrap();
+        assert_eq!(
+            n.format_fixed(
+                Radix::Dec,
+                &FixedFormat::new(1),
+                RoundingMode::ToEven,
+                &mut cc
+            )
+            .unwrap(),
+            "1.0"
+        );
+
+        let n = BigFloatNumber::new(p).unwrap();
+        assert_eq!(
+            n.format_fixed(
+                Radix::Dec,
+                &FixedFormat::new(2),
+                RoundingMode::ToEven,
+                &mut cc
+            )
+            .unwrap(),
+            "0.00"
+        );
+        assert_eq!(
+            n.format_sci(Radix::Dec, Some(2), RoundingMode::ToEven, &mut cc)
+                .unwrap(),
+            "0.00e+0"
+        );
+
+        // other radixes
+        let n = BigFloatNumber::from_f64(p, 10.8125).unwrap();
+        assert_eq!(
+            n.format_fixed(
+                Radix::Bin,
+                &FixedFormat::new(2),
+                RoundingMode::ToEven,
+                &mut cc
+            )
+            .unwrap(),
+            "1010.11"
+        );
+        assert_eq!(
+            n.format_fixed(
+                Radix::Oct,
+                &FixedFormat::new(1),
+                RoundingMode::ToEven,
+                &mut cc
+            )
+            .unwrap(),
+            "12.6"
+        );
+        assert_eq!(
+            n.format_fixed(
+                Radix::Hex,
+                &FixedFormat::new(0),
+                RoundingMode::ToEven,
+                &mut cc
+            )
+            .unwrap(),
+            "B"
+        );
+        assert_eq!(
+            n.format_sci(Radix::Hex, Some(1), RoundingMode::ToEven, &mut cc)
+                .unwrap(),
+            "A.D_e+0"
+        );
+        assert_eq!(
+            n.format_sci(Radix::Bin, Some(3), RoundingMode::ToEven, &mut cc)
+                .unwrap(),
+            "1.011e+11"
+        );
+        assert_eq!(
+            n.format_sci(Radix::Bin, Some(3), RoundingMode::ToZero, &mut cc)
+                .unwrap(),
+            "1.010e+11"
+        );
+
+        // scientific
+        let n = BigFloatNumber::from_f64(p, 9.9996).unwrap();
+        for (d, rm, s) in [
+            (0, RoundingMode::ToEven, "1e+1"),
+            (2, RoundingMode::ToEven, "1.00e+1"),
+            (3, RoundingMode::ToEven, "1.000e+1"),
+            (3, RoundingMode::ToZero, "9.999e+0"),
+            (6, RoundingMode::ToEven, "9.999600e+0"),
+        ] {
+            assert_eq!(n.format_sci(Radix::Dec, Some(d), rm, &mut cc).unwrap(), s);
+        }
+
+        // correct rounding with many digits
+        let n = BigFloatNumber::from_word(1, p)
+            .unwrap()
+            .div(
+                &BigFloatNumber::from_word(3, p).unwrap(),
+                p,
+                RoundingMode::ToEven,
+            )
+            .unwrap();
+        let s = n
+            .format_fixed(
+                Radix::Dec,
+                &FixedFormat::new(60),
+                RoundingMode::ToEven,
+                &mut cc,
+            )
+            .unwrap();
+        assert_eq!(
+            s,
+            "0.333333333333333333333333333333333333333823122646175953128320"
+        );
+        let s = n
+            .format_sci(Radix::Dec, Some(50), RoundingMode::ToEven, &mut cc)
+            .unwrap();
+        assert_eq!(s, "3.33333333333333333333333333333333333333823122646176e-1");
+
+        // formatting parameters
+        let n = BigFloatNumber::from_f64(p, -1234567.125).unwrap();
+        let mut ff = FixedFormat::new(5);
+        assert_eq!(
+            n.format_fixed(Radix::Dec, &ff, RoundingMode::ToEven, &mut cc)
+                .unwrap(),
+            "-1234567.12500"
+        );
+        ff.set_pad_zeroes(false);
+        assert_eq!(
+            n.format_fixed(Radix::Dec, &ff, RoundingMode::ToEven, &mut cc)
+                .unwrap(),
+            "-1234567.125"
+        );
+        ff.set_group_separator(Some(' '));
+        ff.set_decimal_separator(',');
+        assert_eq!(
+            n.format_fixed(Radix::Dec, &ff, RoundingMode::ToEven, &mut cc)
+                .unwrap(),
+            "-1 234 567,125"
+        );
+        ff.set_group_len(4);
+        ff.set_frac_digits(2);
+        assert_eq!(
+            n.format_fixed(Radix::Dec, &ff, RoundingMode::ToEven, &mut cc)
+                .unwrap(),
+            "-123 4567,12"
+        );
+        ff.set_frac_digits(0);
+        assert_eq!(
+            n.format_fixed(Radix::Dec, &ff, RoundingMode::ToEven, &mut cc)
+                .unwrap(),
+            "-123 4567"
+        );
+        ff.set_group_len(0);
+        ff.set_frac_digits(10);
+        let n = BigFloatNumber::from_f64(p, 0.5).unwrap();
+        assert_eq!(
+            n.format_fixed(Radix::Dec, &ff, RoundingMode::ToEven, &mut cc)
+                .unwrap(),
+            "0,5"
+        );
+        let n = BigFloatNumber::from_f64(p, 100.0).unwrap();
+        assert_eq!(
+            n.format_fixed(Radix::Dec, &ff, RoundingMode::ToEven, &mut cc)
+                .unwrap(),
+            "100"
+        );
+        ff.set_group_len(3);
+        ff.set_group_separator(Some('_'));
+        assert_eq!(
+            n.format_fixed(Radix::Dec, &ff, RoundingMode::ToEven, &mut cc)
+                .unwrap(),
+            "100"
+        );
+
+        let mut n = BigFloatNumber::from_word(1, p).unwrap();
+        n.set_exponent(200);
+        let s = n
+            .format_fixed(
+                Radix::Dec,
+                &FixedFormat::new(1),
+                RoundingMode::ToEven,
+                &mut cc,
+            )
+            .unwrap();
+        assert_eq!(
+            s,
+            "803469022129495137770981046170581301261101496891396417650688.0"
+        );
+        let s = n
+            .format_sci(Radix::Dec, Some(5), RoundingMode::ToEven, &mut cc)
+            .unwrap();
+        assert_eq!(s, "8.03469e+59");
+    }
+
+    #[test]
+    fn test_format_rdx() {
+        let mut cc = Consts::new().unwrap();
+        let p = 128;
+
+        // ties in odd radixes: 1/2 = 0.111..., 5/2 = 2.222...
+        let half = BigFloatNumber::from_f64(p, 0.5).unwrap();
+        for (rdx, x, rm, sci, fixed) in [
+            (3, 0.5, RoundingMode::ToEven, "1.12e-1", "0.112"),
+            (3, 0.5, RoundingMode::ToOdd, "1.11e-1", "0.111"),
+            (3, 0.5, RoundingMode::ToZero, "1.11e-1", "0.111"),
+            (3, 0.5, RoundingMode::FromZero, "1.12e-1", "0.112"),
+            (3, -0.5, RoundingMode::Up, "-1.11e-1", "-0.111"),
+            (3, -0.5, RoundingMode::Down, "-1.12e-1", "-0.112"),
+            (3, 1.5, RoundingMode::ToEven, "1.12e+0", "1.112"),
+            (3, 3.5, RoundingMode::ToEven, "1.02e+1", "10.112"),
+            (5, 2.5, RoundingMode::ToEven, "2.22e+0", "2.222"),
+            (5, 2.5, RoundingMode::ToOdd, "2.23e+0", "2.223"),
+            (7, 0.5, RoundingMode::ToEven, "3.34e-1", "0.334"),
+            (7, 0.5, RoundingMode::ToOdd, "3.33e-1", "0.333"),
+        ] {
+            let n = BigFloatNumber::from_f64(p, x).unwrap();
+            let rdx = rdx as u32;
+            assert_eq!(n.format_sci(rdx, Some(2), rm, &mut cc).unwrap(), sci);
+            assert_eq!(
+                n.format_fixed(rdx, &FixedFormat::new(3), rm, &mut cc)
+                    .unwrap(),
+                fixed
+            );
+        }
+
+        assert_eq!(
+            half.format(3u32, RoundingMode::ToEven, &mut cc).unwrap(),
+            "1.11111111111111111111111111111111111111111111111111111111111111111111111111111112e-1"
+        );
+
+        // exponent separator and exponent digits
+        let n = BigFloatNumber::from_word(1261, p).unwrap();
+        for (rdx, s) in [
+            (36, "Z.1_e+1"),
+            (15, "5.91_e+2"),
+            (14, "6.61e+2"),
+            (3, "1.201201e+20"),
+            (32, "1.7D_e+2"),
+            (4, "1.03231e+11"),
+        ] {
+            assert_eq!(
+                n.format(rdx as u32, RoundingMode::ToEven, &mut cc).unwrap(),
+                s
+            );
+            assert_eq!(
+                BigFloatNumber::parse(s, rdx as u32, p, RoundingMode::ToEven, &mut cc)
+                    .unwrap()
+                    .cmp(&n),
+                0
+            );
+        }
+
+        let n = BigFloatNumber::from_f64(p, 1.0 / 1296.0).unwrap();
+        assert_eq!(
+            n.format_sci(36u32, Some(0), RoundingMode::ToEven, &mut cc)
+                .unwrap(),
+            "1_e-2"
+        );
+        assert_eq!(
+            n.format_fixed(36u32, &FixedFormat::new(3), RoundingMode::ToEven, &mut cc)
+                .unwrap(),
+            "0.010"
+        );
+
+        // "inf" and "nan" are numbers in large radixes
+        let n = BigFloatNumber::parse("info", 36u32, p, RoundingMode::ToEven, &mut cc).unwrap();
+        assert_eq!(n.cmp(&BigFloatNumber::from_word(870180, p).unwrap()), 0);
+        assert!(crate::parser::parse("-inf", 36u32).unwrap().is_inf());
+        assert!(crate::parser::parse("nan", 36u32).unwrap().is_nan());
+
+        // invalid radix
+        for rdx in [0, 1, 37, 255] {
+            assert_eq!(
+                half.format(rdx as u32, RoundingMode::ToEven, &mut cc)
+                    .unwrap_err(),
+                Error::InvalidArgument
+            );
+            assert_eq!(
+                BigFloatNumber::parse("1", rdx as u32, p, RoundingMode::ToEven, &mut cc)
+                    .unwrap_err(),
+                Error::InvalidArgument
+            );
+        }
+
+        assert_eq!(u32::from(Radix::Dec), 10);
+        assert_eq!(Radix::Hex as u32, 16);
+
+        // random values
+        for _ in 0..200 {
+            let p1 = (random::<usize>() % 16 + 1) * WORD_BIT_SIZE;
+            let p2 = (random::<usize>() % 16 + 1) * WORD_BIT_SIZE;
+            let p = p1.min(p2);
+            let b = random::<u8>() % 35 + 2;
+            let rdx = b as u32;
+
+            let n = BigFloatNumber::random_normal(p1, -100, 100).unwrap();
+            let s = n.format(rdx, RoundingMode::ToEven, &mut cc).unwrap();
+            let mut d = BigFloatNumber::parse(&s, rdx, p2, RoundingMode::ToEven, &mut cc).unwrap();
+
+            if b.is_power_of_two() {
+                let mut n = n;
+                if p2 < p1 {
+                    n.set_precision(p, RoundingMode::ToEven).unwrap();
+                } else if p2 > p1 {
+                    d.set_precision(p, RoundingMode::ToEven).unwrap();
+                }
+                assert!(n.cmp(&d) == 0);
+            } else {
+                let mut eps = BigFloatNumber::from_word(1, p).unwrap();
+                eps.set_exponent(n.exponent() - p as Exponent + 8);
+                assert!(
+                    d.sub(&n, p, RoundingMode::None)
+                        .unwrap()
+                        .abs()
+                        .unwrap()
+                        .cmp(&eps)
+                        < 0,
+                    "{} {:?} {:?}",
+                    b,
+                    n,
+                    d
+                );
+            }
+        }
+    }
+
+    fn parse_hex_float(s: &str, p: usize, rm: RoundingMode) -> Result<BigFloatNumber, Error> {
+        let ps = crate::parser::parse_hex_float(s)?;
+        assert!(!ps.is_nan() && !ps.is_inf());
+        let (m, s, e) = ps.into_raw_parts();
+        BigFloatNumber::convert_from_hex_float(s, m, e, p, rm)
+    }
+
+    #[test]
+    fn test_format_hex_float() {
+        let p = 128;
+
+        // f64 values as printed by C's %a.
+        for (f, s) in [
+            (core::f64::consts::PI, "0x1.921fb54442d18p+1"),
+            (0.1, "0x1.999999999999ap-4"),
+            (-1.0, "-0x1p+0"),
+            (0.0, "0x0p+0"),
+            (1.5, "0x1.8p+0"),
+            (255.0, "0x1.fep+7"),
+            (f64::MAX, "0x1.fffffffffffffp+1023"),
+            (f64::MIN_POSITIVE, "0x1p-1022"),
+            (5e-324, "0x1p-1074"),
+        ] {
+            let n = BigFloatNumber::from_f64(p, f).unwrap();
+            assert_eq!(n.format_hex_float().unwrap(), s);
+
+            let d = parse_hex_float(s, p, RoundingMode::None).unwrap();
+            assert_eq!(d.cmp(&n), 0);
+        }
+
+        // alternative forms of input.
+        let one = BigFloatNumber::from_word(1, p).unwrap();
+        for s in ["1", "0x1", "0X1P0", "+0x1.p+0", "0x.8p1", "0x0.01p+8", "0x10p-4", "0x00.4p+2"] {
+            let d = parse_hex_float(s, p, RoundingMode::None).unwrap();
+            assert_eq!(d.cmp(&one), 0, "{}", s);
+        }
+
+        for s in ["0x", "-", "p+1", "0x.p1", "x1"] {
+            assert!(crate::parser::parse_hex_float(s).unwrap().is_nan());
+        }
+
+        let ps = crate::parser::parse_hex_float("-inf").unwrap();
+        assert!(ps.is_inf() && ps.sign() == Sign::Neg);
+
+        // rounding of a tie: 1 + 2^-64.
+        let s = "0x1.0000000000000001p0";
+        let d = parse_hex_float(s, 64, RoundingMode::ToEven).unwrap();
+        assert_eq!(d.format_hex_float().unwrap(), "0x1p+0");
+        let d = parse_hex_float(s, 64, RoundingMode::Up).unwrap();
+        assert_eq!(d.format_hex_float().unwrap(), "0x1.0000000000000002p+0");
+        let d = parse_hex_float(s, usize::MAX, RoundingMode::None).unwrap();
+        assert_eq!(d.format_hex_float().unwrap(), s.replace("p0", "p+0"));
+
+        // extreme exponents.
+        let s = format!("0x1p{}", EXPONENT_MAX as isize);
+        assert!(matches!(
+            parse_hex_float(&s, p, RoundingMode::None),
+            Err(Error::ExponentOverflow(Sign::Pos))
+        ));
+
+        let s = format!("-0x1p{}", EXPONENT_MIN as isize - 1 - p as isize);
+        let d = parse_hex_float(&s, p, RoundingMode::ToEven).unwrap();
+        assert!(d.is_zero());
+
+        let s = format!("0x1p{}", EXPONENT_MIN as isize - 2);
+        let d = parse_hex_float(&s, p, RoundingMode::None).unwrap();
+        assert!(d.is_subnormal());
+        assert_eq!(d.cmp(&BigFloatNumber::min_positive_normal(p).unwrap()), -1);
+        assert_eq!(d.format_hex_float().unwrap(), s);
+
+        let n = BigFloatNumber::min_positive(p).unwrap();
+        let s = n.format_hex_float().unwrap();
+        assert_eq!(s, format!("0x1p{}", EXPONENT_MIN as isize - p as isize));
+        let d = parse_hex_float(&s, p, RoundingMode::None).unwrap();
+        assert_eq!(d.cmp(&n), 0);
+
+        // exact roundtrip.
+        for i in 0..1000 {
+            let p = (random::<usize>() % 32 + 1) * WORD_BIT_SIZE;
+
+            let n = if i & 1 == 0 {
+                BigFloatNumber::random_normal(p, EXPONENT_MIN, EXPONENT_MAX).unwrap()
+            } else {
+                random_subnormal(p)
+            };
+
+            let p = n.mantissa_max_bit_len();
+            let s = n.format_hex_float().unwrap();
+            let d = parse_hex_float(&s, p, RoundingMode::None).unwrap();
+            assert_eq!(d.cmp(&n), 0, "{}", s);
+            assert!(d.is_subnormal() == n.is_subnormal());
+
+            let d = parse_hex_float(&s, usize::MAX, RoundingMode::None).unwrap();
+            assert_eq!(d.cmp(&n), 0, "{}", s);
+        }
+    }
+
+    #[test]
+    fn test_write_sci() {
+        // records the sizes of chunks passed to the writer.
+        struct Chunks {
+            s: String,
+            max_chunk: usize,
+            fail_after: usize,
+        }
+
+        impl core::fmt::Write for Chunks {
+            fn write_str(&mut self, s: &str) -> core::fmt::Result {
+                if self.s.len() + s.len() > self.fail_after {
+                    return Err(core::fmt::Error);
+                }
+                self.max_chunk = self.max_chunk.max(s.len());
+                self.s.push_str(s);
+                Ok(())
+            }
+        }
+
+        let mut cc = Consts::new().unwrap();
+
+        for _ in 0..100 {
+            let p = (random::<usize>() % 64 + 1) * WORD_BIT_SIZE;
+            let n = BigFloatNumber::random_normal(p, EXPONENT_MIN, EXPONENT_MAX).unwrap();
+
+            for rdx in [Radix::Bin as u32, Radix::Dec as u32, Radix::Hex as u32, 36] {
+                let digits = match random::<u8>() % 3 {
+                    0 => None,
+                    1 => Some(random::<usize>() % 10),
+                    _ => Some(random::<usize>() % 1000),
+                };
+
+                let s = n
+                    .format_sci(rdx, digits, RoundingMode::ToEven, &mut cc)
+                    .unwrap();
+
+                let mut w = Chunks {
+                    s: String::new(),
+                    max_chunk: 0,
+                    fail_after: usize::MAX,
+                };
+                n.write_sci(&mut w, rdx, digits, RoundingMode::ToEven, &mut cc)
+                    .unwrap();
+
+                assert_eq!(w.s, s);
+                assert!(w.max_chunk <= CHUNK_SIZE);
+
+                // the error of the writer is reported
+                let mut w = Chunks {
+                    s: String::new(),
+                    max_chunk: 0,
+                    fail_after: s.len() / 2,
+                };
+                assert!(matches!(
+                    n.write_sci(&mut w, rdx, digits, RoundingMode::ToEven, &mut cc),
+                    Err(WriteError::Fmt)
+                ));
+            }
+        }
+
+        // formatting errors are reported before anything is written
+        let n = BigFloatNumber::from_word(1, WORD_BIT_SIZE).unwrap();
+        let mut w = String::new();
+        assert!(matches!(
+            n.write_sci(&mut w, 37u32, None, RoundingMode::ToEven, &mut cc),
+            Err(WriteError::Num(Error::InvalidArgument))
+        ));
+        assert!(w.is_empty());
+    }
+
+    #[test]
+    fn test_write_sci_digits() {
+        let mut cc = Consts::new().unwrap();
+
+        // digits rounded in binary are the same as digits rounded in radix `rdx`.
+        for i in 0..200 {
+            let p = (random::<usize>() % 64 + 1) * WORD_BIT_SIZE;
+            let n = if i & 1 == 0 {
+                BigFloatNumber::random_normal(p, -1000, 1000).unwrap()
+            } else {
+                BigFloatNumber::random_normal(p, EXPONENT_MIN, EXPONENT_MAX).unwrap()
+            };
+
+            let rdx = random::<u32>() % 34 + 3;
+            if rdx.is_power_of_two() {
+                continue;
+            }
+
+            let rm = [
+                RoundingMode::ToEven,
+                RoundingMode::ToOdd,
+                RoundingMode::ToZero,
+                RoundingMode::FromZero,
+                RoundingMode::Up,
+                RoundingMode::Down,
+            ][random::<usize>() % 6];
+
+            let digits =
+                if random::<bool>() { random::<usize>() % 10 } else { random::<usize>() % 2000 };
+
+            let (s, mut m, e) = n.conv_to_rdx(rdx as Word, digits + 1, rm, &mut cc).unwrap();
+            m.resize(digits + 1, 0);
+
+            let mut expected = String::new();
+            if s == Sign::Neg {
+                expected.push('-');
+            }
+            expected.push(DIGIT_CHARS[m[0] as usize]);
+            if digits > 0 {
+                expected.push('.');
+            }
+            m[1..]
+                .iter()
+                .for_each(|&d| expected.push(DIGIT_CHARS[d as usize]));
+            expected.push_str(if 'e'.is_digit(rdx) { "_e" } else { "e" });
+            expected.push(if e < 1 { '-' } else { '+' });
+            push_exponent(&mut expected, (e as isize - 1).unsigned_abs(), rdx as Word);
+
+            assert_eq!(
+                n.format_sci(rdx, Some(digits), rm, &mut cc).unwrap(),
+                expected
+            );
+        }
+
+        // digits in radixes of power of 2 are exact.
+        let n = BigFloatNumber::from_f64(64, 2.5).unwrap();
+        for (rdx, digits, rm, s) in [
+            (2, 0, RoundingMode::ToEven, "1e+1"),
+            (2, 0, RoundingMode::FromZero, "1e+10"),
+            (2, 1, RoundingMode::ToEven, "1.0e+1"),
+            (2, 1, RoundingMode::ToOdd, "1.1e+1"),
+            (2, 5, RoundingMode::ToEven, "1.01000e+1"),
+            (8, 0, RoundingMode::ToEven, "2e+0"),
+            (8, 0, RoundingMode::Up, "3e+0"),
+            (8, 1, RoundingMode::ToZero, "2.4e+0"),
+            (16, 0, RoundingMode::ToOdd, "3_e+0"),
+        ] {
+            assert_eq!(
+                n.format_sci(rdx as u32, Some(digits), rm, &mut cc).unwrap(),
+                s
+            );
+        }
+    }
+
     #[test]
     fn test_strop() {
         let mut eps = BigFloatNumber::from_word(1, 192).unwrap();
@@ -0,0 +1,313 @@
+//! Thresholds at which algorithms are switched.
+
+use core::fmt::Display;
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+#[cfg(feature = "std")]
+use crate::defs::Error;
+
+#[cfg(feature = "std")]
+use core::cell::Cell;
+
+const FIELD_CNT: usize = 13;
+
+/// The smallest value of `mul_basic` in effect: operands of this size are always multiplied with the basic multiplication.
+pub(crate) const MUL_BASIC_MIN: usize = 8;
+
+/// The smallest value of `div_recursive` in effect.
+pub(crate) const DIV_RECURSIVE_MIN: usize = 8;
+
+static GLOBAL: [AtomicUsize; FIELD_CNT] = [
+    AtomicUsize::new(Tuning::DEFAULT.mul_basic),
+    AtomicUsize::new(Tuning::DEFAULT.mul_toom2),
+    AtomicUsize::new(Tuning::DEFAULT.mul_toom3),
+    AtomicUsize::new(Tuning::DEFAULT.mul_fft),
+    AtomicUsize::new(Tuning::DEFAULT.mul_ntt),
+    AtomicUsize::new(Tuning::DEFAULT.mul_balance),
+    AtomicUsize::new(Tuning::DEFAULT.div_recursive),
+    AtomicUsize::new(Tuning::DEFAULT.div_newton),
+    AtomicUsize::new(Tuning::DEFAULT.series_rect),
+    AtomicUsize::new(Tuning::DEFAULT.series_bsplit),
+    AtomicUsize::new(Tuning::DEFAULT.ln_agm),
+    AtomicUsize::new(Tuning::DEFAULT.exp_newton),
+    AtomicUsize::new(Tuning::DEFAULT.par_mul),
+];
+
+#[cfg(feature = "std")]
+std::thread_local! {
+    static LOCAL: Cell<Option<Tuning>> = const { Cell::new(None) };
+}
+
+/// Thresholds at which the algorithms of multiplication, division, and series evaluation are switched.
+///
+/// The default values suit a typical modern x86_64 processor, but the optimal values depend on the machine.
+/// They can be measured on the current machine with `Tuning::autotune`.
+///
+/// The tuning can be set globally with `set_global`, for the current thread with `scope`,
+/// or for the expressions computed with a certain context using `Context::set_tuning`.
+/// Values too small for an algorithm to work are raised to the smallest supported values when used.
+/// Thresholds can be added in future versions, so a tuning is created with `Tuning::default()` and then modified.
+///
+/// ## Examples
+///
+/// ```
+/// # use astro_float_num::Tuning;
+/// let mut tuning = Tuning::default();
+/// tuning.mul_toom3 = 4000;
+///
+/// tuning.scope(|| {
+///     assert_eq!(Tuning::thread_current().mul_toom3, 4000);
+/// });
+///
+/// assert_eq!(Tuning::thread_current(), Tuning::global());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub struct Tuning {
+    /// The size of operands in words up to which the basic multiplication is used.
+    pub mul_basic: usize,
+
+    /// The size of operands in words up to which the Karatsuba multiplication is used.
+    pub mul_toom2: usize,
+
+    /// The size of operands in words up to which the Toom-3 multiplication is used.
+    /// Larger operands are multiplied with FFT or NTT.
+    pub mul_toom3: usize,
+
+    /// The size of the larger operand in words up to which the Schönhage–Strassen FFT multiplication is used.
+    /// Larger operands are multiplied with the number-theoretic transform (NTT) modulo three primes.
+    pub mul_fft: usize,
+
+    /// The size of the larger operand in words up to which the NTT multiplication is used.
+    /// Larger operands are multiplied with FFT, which requires less memory.
+    pub mul_ntt: usize,
+
+    /// The size of the shorter operand in words above which an unbalanced multiplication
+    /// is performed by parts of the size of the shorter operand.
+    pub mul_balance: usize,
+
+    /// The size of quotient in words below which the recursive division falls back to the basic division.
+    pub div_recursive: usize,
+
+    /// The size of divisor in words starting from which the division is performed using Newton's iteration.
+    pub div_newton: usize,
+
+    /// The number of series terms starting from which a series is evaluated using rectangular splitting.
+    pub series_rect: usize,
+
+    /// The precision in bits starting from which a series with a short argument is evaluated using binary splitting.
+    pub series_bsplit: usize,
+
+    /// The precision in bits starting from which the logarithm is computed using the arithmetic-geometric mean.
+    pub ln_agm: usize,
+
+    /// The precision in bits starting from which the exponent is computed using Newton's iteration on the logarithm.
+    pub exp_newton: usize,
+
+    /// The size of operands in words starting from which independent parts of a multiplication
+    /// are computed in parallel. It has effect only if the feature `parallel` is enabled.
+    pub par_mul: usize,
+}
+
+impl Tuning {
+    /// Default thresholds.
+    pub const DEFAULT: Tuning = Tuning {
+        mul_basic: 32,
+        mul_toom2: 220,
+        mul_toom3: 5400,
+        mul_fft: 12000,
+        mul_ntt: usize::MAX,
+        mul_balance: 70,
+        div_recursive: 70,
+        div_newton: 8000,
+        series_rect: 108,
+        series_bsplit: 3000,
+        // ln_agm_perf in ops/log.rs and exp_newton_perf in ops/pow.rs measure these crossovers.
+        ln_agm: 2048,
+        exp_newton: 5000,
+        par_mul: 2000,
+    };
+
+    fn to_array(self) -> [usize; FIELD_CNT] {
+        [
+            self.mul_basic,
+            self.mul_toom2,
+            self.mul_toom3,
+            self.mul_fft,
+            self.mul_ntt,
+            self.mul_balance,
+            self.div_recursive,
+            self.div_newton,
+            self.series_rect,
+            self.series_bsplit,
+            self.ln_agm,
+            self.exp_newton,
+            self.par_mul,
+        ]
+    }
+
+    fn from_array(a: [usize; FIELD_CNT]) -> Self {
+        Tuning {
+            mul_basic: a[0],
+            mul_toom2: a[1],
+            mul_toom3: a[2],
+            mul_fft: a[3],
+            mul_ntt: a[4],
+            mul_balance: a[5],
+            div_recursive: a[6],
+            div_newton: a[7],
+            series_rect: a[8],
+            series_bsplit: a[9],
+            ln_agm: a[10],
+            exp_newton: a[11],
+            par_mul: a[12],
+        }
+    }
+
+    /// Returns the global tuning.
+    pub fn global() -> Self {
+        let mut a = [0; FIELD_CNT];
+        for (v, g) in a.iter_mut().zip(GLOBAL.iter()) {
+            *v = g.load(Ordering::Relaxed);
+        }
+        Self::from_array(a)
+    }
+
+    /// Makes `self` the global tuning.
+    /// The global tuning is used unless a different tuning is set for the current thread with `scope`.
+    pub fn set_global(&self) {
+        for (v, g) in self.to_array().iter().zip(GLOBAL.iter()) {
+            g.store(*v, Ordering::Relaxed);
+        }
+    }
+
+    /// Calls `f` with `self` used as the tuning of the current thread, and returns the result of `f`.
+    /// The previous tuning of the current thread is restored after `f` returns or panics.
+    #[cfg(feature = "std")]
+    pub fn scope<R>(&self, f: impl FnOnce() -> R) -> R {
+        struct Restore(Option<Tuning>);
+
+        impl Drop for Restore {
+            fn drop(&mut self) {
+                LOCAL.with(|l| l.set(self.0));
+            }
+        }
+
+        let _restore = Restore(LOCAL.with(|l| l.replace(Some(*self))));
+
+        f()
+    }
+
+    /// Returns the tuning which is in effect for the current thread.
+    pub fn thread_current() -> Self {
+        #[cfg(feature = "std")]
+        if let Some(t) = LOCAL.try_with(|l| l.get()).ok().flatten() {
+            return t;
+        }
+
+        Self::global()
+    }
+
+    // Returns the tuning in effect for the current thread with the values raised to the smallest supported values.
+    // It is fetched once per operation and passed down to the algorithms the operation is composed of.
+    pub(crate) fn current() -> Self {
+        let mut t = Self::thread_current();
+
+        t.mul_basic = t.mul_basic.max(MUL_BASIC_MIN);
+        t.mul_toom2 = t.mul_toom2.max(t.mul_basic);
+        t.mul_toom3 = t.mul_toom3.max(t.mul_toom2).max(300);
+        t.div_recursive = t.div_recursive.max(DIV_RECURSIVE_MIN);
+        t.div_newton = t.div_newton.max(2);
+        t.series_rect = t.series_rect.max(4);
+
+        t
+    }
+
+    /// Measures the thresholds on the current machine and returns them.
+    /// The measurement starts from the tuning in effect for the current thread and takes a few seconds.
+    /// The thresholds `mul_ntt`, `series_rect`, `series_bsplit`, `ln_agm`, `exp_newton`, and `par_mul` are not measured and are kept as is.
+    ///
+    /// ## Errors
+    ///
+    /// - MemoryAllocation: failed to allocate memory.
+    ///
+    /// ## Examples
+    ///
+    /// ```no_run
+    /// # use astro_float_num::Tuning;
+    /// let tuning = Tuning::autotune().expect("Tuning completed");
+    ///
+    /// // Print the values, and apply them.
+    /// println!("{}", tuning);
+    /// tuning.set_global();
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn autotune() -> Result<Self, Error> {
+        crate::mantissa::Mantissa::autotune(Self::thread_current())
+    }
+}
+
+impl Default for Tuning {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+impl Display for Tuning {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        writeln!(f, "mul_basic = {}", self.mul_basic)?;
+        writeln!(f, "mul_toom2 = {}", self.mul_toom2)?;
+        writeln!(f, "mul_toom3 = {}", self.mul_toom3)?;
+        writeln!(f, "mul_fft = {}", self.mul_fft)?;
+        writeln!(f, "mul_ntt = {}", self.mul_ntt)?;
+        writeln!(f, "mul_balance = {}", self.mul_balance)?;
+        writeln!(f, "div_recursive = {}", self.div_recursive)?;
+        writeln!(f, "div_newton = {}", self.div_newton)?;
+        writeln!(f, "series_rect = {}", self.series_rect)?;
+        writeln!(f, "series_bsplit = {}", self.series_bsplit)?;
+        writeln!(f, "ln_agm = {}", self.ln_agm)?;
+        writeln!(f, "exp_newton = {}", self.exp_newton)?;
+        writeln!(f, "par_mul = {}", self.par_mul)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_tuning() {
+        assert_eq!(Tuning::default(), Tuning::DEFAULT);
+
+        let t = Tuning::from_array([1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13]);
+        assert_eq!(t.to_array(), [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13]);
+
+        #[cfg(feature = "std")]
+        {
+            let cur = Tuning::thread_current();
+
+            let ret = t.scope(|| {
+                assert_eq!(Tuning::thread_current(), t);
+
+                let mut t2 = t;
+                t2.mul_basic = 10;
+                t2.scope(|| assert_eq!(Tuning::thread_current(), t2));
+
+                assert_eq!(Tuning::thread_current(), t);
+                1
+            });
+
+            assert_eq!(ret, 1);
+            assert_eq!(Tuning::thread_current(), cur);
+
+            let s = t.to_string();
+            assert_eq!(
+                s,
+                "mul_basic = 1\nmul_toom2 = 2\nmul_toom3 = 3\nmul_fft = 4\nmul_ntt = 5\n\
+                mul_balance = 6\ndiv_recursive = 7\ndiv_newton = 8\nseries_rect = 9\n\
+                series_bsplit = 10\nln_agm = 11\nexp_newton = 12\npar_mul = 13\n"
+            );
+        }
+    }
+}
@@ -198,6 +198,18 @@ extern crate alloc;
 ///  - `asinh(x)`: hyperbolic arcsine of `x`.
 ///  - `acosh(x)`: hyperbolic arccosine of `x`.
 ///  - `atanh(x)`: hyperbolic arctangent of `x`.
+///  - `sec(x)`: secant of `x`.
+///  - `csc(x)`: cosecant of `x`.
+///  - `cot(x)`: cotangent of `x`.
+///  - `asec(x)`: arcsecant of `x`.
+///  - `acsc(x)`: arccosecant of `x`.
+///  - `acot(x)`: arccotangent of `x`.
+///  - `sech(x)`: hyperbolic secant of `x`.
+///  - `csch(x)`: hyperbolic cosecant of `x`.
+///  - `coth(x)`: hyperbolic cotangent of `x`.
+///  - `asech(x)`: hyperbolic arcsecant of `x`.
+///  - `acsch(x)`: hyperbolic arccosecant of `x`.
+///  - `acoth(x)`: hyperbolic arccotangent of `x`.
 ///
 /// Constants:
 ///  - `pi`: pi number.
@@ -210,6 +222,8 @@ extern crate alloc;
 /// Also, the macro uses minimum and maximum exponent values from the context to limit possible exponent range of the result and to set the limit of precision required for error compensation.
 /// It is recommended to set the smallest values for the exponent range to increase the performance of computations (the internal precision may be as large as the exponent of a number).
 ///
+/// If the context has a tuning of algorithm thresholds set with `Context::set_tuning`, the expression is computed using that tuning.
+///
 /// A tuple `(usize, RoundingMode, &mut Consts)`, or `(usize, RoundingMode, &mut Consts, Exponent, Exponent)` can be used as a temporary context (see examples below).
 ///
 /// Any input argument in the expression is interpreted as exact
@@ -100,6 +100,44 @@ fn macro_run_basic_tests() {
 
     let res: BigFloat = expr!(atanh(x), &mut ctx);
     debug_assert_eq!(res, x.atanh(p, rm, &mut cc));
+
+    let res: BigFloat = expr!(asech(x), &mut ctx);
+    debug_assert_eq!(res, x.asech(p, rm, &mut cc));
+
+    let x = BigFloat::from(1.23);
+
+    let res: BigFloat = expr!(sec(x), &mut ctx);
+    debug_assert_eq!(res, x.sec(p, rm, &mut cc));
+
+    let res: BigFloat = expr!(csc(x), &mut ctx);
+    debug_assert_eq!(res, x.csc(p, rm, &mut cc));
+
+    let res: BigFloat = expr!(cot(x), &mut ctx);
+    debug_assert_eq!(res, x.cot(p, rm, &mut cc));
+
+    let res: BigFloat = expr!(asec(x), &mut ctx);
+    debug_assert_eq!(res, x.asec(p, rm, &mut cc));
+
+    let res: BigFloat = expr!(acsc(x), &mut ctx);
+    debug_assert_eq!(res, x.acsc(p, rm, &mut cc));
+
+    let res: BigFloat = expr!(acot(x), &mut ctx);
+    debug_assert_eq!(res, x.acot(p, rm, &mut cc));
+
+    let res: BigFloat = expr!(sech(x), &mut ctx);
+    debug_assert_eq!(res, x.sech(p, rm, &mut cc));
+
+    let res: BigFloat = expr!(csch(x), &mut ctx);
+    debug_assert_eq!(res, x.csch(p, rm, &mut cc));
+
+    let res: BigFloat = expr!(coth(x), &mut ctx);
+    debug_assert_eq!(res, x.coth(p, rm, &mut cc));
+
+    let res: BigFloat = expr!(acsch(x), &mut ctx);
+    debug_assert_eq!(res, x.acsch(p, rm, &mut cc));
+
+    let res: BigFloat = expr!(acoth(x), &mut ctx);
+    debug_assert_eq!(res, x.acoth(p, rm, &mut cc));
 }
 
 #[test]